@@ -0,0 +1,125 @@
+//! Selective disclosure of the text that follows an anchor string.
+//!
+//! The guest proves that a page contains `anchor` and reveals only the span
+//! right after it, e.g. the amount following "Total:".
+
+use alloc::string::String;
+
+/// Where a disclosed span starts and stops
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanSpec {
+    /// Text that must appear right before the span
+    pub anchor: String,
+    /// Maximum number of characters in the span, `None` for no limit
+    pub max_chars: Option<usize>,
+    /// The span stops before the first occurrence of this text, if set
+    pub delimiter: Option<String>,
+}
+
+impl SpanSpec {
+    /// Locate the span in `text` after the first occurrence of the anchor.
+    ///
+    /// The span ends at the delimiter, after `max_chars` characters or at the
+    /// end of the text, whichever comes first. Returns byte offsets into `text`.
+    pub fn find(&self, text: &str) -> Option<(usize, usize)> {
+        let start = text.find(self.anchor.as_str())? + self.anchor.len();
+        let rest = &text[start..];
+
+        let mut len = rest.len();
+        if let Some(delimiter) = self.delimiter.as_deref().filter(|d| !d.is_empty()) {
+            if let Some(pos) = rest.find(delimiter) {
+                len = pos;
+            }
+        }
+        if let Some(max_chars) = self.max_chars {
+            if let Some((pos, _)) = rest.char_indices().nth(max_chars) {
+                len = len.min(pos);
+            }
+        }
+
+        Some((start, start + len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span<'a>(
+        text: &'a str,
+        anchor: &str,
+        max_chars: Option<usize>,
+        delimiter: Option<&str>,
+    ) -> Option<&'a str> {
+        let spec = SpanSpec {
+            anchor: String::from(anchor),
+            max_chars,
+            delimiter: delimiter.map(String::from),
+        };
+        spec.find(text).map(|(start, end)| &text[start..end])
+    }
+
+    #[test]
+    fn max_chars_counts_characters() {
+        let text = "Name: Zoë Müller-Łukasz";
+        assert_eq!(span(text, "Name: ", Some(3), None), Some("Zoë"));
+        assert_eq!(span(text, "Name: ", Some(6), None), Some("Zoë Mü"));
+        assert_eq!(
+            span("Stadt: 東京都港区", "Stadt: ", Some(2), None),
+            Some("東京")
+        );
+        assert_eq!(
+            span(text, "Name: ", Some(100), None),
+            Some("Zoë Müller-Łukasz")
+        );
+        assert_eq!(span(text, "Name: ", Some(0), None), Some(""));
+    }
+
+    #[test]
+    fn delimiter_and_max_chars() {
+        let text = "Total: 1.234,50 EUR due";
+        // The delimiter comes before max_chars
+        assert_eq!(span(text, "Total: ", Some(12), Some(" ")), Some("1.234,50"));
+        // max_chars comes before the delimiter
+        assert_eq!(span(text, "Total: ", Some(5), Some(" ")), Some("1.234"));
+        // Exactly at the delimiter
+        assert_eq!(span(text, "Total: ", Some(8), Some(" ")), Some("1.234,50"));
+        // A missing delimiter runs to max_chars or the end
+        assert_eq!(
+            span(text, "Total: ", Some(10), Some(";")),
+            Some("1.234,50 E")
+        );
+        assert_eq!(
+            span(text, "Total: ", None, Some(";")),
+            Some("1.234,50 EUR due")
+        );
+        // A multi-character delimiter, searched for after the anchor only
+        assert_eq!(
+            span("EUR Total: 5 EUR", "Total: ", None, Some(" EUR")),
+            Some("5")
+        );
+    }
+
+    #[test]
+    fn empty_delimiter_is_ignored() {
+        let text = "Total: 5 EUR";
+        assert_eq!(span(text, "Total: ", None, Some("")), Some("5 EUR"));
+        assert_eq!(span(text, "Total: ", Some(1), Some("")), Some("5"));
+    }
+
+    #[test]
+    fn anchor_position() {
+        assert_eq!(span("Total: 5", "Sum: ", None, None), None);
+        assert_eq!(span("", "Total", None, None), None);
+        // An anchor at the end of the text has an empty span
+        assert_eq!(span("Total: 5 Sum:", "Sum:", Some(4), Some(" ")), Some(""));
+        let spec = SpanSpec {
+            anchor: String::from("Sum:"),
+            max_chars: None,
+            delimiter: None,
+        };
+        assert_eq!(spec.find("Total: 5 Sum:"), Some((13, 13)));
+        // The first occurrence of the anchor is used
+        assert_eq!(span("Id: 1, Id: 2", "Id: ", None, Some(",")), Some("1"));
+    }
+}
@@ -4,8 +4,21 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
 
-pub use extractor_zkvm::{extract_text, PdfError};
+pub mod disclosure;
+pub mod normalize;
+pub mod numeric;
+pub mod pattern;
+
+pub use disclosure::SpanSpec;
+pub use extractor_zkvm::{
+    extract_form_fields, extract_form_fields_from_document, extract_text,
+    extract_text_from_document, Document, FormField, PdfError, Rect,
+};
+pub use normalize::NormalizationProfile;
+pub use numeric::FieldPredicate;
+pub use pattern::Pattern;
 pub use pdf_logger::{log_debug, set_logger, Logger, NullLogger};
 pub use signature_validator_zkvm::{verify_pdf_signature, SignatureAlgorithm};
 
@@ -14,16 +27,120 @@ pub struct PdfValidationResult {
     pub text_pages: Vec<String>,
 }
 
+/// A match located in the extracted text, as byte offsets into `text_pages[page]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageMatch {
+    pub page: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl PdfValidationResult {
+    /// Find the first page whose text matches `pattern`
+    pub fn find_pattern(&self, pattern: &Pattern) -> Option<PageMatch> {
+        self.text_pages.iter().enumerate().find_map(|(page, text)| {
+            pattern.find(text).map(|m| PageMatch {
+                page,
+                start: m.start,
+                end: m.end,
+            })
+        })
+    }
+
+    /// Find the first page containing the anchor of `spec` and locate the
+    /// span that follows it
+    pub fn find_span(&self, spec: &SpanSpec) -> Option<PageMatch> {
+        self.text_pages.iter().enumerate().find_map(|(page, text)| {
+            spec.find(text)
+                .map(|(start, end)| PageMatch { page, start, end })
+        })
+    }
+
+    /// Find the first page with the labelled field of `predicate` and return
+    /// the page index with the parsed value
+    pub fn find_field(&self, predicate: &FieldPredicate) -> Option<(usize, numeric::FieldValue)> {
+        self.text_pages
+            .iter()
+            .enumerate()
+            .find_map(|(page, text)| predicate.find_value(text).map(|value| (page, value)))
+    }
+
+    /// Copy of this result with every page normalised with `profile`
+    pub fn normalized(&self, profile: &NormalizationProfile) -> Self {
+        Self {
+            signature_valid: self.signature_valid,
+            text_pages: self
+                .text_pages
+                .iter()
+                .map(|text| profile.apply(text))
+                .collect(),
+        }
+    }
+
+    /// Text covered by `m`
+    pub fn matched_text(&self, m: &PageMatch) -> &str {
+        &self.text_pages[m.page][m.start..m.end]
+    }
+}
+
+/// SHA-256 commitment to data that stays private to the guest
+pub fn commitment(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Commitment to `statement` made about `document`, as four big-endian output
+/// words: the first 128 bits of SHA-256 over the SHA-256 of the document
+/// followed by the statement. A verifier who knows the document's hash can
+/// recompute it for the statement they expect.
+pub fn statement_commitment(document: &[u8], statement: &[u8]) -> [u32; 4] {
+    let mut hasher = Sha256::new();
+    hasher.update(commitment(document));
+    hasher.update(statement);
+    let digest: [u8; 32] = hasher.finalize().into();
+    core::array::from_fn(|i| {
+        u32::from_be_bytes([
+            digest[4 * i],
+            digest[4 * i + 1],
+            digest[4 * i + 2],
+            digest[4 * i + 3],
+        ])
+    })
+}
+
 pub fn validate_and_extract_pdf(pdf_bytes: &[u8]) -> Result<PdfValidationResult, String> {
     // Verify signature
     let signature_valid = verify_pdf_signature(pdf_bytes)?;
 
     // Extract text
-    let text_pages = extract_text(pdf_bytes.to_vec())
-        .map_err(|e| alloc::format!("Text extraction failed: {e}"))?;
+    let text_pages =
+        extract_text(pdf_bytes).map_err(|e| alloc::format!("Text extraction failed: {e}"))?;
 
     Ok(PdfValidationResult {
         signature_valid,
         text_pages,
     })
 }
+
+/// Extract the text of every page of `document` together with the text
+/// inside `region` of page `page`, in reading order. The region is measured
+/// on the displayed page, from the lower-left corner of its crop box after
+/// `/Rotate`, like [`extractor_zkvm::extract_text_runs_in_region`].
+/// The region text is empty when the page does not exist.
+pub fn extract_text_and_region(
+    document: &Document,
+    page: usize,
+    region: &Rect,
+) -> Result<(Vec<String>, String), PdfError> {
+    let text_pages =
+        extract_text_from_document(document.pages(), document).map_err(PdfError::ParseError)?;
+    let region_text = document
+        .page(page)
+        .map(|page| {
+            let runs =
+                extractor_zkvm::extract_text_runs_in_region_from_page(page, document, region);
+            extractor_zkvm::layout_text(&runs)
+        })
+        .unwrap_or_default();
+    document.check_limits()?;
+    Ok((text_pages, region_text))
+}
@@ -0,0 +1,262 @@
+//! Text normalisation applied before matching.
+//!
+//! Extracted text rarely matches what a human would type: the extractor may
+//! insert extra spaces between `TJ` runs, emit ligature glyphs such as "ﬁ" or
+//! keep words hyphenated across lines. A [`NormalizationProfile`] selects the
+//! steps that are applied to both sides of a comparison. Its bit encoding is
+//! part of the guest input and public output, so every flag value is stable.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Collapse runs of whitespace into a single space and trim both ends
+pub const COLLAPSE_WHITESPACE: u32 = 1 << 0;
+/// Lowercase every character
+pub const FOLD_CASE: u32 = 1 << 1;
+/// Unicode compatibility composition (NFKC)
+pub const NFKC: u32 = 1 << 2;
+/// Expand Latin ligatures (U+FB00..U+FB06) into their letters
+pub const EXPAND_LIGATURES: u32 = 1 << 3;
+/// Join words hyphenated across lines and drop soft hyphens
+pub const DEHYPHENATE: u32 = 1 << 4;
+
+const ALL_FLAGS: u32 = COLLAPSE_WHITESPACE | FOLD_CASE | NFKC | EXPAND_LIGATURES | DEHYPHENATE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NormalizationProfile {
+    bits: u32,
+}
+
+impl NormalizationProfile {
+    /// Compare text exactly as extracted
+    pub const NONE: Self = Self { bits: 0 };
+
+    pub fn from_bits(bits: u32) -> Result<Self, String> {
+        if bits & !ALL_FLAGS != 0 {
+            return Err(alloc::format!(
+                "Unknown normalisation flags: 0x{:x}",
+                bits & !ALL_FLAGS
+            ));
+        }
+        #[cfg(not(feature = "nfkc"))]
+        if bits & NFKC != 0 {
+            return Err(String::from(
+                "NFKC normalisation is not enabled in this build",
+            ));
+        }
+        Ok(Self { bits })
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn contains(&self, flag: u32) -> bool {
+        self.bits & flag == flag
+    }
+
+    /// Apply the selected steps in a fixed order: NFKC, ligatures,
+    /// de-hyphenation, case folding, whitespace
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = String::from(text);
+
+        #[cfg(feature = "nfkc")]
+        if self.contains(NFKC) {
+            use unicode_normalization::UnicodeNormalization;
+            text = text.nfkc().collect();
+        }
+        if self.contains(EXPAND_LIGATURES) {
+            text = expand_ligatures(&text);
+        }
+        if self.contains(DEHYPHENATE) {
+            text = dehyphenate(&text);
+        }
+        if self.contains(FOLD_CASE) {
+            text = text.chars().flat_map(char::to_lowercase).collect();
+        }
+        if self.contains(COLLAPSE_WHITESPACE) {
+            text = collapse_whitespace(&text);
+        }
+
+        text
+    }
+}
+
+fn expand_ligatures(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\u{FB00}' => result.push_str("ff"),
+            '\u{FB01}' => result.push_str("fi"),
+            '\u{FB02}' => result.push_str("fl"),
+            '\u{FB03}' => result.push_str("ffi"),
+            '\u{FB04}' => result.push_str("ffl"),
+            '\u{FB05}' | '\u{FB06}' => result.push_str("st"),
+            ch => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Words joining a suspended hyphen to the next word ("pre- and post",
+/// "Vor- und Nachteile"), after which a hyphen is kept
+const CONJUNCTIONS: &[&str] = &["and", "or", "nor", "und", "oder", "et", "ou", "y", "o", "e"];
+
+/// Remove soft hyphens, and a hyphen between a letter and whitespace when the
+/// whitespace is followed by a lowercase letter ("docu- ment" -> "document").
+/// Page text joins lines with spaces, so a hyphen is kept before a
+/// conjunction on the same line, where it is a suspended hyphen.
+fn dehyphenate(text: &str) -> String {
+    let chars: Vec<char> = text.chars().filter(|&c| c != '\u{AD}').collect();
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        if matches!(ch, '-' | '\u{2010}') && i > 0 && chars[i - 1].is_alphabetic() {
+            let mut next = i + 1;
+            while next < chars.len() && chars[next].is_whitespace() {
+                next += 1;
+            }
+            let line_break = chars[i + 1..next].iter().any(|&c| matches!(c, '\n' | '\r'));
+            if next > i + 1
+                && next < chars.len()
+                && chars[next].is_lowercase()
+                && (line_break || !starts_with_conjunction(&chars[next..]))
+            {
+                i = next;
+                continue;
+            }
+        }
+        result.push(ch);
+        i += 1;
+    }
+
+    result
+}
+
+fn starts_with_conjunction(chars: &[char]) -> bool {
+    let len = chars.iter().take_while(|c| c.is_alphabetic()).count();
+    CONJUNCTIONS
+        .iter()
+        .any(|word| word.chars().eq(chars[..len].iter().copied()))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(bits: u32, text: &str) -> String {
+        NormalizationProfile::from_bits(bits).unwrap().apply(text)
+    }
+
+    #[test]
+    fn from_bits() {
+        assert_eq!(
+            NormalizationProfile::from_bits(0),
+            Ok(NormalizationProfile::NONE)
+        );
+        assert!(NormalizationProfile::from_bits(0).unwrap().is_none());
+        let profile = NormalizationProfile::from_bits(FOLD_CASE | DEHYPHENATE).unwrap();
+        assert_eq!(profile.bits(), FOLD_CASE | DEHYPHENATE);
+        assert!(profile.contains(FOLD_CASE) && !profile.contains(COLLAPSE_WHITESPACE));
+        assert!(NormalizationProfile::from_bits(1 << 5).is_err());
+        assert!(NormalizationProfile::from_bits(COLLAPSE_WHITESPACE | 1 << 31).is_err());
+    }
+
+    #[cfg(feature = "nfkc")]
+    #[test]
+    fn nfkc() {
+        assert_eq!(apply(NFKC, "ﬁle Ⅻ ｆｕｌｌ x²"), "file XII full x2");
+        assert_eq!(apply(NFKC, "e\u{301}"), "\u{e9}");
+    }
+
+    #[cfg(not(feature = "nfkc"))]
+    #[test]
+    fn nfkc_not_built_in() {
+        assert!(NormalizationProfile::from_bits(NFKC).is_err());
+        assert!(NormalizationProfile::from_bits(FOLD_CASE).is_ok());
+    }
+
+    #[test]
+    fn each_flag() {
+        assert_eq!(apply(0, " A\u{FB01}-\n b "), " A\u{FB01}-\n b ");
+        assert_eq!(
+            apply(COLLAPSE_WHITESPACE, "\t Net \u{A0} salary:\n 3 "),
+            "Net salary: 3"
+        );
+        assert_eq!(apply(FOLD_CASE, "Straße ÄBC"), "straße äbc");
+        assert_eq!(
+            apply(
+                EXPAND_LIGATURES,
+                "\u{FB00} \u{FB01} \u{FB02} \u{FB03} \u{FB04} \u{FB05} \u{FB06}"
+            ),
+            "ff fi fl ffi ffl st st"
+        );
+        assert_eq!(apply(DEHYPHENATE, "docu- ment"), "document");
+        assert_eq!(apply(DEHYPHENATE, "docu\u{2010}\n  ment"), "document");
+        assert_eq!(apply(DEHYPHENATE, "docu\u{AD}ment"), "document");
+    }
+
+    #[test]
+    fn dehyphenate_keeps_other_hyphens() {
+        assert_eq!(apply(DEHYPHENATE, "pre- and post"), "pre- and post");
+        assert_eq!(
+            apply(DEHYPHENATE, "Vor- und Nachteile"),
+            "Vor- und Nachteile"
+        );
+        assert_eq!(apply(DEHYPHENATE, "pre- or post"), "pre- or post");
+        // A conjunction at the start of the next line is joined
+        assert_eq!(apply(DEHYPHENATE, "s-\nand"), "sand");
+        // Only a conjunction as a whole word keeps the hyphen
+        assert_eq!(apply(DEHYPHENATE, "hyph- enate"), "hyphenate");
+        assert_eq!(apply(DEHYPHENATE, "br- andy"), "brandy");
+        // Compounds, uppercase words, numbers and trailing hyphens
+        assert_eq!(apply(DEHYPHENATE, "well-known"), "well-known");
+        assert_eq!(apply(DEHYPHENATE, "North- East"), "North- East");
+        assert_eq!(apply(DEHYPHENATE, "10- 20 - x -"), "10- 20 - x -");
+    }
+
+    #[test]
+    fn fixed_order() {
+        // Ligatures are expanded before case folding
+        assert_eq!(apply(FOLD_CASE | EXPAND_LIGATURES, "\u{FB01}NE"), "fine");
+        // De-hyphenation runs before case folding, so it sees "Ment"
+        assert_eq!(apply(DEHYPHENATE | FOLD_CASE, "Docu- Ment"), "docu- ment");
+        // and before collapsing whitespace, so it still sees the line break
+        assert_eq!(
+            apply(DEHYPHENATE | COLLAPSE_WHITESPACE, "s-\n and x"),
+            "sand x"
+        );
+        assert_eq!(
+            apply(
+                COLLAPSE_WHITESPACE | FOLD_CASE | EXPAND_LIGATURES | DEHYPHENATE,
+                " The  Ef\u{FB01}- ciency\u{AD}\n"
+            ),
+            "the efficiency"
+        );
+    }
+
+    #[cfg(feature = "nfkc")]
+    #[test]
+    fn nfkc_runs_first() {
+        // NFKC turns a non-breaking hyphen into the hyphen de-hyphenation removes
+        assert_eq!(apply(DEHYPHENATE, "docu\u{2011} ment"), "docu\u{2011} ment");
+        assert_eq!(apply(NFKC | DEHYPHENATE, "docu\u{2011} ment"), "document");
+    }
+}
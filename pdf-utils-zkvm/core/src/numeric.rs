@@ -0,0 +1,612 @@
+//! Comparison predicates over labelled numeric and date fields.
+//!
+//! A [`FieldPredicate`] finds a label such as "Net salary:" in page text,
+//! parses the value that follows it with a configurable locale and compares it
+//! against a threshold, e.g. "net salary >= 3000.00". Numbers are parsed into
+//! exact decimals, so amounts like `12.345.678,91` never go through floats.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Characters skipped between the label and the value, e.g. a currency code
+const MAX_LABEL_GAP: usize = 16;
+/// Maximum number of digits in a parsed number, small enough that two
+/// mantissas rescaled to a common scale always fit in an `i128`
+const MAX_DIGITS: usize = 18;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    /// Thousands separator. A space also accepts no-break and thin spaces
+    pub group_separator: Option<char>,
+}
+
+impl NumberFormat {
+    /// `1,234.56`
+    pub const DECIMAL_POINT: Self = Self {
+        decimal_separator: '.',
+        group_separator: Some(','),
+    };
+    /// `1.234,56`
+    pub const DECIMAL_COMMA: Self = Self {
+        decimal_separator: ',',
+        group_separator: Some('.'),
+    };
+
+    /// Decode the guest input encoding: the decimal separator in bits 0-7
+    /// and the group separator in bits 8-15 (0 for none), both Latin-1
+    pub fn from_word(word: u32) -> Option<Self> {
+        let decimal_separator = char::from((word & 0xFF) as u8);
+        let group_separator = match ((word >> 8) & 0xFF) as u8 {
+            0 => None,
+            byte => Some(char::from(byte)),
+        };
+        if word >> 16 != 0
+            || decimal_separator.is_ascii_digit()
+            || decimal_separator == '\0'
+            || group_separator.is_some_and(|c| c.is_ascii_digit() || c == decimal_separator)
+        {
+            return None;
+        }
+        Some(Self {
+            decimal_separator,
+            group_separator,
+        })
+    }
+
+    fn is_group_separator(&self, c: char) -> bool {
+        match self.group_separator {
+            Some(' ') => matches!(c, ' ' | '\u{A0}' | '\u{202F}'),
+            Some(sep) => c == sep,
+            None => false,
+        }
+    }
+}
+
+/// Field order of a date; any of `.`, `/` and `-` separates the fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldFormat {
+    Number(NumberFormat),
+    Date(DateOrder),
+}
+
+impl FieldFormat {
+    /// Decode the guest input encoding: the kind in bits 24-31 (0 for
+    /// numbers, 1 for dates) and the kind-specific format in bits 0-23, see
+    /// [`NumberFormat::from_word`]. Dates use 0 for day-month-year, 1 for
+    /// month-day-year and 2 for year-month-day.
+    pub fn from_word(word: u32) -> Option<Self> {
+        let format = word & 0x00FF_FFFF;
+        match word >> 24 {
+            0 => NumberFormat::from_word(format).map(FieldFormat::Number),
+            1 => match format {
+                0 => Some(FieldFormat::Date(DateOrder::DayMonthYear)),
+                1 => Some(FieldFormat::Date(DateOrder::MonthDayYear)),
+                2 => Some(FieldFormat::Date(DateOrder::YearMonthDay)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+    NotEqual,
+}
+
+impl Comparison {
+    /// Decode the guest input encoding: `<`, `<=`, `==`, `>=`, `>`, `!=` as 0-5
+    pub fn from_word(word: u32) -> Option<Self> {
+        match word {
+            0 => Some(Comparison::Less),
+            1 => Some(Comparison::LessOrEqual),
+            2 => Some(Comparison::Equal),
+            3 => Some(Comparison::GreaterOrEqual),
+            4 => Some(Comparison::Greater),
+            5 => Some(Comparison::NotEqual),
+            _ => None,
+        }
+    }
+
+    pub fn holds(&self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Less => ordering == Ordering::Less,
+            Comparison::LessOrEqual => ordering != Ordering::Greater,
+            Comparison::Equal => ordering == Ordering::Equal,
+            Comparison::GreaterOrEqual => ordering != Ordering::Less,
+            Comparison::Greater => ordering == Ordering::Greater,
+            Comparison::NotEqual => ordering != Ordering::Equal,
+        }
+    }
+}
+
+/// Exact decimal number `mantissa * 10^-scale`
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Parse a canonical number: optional sign, digits, optional `.` and
+    /// fraction digits, no grouping
+    pub fn parse(s: &str) -> Option<Self> {
+        let (value, consumed) = parse_number(
+            s,
+            &NumberFormat {
+                decimal_separator: '.',
+                group_separator: None,
+            },
+        )?;
+        (consumed == s.len()).then_some(value)
+    }
+
+    fn rescale(&self, scale: u32) -> i128 {
+        self.mantissa * 10i128.pow(scale - self.scale)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.rescale(scale).cmp(&other.rescale(scale))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Parse a canonical `YYYY-MM-DD` date
+    pub fn parse(s: &str) -> Option<Self> {
+        let (date, consumed) = parse_date(s, DateOrder::YearMonthDay)?;
+        (consumed == s.len()).then_some(date)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldValue {
+    Number(Decimal),
+    Date(Date),
+}
+
+impl FieldValue {
+    /// Parse a canonical threshold for `format`, see [`Decimal::parse`] and
+    /// [`Date::parse`]
+    pub fn parse(s: &str, format: &FieldFormat) -> Option<Self> {
+        match format {
+            FieldFormat::Number(_) => Decimal::parse(s).map(FieldValue::Number),
+            FieldFormat::Date(_) => Date::parse(s).map(FieldValue::Date),
+        }
+    }
+
+    fn compare(&self, other: &FieldValue) -> Option<Ordering> {
+        match (self, other) {
+            (FieldValue::Number(a), FieldValue::Number(b)) => Some(a.cmp(b)),
+            (FieldValue::Date(a), FieldValue::Date(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldPredicate {
+    pub label: String,
+    pub format: FieldFormat,
+    pub comparison: Comparison,
+    pub threshold: FieldValue,
+}
+
+impl FieldPredicate {
+    /// Value of the first occurrence of the label in `text` that is followed
+    /// by a value in the expected format. The value is the first number
+    /// after the label, so a malformed one is not skipped for digits within it.
+    pub fn find_value(&self, text: &str) -> Option<FieldValue> {
+        if self.label.is_empty() {
+            return None;
+        }
+
+        text.match_indices(self.label.as_str())
+            .find_map(|(pos, _)| {
+                let rest = &text[pos + self.label.len()..];
+                // Skip a gap like " EUR " or ": " before the value
+                let (start, _) = rest
+                    .char_indices()
+                    .take(MAX_LABEL_GAP + 1)
+                    .find(|&(i, c)| {
+                        c.is_ascii_digit()
+                            || (matches!(c, '-' | '+')
+                                && rest[i + 1..].starts_with(|n: char| n.is_ascii_digit()))
+                    })?;
+                parse_value(&rest[start..], &self.format)
+            })
+    }
+
+    /// Compare `value` against the threshold
+    pub fn evaluate(&self, value: &FieldValue) -> bool {
+        value
+            .compare(&self.threshold)
+            .is_some_and(|ordering| self.comparison.holds(ordering))
+    }
+}
+
+fn parse_value(s: &str, format: &FieldFormat) -> Option<FieldValue> {
+    match format {
+        FieldFormat::Number(number_format) => {
+            parse_number(s, number_format).map(|(value, _)| FieldValue::Number(value))
+        }
+        FieldFormat::Date(order) => parse_date(s, *order).map(|(date, _)| FieldValue::Date(date)),
+    }
+}
+
+/// Parse a number at the start of `s`, returning it with the number of bytes
+/// consumed. Separators are only accepted between digits, so a trailing "."
+/// ending a sentence is not part of the number. A group separator must be
+/// followed by exactly three digits, otherwise the number is rejected.
+pub fn parse_number(s: &str, format: &NumberFormat) -> Option<(Decimal, usize)> {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut i = 0;
+
+    let negative = match chars.first() {
+        Some((_, '-')) => {
+            i += 1;
+            true
+        }
+        Some((_, '+')) => {
+            i += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let mut mantissa: i128 = 0;
+    let mut digits = 0;
+    let mut scale = 0u32;
+    let mut in_fraction = false;
+    let mut end = 0;
+
+    while i < chars.len() {
+        let c = chars[i].1;
+        if let Some(digit) = c.to_digit(10) {
+            digits += 1;
+            if digits > MAX_DIGITS {
+                return None;
+            }
+            mantissa = mantissa * 10 + digit as i128;
+            if in_fraction {
+                scale += 1;
+            }
+            i += 1;
+            end = chars.get(i).map_or(s.len(), |(pos, _)| *pos);
+            continue;
+        }
+
+        // A separator must sit between two digits
+        let followed_by_digit = chars.get(i + 1).is_some_and(|(_, n)| n.is_ascii_digit());
+        if digits > 0 && followed_by_digit {
+            if c == format.decimal_separator && !in_fraction {
+                in_fraction = true;
+                i += 1;
+                continue;
+            }
+            if !in_fraction && format.is_group_separator(c) {
+                // A group holds exactly three digits, so "1,5" is not 15
+                let group = chars[i + 1..]
+                    .iter()
+                    .take_while(|(_, n)| n.is_ascii_digit())
+                    .count();
+                if group != 3 {
+                    return None;
+                }
+                i += 1;
+                continue;
+            }
+        }
+        break;
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    let mantissa = if negative { -mantissa } else { mantissa };
+    Some((Decimal { mantissa, scale }, end))
+}
+
+/// Parse a date at the start of `s`, returning it with the number of bytes
+/// consumed. Years have four digits, days and months one or two.
+pub fn parse_date(s: &str, order: DateOrder) -> Option<(Date, usize)> {
+    let bytes = s.as_bytes();
+    let mut fields = [0u32; 3];
+    let mut pos = 0;
+    let mut separator = None;
+
+    for (i, field) in fields.iter_mut().enumerate() {
+        if i > 0 {
+            let sep = *bytes.get(pos)?;
+            if !matches!(sep, b'.' | b'/' | b'-') || separator.is_some_and(|s| s != sep) {
+                return None;
+            }
+            separator = Some(sep);
+            pos += 1;
+        }
+
+        let is_year = matches!(
+            (order, i),
+            (DateOrder::YearMonthDay, 0) | (DateOrder::DayMonthYear | DateOrder::MonthDayYear, 2)
+        );
+        let (min_len, max_len) = if is_year { (4, 4) } else { (1, 2) };
+
+        let start = pos;
+        while pos < bytes.len() && pos - start < max_len && bytes[pos].is_ascii_digit() {
+            *field = *field * 10 + (bytes[pos] - b'0') as u32;
+            pos += 1;
+        }
+        if pos - start < min_len {
+            return None;
+        }
+    }
+
+    // Reject a longer run of digits, e.g. a five digit year
+    if bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let (year, month, day) = match order {
+        DateOrder::DayMonthYear => (fields[2], fields[1], fields[0]),
+        DateOrder::MonthDayYear => (fields[2], fields[0], fields[1]),
+        DateOrder::YearMonthDay => (fields[0], fields[1], fields[2]),
+    };
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    Some((
+        Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        },
+        pos,
+    ))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str, format: &NumberFormat) -> Option<(Decimal, usize)> {
+        parse_number(s, format)
+    }
+
+    fn decimal(s: &str) -> Decimal {
+        Decimal::parse(s).unwrap()
+    }
+
+    fn predicate(label: &str, format: FieldFormat, threshold: &str) -> FieldPredicate {
+        FieldPredicate {
+            label: String::from(label),
+            format,
+            comparison: Comparison::Equal,
+            threshold: FieldValue::parse(threshold, &format).unwrap(),
+        }
+    }
+
+    #[test]
+    fn locale_numbers() {
+        let point = NumberFormat::DECIMAL_POINT;
+        let comma = NumberFormat::DECIMAL_COMMA;
+        assert_eq!(number("1,234.56", &point), Some((decimal("1234.56"), 8)));
+        assert_eq!(
+            number("12.345.678,91", &comma),
+            Some((decimal("12345678.91"), 13))
+        );
+        assert_eq!(number("-3.012,5", &comma), Some((decimal("-3012.5"), 8)));
+        assert_eq!(number("+7", &point), Some((decimal("7"), 2)));
+
+        // Groups hold exactly three digits
+        assert_eq!(number("1,5", &point), None);
+        assert_eq!(number("1,23", &point), None);
+        assert_eq!(number("1,2345", &point), None);
+        assert_eq!(number("1.5", &comma), None);
+        assert_eq!(number("1,234,56", &point), None);
+
+        // A separator not followed by a digit ends the number
+        assert_eq!(number("1,234.", &point), Some((decimal("1234"), 5)));
+        assert_eq!(number("1, 2", &point), Some((decimal("1"), 1)));
+        // No grouping in the fraction, and one decimal separator
+        assert_eq!(number("1.234,567", &point), Some((decimal("1.234"), 5)));
+        assert_eq!(number("1.5.5", &point), Some((decimal("1.5"), 3)));
+
+        let spaces = NumberFormat {
+            decimal_separator: ',',
+            group_separator: Some(' '),
+        };
+        assert_eq!(
+            number("1\u{A0}234\u{202F}567,8", &spaces),
+            Some((decimal("1234567.8"), 14))
+        );
+        let ungrouped = NumberFormat {
+            decimal_separator: '.',
+            group_separator: None,
+        };
+        assert_eq!(number("1,234", &ungrouped), Some((decimal("1"), 1)));
+
+        assert_eq!(number("1234567890123456789", &point), None);
+        assert_eq!(number("-", &point), None);
+        assert_eq!(number(".5", &point), None);
+    }
+
+    #[test]
+    fn format_words() {
+        assert_eq!(
+            FieldFormat::from_word(0x2C2E),
+            Some(FieldFormat::Number(NumberFormat::DECIMAL_POINT))
+        );
+        assert_eq!(
+            FieldFormat::from_word(0x2E2C),
+            Some(FieldFormat::Number(NumberFormat::DECIMAL_COMMA))
+        );
+        assert_eq!(NumberFormat::from_word(0x2E2E), None);
+        assert_eq!(NumberFormat::from_word(0x3030), None);
+        assert_eq!(NumberFormat::from_word(0x1_002E), None);
+        assert_eq!(
+            FieldFormat::from_word(0x0100_0002),
+            Some(FieldFormat::Date(DateOrder::YearMonthDay))
+        );
+        assert_eq!(FieldFormat::from_word(0x0100_0003), None);
+        assert_eq!(FieldFormat::from_word(0x0200_0000), None);
+    }
+
+    #[test]
+    fn dates() {
+        let date = |year, month, day| Date { year, month, day };
+        assert_eq!(
+            parse_date("31.12.2023", DateOrder::DayMonthYear),
+            Some((date(2023, 12, 31), 10))
+        );
+        assert_eq!(
+            parse_date("2/29/2024.", DateOrder::MonthDayYear),
+            Some((date(2024, 2, 29), 9))
+        );
+        assert_eq!(
+            parse_date("2024-1-5", DateOrder::YearMonthDay),
+            Some((date(2024, 1, 5), 8))
+        );
+
+        // Leap years
+        assert!(Date::parse("2024-02-29").is_some());
+        assert!(Date::parse("2000-02-29").is_some());
+        assert!(Date::parse("2023-02-29").is_none());
+        assert!(Date::parse("1900-02-29").is_none());
+
+        // Invalid days and months
+        assert!(Date::parse("2024-04-31").is_none());
+        assert!(Date::parse("2024-01-32").is_none());
+        assert!(Date::parse("2024-01-00").is_none());
+        assert!(Date::parse("2024-13-01").is_none());
+        assert!(Date::parse("2024-00-01").is_none());
+
+        // Mixed separators, short or long years
+        assert!(parse_date("01.02/2024", DateOrder::DayMonthYear).is_none());
+        assert!(parse_date("01.02.24", DateOrder::DayMonthYear).is_none());
+        assert!(parse_date("01.02.20245", DateOrder::DayMonthYear).is_none());
+        assert!(Date::parse("2024-01-01x").is_none());
+    }
+
+    #[test]
+    fn find_value_gaps() {
+        let euro = FieldFormat::Number(NumberFormat::DECIMAL_COMMA);
+        let salary = predicate("Net salary:", euro, "0");
+        let value = |text| salary.find_value(text);
+        let number = |s| Some(FieldValue::Number(decimal(s)));
+
+        assert_eq!(value("Net salary: 3.012,50"), number("3012.5"));
+        assert_eq!(value("Net salary: EUR 3.012,50 EUR"), number("3012.5"));
+        assert_eq!(value("Net salary:-12"), number("-12"));
+        assert_eq!(value("Net salary: - 12"), number("12"));
+        // At most 16 characters between the label and the value
+        assert_eq!(value("Net salary: abcdefghijklmn 1"), number("1"));
+        assert_eq!(value("Net salary: abcdefghijklmno 1"), None);
+        // A malformed value is not read from its last digits
+        assert_eq!(value("Net salary: 3.01,50"), None);
+        // A later occurrence of the label with a value is used
+        assert_eq!(value("Net salary: n/a. Net salary: 2.000"), number("2000"));
+        assert_eq!(value("Gross salary: 4.000"), None);
+
+        let empty = predicate("", euro, "0");
+        assert_eq!(empty.find_value("1"), None);
+
+        let dated = predicate(
+            "Date",
+            FieldFormat::Date(DateOrder::DayMonthYear),
+            "2024-01-01",
+        );
+        assert_eq!(
+            dated.find_value("Date of issue: 05.06.2024"),
+            Some(FieldValue::Date(Date {
+                year: 2024,
+                month: 6,
+                day: 5
+            }))
+        );
+        assert_eq!(dated.find_value("Date: 30.02.2024"), None);
+    }
+
+    #[test]
+    fn comparisons() {
+        let cases = [
+            (0, Comparison::Less, [true, false, false]),
+            (1, Comparison::LessOrEqual, [true, true, false]),
+            (2, Comparison::Equal, [false, true, false]),
+            (3, Comparison::GreaterOrEqual, [false, true, true]),
+            (4, Comparison::Greater, [false, false, true]),
+            (5, Comparison::NotEqual, [true, false, true]),
+        ];
+        for (word, comparison, holds) in cases {
+            assert_eq!(Comparison::from_word(word), Some(comparison));
+            let mut predicate = predicate(
+                "Total",
+                FieldFormat::Number(NumberFormat::DECIMAL_POINT),
+                "3000",
+            );
+            predicate.comparison = comparison;
+            for (value, holds) in ["2999.99", "3000.00", "3000.01"].into_iter().zip(holds) {
+                let value = FieldValue::Number(decimal(value));
+                assert_eq!(
+                    predicate.evaluate(&value),
+                    holds,
+                    "{comparison:?} {value:?}"
+                );
+            }
+            // A date never compares with a number
+            let date = FieldValue::Date(Date::parse("2024-01-01").unwrap());
+            assert!(!predicate.evaluate(&date));
+        }
+        assert_eq!(Comparison::from_word(6), None);
+    }
+}
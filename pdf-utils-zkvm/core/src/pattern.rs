@@ -0,0 +1,702 @@
+//! Small regular expression engine for text predicates evaluated in the zkVM.
+//!
+//! Supported syntax:
+//! - literals and escaped metacharacters (`\.`, `\(`, `\\`, ...), `\n`, `\t`, `\r`
+//! - `.` (any character except newline)
+//! - classes `[abc]`, `[a-z0-9]`, `[^,]` and the shorthands `\d \w \s \D \W \S`
+//! - anchors `^` and `$` (start and end of the page text)
+//! - groups `(...)` / `(?:...)` and alternation `|`
+//! - quantifiers `* + ? {n} {n,} {n,m}`, lazy with a trailing `?`
+//!
+//! Patterns are compiled to a Pike VM program, so matching never backtracks and
+//! runs in `O(text.len() * program.len())` steps. That keeps the cycle count of
+//! the guest predictable regardless of the pattern or the document.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Upper bound for `{n,m}` counts
+const MAX_REPEAT: u32 = 1000;
+/// Upper bound for the compiled program size
+const MAX_PROGRAM_SIZE: usize = 20_000;
+/// Upper bound for nested groups, which the parser and compiler recurse into
+const MAX_GROUP_DEPTH: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    program: Vec<Inst>,
+    classes: Vec<Class>,
+}
+
+/// Location of a match, as byte offsets into the matched text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Pattern {
+    pub fn compile(source: &str) -> Result<Self, String> {
+        let mut parser = PatternParser {
+            chars: source.chars().collect(),
+            pos: 0,
+            depth: 0,
+        };
+        let node = parser.parse_alternation()?;
+        if parser.pos < parser.chars.len() {
+            return Err(alloc::format!(
+                "Unmatched ')' at position {} in pattern",
+                parser.pos
+            ));
+        }
+
+        let mut compiler = Compiler {
+            program: Vec::new(),
+            classes: Vec::new(),
+        };
+        compiler.compile(&node)?;
+        compiler.emit(Inst::Match)?;
+
+        Ok(Self {
+            source: source.to_string(),
+            program: compiler.program,
+            classes: compiler.classes,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// Find the leftmost match in `text`. Among matches starting at the same
+    /// position, the one preferred by greedy/lazy quantifiers wins.
+    pub fn find(&self, text: &str) -> Option<Match> {
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut matched = None;
+
+        let mut chars = text.char_indices().peekable();
+        loop {
+            let (at, ch) = match chars.peek() {
+                Some(&(at, ch)) => (at, Some(ch)),
+                None => (text.len(), None),
+            };
+
+            // Start a new attempt at this position unless a match was already
+            // found; new attempts always have the lowest priority
+            if matched.is_none() {
+                self.add_thread(&mut current, 0, at, at, text.len());
+            }
+            if current.is_empty() {
+                break;
+            }
+
+            let next_at = at + ch.map_or(0, char::len_utf8);
+            for i in 0..current.len() {
+                let (pc, start) = current.threads[i];
+                let advance = match (&self.program[pc], ch) {
+                    (Inst::Char(expected), Some(c)) => *expected == c,
+                    (Inst::Any, Some(c)) => c != '\n',
+                    (Inst::Class(idx), Some(c)) => self.classes[*idx].matches(c),
+                    (Inst::Match, _) => {
+                        matched = Some(Match { start, end: at });
+                        // Lower-priority threads can no longer win
+                        break;
+                    }
+                    _ => false,
+                };
+                if advance {
+                    self.add_thread(&mut next, pc + 1, start, next_at, text.len());
+                }
+            }
+
+            if ch.is_none() {
+                break;
+            }
+            chars.next();
+            core::mem::swap(&mut current, &mut next);
+            next.clear();
+        }
+
+        matched
+    }
+
+    /// Follow jumps, splits and assertions from `pc`, adding every reachable
+    /// consuming instruction to `threads` in priority order
+    fn add_thread(&self, threads: &mut Threads, pc: usize, start: usize, at: usize, len: usize) {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if !threads.insert(pc, start) {
+                continue;
+            }
+            match self.program[pc] {
+                Inst::Jump(target) => stack.push(target),
+                Inst::Split(preferred, other) => {
+                    stack.push(other);
+                    stack.push(preferred);
+                }
+                Inst::AssertStart if at == 0 => stack.push(pc + 1),
+                Inst::AssertEnd if at == len => stack.push(pc + 1),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Inst {
+    Char(char),
+    Any,
+    Class(usize),
+    Split(usize, usize),
+    Jump(usize),
+    AssertStart,
+    AssertEnd,
+    Match,
+}
+
+/// Ordered thread list with O(1) membership checks
+struct Threads {
+    threads: Vec<(usize, usize)>,
+    seen: Vec<bool>,
+}
+
+impl Threads {
+    fn new(size: usize) -> Self {
+        Self {
+            threads: Vec::new(),
+            seen: vec![false; size],
+        }
+    }
+
+    fn insert(&mut self, pc: usize, start: usize) -> bool {
+        if self.seen[pc] {
+            return false;
+        }
+        self.seen[pc] = true;
+        self.threads.push((pc, start));
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.threads.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.threads.is_empty()
+    }
+
+    fn clear(&mut self) {
+        for &(pc, _) in &self.threads {
+            self.seen[pc] = false;
+        }
+        self.threads.clear();
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ClassItem {
+    Range(char, char),
+    Digit(bool),
+    Word(bool),
+    Space(bool),
+}
+
+impl ClassItem {
+    fn matches(&self, c: char) -> bool {
+        match *self {
+            ClassItem::Range(lo, hi) => lo <= c && c <= hi,
+            ClassItem::Digit(negated) => c.is_ascii_digit() != negated,
+            ClassItem::Word(negated) => (c.is_ascii_alphanumeric() || c == '_') != negated,
+            ClassItem::Space(negated) => c.is_whitespace() != negated,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Class {
+    items: Vec<ClassItem>,
+    negated: bool,
+}
+
+impl Class {
+    fn matches(&self, c: char) -> bool {
+        self.items.iter().any(|item| item.matches(c)) != self.negated
+    }
+
+    fn single(item: ClassItem) -> Self {
+        Self {
+            items: vec![item],
+            negated: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Empty,
+    Literal(char),
+    Any,
+    Class(Class),
+    Start,
+    End,
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+struct PatternParser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Groups open at the current position
+    depth: usize,
+}
+
+impl PatternParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += 1;
+        Some(ch)
+    }
+
+    fn parse_alternation(&mut self) -> Result<Node, String> {
+        let mut alternatives = vec![self.parse_concat()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.parse_concat()?);
+        }
+
+        if alternatives.len() == 1 {
+            Ok(alternatives.pop().unwrap_or(Node::Empty))
+        } else {
+            Ok(Node::Alternate(alternatives))
+        }
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, String> {
+        let mut nodes = Vec::new();
+        while let Some(ch) = self.peek() {
+            if ch == '|' || ch == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_quantifier(atom)?);
+        }
+
+        match nodes.len() {
+            0 => Ok(Node::Empty),
+            1 => Ok(nodes.pop().unwrap_or(Node::Empty)),
+            _ => Ok(Node::Concat(nodes)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, String> {
+        let pos = self.pos;
+        match self.next().ok_or("Unexpected end of pattern")? {
+            '(' => {
+                if self.peek() == Some('?') {
+                    if self.chars.get(self.pos + 1) != Some(&':') {
+                        return Err(alloc::format!("Unsupported group syntax at position {pos}"));
+                    }
+                    self.pos += 2;
+                }
+                if self.depth >= MAX_GROUP_DEPTH {
+                    return Err(alloc::format!(
+                        "Groups nested deeper than {MAX_GROUP_DEPTH} at position {pos}"
+                    ));
+                }
+                self.depth += 1;
+                let node = self.parse_alternation()?;
+                self.depth -= 1;
+                if self.next() != Some(')') {
+                    return Err(alloc::format!("Unclosed group at position {pos}"));
+                }
+                Ok(node)
+            }
+            '[' => self.parse_class(pos).map(Node::Class),
+            '.' => Ok(Node::Any),
+            '^' => Ok(Node::Start),
+            '$' => Ok(Node::End),
+            '\\' => match self.parse_escape()? {
+                Escape::Literal(ch) => Ok(Node::Literal(ch)),
+                Escape::Class(item) => Ok(Node::Class(Class::single(item))),
+            },
+            '*' | '+' | '?' | '{' => Err(alloc::format!("Nothing to repeat at position {pos}")),
+            ch => Ok(Node::Literal(ch)),
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<Escape, String> {
+        let ch = self
+            .next()
+            .ok_or("Pattern ends with a trailing backslash")?;
+        let escape = match ch {
+            'd' => Escape::Class(ClassItem::Digit(false)),
+            'D' => Escape::Class(ClassItem::Digit(true)),
+            'w' => Escape::Class(ClassItem::Word(false)),
+            'W' => Escape::Class(ClassItem::Word(true)),
+            's' => Escape::Class(ClassItem::Space(false)),
+            'S' => Escape::Class(ClassItem::Space(true)),
+            'n' => Escape::Literal('\n'),
+            'r' => Escape::Literal('\r'),
+            't' => Escape::Literal('\t'),
+            ch if ch.is_ascii_alphanumeric() => {
+                return Err(alloc::format!("Unsupported escape sequence \\{ch}"));
+            }
+            ch => Escape::Literal(ch),
+        };
+        Ok(escape)
+    }
+
+    fn parse_class(&mut self, pos: usize) -> Result<Class, String> {
+        let mut class = Class {
+            items: Vec::new(),
+            negated: false,
+        };
+        if self.peek() == Some('^') {
+            class.negated = true;
+            self.pos += 1;
+        }
+
+        // A leading ']' is a literal
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            class.items.push(ClassItem::Range(']', ']'));
+        }
+
+        loop {
+            let lo = match self.next() {
+                None => return Err(alloc::format!("Unclosed class at position {pos}")),
+                Some(']') => break,
+                Some('\\') => match self.parse_escape()? {
+                    Escape::Literal(ch) => ch,
+                    Escape::Class(item) => {
+                        class.items.push(item);
+                        continue;
+                    }
+                },
+                Some(ch) => ch,
+            };
+
+            // Range unless the '-' is the last character of the class
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|&c| c != ']') {
+                self.pos += 1;
+                let hi = match self.next() {
+                    Some('\\') => match self.parse_escape()? {
+                        Escape::Literal(ch) => ch,
+                        Escape::Class(_) => {
+                            return Err(alloc::format!("Invalid class range at position {pos}"))
+                        }
+                    },
+                    Some(ch) => ch,
+                    None => return Err(alloc::format!("Unclosed class at position {pos}")),
+                };
+                if hi < lo {
+                    return Err(alloc::format!("Invalid class range {lo}-{hi}"));
+                }
+                class.items.push(ClassItem::Range(lo, hi));
+            } else {
+                class.items.push(ClassItem::Range(lo, lo));
+            }
+        }
+
+        Ok(class)
+    }
+
+    fn parse_quantifier(&mut self, atom: Node) -> Result<Node, String> {
+        let pos = self.pos;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                self.pos += 1;
+                let min = self.parse_count()?;
+                let max = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    if self.peek() == Some('}') {
+                        None
+                    } else {
+                        Some(self.parse_count()?)
+                    }
+                } else {
+                    Some(min)
+                };
+                if self.peek() != Some('}') {
+                    return Err(alloc::format!("Unclosed repetition at position {pos}"));
+                }
+                if max.is_some_and(|max| max < min) {
+                    return Err(alloc::format!("Invalid repetition range at position {pos}"));
+                }
+                (min, max)
+            }
+            _ => return Ok(atom),
+        };
+        self.pos += 1;
+
+        let greedy = if self.peek() == Some('?') {
+            self.pos += 1;
+            false
+        } else {
+            true
+        };
+
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            return Err(alloc::format!("Nested quantifier at position {}", self.pos));
+        }
+
+        Ok(Node::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+            greedy,
+        })
+    }
+
+    fn parse_count(&mut self) -> Result<u32, String> {
+        let start = self.pos;
+        let mut value = 0u32;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(digit);
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(alloc::format!(
+                "Expected repetition count at position {start}"
+            ));
+        }
+        if value > MAX_REPEAT {
+            return Err(alloc::format!(
+                "Repetition count {value} exceeds limit of {MAX_REPEAT}"
+            ));
+        }
+        Ok(value)
+    }
+}
+
+enum Escape {
+    Literal(char),
+    Class(ClassItem),
+}
+
+struct Compiler {
+    program: Vec<Inst>,
+    classes: Vec<Class>,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> Result<usize, String> {
+        if self.program.len() >= MAX_PROGRAM_SIZE {
+            return Err(alloc::format!(
+                "Pattern too large (more than {MAX_PROGRAM_SIZE} instructions)"
+            ));
+        }
+        self.program.push(inst);
+        Ok(self.program.len() - 1)
+    }
+
+    fn split(&mut self, body: usize, exit: usize, greedy: bool) -> Inst {
+        if greedy {
+            Inst::Split(body, exit)
+        } else {
+            Inst::Split(exit, body)
+        }
+    }
+
+    fn compile(&mut self, node: &Node) -> Result<(), String> {
+        match node {
+            Node::Empty => {}
+            Node::Literal(ch) => {
+                self.emit(Inst::Char(*ch))?;
+            }
+            Node::Any => {
+                self.emit(Inst::Any)?;
+            }
+            Node::Class(class) => {
+                self.classes.push(class.clone());
+                self.emit(Inst::Class(self.classes.len() - 1))?;
+            }
+            Node::Start => {
+                self.emit(Inst::AssertStart)?;
+            }
+            Node::End => {
+                self.emit(Inst::AssertEnd)?;
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    self.compile(node)?;
+                }
+            }
+            Node::Alternate(alternatives) => {
+                let mut jumps = Vec::new();
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    if i + 1 < alternatives.len() {
+                        let split = self.emit(Inst::Split(0, 0))?;
+                        self.compile(alternative)?;
+                        jumps.push(self.emit(Inst::Jump(0))?);
+                        self.program[split] = Inst::Split(split + 1, self.program.len());
+                    } else {
+                        self.compile(alternative)?;
+                    }
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jump(end);
+                }
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+            } => {
+                for _ in 0..*min {
+                    self.compile(node)?;
+                }
+                match max {
+                    None => {
+                        // L: split(body, exit); body; jump L
+                        let split = self.emit(Inst::Split(0, 0))?;
+                        self.compile(node)?;
+                        self.emit(Inst::Jump(split))?;
+                        let exit = self.program.len();
+                        self.program[split] = self.split(split + 1, exit, *greedy);
+                    }
+                    Some(max) => {
+                        // Optional copies, each one skipping straight to the end
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.emit(Inst::Split(0, 0))?);
+                            self.compile(node)?;
+                        }
+                        let exit = self.program.len();
+                        for split in splits {
+                            self.program[split] = self.split(split + 1, exit, *greedy);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The text of the leftmost match of `pattern` in `text`
+    fn find<'a>(pattern: &str, text: &'a str) -> Option<&'a str> {
+        let found = Pattern::compile(pattern).unwrap().find(text)?;
+        Some(&text[found.start..found.end])
+    }
+
+    #[test]
+    fn classes_and_shorthands() {
+        assert_eq!(find("[a-c]+", "xxbcaz"), Some("bca"));
+        assert_eq!(find("[^,]+", ",ab,"), Some("ab"));
+        assert_eq!(find("[]x]+", "a]x]b"), Some("]x]"));
+        assert_eq!(find("[a-]+", "b-a-"), Some("-a-"));
+        assert_eq!(find(r"[\d.]+", "v1.25"), Some("1.25"));
+        assert_eq!(find(r"\d+", "ab 123 c"), Some("123"));
+        assert_eq!(find(r"\D+", "12ab3"), Some("ab"));
+        assert_eq!(find(r"\w+", "  a_1-"), Some("a_1"));
+        assert_eq!(find(r"\W", "ab-c"), Some("-"));
+        assert_eq!(find(r"\s+", "a \t\nb"), Some(" \t\n"));
+        assert_eq!(find(r"\S+", "  ab "), Some("ab"));
+        assert_eq!(find("a.c", "abc"), Some("abc"));
+        assert_eq!(find("a.c", "a\nc"), None);
+        assert_eq!(find(r"\.\(\\", r"x.(\"), Some(r".(\"));
+        assert_eq!(find("é.", "aéö"), Some("éö"));
+    }
+
+    #[test]
+    fn anchors() {
+        assert_eq!(find("^ab", "abab"), Some("ab"));
+        assert_eq!(find("^b", "ab"), None);
+        assert_eq!(find("b$", "abab"), Some("b"));
+        assert_eq!(find("a$", "ab"), None);
+        assert_eq!(find("^$", ""), Some(""));
+        assert_eq!(find("^a|b$", "cab"), Some("b"));
+    }
+
+    #[test]
+    fn alternation_prefers_the_first_alternative() {
+        assert_eq!(find("a|ab", "ab"), Some("a"));
+        assert_eq!(find("ab|a", "ab"), Some("ab"));
+        assert_eq!(find("(?:x|y)+z", "axyxz"), Some("xyxz"));
+        // The leftmost match wins over a later, longer one
+        assert_eq!(find("b|abc", "abc"), Some("abc"));
+    }
+
+    #[test]
+    fn greedy_and_lazy() {
+        assert_eq!(find("<.*>", "<a><b>"), Some("<a><b>"));
+        assert_eq!(find("<.*?>", "<a><b>"), Some("<a>"));
+        assert_eq!(find("a+?", "aaa"), Some("a"));
+        assert_eq!(find("a??b", "ab"), Some("ab"));
+        assert_eq!(find("a{2,3}?", "aaaa"), Some("aa"));
+    }
+
+    #[test]
+    fn counted_repetition() {
+        assert_eq!(find("a{2}", "aaa"), Some("aa"));
+        assert_eq!(find("a{2,}", "aaaa"), Some("aaaa"));
+        assert_eq!(find("a{2,3}", "aaaa"), Some("aaa"));
+        assert_eq!(find("^a{2,3}$", "a"), None);
+        assert_eq!(find("(?:ab){0,2}c", "ababc"), Some("ababc"));
+        assert!(Pattern::compile("a{3,2}").is_err());
+        assert!(Pattern::compile("a{2").is_err());
+        assert!(Pattern::compile("a{,2}").is_err());
+        assert!(Pattern::compile("a**").is_err());
+        assert!(Pattern::compile("*a").is_err());
+    }
+
+    #[test]
+    fn limits() {
+        let nested = |depth| "(".repeat(depth) + "a" + &")".repeat(depth);
+        assert!(Pattern::compile(&nested(MAX_GROUP_DEPTH)).is_ok());
+        assert!(Pattern::compile(&nested(MAX_GROUP_DEPTH + 1)).is_err());
+        assert!(Pattern::compile("a{1000}").is_ok());
+        assert!(Pattern::compile("a{1001}").is_err());
+        // Each copy of the group is compiled, so the program outgrows its limit
+        assert!(Pattern::compile("(?:a{1000}){1000}").is_err());
+        assert!(Pattern::compile("(a").is_err());
+        assert!(Pattern::compile("a)").is_err());
+        assert!(Pattern::compile("[a").is_err());
+        assert!(Pattern::compile("(?=a)").is_err());
+        assert!(Pattern::compile(r"\q").is_err());
+        assert!(Pattern::compile("[z-a]").is_err());
+    }
+
+    #[test]
+    fn request_examples() {
+        let iban = r"IBAN: DE\d{20}";
+        assert_eq!(
+            find(iban, "Account IBAN: DE89370400440532013000 BIC"),
+            Some("IBAN: DE89370400440532013000")
+        );
+        assert_eq!(find(iban, "IBAN: DE8937040044053201300"), None);
+        assert_eq!(
+            find("Total: EUR [0-9.,]+", "Net 10 Total: EUR 1.234,50 due"),
+            Some("Total: EUR 1.234,50")
+        );
+        assert_eq!(find("Total: EUR [0-9.,]+", "Total: USD 12"), None);
+    }
+}
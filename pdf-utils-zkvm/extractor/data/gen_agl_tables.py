@@ -0,0 +1,126 @@
+#!/usr/bin/env python3
+"""Generate the glyph list tables at the top of src/agl.rs.
+
+Reads Adobe's glyphlist.txt (https://github.com/adobe-type-tools/agl-aglfn)
+and rewrites src/agl.rs up to the first function, keeping the hand-written
+code below the tables. Run from this directory:
+
+    python3 gen_agl_tables.py path/to/glyphlist.txt
+"""
+
+import sys
+
+TARGET = "../src/agl.rs"
+# Everything from here on in TARGET is kept as is
+CODE_START = "\n/// Unicode text of a glyph name"
+WIDTH = 100
+
+HEADER = '''\
+//! The Adobe Glyph List 2.0 and the glyph name mapping of the Adobe Glyph List
+//! Specification, generated from `glyphlist.txt` by `data/gen_agl_tables.py`.
+//! Names are sorted and stored back to back in one string, with the end of
+//! each name and its Unicode value in parallel `u16` tables, which keeps the
+//! list small in the guest.
+//!
+//! Glyph list data:
+//!
+//! Copyright 2002-2019 Adobe (http://www.adobe.com/).
+//!
+//! Redistribution and use in source and binary forms, with or without
+//! modification, are permitted provided that the following conditions are
+//! met:
+//!
+//! Redistributions of source code must retain the above copyright notice,
+//! this list of conditions and the following disclaimer.
+//!
+//! Redistributions in binary form must reproduce the above copyright
+//! notice, this list of conditions and the following disclaimer in the
+//! documentation and/or other materials provided with the distribution.
+//!
+//! Neither the name of Adobe nor the names of its contributors may be
+//! used to endorse or promote products derived from this software without
+//! specific prior written permission.
+//!
+//! THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+//! "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+//! LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+//! A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+//! HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+//! SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+//! LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+//! DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+//! THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+//! (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+//! OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use alloc::string::String;
+'''
+
+
+def read_glyph_list(path):
+    singles, sequences = {}, {}
+    with open(path, encoding="ascii") as glyphs:
+        for line in glyphs:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            name, codes = line.split(";")
+            codes = [int(code, 16) for code in codes.split()]
+            if len(codes) == 1:
+                singles[name] = codes[0]
+            else:
+                sequences[name] = codes
+    return singles, sequences
+
+
+def wrap(items):
+    """Pack items onto lines of at most WIDTH columns, as rustfmt does"""
+    lines, line = [], "   "
+    for item in items:
+        if len(line) + len(item) + 2 > WIDTH:
+            lines.append(line)
+            line = "   "
+        line += f" {item},"
+    lines.append(line)
+    return "\n".join(lines)
+
+
+def generate(singles, sequences):
+    names = sorted(singles)
+    ends, end = [], 0
+    for name in names:
+        end += len(name)
+        ends.append(end)
+    assert end <= 0xFFFF, "name offsets must fit in u16"
+
+    out = [HEADER]
+    out.append("/// Glyph names mapped to one code point, sorted and concatenated")
+    out.append('static NAMES: &str = "\\')
+    out.append("\\\n".join(names) + '";')
+    out.append("")
+    out.append("/// Offset in `NAMES` just past each name")
+    out.append(f"static ENDS: [u16; {len(names)}] = [")
+    out.append(wrap(str(end) for end in ends))
+    out.append("];")
+    out.append("")
+    out.append("/// Code point of each name")
+    out.append(f"static UNICODE: [u16; {len(names)}] = [")
+    out.append(wrap(f"0x{singles[name]:04X}" for name in names))
+    out.append("];")
+    out.append("")
+    out.append("/// Glyph names mapped to several code points, sorted")
+    out.append("static SEQUENCES: &[(&str, &str)] = &[")
+    for name in sorted(sequences):
+        text = "".join(f"\\u{{{code:04X}}}" for code in sequences[name])
+        out.append(f'    ("{name}", "{text}"),')
+    out.append("];")
+    return "\n".join(out) + "\n"
+
+
+if len(sys.argv) != 2:
+    sys.exit(__doc__)
+with open(TARGET, encoding="utf-8") as source:
+    code = source.read()
+tables = generate(*read_glyph_list(sys.argv[1]))
+with open(TARGET, "w", encoding="utf-8") as source:
+    source.write(tables + code[code.index(CODE_START) :])
@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+"""Generate the two-byte code tables behind the predefined CJK CMaps.
+
+Each table is a row-major array of little-endian u16 Unicode values, one per
+(lead, trail) pair in the ranges below, 0 where the code is unmapped. The
+mappings come from Python's codecs, which follow the Microsoft code pages the
+Adobe *ms* CMaps are built on. User-defined areas are left unmapped. Run from this directory:
+
+    python3 gen_cjk_tables.py
+"""
+
+import struct
+
+# name: (codec, lead range, trail range), ranges inclusive. Must match cjk.rs.
+TABLES = {
+    "cp932": ("cp932", (0x81, 0xFC), (0x40, 0xFC)),
+    "gbk": ("gbk", (0x81, 0xFE), (0x40, 0xFE)),
+    "cp950": ("cp950", (0x81, 0xFE), (0x40, 0xFE)),
+    "cp949": ("cp949", (0x81, 0xFE), (0x41, 0xFE)),
+}
+
+
+def generate(name, codec, lead, trail):
+    values = []
+    mapped = 0
+    for hi in range(lead[0], lead[1] + 1):
+        for lo in range(trail[0], trail[1] + 1):
+            try:
+                text = bytes([hi, lo]).decode(codec)
+            except UnicodeDecodeError:
+                text = ""
+            unit = ord(text) if len(text) == 1 else 0
+            # User-defined areas decode to the private use area; leave them out
+            if 0 < unit <= 0xFFFF and not 0xE000 <= unit <= 0xF8FF:
+                values.append(unit)
+                mapped += 1
+            else:
+                values.append(0)
+    with open(f"{name}.bin", "wb") as out:
+        out.write(struct.pack(f"<{len(values)}H", *values))
+    print(f"{name}.bin: {mapped} codes, {2 * len(values)} bytes")
+
+
+for name, (codec, lead, trail) in TABLES.items():
+    generate(name, codec, lead, trail)
@@ -0,0 +1,1961 @@
+//! Advance widths of the standard 14 fonts, generated from the Adobe Core14
+//! AFM files. Glyphs are keyed by Unicode scalar value and sorted for binary
+//! search; widths are in thousandths of an em.
+
+pub(crate) static HELVETICA: &[(char, u16)] = &[
+    (' ', 278),
+    ('!', 278),
+    ('"', 355),
+    ('#', 556),
+    ('$', 556),
+    ('%', 889),
+    ('&', 667),
+    ('\'', 191),
+    ('(', 333),
+    (')', 333),
+    ('*', 389),
+    ('+', 584),
+    (',', 278),
+    ('-', 333),
+    ('.', 278),
+    ('/', 278),
+    ('0', 556),
+    ('1', 556),
+    ('2', 556),
+    ('3', 556),
+    ('4', 556),
+    ('5', 556),
+    ('6', 556),
+    ('7', 556),
+    ('8', 556),
+    ('9', 556),
+    (':', 278),
+    (';', 278),
+    ('<', 584),
+    ('=', 584),
+    ('>', 584),
+    ('?', 556),
+    ('@', 1015),
+    ('A', 667),
+    ('B', 667),
+    ('C', 722),
+    ('D', 722),
+    ('E', 667),
+    ('F', 611),
+    ('G', 778),
+    ('H', 722),
+    ('I', 278),
+    ('J', 500),
+    ('K', 667),
+    ('L', 556),
+    ('M', 833),
+    ('N', 722),
+    ('O', 778),
+    ('P', 667),
+    ('Q', 778),
+    ('R', 722),
+    ('S', 667),
+    ('T', 611),
+    ('U', 722),
+    ('V', 667),
+    ('W', 944),
+    ('X', 667),
+    ('Y', 667),
+    ('Z', 611),
+    ('[', 278),
+    ('\\', 278),
+    (']', 278),
+    ('^', 469),
+    ('_', 556),
+    ('`', 333),
+    ('a', 556),
+    ('b', 556),
+    ('c', 500),
+    ('d', 556),
+    ('e', 556),
+    ('f', 278),
+    ('g', 556),
+    ('h', 556),
+    ('i', 222),
+    ('j', 222),
+    ('k', 500),
+    ('l', 222),
+    ('m', 833),
+    ('n', 556),
+    ('o', 556),
+    ('p', 556),
+    ('q', 556),
+    ('r', 333),
+    ('s', 500),
+    ('t', 278),
+    ('u', 556),
+    ('v', 500),
+    ('w', 722),
+    ('x', 500),
+    ('y', 500),
+    ('z', 500),
+    ('{', 334),
+    ('|', 260),
+    ('}', 334),
+    ('~', 584),
+    ('\u{00a1}', 333),
+    ('\u{00a2}', 556),
+    ('\u{00a3}', 556),
+    ('\u{00a4}', 556),
+    ('\u{00a5}', 556),
+    ('\u{00a6}', 260),
+    ('\u{00a7}', 556),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 737),
+    ('\u{00aa}', 370),
+    ('\u{00ab}', 556),
+    ('\u{00ac}', 584),
+    ('\u{00ae}', 737),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 584),
+    ('\u{00b2}', 333),
+    ('\u{00b3}', 333),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 556),
+    ('\u{00b6}', 537),
+    ('\u{00b7}', 278),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 333),
+    ('\u{00ba}', 365),
+    ('\u{00bb}', 556),
+    ('\u{00bc}', 834),
+    ('\u{00bd}', 834),
+    ('\u{00be}', 834),
+    ('\u{00bf}', 611),
+    ('\u{00c0}', 667),
+    ('\u{00c1}', 667),
+    ('\u{00c2}', 667),
+    ('\u{00c3}', 667),
+    ('\u{00c4}', 667),
+    ('\u{00c5}', 667),
+    ('\u{00c6}', 1000),
+    ('\u{00c7}', 722),
+    ('\u{00c8}', 667),
+    ('\u{00c9}', 667),
+    ('\u{00ca}', 667),
+    ('\u{00cb}', 667),
+    ('\u{00cc}', 278),
+    ('\u{00cd}', 278),
+    ('\u{00ce}', 278),
+    ('\u{00cf}', 278),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 722),
+    ('\u{00d2}', 778),
+    ('\u{00d3}', 778),
+    ('\u{00d4}', 778),
+    ('\u{00d5}', 778),
+    ('\u{00d6}', 778),
+    ('\u{00d7}', 584),
+    ('\u{00d8}', 778),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 667),
+    ('\u{00de}', 667),
+    ('\u{00df}', 611),
+    ('\u{00e0}', 556),
+    ('\u{00e1}', 556),
+    ('\u{00e2}', 556),
+    ('\u{00e3}', 556),
+    ('\u{00e4}', 556),
+    ('\u{00e5}', 556),
+    ('\u{00e6}', 889),
+    ('\u{00e7}', 500),
+    ('\u{00e8}', 556),
+    ('\u{00e9}', 556),
+    ('\u{00ea}', 556),
+    ('\u{00eb}', 556),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 556),
+    ('\u{00f1}', 556),
+    ('\u{00f2}', 556),
+    ('\u{00f3}', 556),
+    ('\u{00f4}', 556),
+    ('\u{00f5}', 556),
+    ('\u{00f6}', 556),
+    ('\u{00f7}', 584),
+    ('\u{00f8}', 611),
+    ('\u{00f9}', 556),
+    ('\u{00fa}', 556),
+    ('\u{00fb}', 556),
+    ('\u{00fc}', 556),
+    ('\u{00fd}', 500),
+    ('\u{00fe}', 556),
+    ('\u{00ff}', 500),
+    ('\u{0100}', 667),
+    ('\u{0101}', 556),
+    ('\u{0102}', 667),
+    ('\u{0103}', 556),
+    ('\u{0104}', 667),
+    ('\u{0105}', 556),
+    ('\u{0106}', 722),
+    ('\u{0107}', 500),
+    ('\u{010c}', 722),
+    ('\u{010d}', 500),
+    ('\u{010e}', 722),
+    ('\u{010f}', 643),
+    ('\u{0110}', 722),
+    ('\u{0111}', 556),
+    ('\u{0112}', 667),
+    ('\u{0113}', 556),
+    ('\u{0116}', 667),
+    ('\u{0117}', 556),
+    ('\u{0118}', 667),
+    ('\u{0119}', 556),
+    ('\u{011a}', 667),
+    ('\u{011b}', 556),
+    ('\u{011e}', 778),
+    ('\u{011f}', 556),
+    ('\u{0122}', 778),
+    ('\u{0123}', 556),
+    ('\u{012a}', 278),
+    ('\u{012b}', 278),
+    ('\u{012e}', 278),
+    ('\u{012f}', 222),
+    ('\u{0130}', 278),
+    ('\u{0131}', 278),
+    ('\u{0136}', 667),
+    ('\u{0137}', 500),
+    ('\u{0139}', 556),
+    ('\u{013a}', 222),
+    ('\u{013b}', 556),
+    ('\u{013c}', 222),
+    ('\u{013d}', 556),
+    ('\u{013e}', 299),
+    ('\u{0141}', 556),
+    ('\u{0142}', 222),
+    ('\u{0143}', 722),
+    ('\u{0144}', 556),
+    ('\u{0145}', 722),
+    ('\u{0146}', 556),
+    ('\u{0147}', 722),
+    ('\u{0148}', 556),
+    ('\u{014c}', 778),
+    ('\u{014d}', 556),
+    ('\u{0150}', 778),
+    ('\u{0151}', 556),
+    ('\u{0152}', 1000),
+    ('\u{0153}', 944),
+    ('\u{0154}', 722),
+    ('\u{0155}', 333),
+    ('\u{0156}', 722),
+    ('\u{0157}', 333),
+    ('\u{0158}', 722),
+    ('\u{0159}', 333),
+    ('\u{015a}', 667),
+    ('\u{015b}', 500),
+    ('\u{015e}', 667),
+    ('\u{015f}', 500),
+    ('\u{0160}', 667),
+    ('\u{0161}', 500),
+    ('\u{0162}', 611),
+    ('\u{0163}', 278),
+    ('\u{0164}', 611),
+    ('\u{0165}', 317),
+    ('\u{016a}', 722),
+    ('\u{016b}', 556),
+    ('\u{016e}', 722),
+    ('\u{016f}', 556),
+    ('\u{0170}', 722),
+    ('\u{0171}', 556),
+    ('\u{0172}', 722),
+    ('\u{0173}', 556),
+    ('\u{0178}', 667),
+    ('\u{0179}', 611),
+    ('\u{017a}', 500),
+    ('\u{017b}', 611),
+    ('\u{017c}', 500),
+    ('\u{017d}', 611),
+    ('\u{017e}', 500),
+    ('\u{0192}', 556),
+    ('\u{0218}', 667),
+    ('\u{0219}', 500),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 556),
+    ('\u{2014}', 1000),
+    ('\u{2018}', 222),
+    ('\u{2019}', 222),
+    ('\u{201a}', 222),
+    ('\u{201c}', 333),
+    ('\u{201d}', 333),
+    ('\u{201e}', 333),
+    ('\u{2020}', 556),
+    ('\u{2021}', 556),
+    ('\u{2022}', 350),
+    ('\u{2026}', 1000),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 556),
+    ('\u{2122}', 1000),
+    ('\u{2202}', 476),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 584),
+    ('\u{221a}', 453),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 471),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 500),
+    ('\u{fb02}', 500),
+];
+
+pub(crate) static HELVETICA_BOLD: &[(char, u16)] = &[
+    (' ', 278),
+    ('!', 333),
+    ('"', 474),
+    ('#', 556),
+    ('$', 556),
+    ('%', 889),
+    ('&', 722),
+    ('\'', 238),
+    ('(', 333),
+    (')', 333),
+    ('*', 389),
+    ('+', 584),
+    (',', 278),
+    ('-', 333),
+    ('.', 278),
+    ('/', 278),
+    ('0', 556),
+    ('1', 556),
+    ('2', 556),
+    ('3', 556),
+    ('4', 556),
+    ('5', 556),
+    ('6', 556),
+    ('7', 556),
+    ('8', 556),
+    ('9', 556),
+    (':', 333),
+    (';', 333),
+    ('<', 584),
+    ('=', 584),
+    ('>', 584),
+    ('?', 611),
+    ('@', 975),
+    ('A', 722),
+    ('B', 722),
+    ('C', 722),
+    ('D', 722),
+    ('E', 667),
+    ('F', 611),
+    ('G', 778),
+    ('H', 722),
+    ('I', 278),
+    ('J', 556),
+    ('K', 722),
+    ('L', 611),
+    ('M', 833),
+    ('N', 722),
+    ('O', 778),
+    ('P', 667),
+    ('Q', 778),
+    ('R', 722),
+    ('S', 667),
+    ('T', 611),
+    ('U', 722),
+    ('V', 667),
+    ('W', 944),
+    ('X', 667),
+    ('Y', 667),
+    ('Z', 611),
+    ('[', 333),
+    ('\\', 278),
+    (']', 333),
+    ('^', 584),
+    ('_', 556),
+    ('`', 333),
+    ('a', 556),
+    ('b', 611),
+    ('c', 556),
+    ('d', 611),
+    ('e', 556),
+    ('f', 333),
+    ('g', 611),
+    ('h', 611),
+    ('i', 278),
+    ('j', 278),
+    ('k', 556),
+    ('l', 278),
+    ('m', 889),
+    ('n', 611),
+    ('o', 611),
+    ('p', 611),
+    ('q', 611),
+    ('r', 389),
+    ('s', 556),
+    ('t', 333),
+    ('u', 611),
+    ('v', 556),
+    ('w', 778),
+    ('x', 556),
+    ('y', 556),
+    ('z', 500),
+    ('{', 389),
+    ('|', 280),
+    ('}', 389),
+    ('~', 584),
+    ('\u{00a1}', 333),
+    ('\u{00a2}', 556),
+    ('\u{00a3}', 556),
+    ('\u{00a4}', 556),
+    ('\u{00a5}', 556),
+    ('\u{00a6}', 280),
+    ('\u{00a7}', 556),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 737),
+    ('\u{00aa}', 370),
+    ('\u{00ab}', 556),
+    ('\u{00ac}', 584),
+    ('\u{00ae}', 737),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 584),
+    ('\u{00b2}', 333),
+    ('\u{00b3}', 333),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 611),
+    ('\u{00b6}', 556),
+    ('\u{00b7}', 278),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 333),
+    ('\u{00ba}', 365),
+    ('\u{00bb}', 556),
+    ('\u{00bc}', 834),
+    ('\u{00bd}', 834),
+    ('\u{00be}', 834),
+    ('\u{00bf}', 611),
+    ('\u{00c0}', 722),
+    ('\u{00c1}', 722),
+    ('\u{00c2}', 722),
+    ('\u{00c3}', 722),
+    ('\u{00c4}', 722),
+    ('\u{00c5}', 722),
+    ('\u{00c6}', 1000),
+    ('\u{00c7}', 722),
+    ('\u{00c8}', 667),
+    ('\u{00c9}', 667),
+    ('\u{00ca}', 667),
+    ('\u{00cb}', 667),
+    ('\u{00cc}', 278),
+    ('\u{00cd}', 278),
+    ('\u{00ce}', 278),
+    ('\u{00cf}', 278),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 722),
+    ('\u{00d2}', 778),
+    ('\u{00d3}', 778),
+    ('\u{00d4}', 778),
+    ('\u{00d5}', 778),
+    ('\u{00d6}', 778),
+    ('\u{00d7}', 584),
+    ('\u{00d8}', 778),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 667),
+    ('\u{00de}', 667),
+    ('\u{00df}', 611),
+    ('\u{00e0}', 556),
+    ('\u{00e1}', 556),
+    ('\u{00e2}', 556),
+    ('\u{00e3}', 556),
+    ('\u{00e4}', 556),
+    ('\u{00e5}', 556),
+    ('\u{00e6}', 889),
+    ('\u{00e7}', 556),
+    ('\u{00e8}', 556),
+    ('\u{00e9}', 556),
+    ('\u{00ea}', 556),
+    ('\u{00eb}', 556),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 611),
+    ('\u{00f1}', 611),
+    ('\u{00f2}', 611),
+    ('\u{00f3}', 611),
+    ('\u{00f4}', 611),
+    ('\u{00f5}', 611),
+    ('\u{00f6}', 611),
+    ('\u{00f7}', 584),
+    ('\u{00f8}', 611),
+    ('\u{00f9}', 611),
+    ('\u{00fa}', 611),
+    ('\u{00fb}', 611),
+    ('\u{00fc}', 611),
+    ('\u{00fd}', 556),
+    ('\u{00fe}', 611),
+    ('\u{00ff}', 556),
+    ('\u{0100}', 722),
+    ('\u{0101}', 556),
+    ('\u{0102}', 722),
+    ('\u{0103}', 556),
+    ('\u{0104}', 722),
+    ('\u{0105}', 556),
+    ('\u{0106}', 722),
+    ('\u{0107}', 556),
+    ('\u{010c}', 722),
+    ('\u{010d}', 556),
+    ('\u{010e}', 722),
+    ('\u{010f}', 743),
+    ('\u{0110}', 722),
+    ('\u{0111}', 611),
+    ('\u{0112}', 667),
+    ('\u{0113}', 556),
+    ('\u{0116}', 667),
+    ('\u{0117}', 556),
+    ('\u{0118}', 667),
+    ('\u{0119}', 556),
+    ('\u{011a}', 667),
+    ('\u{011b}', 556),
+    ('\u{011e}', 778),
+    ('\u{011f}', 611),
+    ('\u{0122}', 778),
+    ('\u{0123}', 611),
+    ('\u{012a}', 278),
+    ('\u{012b}', 278),
+    ('\u{012e}', 278),
+    ('\u{012f}', 278),
+    ('\u{0130}', 278),
+    ('\u{0131}', 278),
+    ('\u{0136}', 722),
+    ('\u{0137}', 556),
+    ('\u{0139}', 611),
+    ('\u{013a}', 278),
+    ('\u{013b}', 611),
+    ('\u{013c}', 278),
+    ('\u{013d}', 611),
+    ('\u{013e}', 400),
+    ('\u{0141}', 611),
+    ('\u{0142}', 278),
+    ('\u{0143}', 722),
+    ('\u{0144}', 611),
+    ('\u{0145}', 722),
+    ('\u{0146}', 611),
+    ('\u{0147}', 722),
+    ('\u{0148}', 611),
+    ('\u{014c}', 778),
+    ('\u{014d}', 611),
+    ('\u{0150}', 778),
+    ('\u{0151}', 611),
+    ('\u{0152}', 1000),
+    ('\u{0153}', 944),
+    ('\u{0154}', 722),
+    ('\u{0155}', 389),
+    ('\u{0156}', 722),
+    ('\u{0157}', 389),
+    ('\u{0158}', 722),
+    ('\u{0159}', 389),
+    ('\u{015a}', 667),
+    ('\u{015b}', 556),
+    ('\u{015e}', 667),
+    ('\u{015f}', 556),
+    ('\u{0160}', 667),
+    ('\u{0161}', 556),
+    ('\u{0162}', 611),
+    ('\u{0163}', 333),
+    ('\u{0164}', 611),
+    ('\u{0165}', 389),
+    ('\u{016a}', 722),
+    ('\u{016b}', 611),
+    ('\u{016e}', 722),
+    ('\u{016f}', 611),
+    ('\u{0170}', 722),
+    ('\u{0171}', 611),
+    ('\u{0172}', 722),
+    ('\u{0173}', 611),
+    ('\u{0178}', 667),
+    ('\u{0179}', 611),
+    ('\u{017a}', 500),
+    ('\u{017b}', 611),
+    ('\u{017c}', 500),
+    ('\u{017d}', 611),
+    ('\u{017e}', 500),
+    ('\u{0192}', 556),
+    ('\u{0218}', 667),
+    ('\u{0219}', 556),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 556),
+    ('\u{2014}', 1000),
+    ('\u{2018}', 278),
+    ('\u{2019}', 278),
+    ('\u{201a}', 278),
+    ('\u{201c}', 500),
+    ('\u{201d}', 500),
+    ('\u{201e}', 500),
+    ('\u{2020}', 556),
+    ('\u{2021}', 556),
+    ('\u{2022}', 350),
+    ('\u{2026}', 1000),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 556),
+    ('\u{2122}', 1000),
+    ('\u{2202}', 494),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 584),
+    ('\u{221a}', 549),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 494),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 611),
+    ('\u{fb02}', 611),
+];
+
+pub(crate) static TIMES_ROMAN: &[(char, u16)] = &[
+    (' ', 250),
+    ('!', 333),
+    ('"', 408),
+    ('#', 500),
+    ('$', 500),
+    ('%', 833),
+    ('&', 778),
+    ('\'', 180),
+    ('(', 333),
+    (')', 333),
+    ('*', 500),
+    ('+', 564),
+    (',', 250),
+    ('-', 333),
+    ('.', 250),
+    ('/', 278),
+    ('0', 500),
+    ('1', 500),
+    ('2', 500),
+    ('3', 500),
+    ('4', 500),
+    ('5', 500),
+    ('6', 500),
+    ('7', 500),
+    ('8', 500),
+    ('9', 500),
+    (':', 278),
+    (';', 278),
+    ('<', 564),
+    ('=', 564),
+    ('>', 564),
+    ('?', 444),
+    ('@', 921),
+    ('A', 722),
+    ('B', 667),
+    ('C', 667),
+    ('D', 722),
+    ('E', 611),
+    ('F', 556),
+    ('G', 722),
+    ('H', 722),
+    ('I', 333),
+    ('J', 389),
+    ('K', 722),
+    ('L', 611),
+    ('M', 889),
+    ('N', 722),
+    ('O', 722),
+    ('P', 556),
+    ('Q', 722),
+    ('R', 667),
+    ('S', 556),
+    ('T', 611),
+    ('U', 722),
+    ('V', 722),
+    ('W', 944),
+    ('X', 722),
+    ('Y', 722),
+    ('Z', 611),
+    ('[', 333),
+    ('\\', 278),
+    (']', 333),
+    ('^', 469),
+    ('_', 500),
+    ('`', 333),
+    ('a', 444),
+    ('b', 500),
+    ('c', 444),
+    ('d', 500),
+    ('e', 444),
+    ('f', 333),
+    ('g', 500),
+    ('h', 500),
+    ('i', 278),
+    ('j', 278),
+    ('k', 500),
+    ('l', 278),
+    ('m', 778),
+    ('n', 500),
+    ('o', 500),
+    ('p', 500),
+    ('q', 500),
+    ('r', 333),
+    ('s', 389),
+    ('t', 278),
+    ('u', 500),
+    ('v', 500),
+    ('w', 722),
+    ('x', 500),
+    ('y', 500),
+    ('z', 444),
+    ('{', 480),
+    ('|', 200),
+    ('}', 480),
+    ('~', 541),
+    ('\u{00a1}', 333),
+    ('\u{00a2}', 500),
+    ('\u{00a3}', 500),
+    ('\u{00a4}', 500),
+    ('\u{00a5}', 500),
+    ('\u{00a6}', 200),
+    ('\u{00a7}', 500),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 760),
+    ('\u{00aa}', 276),
+    ('\u{00ab}', 500),
+    ('\u{00ac}', 564),
+    ('\u{00ae}', 760),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 564),
+    ('\u{00b2}', 300),
+    ('\u{00b3}', 300),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 500),
+    ('\u{00b6}', 453),
+    ('\u{00b7}', 250),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 300),
+    ('\u{00ba}', 310),
+    ('\u{00bb}', 500),
+    ('\u{00bc}', 750),
+    ('\u{00bd}', 750),
+    ('\u{00be}', 750),
+    ('\u{00bf}', 444),
+    ('\u{00c0}', 722),
+    ('\u{00c1}', 722),
+    ('\u{00c2}', 722),
+    ('\u{00c3}', 722),
+    ('\u{00c4}', 722),
+    ('\u{00c5}', 722),
+    ('\u{00c6}', 889),
+    ('\u{00c7}', 667),
+    ('\u{00c8}', 611),
+    ('\u{00c9}', 611),
+    ('\u{00ca}', 611),
+    ('\u{00cb}', 611),
+    ('\u{00cc}', 333),
+    ('\u{00cd}', 333),
+    ('\u{00ce}', 333),
+    ('\u{00cf}', 333),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 722),
+    ('\u{00d2}', 722),
+    ('\u{00d3}', 722),
+    ('\u{00d4}', 722),
+    ('\u{00d5}', 722),
+    ('\u{00d6}', 722),
+    ('\u{00d7}', 564),
+    ('\u{00d8}', 722),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 722),
+    ('\u{00de}', 556),
+    ('\u{00df}', 500),
+    ('\u{00e0}', 444),
+    ('\u{00e1}', 444),
+    ('\u{00e2}', 444),
+    ('\u{00e3}', 444),
+    ('\u{00e4}', 444),
+    ('\u{00e5}', 444),
+    ('\u{00e6}', 667),
+    ('\u{00e7}', 444),
+    ('\u{00e8}', 444),
+    ('\u{00e9}', 444),
+    ('\u{00ea}', 444),
+    ('\u{00eb}', 444),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 500),
+    ('\u{00f1}', 500),
+    ('\u{00f2}', 500),
+    ('\u{00f3}', 500),
+    ('\u{00f4}', 500),
+    ('\u{00f5}', 500),
+    ('\u{00f6}', 500),
+    ('\u{00f7}', 564),
+    ('\u{00f8}', 500),
+    ('\u{00f9}', 500),
+    ('\u{00fa}', 500),
+    ('\u{00fb}', 500),
+    ('\u{00fc}', 500),
+    ('\u{00fd}', 500),
+    ('\u{00fe}', 500),
+    ('\u{00ff}', 500),
+    ('\u{0100}', 722),
+    ('\u{0101}', 444),
+    ('\u{0102}', 722),
+    ('\u{0103}', 444),
+    ('\u{0104}', 722),
+    ('\u{0105}', 444),
+    ('\u{0106}', 667),
+    ('\u{0107}', 444),
+    ('\u{010c}', 667),
+    ('\u{010d}', 444),
+    ('\u{010e}', 722),
+    ('\u{010f}', 588),
+    ('\u{0110}', 722),
+    ('\u{0111}', 500),
+    ('\u{0112}', 611),
+    ('\u{0113}', 444),
+    ('\u{0116}', 611),
+    ('\u{0117}', 444),
+    ('\u{0118}', 611),
+    ('\u{0119}', 444),
+    ('\u{011a}', 611),
+    ('\u{011b}', 444),
+    ('\u{011e}', 722),
+    ('\u{011f}', 500),
+    ('\u{0122}', 722),
+    ('\u{0123}', 500),
+    ('\u{012a}', 333),
+    ('\u{012b}', 278),
+    ('\u{012e}', 333),
+    ('\u{012f}', 278),
+    ('\u{0130}', 333),
+    ('\u{0131}', 278),
+    ('\u{0136}', 722),
+    ('\u{0137}', 500),
+    ('\u{0139}', 611),
+    ('\u{013a}', 278),
+    ('\u{013b}', 611),
+    ('\u{013c}', 278),
+    ('\u{013d}', 611),
+    ('\u{013e}', 344),
+    ('\u{0141}', 611),
+    ('\u{0142}', 278),
+    ('\u{0143}', 722),
+    ('\u{0144}', 500),
+    ('\u{0145}', 722),
+    ('\u{0146}', 500),
+    ('\u{0147}', 722),
+    ('\u{0148}', 500),
+    ('\u{014c}', 722),
+    ('\u{014d}', 500),
+    ('\u{0150}', 722),
+    ('\u{0151}', 500),
+    ('\u{0152}', 889),
+    ('\u{0153}', 722),
+    ('\u{0154}', 667),
+    ('\u{0155}', 333),
+    ('\u{0156}', 667),
+    ('\u{0157}', 333),
+    ('\u{0158}', 667),
+    ('\u{0159}', 333),
+    ('\u{015a}', 556),
+    ('\u{015b}', 389),
+    ('\u{015e}', 556),
+    ('\u{015f}', 389),
+    ('\u{0160}', 556),
+    ('\u{0161}', 389),
+    ('\u{0162}', 611),
+    ('\u{0163}', 278),
+    ('\u{0164}', 611),
+    ('\u{0165}', 326),
+    ('\u{016a}', 722),
+    ('\u{016b}', 500),
+    ('\u{016e}', 722),
+    ('\u{016f}', 500),
+    ('\u{0170}', 722),
+    ('\u{0171}', 500),
+    ('\u{0172}', 722),
+    ('\u{0173}', 500),
+    ('\u{0178}', 722),
+    ('\u{0179}', 611),
+    ('\u{017a}', 444),
+    ('\u{017b}', 611),
+    ('\u{017c}', 444),
+    ('\u{017d}', 611),
+    ('\u{017e}', 444),
+    ('\u{0192}', 500),
+    ('\u{0218}', 556),
+    ('\u{0219}', 389),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 500),
+    ('\u{2014}', 1000),
+    ('\u{2018}', 333),
+    ('\u{2019}', 333),
+    ('\u{201a}', 333),
+    ('\u{201c}', 444),
+    ('\u{201d}', 444),
+    ('\u{201e}', 444),
+    ('\u{2020}', 500),
+    ('\u{2021}', 500),
+    ('\u{2022}', 350),
+    ('\u{2026}', 1000),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 500),
+    ('\u{2122}', 980),
+    ('\u{2202}', 476),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 564),
+    ('\u{221a}', 453),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 471),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 556),
+    ('\u{fb02}', 556),
+];
+
+pub(crate) static TIMES_BOLD: &[(char, u16)] = &[
+    (' ', 250),
+    ('!', 333),
+    ('"', 555),
+    ('#', 500),
+    ('$', 500),
+    ('%', 1000),
+    ('&', 833),
+    ('\'', 278),
+    ('(', 333),
+    (')', 333),
+    ('*', 500),
+    ('+', 570),
+    (',', 250),
+    ('-', 333),
+    ('.', 250),
+    ('/', 278),
+    ('0', 500),
+    ('1', 500),
+    ('2', 500),
+    ('3', 500),
+    ('4', 500),
+    ('5', 500),
+    ('6', 500),
+    ('7', 500),
+    ('8', 500),
+    ('9', 500),
+    (':', 333),
+    (';', 333),
+    ('<', 570),
+    ('=', 570),
+    ('>', 570),
+    ('?', 500),
+    ('@', 930),
+    ('A', 722),
+    ('B', 667),
+    ('C', 722),
+    ('D', 722),
+    ('E', 667),
+    ('F', 611),
+    ('G', 778),
+    ('H', 778),
+    ('I', 389),
+    ('J', 500),
+    ('K', 778),
+    ('L', 667),
+    ('M', 944),
+    ('N', 722),
+    ('O', 778),
+    ('P', 611),
+    ('Q', 778),
+    ('R', 722),
+    ('S', 556),
+    ('T', 667),
+    ('U', 722),
+    ('V', 722),
+    ('W', 1000),
+    ('X', 722),
+    ('Y', 722),
+    ('Z', 667),
+    ('[', 333),
+    ('\\', 278),
+    (']', 333),
+    ('^', 581),
+    ('_', 500),
+    ('`', 333),
+    ('a', 500),
+    ('b', 556),
+    ('c', 444),
+    ('d', 556),
+    ('e', 444),
+    ('f', 333),
+    ('g', 500),
+    ('h', 556),
+    ('i', 278),
+    ('j', 333),
+    ('k', 556),
+    ('l', 278),
+    ('m', 833),
+    ('n', 556),
+    ('o', 500),
+    ('p', 556),
+    ('q', 556),
+    ('r', 444),
+    ('s', 389),
+    ('t', 333),
+    ('u', 556),
+    ('v', 500),
+    ('w', 722),
+    ('x', 500),
+    ('y', 500),
+    ('z', 444),
+    ('{', 394),
+    ('|', 220),
+    ('}', 394),
+    ('~', 520),
+    ('\u{00a1}', 333),
+    ('\u{00a2}', 500),
+    ('\u{00a3}', 500),
+    ('\u{00a4}', 500),
+    ('\u{00a5}', 500),
+    ('\u{00a6}', 220),
+    ('\u{00a7}', 500),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 747),
+    ('\u{00aa}', 300),
+    ('\u{00ab}', 500),
+    ('\u{00ac}', 570),
+    ('\u{00ae}', 747),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 570),
+    ('\u{00b2}', 300),
+    ('\u{00b3}', 300),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 556),
+    ('\u{00b6}', 540),
+    ('\u{00b7}', 250),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 300),
+    ('\u{00ba}', 330),
+    ('\u{00bb}', 500),
+    ('\u{00bc}', 750),
+    ('\u{00bd}', 750),
+    ('\u{00be}', 750),
+    ('\u{00bf}', 500),
+    ('\u{00c0}', 722),
+    ('\u{00c1}', 722),
+    ('\u{00c2}', 722),
+    ('\u{00c3}', 722),
+    ('\u{00c4}', 722),
+    ('\u{00c5}', 722),
+    ('\u{00c6}', 1000),
+    ('\u{00c7}', 722),
+    ('\u{00c8}', 667),
+    ('\u{00c9}', 667),
+    ('\u{00ca}', 667),
+    ('\u{00cb}', 667),
+    ('\u{00cc}', 389),
+    ('\u{00cd}', 389),
+    ('\u{00ce}', 389),
+    ('\u{00cf}', 389),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 722),
+    ('\u{00d2}', 778),
+    ('\u{00d3}', 778),
+    ('\u{00d4}', 778),
+    ('\u{00d5}', 778),
+    ('\u{00d6}', 778),
+    ('\u{00d7}', 570),
+    ('\u{00d8}', 778),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 722),
+    ('\u{00de}', 611),
+    ('\u{00df}', 556),
+    ('\u{00e0}', 500),
+    ('\u{00e1}', 500),
+    ('\u{00e2}', 500),
+    ('\u{00e3}', 500),
+    ('\u{00e4}', 500),
+    ('\u{00e5}', 500),
+    ('\u{00e6}', 722),
+    ('\u{00e7}', 444),
+    ('\u{00e8}', 444),
+    ('\u{00e9}', 444),
+    ('\u{00ea}', 444),
+    ('\u{00eb}', 444),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 500),
+    ('\u{00f1}', 556),
+    ('\u{00f2}', 500),
+    ('\u{00f3}', 500),
+    ('\u{00f4}', 500),
+    ('\u{00f5}', 500),
+    ('\u{00f6}', 500),
+    ('\u{00f7}', 570),
+    ('\u{00f8}', 500),
+    ('\u{00f9}', 556),
+    ('\u{00fa}', 556),
+    ('\u{00fb}', 556),
+    ('\u{00fc}', 556),
+    ('\u{00fd}', 500),
+    ('\u{00fe}', 556),
+    ('\u{00ff}', 500),
+    ('\u{0100}', 722),
+    ('\u{0101}', 500),
+    ('\u{0102}', 722),
+    ('\u{0103}', 500),
+    ('\u{0104}', 722),
+    ('\u{0105}', 500),
+    ('\u{0106}', 722),
+    ('\u{0107}', 444),
+    ('\u{010c}', 722),
+    ('\u{010d}', 444),
+    ('\u{010e}', 722),
+    ('\u{010f}', 672),
+    ('\u{0110}', 722),
+    ('\u{0111}', 556),
+    ('\u{0112}', 667),
+    ('\u{0113}', 444),
+    ('\u{0116}', 667),
+    ('\u{0117}', 444),
+    ('\u{0118}', 667),
+    ('\u{0119}', 444),
+    ('\u{011a}', 667),
+    ('\u{011b}', 444),
+    ('\u{011e}', 778),
+    ('\u{011f}', 500),
+    ('\u{0122}', 778),
+    ('\u{0123}', 500),
+    ('\u{012a}', 389),
+    ('\u{012b}', 278),
+    ('\u{012e}', 389),
+    ('\u{012f}', 278),
+    ('\u{0130}', 389),
+    ('\u{0131}', 278),
+    ('\u{0136}', 778),
+    ('\u{0137}', 556),
+    ('\u{0139}', 667),
+    ('\u{013a}', 278),
+    ('\u{013b}', 667),
+    ('\u{013c}', 278),
+    ('\u{013d}', 667),
+    ('\u{013e}', 394),
+    ('\u{0141}', 667),
+    ('\u{0142}', 278),
+    ('\u{0143}', 722),
+    ('\u{0144}', 556),
+    ('\u{0145}', 722),
+    ('\u{0146}', 556),
+    ('\u{0147}', 722),
+    ('\u{0148}', 556),
+    ('\u{014c}', 778),
+    ('\u{014d}', 500),
+    ('\u{0150}', 778),
+    ('\u{0151}', 500),
+    ('\u{0152}', 1000),
+    ('\u{0153}', 722),
+    ('\u{0154}', 722),
+    ('\u{0155}', 444),
+    ('\u{0156}', 722),
+    ('\u{0157}', 444),
+    ('\u{0158}', 722),
+    ('\u{0159}', 444),
+    ('\u{015a}', 556),
+    ('\u{015b}', 389),
+    ('\u{015e}', 556),
+    ('\u{015f}', 389),
+    ('\u{0160}', 556),
+    ('\u{0161}', 389),
+    ('\u{0162}', 667),
+    ('\u{0163}', 333),
+    ('\u{0164}', 667),
+    ('\u{0165}', 416),
+    ('\u{016a}', 722),
+    ('\u{016b}', 556),
+    ('\u{016e}', 722),
+    ('\u{016f}', 556),
+    ('\u{0170}', 722),
+    ('\u{0171}', 556),
+    ('\u{0172}', 722),
+    ('\u{0173}', 556),
+    ('\u{0178}', 722),
+    ('\u{0179}', 667),
+    ('\u{017a}', 444),
+    ('\u{017b}', 667),
+    ('\u{017c}', 444),
+    ('\u{017d}', 667),
+    ('\u{017e}', 444),
+    ('\u{0192}', 500),
+    ('\u{0218}', 556),
+    ('\u{0219}', 389),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 500),
+    ('\u{2014}', 1000),
+    ('\u{2018}', 333),
+    ('\u{2019}', 333),
+    ('\u{201a}', 333),
+    ('\u{201c}', 500),
+    ('\u{201d}', 500),
+    ('\u{201e}', 500),
+    ('\u{2020}', 500),
+    ('\u{2021}', 500),
+    ('\u{2022}', 350),
+    ('\u{2026}', 1000),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 500),
+    ('\u{2122}', 1000),
+    ('\u{2202}', 494),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 570),
+    ('\u{221a}', 549),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 494),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 556),
+    ('\u{fb02}', 556),
+];
+
+pub(crate) static TIMES_ITALIC: &[(char, u16)] = &[
+    (' ', 250),
+    ('!', 333),
+    ('"', 420),
+    ('#', 500),
+    ('$', 500),
+    ('%', 833),
+    ('&', 778),
+    ('\'', 214),
+    ('(', 333),
+    (')', 333),
+    ('*', 500),
+    ('+', 675),
+    (',', 250),
+    ('-', 333),
+    ('.', 250),
+    ('/', 278),
+    ('0', 500),
+    ('1', 500),
+    ('2', 500),
+    ('3', 500),
+    ('4', 500),
+    ('5', 500),
+    ('6', 500),
+    ('7', 500),
+    ('8', 500),
+    ('9', 500),
+    (':', 333),
+    (';', 333),
+    ('<', 675),
+    ('=', 675),
+    ('>', 675),
+    ('?', 500),
+    ('@', 920),
+    ('A', 611),
+    ('B', 611),
+    ('C', 667),
+    ('D', 722),
+    ('E', 611),
+    ('F', 611),
+    ('G', 722),
+    ('H', 722),
+    ('I', 333),
+    ('J', 444),
+    ('K', 667),
+    ('L', 556),
+    ('M', 833),
+    ('N', 667),
+    ('O', 722),
+    ('P', 611),
+    ('Q', 722),
+    ('R', 611),
+    ('S', 500),
+    ('T', 556),
+    ('U', 722),
+    ('V', 611),
+    ('W', 833),
+    ('X', 611),
+    ('Y', 556),
+    ('Z', 556),
+    ('[', 389),
+    ('\\', 278),
+    (']', 389),
+    ('^', 422),
+    ('_', 500),
+    ('`', 333),
+    ('a', 500),
+    ('b', 500),
+    ('c', 444),
+    ('d', 500),
+    ('e', 444),
+    ('f', 278),
+    ('g', 500),
+    ('h', 500),
+    ('i', 278),
+    ('j', 278),
+    ('k', 444),
+    ('l', 278),
+    ('m', 722),
+    ('n', 500),
+    ('o', 500),
+    ('p', 500),
+    ('q', 500),
+    ('r', 389),
+    ('s', 389),
+    ('t', 278),
+    ('u', 500),
+    ('v', 444),
+    ('w', 667),
+    ('x', 444),
+    ('y', 444),
+    ('z', 389),
+    ('{', 400),
+    ('|', 275),
+    ('}', 400),
+    ('~', 541),
+    ('\u{00a1}', 389),
+    ('\u{00a2}', 500),
+    ('\u{00a3}', 500),
+    ('\u{00a4}', 500),
+    ('\u{00a5}', 500),
+    ('\u{00a6}', 275),
+    ('\u{00a7}', 500),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 760),
+    ('\u{00aa}', 276),
+    ('\u{00ab}', 500),
+    ('\u{00ac}', 675),
+    ('\u{00ae}', 760),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 675),
+    ('\u{00b2}', 300),
+    ('\u{00b3}', 300),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 500),
+    ('\u{00b6}', 523),
+    ('\u{00b7}', 250),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 300),
+    ('\u{00ba}', 310),
+    ('\u{00bb}', 500),
+    ('\u{00bc}', 750),
+    ('\u{00bd}', 750),
+    ('\u{00be}', 750),
+    ('\u{00bf}', 500),
+    ('\u{00c0}', 611),
+    ('\u{00c1}', 611),
+    ('\u{00c2}', 611),
+    ('\u{00c3}', 611),
+    ('\u{00c4}', 611),
+    ('\u{00c5}', 611),
+    ('\u{00c6}', 889),
+    ('\u{00c7}', 667),
+    ('\u{00c8}', 611),
+    ('\u{00c9}', 611),
+    ('\u{00ca}', 611),
+    ('\u{00cb}', 611),
+    ('\u{00cc}', 333),
+    ('\u{00cd}', 333),
+    ('\u{00ce}', 333),
+    ('\u{00cf}', 333),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 667),
+    ('\u{00d2}', 722),
+    ('\u{00d3}', 722),
+    ('\u{00d4}', 722),
+    ('\u{00d5}', 722),
+    ('\u{00d6}', 722),
+    ('\u{00d7}', 675),
+    ('\u{00d8}', 722),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 556),
+    ('\u{00de}', 611),
+    ('\u{00df}', 500),
+    ('\u{00e0}', 500),
+    ('\u{00e1}', 500),
+    ('\u{00e2}', 500),
+    ('\u{00e3}', 500),
+    ('\u{00e4}', 500),
+    ('\u{00e5}', 500),
+    ('\u{00e6}', 667),
+    ('\u{00e7}', 444),
+    ('\u{00e8}', 444),
+    ('\u{00e9}', 444),
+    ('\u{00ea}', 444),
+    ('\u{00eb}', 444),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 500),
+    ('\u{00f1}', 500),
+    ('\u{00f2}', 500),
+    ('\u{00f3}', 500),
+    ('\u{00f4}', 500),
+    ('\u{00f5}', 500),
+    ('\u{00f6}', 500),
+    ('\u{00f7}', 675),
+    ('\u{00f8}', 500),
+    ('\u{00f9}', 500),
+    ('\u{00fa}', 500),
+    ('\u{00fb}', 500),
+    ('\u{00fc}', 500),
+    ('\u{00fd}', 444),
+    ('\u{00fe}', 500),
+    ('\u{00ff}', 444),
+    ('\u{0100}', 611),
+    ('\u{0101}', 500),
+    ('\u{0102}', 611),
+    ('\u{0103}', 500),
+    ('\u{0104}', 611),
+    ('\u{0105}', 500),
+    ('\u{0106}', 667),
+    ('\u{0107}', 444),
+    ('\u{010c}', 667),
+    ('\u{010d}', 444),
+    ('\u{010e}', 722),
+    ('\u{010f}', 544),
+    ('\u{0110}', 722),
+    ('\u{0111}', 500),
+    ('\u{0112}', 611),
+    ('\u{0113}', 444),
+    ('\u{0116}', 611),
+    ('\u{0117}', 444),
+    ('\u{0118}', 611),
+    ('\u{0119}', 444),
+    ('\u{011a}', 611),
+    ('\u{011b}', 444),
+    ('\u{011e}', 722),
+    ('\u{011f}', 500),
+    ('\u{0122}', 722),
+    ('\u{0123}', 500),
+    ('\u{012a}', 333),
+    ('\u{012b}', 278),
+    ('\u{012e}', 333),
+    ('\u{012f}', 278),
+    ('\u{0130}', 333),
+    ('\u{0131}', 278),
+    ('\u{0136}', 667),
+    ('\u{0137}', 444),
+    ('\u{0139}', 556),
+    ('\u{013a}', 278),
+    ('\u{013b}', 556),
+    ('\u{013c}', 278),
+    ('\u{013d}', 611),
+    ('\u{013e}', 300),
+    ('\u{0141}', 556),
+    ('\u{0142}', 278),
+    ('\u{0143}', 667),
+    ('\u{0144}', 500),
+    ('\u{0145}', 667),
+    ('\u{0146}', 500),
+    ('\u{0147}', 667),
+    ('\u{0148}', 500),
+    ('\u{014c}', 722),
+    ('\u{014d}', 500),
+    ('\u{0150}', 722),
+    ('\u{0151}', 500),
+    ('\u{0152}', 944),
+    ('\u{0153}', 667),
+    ('\u{0154}', 611),
+    ('\u{0155}', 389),
+    ('\u{0156}', 611),
+    ('\u{0157}', 389),
+    ('\u{0158}', 611),
+    ('\u{0159}', 389),
+    ('\u{015a}', 500),
+    ('\u{015b}', 389),
+    ('\u{015e}', 500),
+    ('\u{015f}', 389),
+    ('\u{0160}', 500),
+    ('\u{0161}', 389),
+    ('\u{0162}', 556),
+    ('\u{0163}', 278),
+    ('\u{0164}', 556),
+    ('\u{0165}', 300),
+    ('\u{016a}', 722),
+    ('\u{016b}', 500),
+    ('\u{016e}', 722),
+    ('\u{016f}', 500),
+    ('\u{0170}', 722),
+    ('\u{0171}', 500),
+    ('\u{0172}', 722),
+    ('\u{0173}', 500),
+    ('\u{0178}', 556),
+    ('\u{0179}', 556),
+    ('\u{017a}', 389),
+    ('\u{017b}', 556),
+    ('\u{017c}', 389),
+    ('\u{017d}', 556),
+    ('\u{017e}', 389),
+    ('\u{0192}', 500),
+    ('\u{0218}', 500),
+    ('\u{0219}', 389),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 500),
+    ('\u{2014}', 889),
+    ('\u{2018}', 333),
+    ('\u{2019}', 333),
+    ('\u{201a}', 333),
+    ('\u{201c}', 556),
+    ('\u{201d}', 556),
+    ('\u{201e}', 556),
+    ('\u{2020}', 500),
+    ('\u{2021}', 500),
+    ('\u{2022}', 350),
+    ('\u{2026}', 889),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 500),
+    ('\u{2122}', 980),
+    ('\u{2202}', 476),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 675),
+    ('\u{221a}', 453),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 471),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 500),
+    ('\u{fb02}', 500),
+];
+
+pub(crate) static TIMES_BOLD_ITALIC: &[(char, u16)] = &[
+    (' ', 250),
+    ('!', 389),
+    ('"', 555),
+    ('#', 500),
+    ('$', 500),
+    ('%', 833),
+    ('&', 778),
+    ('\'', 278),
+    ('(', 333),
+    (')', 333),
+    ('*', 500),
+    ('+', 570),
+    (',', 250),
+    ('-', 333),
+    ('.', 250),
+    ('/', 278),
+    ('0', 500),
+    ('1', 500),
+    ('2', 500),
+    ('3', 500),
+    ('4', 500),
+    ('5', 500),
+    ('6', 500),
+    ('7', 500),
+    ('8', 500),
+    ('9', 500),
+    (':', 333),
+    (';', 333),
+    ('<', 570),
+    ('=', 570),
+    ('>', 570),
+    ('?', 500),
+    ('@', 832),
+    ('A', 667),
+    ('B', 667),
+    ('C', 667),
+    ('D', 722),
+    ('E', 667),
+    ('F', 667),
+    ('G', 722),
+    ('H', 778),
+    ('I', 389),
+    ('J', 500),
+    ('K', 667),
+    ('L', 611),
+    ('M', 889),
+    ('N', 722),
+    ('O', 722),
+    ('P', 611),
+    ('Q', 722),
+    ('R', 667),
+    ('S', 556),
+    ('T', 611),
+    ('U', 722),
+    ('V', 667),
+    ('W', 889),
+    ('X', 667),
+    ('Y', 611),
+    ('Z', 611),
+    ('[', 333),
+    ('\\', 278),
+    (']', 333),
+    ('^', 570),
+    ('_', 500),
+    ('`', 333),
+    ('a', 500),
+    ('b', 500),
+    ('c', 444),
+    ('d', 500),
+    ('e', 444),
+    ('f', 333),
+    ('g', 500),
+    ('h', 556),
+    ('i', 278),
+    ('j', 278),
+    ('k', 500),
+    ('l', 278),
+    ('m', 778),
+    ('n', 556),
+    ('o', 500),
+    ('p', 500),
+    ('q', 500),
+    ('r', 389),
+    ('s', 389),
+    ('t', 278),
+    ('u', 556),
+    ('v', 444),
+    ('w', 667),
+    ('x', 500),
+    ('y', 444),
+    ('z', 389),
+    ('{', 348),
+    ('|', 220),
+    ('}', 348),
+    ('~', 570),
+    ('\u{00a1}', 389),
+    ('\u{00a2}', 500),
+    ('\u{00a3}', 500),
+    ('\u{00a4}', 500),
+    ('\u{00a5}', 500),
+    ('\u{00a6}', 220),
+    ('\u{00a7}', 500),
+    ('\u{00a8}', 333),
+    ('\u{00a9}', 747),
+    ('\u{00aa}', 266),
+    ('\u{00ab}', 500),
+    ('\u{00ac}', 606),
+    ('\u{00ae}', 747),
+    ('\u{00af}', 333),
+    ('\u{00b0}', 400),
+    ('\u{00b1}', 570),
+    ('\u{00b2}', 300),
+    ('\u{00b3}', 300),
+    ('\u{00b4}', 333),
+    ('\u{00b5}', 576),
+    ('\u{00b6}', 500),
+    ('\u{00b7}', 250),
+    ('\u{00b8}', 333),
+    ('\u{00b9}', 300),
+    ('\u{00ba}', 300),
+    ('\u{00bb}', 500),
+    ('\u{00bc}', 750),
+    ('\u{00bd}', 750),
+    ('\u{00be}', 750),
+    ('\u{00bf}', 500),
+    ('\u{00c0}', 667),
+    ('\u{00c1}', 667),
+    ('\u{00c2}', 667),
+    ('\u{00c3}', 667),
+    ('\u{00c4}', 667),
+    ('\u{00c5}', 667),
+    ('\u{00c6}', 944),
+    ('\u{00c7}', 667),
+    ('\u{00c8}', 667),
+    ('\u{00c9}', 667),
+    ('\u{00ca}', 667),
+    ('\u{00cb}', 667),
+    ('\u{00cc}', 389),
+    ('\u{00cd}', 389),
+    ('\u{00ce}', 389),
+    ('\u{00cf}', 389),
+    ('\u{00d0}', 722),
+    ('\u{00d1}', 722),
+    ('\u{00d2}', 722),
+    ('\u{00d3}', 722),
+    ('\u{00d4}', 722),
+    ('\u{00d5}', 722),
+    ('\u{00d6}', 722),
+    ('\u{00d7}', 570),
+    ('\u{00d8}', 722),
+    ('\u{00d9}', 722),
+    ('\u{00da}', 722),
+    ('\u{00db}', 722),
+    ('\u{00dc}', 722),
+    ('\u{00dd}', 611),
+    ('\u{00de}', 611),
+    ('\u{00df}', 500),
+    ('\u{00e0}', 500),
+    ('\u{00e1}', 500),
+    ('\u{00e2}', 500),
+    ('\u{00e3}', 500),
+    ('\u{00e4}', 500),
+    ('\u{00e5}', 500),
+    ('\u{00e6}', 722),
+    ('\u{00e7}', 444),
+    ('\u{00e8}', 444),
+    ('\u{00e9}', 444),
+    ('\u{00ea}', 444),
+    ('\u{00eb}', 444),
+    ('\u{00ec}', 278),
+    ('\u{00ed}', 278),
+    ('\u{00ee}', 278),
+    ('\u{00ef}', 278),
+    ('\u{00f0}', 500),
+    ('\u{00f1}', 556),
+    ('\u{00f2}', 500),
+    ('\u{00f3}', 500),
+    ('\u{00f4}', 500),
+    ('\u{00f5}', 500),
+    ('\u{00f6}', 500),
+    ('\u{00f7}', 570),
+    ('\u{00f8}', 500),
+    ('\u{00f9}', 556),
+    ('\u{00fa}', 556),
+    ('\u{00fb}', 556),
+    ('\u{00fc}', 556),
+    ('\u{00fd}', 444),
+    ('\u{00fe}', 500),
+    ('\u{00ff}', 444),
+    ('\u{0100}', 667),
+    ('\u{0101}', 500),
+    ('\u{0102}', 667),
+    ('\u{0103}', 500),
+    ('\u{0104}', 667),
+    ('\u{0105}', 500),
+    ('\u{0106}', 667),
+    ('\u{0107}', 444),
+    ('\u{010c}', 667),
+    ('\u{010d}', 444),
+    ('\u{010e}', 722),
+    ('\u{010f}', 608),
+    ('\u{0110}', 722),
+    ('\u{0111}', 500),
+    ('\u{0112}', 667),
+    ('\u{0113}', 444),
+    ('\u{0116}', 667),
+    ('\u{0117}', 444),
+    ('\u{0118}', 667),
+    ('\u{0119}', 444),
+    ('\u{011a}', 667),
+    ('\u{011b}', 444),
+    ('\u{011e}', 722),
+    ('\u{011f}', 500),
+    ('\u{0122}', 722),
+    ('\u{0123}', 500),
+    ('\u{012a}', 389),
+    ('\u{012b}', 278),
+    ('\u{012e}', 389),
+    ('\u{012f}', 278),
+    ('\u{0130}', 389),
+    ('\u{0131}', 278),
+    ('\u{0136}', 667),
+    ('\u{0137}', 500),
+    ('\u{0139}', 611),
+    ('\u{013a}', 278),
+    ('\u{013b}', 611),
+    ('\u{013c}', 278),
+    ('\u{013d}', 611),
+    ('\u{013e}', 382),
+    ('\u{0141}', 611),
+    ('\u{0142}', 278),
+    ('\u{0143}', 722),
+    ('\u{0144}', 556),
+    ('\u{0145}', 722),
+    ('\u{0146}', 556),
+    ('\u{0147}', 722),
+    ('\u{0148}', 556),
+    ('\u{014c}', 722),
+    ('\u{014d}', 500),
+    ('\u{0150}', 722),
+    ('\u{0151}', 500),
+    ('\u{0152}', 944),
+    ('\u{0153}', 722),
+    ('\u{0154}', 667),
+    ('\u{0155}', 389),
+    ('\u{0156}', 667),
+    ('\u{0157}', 389),
+    ('\u{0158}', 667),
+    ('\u{0159}', 389),
+    ('\u{015a}', 556),
+    ('\u{015b}', 389),
+    ('\u{015e}', 556),
+    ('\u{015f}', 389),
+    ('\u{0160}', 556),
+    ('\u{0161}', 389),
+    ('\u{0162}', 611),
+    ('\u{0163}', 278),
+    ('\u{0164}', 611),
+    ('\u{0165}', 366),
+    ('\u{016a}', 722),
+    ('\u{016b}', 556),
+    ('\u{016e}', 722),
+    ('\u{016f}', 556),
+    ('\u{0170}', 722),
+    ('\u{0171}', 556),
+    ('\u{0172}', 722),
+    ('\u{0173}', 556),
+    ('\u{0178}', 611),
+    ('\u{0179}', 611),
+    ('\u{017a}', 389),
+    ('\u{017b}', 611),
+    ('\u{017c}', 389),
+    ('\u{017d}', 611),
+    ('\u{017e}', 389),
+    ('\u{0192}', 500),
+    ('\u{0218}', 556),
+    ('\u{0219}', 389),
+    ('\u{02c6}', 333),
+    ('\u{02c7}', 333),
+    ('\u{02d8}', 333),
+    ('\u{02d9}', 333),
+    ('\u{02da}', 333),
+    ('\u{02db}', 333),
+    ('\u{02dc}', 333),
+    ('\u{02dd}', 333),
+    ('\u{2013}', 500),
+    ('\u{2014}', 1000),
+    ('\u{2018}', 333),
+    ('\u{2019}', 333),
+    ('\u{201a}', 333),
+    ('\u{201c}', 500),
+    ('\u{201d}', 500),
+    ('\u{201e}', 500),
+    ('\u{2020}', 500),
+    ('\u{2021}', 500),
+    ('\u{2022}', 350),
+    ('\u{2026}', 1000),
+    ('\u{2030}', 1000),
+    ('\u{2039}', 333),
+    ('\u{203a}', 333),
+    ('\u{2044}', 167),
+    ('\u{20ac}', 500),
+    ('\u{2122}', 1000),
+    ('\u{2202}', 494),
+    ('\u{2206}', 612),
+    ('\u{2211}', 600),
+    ('\u{2212}', 606),
+    ('\u{221a}', 549),
+    ('\u{2260}', 549),
+    ('\u{2264}', 549),
+    ('\u{2265}', 549),
+    ('\u{25ca}', 494),
+    ('\u{f6c3}', 250),
+    ('\u{fb01}', 556),
+    ('\u{fb02}', 556),
+];
+/// Metrics of a standard 14 font
+pub(crate) enum StandardMetrics {
+    /// Proportional font, widths by character
+    Table(&'static [(char, u16)]),
+    /// Courier: every glyph has the same width
+    Fixed(u16),
+}
+
+impl StandardMetrics {
+    /// Metrics for a `BaseFont` name, accepting subset prefixes and the
+    /// Arial, Times New Roman and Courier New aliases. Symbol and ZapfDingbats
+    /// are not covered.
+    pub(crate) fn for_base_font(base_font: &str) -> Option<Self> {
+        let name = base_font
+            .split_once('+')
+            .map_or(base_font, |(_, name)| name);
+        let name: alloc::string::String = name
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let bold = name.contains("bold");
+        let italic = name.contains("italic") || name.contains("oblique");
+
+        if name.starts_with("helvetica") || name.starts_with("arial") {
+            Some(Self::Table(if bold { HELVETICA_BOLD } else { HELVETICA }))
+        } else if name.starts_with("times") {
+            Some(Self::Table(match (bold, italic) {
+                (false, false) => TIMES_ROMAN,
+                (true, false) => TIMES_BOLD,
+                (false, true) => TIMES_ITALIC,
+                (true, true) => TIMES_BOLD_ITALIC,
+            }))
+        } else if name.starts_with("courier") {
+            Some(Self::Fixed(600))
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn width(&self, ch: char) -> Option<u16> {
+        match self {
+            Self::Table(table) => table
+                .binary_search_by_key(&ch, |&(c, _)| c)
+                .ok()
+                .map(|i| table[i].1),
+            Self::Fixed(width) => Some(*width),
+        }
+    }
+}
@@ -0,0 +1,5051 @@
+//! The Adobe Glyph List 2.0 and the glyph name mapping of the Adobe Glyph List
+//! Specification, generated from `glyphlist.txt` by `data/gen_agl_tables.py`.
+//! Names are sorted and stored back to back in one string, with the end of
+//! each name and its Unicode value in parallel `u16` tables, which keeps the
+//! list small in the guest.
+//!
+//! Glyph list data:
+//!
+//! Copyright 2002-2019 Adobe (http://www.adobe.com/).
+//!
+//! Redistribution and use in source and binary forms, with or without
+//! modification, are permitted provided that the following conditions are
+//! met:
+//!
+//! Redistributions of source code must retain the above copyright notice,
+//! this list of conditions and the following disclaimer.
+//!
+//! Redistributions in binary form must reproduce the above copyright
+//! notice, this list of conditions and the following disclaimer in the
+//! documentation and/or other materials provided with the distribution.
+//!
+//! Neither the name of Adobe nor the names of its contributors may be
+//! used to endorse or promote products derived from this software without
+//! specific prior written permission.
+//!
+//! THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS
+//! "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT
+//! LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+//! A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+//! HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+//! SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+//! LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+//! DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+//! THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+//! (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+//! OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use alloc::string::String;
+
+/// Glyph names mapped to one code point, sorted and concatenated
+static NAMES: &str = "\
+A\
+AE\
+AEacute\
+AEmacron\
+AEsmall\
+Aacute\
+Aacutesmall\
+Abreve\
+Abreveacute\
+Abrevecyrillic\
+Abrevedotbelow\
+Abrevegrave\
+Abrevehookabove\
+Abrevetilde\
+Acaron\
+Acircle\
+Acircumflex\
+Acircumflexacute\
+Acircumflexdotbelow\
+Acircumflexgrave\
+Acircumflexhookabove\
+Acircumflexsmall\
+Acircumflextilde\
+Acute\
+Acutesmall\
+Acyrillic\
+Adblgrave\
+Adieresis\
+Adieresiscyrillic\
+Adieresismacron\
+Adieresissmall\
+Adotbelow\
+Adotmacron\
+Agrave\
+Agravesmall\
+Ahookabove\
+Aiecyrillic\
+Ainvertedbreve\
+Alpha\
+Alphatonos\
+Amacron\
+Amonospace\
+Aogonek\
+Aring\
+Aringacute\
+Aringbelow\
+Aringsmall\
+Asmall\
+Atilde\
+Atildesmall\
+Aybarmenian\
+B\
+Bcircle\
+Bdotaccent\
+Bdotbelow\
+Becyrillic\
+Benarmenian\
+Beta\
+Bhook\
+Blinebelow\
+Bmonospace\
+Brevesmall\
+Bsmall\
+Btopbar\
+C\
+Caarmenian\
+Cacute\
+Caron\
+Caronsmall\
+Ccaron\
+Ccedilla\
+Ccedillaacute\
+Ccedillasmall\
+Ccircle\
+Ccircumflex\
+Cdot\
+Cdotaccent\
+Cedillasmall\
+Chaarmenian\
+Cheabkhasiancyrillic\
+Checyrillic\
+Chedescenderabkhasiancyrillic\
+Chedescendercyrillic\
+Chedieresiscyrillic\
+Cheharmenian\
+Chekhakassiancyrillic\
+Cheverticalstrokecyrillic\
+Chi\
+Chook\
+Circumflexsmall\
+Cmonospace\
+Coarmenian\
+Csmall\
+D\
+DZ\
+DZcaron\
+Daarmenian\
+Dafrican\
+Dcaron\
+Dcedilla\
+Dcircle\
+Dcircumflexbelow\
+Dcroat\
+Ddotaccent\
+Ddotbelow\
+Decyrillic\
+Deicoptic\
+Delta\
+Deltagreek\
+Dhook\
+Dieresis\
+DieresisAcute\
+DieresisGrave\
+Dieresissmall\
+Digammagreek\
+Djecyrillic\
+Dlinebelow\
+Dmonospace\
+Dotaccentsmall\
+Dslash\
+Dsmall\
+Dtopbar\
+Dz\
+Dzcaron\
+Dzeabkhasiancyrillic\
+Dzecyrillic\
+Dzhecyrillic\
+E\
+Eacute\
+Eacutesmall\
+Ebreve\
+Ecaron\
+Ecedillabreve\
+Echarmenian\
+Ecircle\
+Ecircumflex\
+Ecircumflexacute\
+Ecircumflexbelow\
+Ecircumflexdotbelow\
+Ecircumflexgrave\
+Ecircumflexhookabove\
+Ecircumflexsmall\
+Ecircumflextilde\
+Ecyrillic\
+Edblgrave\
+Edieresis\
+Edieresissmall\
+Edot\
+Edotaccent\
+Edotbelow\
+Efcyrillic\
+Egrave\
+Egravesmall\
+Eharmenian\
+Ehookabove\
+Eightroman\
+Einvertedbreve\
+Eiotifiedcyrillic\
+Elcyrillic\
+Elevenroman\
+Emacron\
+Emacronacute\
+Emacrongrave\
+Emcyrillic\
+Emonospace\
+Encyrillic\
+Endescendercyrillic\
+Eng\
+Enghecyrillic\
+Enhookcyrillic\
+Eogonek\
+Eopen\
+Epsilon\
+Epsilontonos\
+Ercyrillic\
+Ereversed\
+Ereversedcyrillic\
+Escyrillic\
+Esdescendercyrillic\
+Esh\
+Esmall\
+Eta\
+Etarmenian\
+Etatonos\
+Eth\
+Ethsmall\
+Etilde\
+Etildebelow\
+Euro\
+Ezh\
+Ezhcaron\
+Ezhreversed\
+F\
+Fcircle\
+Fdotaccent\
+Feharmenian\
+Feicoptic\
+Fhook\
+Fitacyrillic\
+Fiveroman\
+Fmonospace\
+Fourroman\
+Fsmall\
+G\
+GBsquare\
+Gacute\
+Gamma\
+Gammaafrican\
+Gangiacoptic\
+Gbreve\
+Gcaron\
+Gcedilla\
+Gcircle\
+Gcircumflex\
+Gcommaaccent\
+Gdot\
+Gdotaccent\
+Gecyrillic\
+Ghadarmenian\
+Ghemiddlehookcyrillic\
+Ghestrokecyrillic\
+Gheupturncyrillic\
+Ghook\
+Gimarmenian\
+Gjecyrillic\
+Gmacron\
+Gmonospace\
+Grave\
+Gravesmall\
+Gsmall\
+Gsmallhook\
+Gstroke\
+H\
+H18533\
+H18543\
+H18551\
+H22073\
+HPsquare\
+Haabkhasiancyrillic\
+Hadescendercyrillic\
+Hardsigncyrillic\
+Hbar\
+Hbrevebelow\
+Hcedilla\
+Hcircle\
+Hcircumflex\
+Hdieresis\
+Hdotaccent\
+Hdotbelow\
+Hmonospace\
+Hoarmenian\
+Horicoptic\
+Hsmall\
+Hungarumlaut\
+Hungarumlautsmall\
+Hzsquare\
+I\
+IAcyrillic\
+IJ\
+IUcyrillic\
+Iacute\
+Iacutesmall\
+Ibreve\
+Icaron\
+Icircle\
+Icircumflex\
+Icircumflexsmall\
+Icyrillic\
+Idblgrave\
+Idieresis\
+Idieresisacute\
+Idieresiscyrillic\
+Idieresissmall\
+Idot\
+Idotaccent\
+Idotbelow\
+Iebrevecyrillic\
+Iecyrillic\
+Ifraktur\
+Igrave\
+Igravesmall\
+Ihookabove\
+Iicyrillic\
+Iinvertedbreve\
+Iishortcyrillic\
+Imacron\
+Imacroncyrillic\
+Imonospace\
+Iniarmenian\
+Iocyrillic\
+Iogonek\
+Iota\
+Iotaafrican\
+Iotadieresis\
+Iotatonos\
+Ismall\
+Istroke\
+Itilde\
+Itildebelow\
+Izhitsacyrillic\
+Izhitsadblgravecyrillic\
+J\
+Jaarmenian\
+Jcircle\
+Jcircumflex\
+Jecyrillic\
+Jheharmenian\
+Jmonospace\
+Jsmall\
+K\
+KBsquare\
+KKsquare\
+Kabashkircyrillic\
+Kacute\
+Kacyrillic\
+Kadescendercyrillic\
+Kahookcyrillic\
+Kappa\
+Kastrokecyrillic\
+Kaverticalstrokecyrillic\
+Kcaron\
+Kcedilla\
+Kcircle\
+Kcommaaccent\
+Kdotbelow\
+Keharmenian\
+Kenarmenian\
+Khacyrillic\
+Kheicoptic\
+Khook\
+Kjecyrillic\
+Klinebelow\
+Kmonospace\
+Koppacyrillic\
+Koppagreek\
+Ksicyrillic\
+Ksmall\
+L\
+LJ\
+LL\
+Lacute\
+Lambda\
+Lcaron\
+Lcedilla\
+Lcircle\
+Lcircumflexbelow\
+Lcommaaccent\
+Ldot\
+Ldotaccent\
+Ldotbelow\
+Ldotbelowmacron\
+Liwnarmenian\
+Lj\
+Ljecyrillic\
+Llinebelow\
+Lmonospace\
+Lslash\
+Lslashsmall\
+Lsmall\
+M\
+MBsquare\
+Macron\
+Macronsmall\
+Macute\
+Mcircle\
+Mdotaccent\
+Mdotbelow\
+Menarmenian\
+Mmonospace\
+Msmall\
+Mturned\
+Mu\
+N\
+NJ\
+Nacute\
+Ncaron\
+Ncedilla\
+Ncircle\
+Ncircumflexbelow\
+Ncommaaccent\
+Ndotaccent\
+Ndotbelow\
+Nhookleft\
+Nineroman\
+Nj\
+Njecyrillic\
+Nlinebelow\
+Nmonospace\
+Nowarmenian\
+Nsmall\
+Ntilde\
+Ntildesmall\
+Nu\
+O\
+OE\
+OEsmall\
+Oacute\
+Oacutesmall\
+Obarredcyrillic\
+Obarreddieresiscyrillic\
+Obreve\
+Ocaron\
+Ocenteredtilde\
+Ocircle\
+Ocircumflex\
+Ocircumflexacute\
+Ocircumflexdotbelow\
+Ocircumflexgrave\
+Ocircumflexhookabove\
+Ocircumflexsmall\
+Ocircumflextilde\
+Ocyrillic\
+Odblacute\
+Odblgrave\
+Odieresis\
+Odieresiscyrillic\
+Odieresissmall\
+Odotbelow\
+Ogoneksmall\
+Ograve\
+Ogravesmall\
+Oharmenian\
+Ohm\
+Ohookabove\
+Ohorn\
+Ohornacute\
+Ohorndotbelow\
+Ohorngrave\
+Ohornhookabove\
+Ohorntilde\
+Ohungarumlaut\
+Oi\
+Oinvertedbreve\
+Omacron\
+Omacronacute\
+Omacrongrave\
+Omega\
+Omegacyrillic\
+Omegagreek\
+Omegaroundcyrillic\
+Omegatitlocyrillic\
+Omegatonos\
+Omicron\
+Omicrontonos\
+Omonospace\
+Oneroman\
+Oogonek\
+Oogonekmacron\
+Oopen\
+Oslash\
+Oslashacute\
+Oslashsmall\
+Osmall\
+Ostrokeacute\
+Otcyrillic\
+Otilde\
+Otildeacute\
+Otildedieresis\
+Otildesmall\
+P\
+Pacute\
+Pcircle\
+Pdotaccent\
+Pecyrillic\
+Peharmenian\
+Pemiddlehookcyrillic\
+Phi\
+Phook\
+Pi\
+Piwrarmenian\
+Pmonospace\
+Psi\
+Psicyrillic\
+Psmall\
+Q\
+Qcircle\
+Qmonospace\
+Qsmall\
+R\
+Raarmenian\
+Racute\
+Rcaron\
+Rcedilla\
+Rcircle\
+Rcommaaccent\
+Rdblgrave\
+Rdotaccent\
+Rdotbelow\
+Rdotbelowmacron\
+Reharmenian\
+Rfraktur\
+Rho\
+Ringsmall\
+Rinvertedbreve\
+Rlinebelow\
+Rmonospace\
+Rsmall\
+Rsmallinverted\
+Rsmallinvertedsuperior\
+S\
+SF010000\
+SF020000\
+SF030000\
+SF040000\
+SF050000\
+SF060000\
+SF070000\
+SF080000\
+SF090000\
+SF100000\
+SF110000\
+SF190000\
+SF200000\
+SF210000\
+SF220000\
+SF230000\
+SF240000\
+SF250000\
+SF260000\
+SF270000\
+SF280000\
+SF360000\
+SF370000\
+SF380000\
+SF390000\
+SF400000\
+SF410000\
+SF420000\
+SF430000\
+SF440000\
+SF450000\
+SF460000\
+SF470000\
+SF480000\
+SF490000\
+SF500000\
+SF510000\
+SF520000\
+SF530000\
+SF540000\
+Sacute\
+Sacutedotaccent\
+Sampigreek\
+Scaron\
+Scarondotaccent\
+Scaronsmall\
+Scedilla\
+Schwa\
+Schwacyrillic\
+Schwadieresiscyrillic\
+Scircle\
+Scircumflex\
+Scommaaccent\
+Sdotaccent\
+Sdotbelow\
+Sdotbelowdotaccent\
+Seharmenian\
+Sevenroman\
+Shaarmenian\
+Shacyrillic\
+Shchacyrillic\
+Sheicoptic\
+Shhacyrillic\
+Shimacoptic\
+Sigma\
+Sixroman\
+Smonospace\
+Softsigncyrillic\
+Ssmall\
+Stigmagreek\
+T\
+Tau\
+Tbar\
+Tcaron\
+Tcedilla\
+Tcircle\
+Tcircumflexbelow\
+Tcommaaccent\
+Tdotaccent\
+Tdotbelow\
+Tecyrillic\
+Tedescendercyrillic\
+Tenroman\
+Tetsecyrillic\
+Theta\
+Thook\
+Thorn\
+Thornsmall\
+Threeroman\
+Tildesmall\
+Tiwnarmenian\
+Tlinebelow\
+Tmonospace\
+Toarmenian\
+Tonefive\
+Tonesix\
+Tonetwo\
+Tretroflexhook\
+Tsecyrillic\
+Tshecyrillic\
+Tsmall\
+Twelveroman\
+Tworoman\
+U\
+Uacute\
+Uacutesmall\
+Ubreve\
+Ucaron\
+Ucircle\
+Ucircumflex\
+Ucircumflexbelow\
+Ucircumflexsmall\
+Ucyrillic\
+Udblacute\
+Udblgrave\
+Udieresis\
+Udieresisacute\
+Udieresisbelow\
+Udieresiscaron\
+Udieresiscyrillic\
+Udieresisgrave\
+Udieresismacron\
+Udieresissmall\
+Udotbelow\
+Ugrave\
+Ugravesmall\
+Uhookabove\
+Uhorn\
+Uhornacute\
+Uhorndotbelow\
+Uhorngrave\
+Uhornhookabove\
+Uhorntilde\
+Uhungarumlaut\
+Uhungarumlautcyrillic\
+Uinvertedbreve\
+Ukcyrillic\
+Umacron\
+Umacroncyrillic\
+Umacrondieresis\
+Umonospace\
+Uogonek\
+Upsilon\
+Upsilon1\
+Upsilonacutehooksymbolgreek\
+Upsilonafrican\
+Upsilondieresis\
+Upsilondieresishooksymbolgreek\
+Upsilonhooksymbol\
+Upsilontonos\
+Uring\
+Ushortcyrillic\
+Usmall\
+Ustraightcyrillic\
+Ustraightstrokecyrillic\
+Utilde\
+Utildeacute\
+Utildebelow\
+V\
+Vcircle\
+Vdotbelow\
+Vecyrillic\
+Vewarmenian\
+Vhook\
+Vmonospace\
+Voarmenian\
+Vsmall\
+Vtilde\
+W\
+Wacute\
+Wcircle\
+Wcircumflex\
+Wdieresis\
+Wdotaccent\
+Wdotbelow\
+Wgrave\
+Wmonospace\
+Wsmall\
+X\
+Xcircle\
+Xdieresis\
+Xdotaccent\
+Xeharmenian\
+Xi\
+Xmonospace\
+Xsmall\
+Y\
+Yacute\
+Yacutesmall\
+Yatcyrillic\
+Ycircle\
+Ycircumflex\
+Ydieresis\
+Ydieresissmall\
+Ydotaccent\
+Ydotbelow\
+Yericyrillic\
+Yerudieresiscyrillic\
+Ygrave\
+Yhook\
+Yhookabove\
+Yiarmenian\
+Yicyrillic\
+Yiwnarmenian\
+Ymonospace\
+Ysmall\
+Ytilde\
+Yusbigcyrillic\
+Yusbigiotifiedcyrillic\
+Yuslittlecyrillic\
+Yuslittleiotifiedcyrillic\
+Z\
+Zaarmenian\
+Zacute\
+Zcaron\
+Zcaronsmall\
+Zcircle\
+Zcircumflex\
+Zdot\
+Zdotaccent\
+Zdotbelow\
+Zecyrillic\
+Zedescendercyrillic\
+Zedieresiscyrillic\
+Zeta\
+Zhearmenian\
+Zhebrevecyrillic\
+Zhecyrillic\
+Zhedescendercyrillic\
+Zhedieresiscyrillic\
+Zlinebelow\
+Zmonospace\
+Zsmall\
+Zstroke\
+a\
+aabengali\
+aacute\
+aadeva\
+aagujarati\
+aagurmukhi\
+aamatragurmukhi\
+aarusquare\
+aavowelsignbengali\
+aavowelsigndeva\
+aavowelsigngujarati\
+abbreviationmarkarmenian\
+abbreviationsigndeva\
+abengali\
+abopomofo\
+abreve\
+abreveacute\
+abrevecyrillic\
+abrevedotbelow\
+abrevegrave\
+abrevehookabove\
+abrevetilde\
+acaron\
+acircle\
+acircumflex\
+acircumflexacute\
+acircumflexdotbelow\
+acircumflexgrave\
+acircumflexhookabove\
+acircumflextilde\
+acute\
+acutebelowcmb\
+acutecmb\
+acutecomb\
+acutedeva\
+acutelowmod\
+acutetonecmb\
+acyrillic\
+adblgrave\
+addakgurmukhi\
+adeva\
+adieresis\
+adieresiscyrillic\
+adieresismacron\
+adotbelow\
+adotmacron\
+ae\
+aeacute\
+aekorean\
+aemacron\
+afii00208\
+afii08941\
+afii10017\
+afii10018\
+afii10019\
+afii10020\
+afii10021\
+afii10022\
+afii10023\
+afii10024\
+afii10025\
+afii10026\
+afii10027\
+afii10028\
+afii10029\
+afii10030\
+afii10031\
+afii10032\
+afii10033\
+afii10034\
+afii10035\
+afii10036\
+afii10037\
+afii10038\
+afii10039\
+afii10040\
+afii10041\
+afii10042\
+afii10043\
+afii10044\
+afii10045\
+afii10046\
+afii10047\
+afii10048\
+afii10049\
+afii10050\
+afii10051\
+afii10052\
+afii10053\
+afii10054\
+afii10055\
+afii10056\
+afii10057\
+afii10058\
+afii10059\
+afii10060\
+afii10061\
+afii10062\
+afii10063\
+afii10064\
+afii10065\
+afii10066\
+afii10067\
+afii10068\
+afii10069\
+afii10070\
+afii10071\
+afii10072\
+afii10073\
+afii10074\
+afii10075\
+afii10076\
+afii10077\
+afii10078\
+afii10079\
+afii10080\
+afii10081\
+afii10082\
+afii10083\
+afii10084\
+afii10085\
+afii10086\
+afii10087\
+afii10088\
+afii10089\
+afii10090\
+afii10091\
+afii10092\
+afii10093\
+afii10094\
+afii10095\
+afii10096\
+afii10097\
+afii10098\
+afii10099\
+afii10100\
+afii10101\
+afii10102\
+afii10103\
+afii10104\
+afii10105\
+afii10106\
+afii10107\
+afii10108\
+afii10109\
+afii10110\
+afii10145\
+afii10146\
+afii10147\
+afii10148\
+afii10192\
+afii10193\
+afii10194\
+afii10195\
+afii10196\
+afii10831\
+afii10832\
+afii10846\
+afii299\
+afii300\
+afii301\
+afii57381\
+afii57388\
+afii57392\
+afii57393\
+afii57394\
+afii57395\
+afii57396\
+afii57397\
+afii57398\
+afii57399\
+afii57400\
+afii57401\
+afii57403\
+afii57407\
+afii57409\
+afii57410\
+afii57411\
+afii57412\
+afii57413\
+afii57414\
+afii57415\
+afii57416\
+afii57417\
+afii57418\
+afii57419\
+afii57420\
+afii57421\
+afii57422\
+afii57423\
+afii57424\
+afii57425\
+afii57426\
+afii57427\
+afii57428\
+afii57429\
+afii57430\
+afii57431\
+afii57432\
+afii57433\
+afii57434\
+afii57440\
+afii57441\
+afii57442\
+afii57443\
+afii57444\
+afii57445\
+afii57446\
+afii57448\
+afii57449\
+afii57450\
+afii57451\
+afii57452\
+afii57453\
+afii57454\
+afii57455\
+afii57456\
+afii57457\
+afii57458\
+afii57470\
+afii57505\
+afii57506\
+afii57507\
+afii57508\
+afii57509\
+afii57511\
+afii57512\
+afii57513\
+afii57514\
+afii57519\
+afii57534\
+afii57636\
+afii57645\
+afii57658\
+afii57664\
+afii57665\
+afii57666\
+afii57667\
+afii57668\
+afii57669\
+afii57670\
+afii57671\
+afii57672\
+afii57673\
+afii57674\
+afii57675\
+afii57676\
+afii57677\
+afii57678\
+afii57679\
+afii57680\
+afii57681\
+afii57682\
+afii57683\
+afii57684\
+afii57685\
+afii57686\
+afii57687\
+afii57688\
+afii57689\
+afii57690\
+afii57694\
+afii57695\
+afii57700\
+afii57705\
+afii57716\
+afii57717\
+afii57718\
+afii57723\
+afii57793\
+afii57794\
+afii57795\
+afii57796\
+afii57797\
+afii57798\
+afii57799\
+afii57800\
+afii57801\
+afii57802\
+afii57803\
+afii57804\
+afii57806\
+afii57807\
+afii57839\
+afii57841\
+afii57842\
+afii57929\
+afii61248\
+afii61289\
+afii61352\
+afii61573\
+afii61574\
+afii61575\
+afii61664\
+afii63167\
+afii64937\
+agrave\
+agujarati\
+agurmukhi\
+ahiragana\
+ahookabove\
+aibengali\
+aibopomofo\
+aideva\
+aiecyrillic\
+aigujarati\
+aigurmukhi\
+aimatragurmukhi\
+ainarabic\
+ainfinalarabic\
+aininitialarabic\
+ainmedialarabic\
+ainvertedbreve\
+aivowelsignbengali\
+aivowelsigndeva\
+aivowelsigngujarati\
+akatakana\
+akatakanahalfwidth\
+akorean\
+alef\
+alefarabic\
+alefdageshhebrew\
+aleffinalarabic\
+alefhamzaabovearabic\
+alefhamzaabovefinalarabic\
+alefhamzabelowarabic\
+alefhamzabelowfinalarabic\
+alefhebrew\
+aleflamedhebrew\
+alefmaddaabovearabic\
+alefmaddaabovefinalarabic\
+alefmaksuraarabic\
+alefmaksurafinalarabic\
+alefmaksurainitialarabic\
+alefmaksuramedialarabic\
+alefpatahhebrew\
+alefqamatshebrew\
+aleph\
+allequal\
+alpha\
+alphatonos\
+amacron\
+amonospace\
+ampersand\
+ampersandmonospace\
+ampersandsmall\
+amsquare\
+anbopomofo\
+angbopomofo\
+angkhankhuthai\
+angle\
+anglebracketleft\
+anglebracketleftvertical\
+anglebracketright\
+anglebracketrightvertical\
+angleleft\
+angleright\
+angstrom\
+anoteleia\
+anudattadeva\
+anusvarabengali\
+anusvaradeva\
+anusvaragujarati\
+aogonek\
+apaatosquare\
+aparen\
+apostrophearmenian\
+apostrophemod\
+apple\
+approaches\
+approxequal\
+approxequalorimage\
+approximatelyequal\
+araeaekorean\
+araeakorean\
+arc\
+arighthalfring\
+aring\
+aringacute\
+aringbelow\
+arrowboth\
+arrowdashdown\
+arrowdashleft\
+arrowdashright\
+arrowdashup\
+arrowdblboth\
+arrowdbldown\
+arrowdblleft\
+arrowdblright\
+arrowdblup\
+arrowdown\
+arrowdownleft\
+arrowdownright\
+arrowdownwhite\
+arrowheaddownmod\
+arrowheadleftmod\
+arrowheadrightmod\
+arrowheadupmod\
+arrowhorizex\
+arrowleft\
+arrowleftdbl\
+arrowleftdblstroke\
+arrowleftoverright\
+arrowleftwhite\
+arrowright\
+arrowrightdblstroke\
+arrowrightheavy\
+arrowrightoverleft\
+arrowrightwhite\
+arrowtableft\
+arrowtabright\
+arrowup\
+arrowupdn\
+arrowupdnbse\
+arrowupdownbase\
+arrowupleft\
+arrowupleftofdown\
+arrowupright\
+arrowupwhite\
+arrowvertex\
+asciicircum\
+asciicircummonospace\
+asciitilde\
+asciitildemonospace\
+ascript\
+ascriptturned\
+asmallhiragana\
+asmallkatakana\
+asmallkatakanahalfwidth\
+asterisk\
+asteriskaltonearabic\
+asteriskarabic\
+asteriskmath\
+asteriskmonospace\
+asterisksmall\
+asterism\
+asuperior\
+asymptoticallyequal\
+at\
+atilde\
+atmonospace\
+atsmall\
+aturned\
+aubengali\
+aubopomofo\
+audeva\
+augujarati\
+augurmukhi\
+aulengthmarkbengali\
+aumatragurmukhi\
+auvowelsignbengali\
+auvowelsigndeva\
+auvowelsigngujarati\
+avagrahadeva\
+aybarmenian\
+ayin\
+ayinaltonehebrew\
+ayinhebrew\
+b\
+babengali\
+backslash\
+backslashmonospace\
+badeva\
+bagujarati\
+bagurmukhi\
+bahiragana\
+bahtthai\
+bakatakana\
+bar\
+barmonospace\
+bbopomofo\
+bcircle\
+bdotaccent\
+bdotbelow\
+beamedsixteenthnotes\
+because\
+becyrillic\
+beharabic\
+behfinalarabic\
+behinitialarabic\
+behiragana\
+behmedialarabic\
+behmeeminitialarabic\
+behmeemisolatedarabic\
+behnoonfinalarabic\
+bekatakana\
+benarmenian\
+bet\
+beta\
+betasymbolgreek\
+betdagesh\
+betdageshhebrew\
+bethebrew\
+betrafehebrew\
+bhabengali\
+bhadeva\
+bhagujarati\
+bhagurmukhi\
+bhook\
+bihiragana\
+bikatakana\
+bilabialclick\
+bindigurmukhi\
+birusquare\
+blackcircle\
+blackdiamond\
+blackdownpointingtriangle\
+blackleftpointingpointer\
+blackleftpointingtriangle\
+blacklenticularbracketleft\
+blacklenticularbracketleftvertical\
+blacklenticularbracketright\
+blacklenticularbracketrightvertical\
+blacklowerlefttriangle\
+blacklowerrighttriangle\
+blackrectangle\
+blackrightpointingpointer\
+blackrightpointingtriangle\
+blacksmallsquare\
+blacksmilingface\
+blacksquare\
+blackstar\
+blackupperlefttriangle\
+blackupperrighttriangle\
+blackuppointingsmalltriangle\
+blackuppointingtriangle\
+blank\
+blinebelow\
+block\
+bmonospace\
+bobaimaithai\
+bohiragana\
+bokatakana\
+bparen\
+bqsquare\
+braceex\
+braceleft\
+braceleftbt\
+braceleftmid\
+braceleftmonospace\
+braceleftsmall\
+bracelefttp\
+braceleftvertical\
+braceright\
+bracerightbt\
+bracerightmid\
+bracerightmonospace\
+bracerightsmall\
+bracerighttp\
+bracerightvertical\
+bracketleft\
+bracketleftbt\
+bracketleftex\
+bracketleftmonospace\
+bracketlefttp\
+bracketright\
+bracketrightbt\
+bracketrightex\
+bracketrightmonospace\
+bracketrighttp\
+breve\
+brevebelowcmb\
+brevecmb\
+breveinvertedbelowcmb\
+breveinvertedcmb\
+breveinverteddoublecmb\
+bridgebelowcmb\
+bridgeinvertedbelowcmb\
+brokenbar\
+bstroke\
+bsuperior\
+btopbar\
+buhiragana\
+bukatakana\
+bullet\
+bulletinverse\
+bulletoperator\
+bullseye\
+c\
+caarmenian\
+cabengali\
+cacute\
+cadeva\
+cagujarati\
+cagurmukhi\
+calsquare\
+candrabindubengali\
+candrabinducmb\
+candrabindudeva\
+candrabindugujarati\
+capslock\
+careof\
+caron\
+caronbelowcmb\
+caroncmb\
+carriagereturn\
+cbopomofo\
+ccaron\
+ccedilla\
+ccedillaacute\
+ccircle\
+ccircumflex\
+ccurl\
+cdot\
+cdotaccent\
+cdsquare\
+cedilla\
+cedillacmb\
+cent\
+centigrade\
+centinferior\
+centmonospace\
+centoldstyle\
+centsuperior\
+chaarmenian\
+chabengali\
+chadeva\
+chagujarati\
+chagurmukhi\
+chbopomofo\
+cheabkhasiancyrillic\
+checkmark\
+checyrillic\
+chedescenderabkhasiancyrillic\
+chedescendercyrillic\
+chedieresiscyrillic\
+cheharmenian\
+chekhakassiancyrillic\
+cheverticalstrokecyrillic\
+chi\
+chieuchacirclekorean\
+chieuchaparenkorean\
+chieuchcirclekorean\
+chieuchkorean\
+chieuchparenkorean\
+chochangthai\
+chochanthai\
+chochingthai\
+chochoethai\
+chook\
+cieucacirclekorean\
+cieucaparenkorean\
+cieuccirclekorean\
+cieuckorean\
+cieucparenkorean\
+cieucuparenkorean\
+circle\
+circlemultiply\
+circleot\
+circleplus\
+circlepostalmark\
+circlewithlefthalfblack\
+circlewithrighthalfblack\
+circumflex\
+circumflexbelowcmb\
+circumflexcmb\
+clear\
+clickalveolar\
+clickdental\
+clicklateral\
+clickretroflex\
+club\
+clubsuitblack\
+clubsuitwhite\
+cmcubedsquare\
+cmonospace\
+cmsquaredsquare\
+coarmenian\
+colon\
+colonmonetary\
+colonmonospace\
+colonsign\
+colonsmall\
+colontriangularhalfmod\
+colontriangularmod\
+comma\
+commaabovecmb\
+commaaboverightcmb\
+commaaccent\
+commaarabic\
+commaarmenian\
+commainferior\
+commamonospace\
+commareversedabovecmb\
+commareversedmod\
+commasmall\
+commasuperior\
+commaturnedabovecmb\
+commaturnedmod\
+compass\
+congruent\
+contourintegral\
+control\
+controlACK\
+controlBEL\
+controlBS\
+controlCAN\
+controlCR\
+controlDC1\
+controlDC2\
+controlDC3\
+controlDC4\
+controlDEL\
+controlDLE\
+controlEM\
+controlENQ\
+controlEOT\
+controlESC\
+controlETB\
+controlETX\
+controlFF\
+controlFS\
+controlGS\
+controlHT\
+controlLF\
+controlNAK\
+controlRS\
+controlSI\
+controlSO\
+controlSOT\
+controlSTX\
+controlSUB\
+controlSYN\
+controlUS\
+controlVT\
+copyright\
+copyrightsans\
+copyrightserif\
+cornerbracketleft\
+cornerbracketlefthalfwidth\
+cornerbracketleftvertical\
+cornerbracketright\
+cornerbracketrighthalfwidth\
+cornerbracketrightvertical\
+corporationsquare\
+cosquare\
+coverkgsquare\
+cparen\
+cruzeiro\
+cstretched\
+curlyand\
+curlyor\
+currency\
+cyrBreve\
+cyrFlex\
+cyrbreve\
+cyrflex\
+d\
+daarmenian\
+dabengali\
+dadarabic\
+dadeva\
+dadfinalarabic\
+dadinitialarabic\
+dadmedialarabic\
+dagesh\
+dageshhebrew\
+dagger\
+daggerdbl\
+dagujarati\
+dagurmukhi\
+dahiragana\
+dakatakana\
+dalarabic\
+dalet\
+daletdagesh\
+daletdageshhebrew\
+dalethebrew\
+dalfinalarabic\
+dammaarabic\
+dammalowarabic\
+dammatanaltonearabic\
+dammatanarabic\
+danda\
+dargahebrew\
+dargalefthebrew\
+dasiapneumatacyrilliccmb\
+dblGrave\
+dblanglebracketleft\
+dblanglebracketleftvertical\
+dblanglebracketright\
+dblanglebracketrightvertical\
+dblarchinvertedbelowcmb\
+dblarrowleft\
+dblarrowright\
+dbldanda\
+dblgrave\
+dblgravecmb\
+dblintegral\
+dbllowline\
+dbllowlinecmb\
+dbloverlinecmb\
+dblprimemod\
+dblverticalbar\
+dblverticallineabovecmb\
+dbopomofo\
+dbsquare\
+dcaron\
+dcedilla\
+dcircle\
+dcircumflexbelow\
+dcroat\
+ddabengali\
+ddadeva\
+ddagujarati\
+ddagurmukhi\
+ddalarabic\
+ddalfinalarabic\
+dddhadeva\
+ddhabengali\
+ddhadeva\
+ddhagujarati\
+ddhagurmukhi\
+ddotaccent\
+ddotbelow\
+decimalseparatorarabic\
+decimalseparatorpersian\
+decyrillic\
+degree\
+dehihebrew\
+dehiragana\
+deicoptic\
+dekatakana\
+deleteleft\
+deleteright\
+delta\
+deltaturned\
+denominatorminusonenumeratorbengali\
+dezh\
+dhabengali\
+dhadeva\
+dhagujarati\
+dhagurmukhi\
+dhook\
+dialytikatonos\
+dialytikatonoscmb\
+diamond\
+diamondsuitwhite\
+dieresis\
+dieresisacute\
+dieresisbelowcmb\
+dieresiscmb\
+dieresisgrave\
+dieresistonos\
+dihiragana\
+dikatakana\
+dittomark\
+divide\
+divides\
+divisionslash\
+djecyrillic\
+dkshade\
+dlinebelow\
+dlsquare\
+dmacron\
+dmonospace\
+dnblock\
+dochadathai\
+dodekthai\
+dohiragana\
+dokatakana\
+dollar\
+dollarinferior\
+dollarmonospace\
+dollaroldstyle\
+dollarsmall\
+dollarsuperior\
+dong\
+dorusquare\
+dotaccent\
+dotaccentcmb\
+dotbelowcmb\
+dotbelowcomb\
+dotkatakana\
+dotlessi\
+dotlessj\
+dotlessjstrokehook\
+dotmath\
+dottedcircle\
+doubleyodpatah\
+doubleyodpatahhebrew\
+downtackbelowcmb\
+downtackmod\
+dparen\
+dsuperior\
+dtail\
+dtopbar\
+duhiragana\
+dukatakana\
+dz\
+dzaltone\
+dzcaron\
+dzcurl\
+dzeabkhasiancyrillic\
+dzecyrillic\
+dzhecyrillic\
+e\
+eacute\
+earth\
+ebengali\
+ebopomofo\
+ebreve\
+ecandradeva\
+ecandragujarati\
+ecandravowelsigndeva\
+ecandravowelsigngujarati\
+ecaron\
+ecedillabreve\
+echarmenian\
+echyiwnarmenian\
+ecircle\
+ecircumflex\
+ecircumflexacute\
+ecircumflexbelow\
+ecircumflexdotbelow\
+ecircumflexgrave\
+ecircumflexhookabove\
+ecircumflextilde\
+ecyrillic\
+edblgrave\
+edeva\
+edieresis\
+edot\
+edotaccent\
+edotbelow\
+eegurmukhi\
+eematragurmukhi\
+efcyrillic\
+egrave\
+egujarati\
+eharmenian\
+ehbopomofo\
+ehiragana\
+ehookabove\
+eibopomofo\
+eight\
+eightarabic\
+eightbengali\
+eightcircle\
+eightcircleinversesansserif\
+eightdeva\
+eighteencircle\
+eighteenparen\
+eighteenperiod\
+eightgujarati\
+eightgurmukhi\
+eighthackarabic\
+eighthangzhou\
+eighthnotebeamed\
+eightideographicparen\
+eightinferior\
+eightmonospace\
+eightoldstyle\
+eightparen\
+eightperiod\
+eightpersian\
+eightroman\
+eightsuperior\
+eightthai\
+einvertedbreve\
+eiotifiedcyrillic\
+ekatakana\
+ekatakanahalfwidth\
+ekonkargurmukhi\
+ekorean\
+elcyrillic\
+element\
+elevencircle\
+elevenparen\
+elevenperiod\
+elevenroman\
+ellipsis\
+ellipsisvertical\
+emacron\
+emacronacute\
+emacrongrave\
+emcyrillic\
+emdash\
+emdashvertical\
+emonospace\
+emphasismarkarmenian\
+emptyset\
+enbopomofo\
+encyrillic\
+endash\
+endashvertical\
+endescendercyrillic\
+eng\
+engbopomofo\
+enghecyrillic\
+enhookcyrillic\
+enspace\
+eogonek\
+eokorean\
+eopen\
+eopenclosed\
+eopenreversed\
+eopenreversedclosed\
+eopenreversedhook\
+eparen\
+epsilon\
+epsilontonos\
+equal\
+equalmonospace\
+equalsmall\
+equalsuperior\
+equivalence\
+erbopomofo\
+ercyrillic\
+ereversed\
+ereversedcyrillic\
+escyrillic\
+esdescendercyrillic\
+esh\
+eshcurl\
+eshortdeva\
+eshortvowelsigndeva\
+eshreversedloop\
+eshsquatreversed\
+esmallhiragana\
+esmallkatakana\
+esmallkatakanahalfwidth\
+estimated\
+esuperior\
+eta\
+etarmenian\
+etatonos\
+eth\
+etilde\
+etildebelow\
+etnahtafoukhhebrew\
+etnahtafoukhlefthebrew\
+etnahtahebrew\
+etnahtalefthebrew\
+eturned\
+eukorean\
+euro\
+evowelsignbengali\
+evowelsigndeva\
+evowelsigngujarati\
+exclam\
+exclamarmenian\
+exclamdbl\
+exclamdown\
+exclamdownsmall\
+exclammonospace\
+exclamsmall\
+existential\
+ezh\
+ezhcaron\
+ezhcurl\
+ezhreversed\
+ezhtail\
+f\
+fadeva\
+fagurmukhi\
+fahrenheit\
+fathaarabic\
+fathalowarabic\
+fathatanarabic\
+fbopomofo\
+fcircle\
+fdotaccent\
+feharabic\
+feharmenian\
+fehfinalarabic\
+fehinitialarabic\
+fehmedialarabic\
+feicoptic\
+female\
+ff\
+ffi\
+ffl\
+fi\
+fifteencircle\
+fifteenparen\
+fifteenperiod\
+figuredash\
+filledbox\
+filledrect\
+finalkaf\
+finalkafdagesh\
+finalkafdageshhebrew\
+finalkafhebrew\
+finalmem\
+finalmemhebrew\
+finalnun\
+finalnunhebrew\
+finalpe\
+finalpehebrew\
+finaltsadi\
+finaltsadihebrew\
+firsttonechinese\
+fisheye\
+fitacyrillic\
+five\
+fivearabic\
+fivebengali\
+fivecircle\
+fivecircleinversesansserif\
+fivedeva\
+fiveeighths\
+fivegujarati\
+fivegurmukhi\
+fivehackarabic\
+fivehangzhou\
+fiveideographicparen\
+fiveinferior\
+fivemonospace\
+fiveoldstyle\
+fiveparen\
+fiveperiod\
+fivepersian\
+fiveroman\
+fivesuperior\
+fivethai\
+fl\
+florin\
+fmonospace\
+fmsquare\
+fofanthai\
+fofathai\
+fongmanthai\
+forall\
+four\
+fourarabic\
+fourbengali\
+fourcircle\
+fourcircleinversesansserif\
+fourdeva\
+fourgujarati\
+fourgurmukhi\
+fourhackarabic\
+fourhangzhou\
+fourideographicparen\
+fourinferior\
+fourmonospace\
+fournumeratorbengali\
+fouroldstyle\
+fourparen\
+fourperiod\
+fourpersian\
+fourroman\
+foursuperior\
+fourteencircle\
+fourteenparen\
+fourteenperiod\
+fourthai\
+fourthtonechinese\
+fparen\
+fraction\
+franc\
+g\
+gabengali\
+gacute\
+gadeva\
+gafarabic\
+gaffinalarabic\
+gafinitialarabic\
+gafmedialarabic\
+gagujarati\
+gagurmukhi\
+gahiragana\
+gakatakana\
+gamma\
+gammalatinsmall\
+gammasuperior\
+gangiacoptic\
+gbopomofo\
+gbreve\
+gcaron\
+gcedilla\
+gcircle\
+gcircumflex\
+gcommaaccent\
+gdot\
+gdotaccent\
+gecyrillic\
+gehiragana\
+gekatakana\
+geometricallyequal\
+gereshaccenthebrew\
+gereshhebrew\
+gereshmuqdamhebrew\
+germandbls\
+gershayimaccenthebrew\
+gershayimhebrew\
+getamark\
+ghabengali\
+ghadarmenian\
+ghadeva\
+ghagujarati\
+ghagurmukhi\
+ghainarabic\
+ghainfinalarabic\
+ghaininitialarabic\
+ghainmedialarabic\
+ghemiddlehookcyrillic\
+ghestrokecyrillic\
+gheupturncyrillic\
+ghhadeva\
+ghhagurmukhi\
+ghook\
+ghzsquare\
+gihiragana\
+gikatakana\
+gimarmenian\
+gimel\
+gimeldagesh\
+gimeldageshhebrew\
+gimelhebrew\
+gjecyrillic\
+glottalinvertedstroke\
+glottalstop\
+glottalstopinverted\
+glottalstopmod\
+glottalstopreversed\
+glottalstopreversedmod\
+glottalstopreversedsuperior\
+glottalstopstroke\
+glottalstopstrokereversed\
+gmacron\
+gmonospace\
+gohiragana\
+gokatakana\
+gparen\
+gpasquare\
+gradient\
+grave\
+gravebelowcmb\
+gravecmb\
+gravecomb\
+gravedeva\
+gravelowmod\
+gravemonospace\
+gravetonecmb\
+greater\
+greaterequal\
+greaterequalorless\
+greatermonospace\
+greaterorequivalent\
+greaterorless\
+greateroverequal\
+greatersmall\
+gscript\
+gstroke\
+guhiragana\
+guillemotleft\
+guillemotright\
+guilsinglleft\
+guilsinglright\
+gukatakana\
+guramusquare\
+gysquare\
+h\
+haabkhasiancyrillic\
+haaltonearabic\
+habengali\
+hadescendercyrillic\
+hadeva\
+hagujarati\
+hagurmukhi\
+haharabic\
+hahfinalarabic\
+hahinitialarabic\
+hahiragana\
+hahmedialarabic\
+haitusquare\
+hakatakana\
+hakatakanahalfwidth\
+halantgurmukhi\
+hamzaarabic\
+hamzalowarabic\
+hangulfiller\
+hardsigncyrillic\
+harpoonleftbarbup\
+harpoonrightbarbup\
+hasquare\
+hatafpatah\
+hatafpatah16\
+hatafpatah23\
+hatafpatah2f\
+hatafpatahhebrew\
+hatafpatahnarrowhebrew\
+hatafpatahquarterhebrew\
+hatafpatahwidehebrew\
+hatafqamats\
+hatafqamats1b\
+hatafqamats28\
+hatafqamats34\
+hatafqamatshebrew\
+hatafqamatsnarrowhebrew\
+hatafqamatsquarterhebrew\
+hatafqamatswidehebrew\
+hatafsegol\
+hatafsegol17\
+hatafsegol24\
+hatafsegol30\
+hatafsegolhebrew\
+hatafsegolnarrowhebrew\
+hatafsegolquarterhebrew\
+hatafsegolwidehebrew\
+hbar\
+hbopomofo\
+hbrevebelow\
+hcedilla\
+hcircle\
+hcircumflex\
+hdieresis\
+hdotaccent\
+hdotbelow\
+he\
+heart\
+heartsuitblack\
+heartsuitwhite\
+hedagesh\
+hedageshhebrew\
+hehaltonearabic\
+heharabic\
+hehebrew\
+hehfinalaltonearabic\
+hehfinalalttwoarabic\
+hehfinalarabic\
+hehhamzaabovefinalarabic\
+hehhamzaaboveisolatedarabic\
+hehinitialaltonearabic\
+hehinitialarabic\
+hehiragana\
+hehmedialaltonearabic\
+hehmedialarabic\
+heiseierasquare\
+hekatakana\
+hekatakanahalfwidth\
+hekutaarusquare\
+henghook\
+herutusquare\
+het\
+hethebrew\
+hhook\
+hhooksuperior\
+hieuhacirclekorean\
+hieuhaparenkorean\
+hieuhcirclekorean\
+hieuhkorean\
+hieuhparenkorean\
+hihiragana\
+hikatakana\
+hikatakanahalfwidth\
+hiriq\
+hiriq14\
+hiriq21\
+hiriq2d\
+hiriqhebrew\
+hiriqnarrowhebrew\
+hiriqquarterhebrew\
+hiriqwidehebrew\
+hlinebelow\
+hmonospace\
+hoarmenian\
+hohipthai\
+hohiragana\
+hokatakana\
+hokatakanahalfwidth\
+holam\
+holam19\
+holam26\
+holam32\
+holamhebrew\
+holamnarrowhebrew\
+holamquarterhebrew\
+holamwidehebrew\
+honokhukthai\
+hookabovecomb\
+hookcmb\
+hookpalatalizedbelowcmb\
+hookretroflexbelowcmb\
+hoonsquare\
+horicoptic\
+horizontalbar\
+horncmb\
+hotsprings\
+house\
+hparen\
+hsuperior\
+hturned\
+huhiragana\
+huiitosquare\
+hukatakana\
+hukatakanahalfwidth\
+hungarumlaut\
+hungarumlautcmb\
+hv\
+hyphen\
+hypheninferior\
+hyphenmonospace\
+hyphensmall\
+hyphensuperior\
+hyphentwo\
+i\
+iacute\
+iacyrillic\
+ibengali\
+ibopomofo\
+ibreve\
+icaron\
+icircle\
+icircumflex\
+icyrillic\
+idblgrave\
+ideographearthcircle\
+ideographfirecircle\
+ideographicallianceparen\
+ideographiccallparen\
+ideographiccentrecircle\
+ideographicclose\
+ideographiccomma\
+ideographiccommaleft\
+ideographiccongratulationparen\
+ideographiccorrectcircle\
+ideographicearthparen\
+ideographicenterpriseparen\
+ideographicexcellentcircle\
+ideographicfestivalparen\
+ideographicfinancialcircle\
+ideographicfinancialparen\
+ideographicfireparen\
+ideographichaveparen\
+ideographichighcircle\
+ideographiciterationmark\
+ideographiclaborcircle\
+ideographiclaborparen\
+ideographicleftcircle\
+ideographiclowcircle\
+ideographicmedicinecircle\
+ideographicmetalparen\
+ideographicmoonparen\
+ideographicnameparen\
+ideographicperiod\
+ideographicprintcircle\
+ideographicreachparen\
+ideographicrepresentparen\
+ideographicresourceparen\
+ideographicrightcircle\
+ideographicsecretcircle\
+ideographicselfparen\
+ideographicsocietyparen\
+ideographicspace\
+ideographicspecialparen\
+ideographicstockparen\
+ideographicstudyparen\
+ideographicsunparen\
+ideographicsuperviseparen\
+ideographicwaterparen\
+ideographicwoodparen\
+ideographiczero\
+ideographmetalcircle\
+ideographmooncircle\
+ideographnamecircle\
+ideographsuncircle\
+ideographwatercircle\
+ideographwoodcircle\
+ideva\
+idieresis\
+idieresisacute\
+idieresiscyrillic\
+idotbelow\
+iebrevecyrillic\
+iecyrillic\
+ieungacirclekorean\
+ieungaparenkorean\
+ieungcirclekorean\
+ieungkorean\
+ieungparenkorean\
+igrave\
+igujarati\
+igurmukhi\
+ihiragana\
+ihookabove\
+iibengali\
+iicyrillic\
+iideva\
+iigujarati\
+iigurmukhi\
+iimatragurmukhi\
+iinvertedbreve\
+iishortcyrillic\
+iivowelsignbengali\
+iivowelsigndeva\
+iivowelsigngujarati\
+ij\
+ikatakana\
+ikatakanahalfwidth\
+ikorean\
+ilde\
+iluyhebrew\
+imacron\
+imacroncyrillic\
+imageorapproximatelyequal\
+imatragurmukhi\
+imonospace\
+increment\
+infinity\
+iniarmenian\
+integral\
+integralbottom\
+integralbt\
+integralex\
+integraltop\
+integraltp\
+intersection\
+intisquare\
+invbullet\
+invcircle\
+invsmileface\
+iocyrillic\
+iogonek\
+iota\
+iotadieresis\
+iotadieresistonos\
+iotalatin\
+iotatonos\
+iparen\
+irigurmukhi\
+ismallhiragana\
+ismallkatakana\
+ismallkatakanahalfwidth\
+issharbengali\
+istroke\
+isuperior\
+iterationhiragana\
+iterationkatakana\
+itilde\
+itildebelow\
+iubopomofo\
+iucyrillic\
+ivowelsignbengali\
+ivowelsigndeva\
+ivowelsigngujarati\
+izhitsacyrillic\
+izhitsadblgravecyrillic\
+j\
+jaarmenian\
+jabengali\
+jadeva\
+jagujarati\
+jagurmukhi\
+jbopomofo\
+jcaron\
+jcircle\
+jcircumflex\
+jcrossedtail\
+jdotlessstroke\
+jecyrillic\
+jeemarabic\
+jeemfinalarabic\
+jeeminitialarabic\
+jeemmedialarabic\
+jeharabic\
+jehfinalarabic\
+jhabengali\
+jhadeva\
+jhagujarati\
+jhagurmukhi\
+jheharmenian\
+jis\
+jmonospace\
+jparen\
+jsuperior\
+k\
+kabashkircyrillic\
+kabengali\
+kacute\
+kacyrillic\
+kadescendercyrillic\
+kadeva\
+kaf\
+kafarabic\
+kafdagesh\
+kafdageshhebrew\
+kaffinalarabic\
+kafhebrew\
+kafinitialarabic\
+kafmedialarabic\
+kafrafehebrew\
+kagujarati\
+kagurmukhi\
+kahiragana\
+kahookcyrillic\
+kakatakana\
+kakatakanahalfwidth\
+kappa\
+kappasymbolgreek\
+kapyeounmieumkorean\
+kapyeounphieuphkorean\
+kapyeounpieupkorean\
+kapyeounssangpieupkorean\
+karoriisquare\
+kashidaautoarabic\
+kashidaautonosidebearingarabic\
+kasmallkatakana\
+kasquare\
+kasraarabic\
+kasratanarabic\
+kastrokecyrillic\
+katahiraprolongmarkhalfwidth\
+kaverticalstrokecyrillic\
+kbopomofo\
+kcalsquare\
+kcaron\
+kcedilla\
+kcircle\
+kcommaaccent\
+kdotbelow\
+keharmenian\
+kehiragana\
+kekatakana\
+kekatakanahalfwidth\
+kenarmenian\
+kesmallkatakana\
+kgreenlandic\
+khabengali\
+khacyrillic\
+khadeva\
+khagujarati\
+khagurmukhi\
+khaharabic\
+khahfinalarabic\
+khahinitialarabic\
+khahmedialarabic\
+kheicoptic\
+khhadeva\
+khhagurmukhi\
+khieukhacirclekorean\
+khieukhaparenkorean\
+khieukhcirclekorean\
+khieukhkorean\
+khieukhparenkorean\
+khokhaithai\
+khokhonthai\
+khokhuatthai\
+khokhwaithai\
+khomutthai\
+khook\
+khorakhangthai\
+khzsquare\
+kihiragana\
+kikatakana\
+kikatakanahalfwidth\
+kiroguramusquare\
+kiromeetorusquare\
+kirosquare\
+kiyeokacirclekorean\
+kiyeokaparenkorean\
+kiyeokcirclekorean\
+kiyeokkorean\
+kiyeokparenkorean\
+kiyeoksioskorean\
+kjecyrillic\
+klinebelow\
+klsquare\
+kmcubedsquare\
+kmonospace\
+kmsquaredsquare\
+kohiragana\
+kohmsquare\
+kokaithai\
+kokatakana\
+kokatakanahalfwidth\
+kooposquare\
+koppacyrillic\
+koreanstandardsymbol\
+koroniscmb\
+kparen\
+kpasquare\
+ksicyrillic\
+ktsquare\
+kturned\
+kuhiragana\
+kukatakana\
+kukatakanahalfwidth\
+kvsquare\
+kwsquare\
+l\
+labengali\
+lacute\
+ladeva\
+lagujarati\
+lagurmukhi\
+lakkhangyaothai\
+lamaleffinalarabic\
+lamalefhamzaabovefinalarabic\
+lamalefhamzaaboveisolatedarabic\
+lamalefhamzabelowfinalarabic\
+lamalefhamzabelowisolatedarabic\
+lamalefisolatedarabic\
+lamalefmaddaabovefinalarabic\
+lamalefmaddaaboveisolatedarabic\
+lamarabic\
+lambda\
+lambdastroke\
+lamed\
+lameddagesh\
+lameddageshhebrew\
+lamedhebrew\
+lamfinalarabic\
+lamhahinitialarabic\
+laminitialarabic\
+lamjeeminitialarabic\
+lamkhahinitialarabic\
+lamlamhehisolatedarabic\
+lammedialarabic\
+lammeemhahinitialarabic\
+lammeeminitialarabic\
+largecircle\
+lbar\
+lbelt\
+lbopomofo\
+lcaron\
+lcedilla\
+lcircle\
+lcircumflexbelow\
+lcommaaccent\
+ldot\
+ldotaccent\
+ldotbelow\
+ldotbelowmacron\
+leftangleabovecmb\
+lefttackbelowcmb\
+less\
+lessequal\
+lessequalorgreater\
+lessmonospace\
+lessorequivalent\
+lessorgreater\
+lessoverequal\
+lesssmall\
+lezh\
+lfblock\
+lhookretroflex\
+lira\
+liwnarmenian\
+lj\
+ljecyrillic\
+ll\
+lladeva\
+llagujarati\
+llinebelow\
+llladeva\
+llvocalicbengali\
+llvocalicdeva\
+llvocalicvowelsignbengali\
+llvocalicvowelsigndeva\
+lmiddletilde\
+lmonospace\
+lmsquare\
+lochulathai\
+logicaland\
+logicalnot\
+logicalnotreversed\
+logicalor\
+lolingthai\
+longs\
+lowlinecenterline\
+lowlinecmb\
+lowlinedashed\
+lozenge\
+lparen\
+lslash\
+lsquare\
+lsuperior\
+ltshade\
+luthai\
+lvocalicbengali\
+lvocalicdeva\
+lvocalicvowelsignbengali\
+lvocalicvowelsigndeva\
+lxsquare\
+m\
+mabengali\
+macron\
+macronbelowcmb\
+macroncmb\
+macronlowmod\
+macronmonospace\
+macute\
+madeva\
+magujarati\
+magurmukhi\
+mahapakhhebrew\
+mahapakhlefthebrew\
+mahiragana\
+maichattawalowleftthai\
+maichattawalowrightthai\
+maichattawathai\
+maichattawaupperleftthai\
+maieklowleftthai\
+maieklowrightthai\
+maiekthai\
+maiekupperleftthai\
+maihanakatleftthai\
+maihanakatthai\
+maitaikhuleftthai\
+maitaikhuthai\
+maitholowleftthai\
+maitholowrightthai\
+maithothai\
+maithoupperleftthai\
+maitrilowleftthai\
+maitrilowrightthai\
+maitrithai\
+maitriupperleftthai\
+maiyamokthai\
+makatakana\
+makatakanahalfwidth\
+male\
+mansyonsquare\
+maqafhebrew\
+mars\
+masoracirclehebrew\
+masquare\
+mbopomofo\
+mbsquare\
+mcircle\
+mcubedsquare\
+mdotaccent\
+mdotbelow\
+meemarabic\
+meemfinalarabic\
+meeminitialarabic\
+meemmedialarabic\
+meemmeeminitialarabic\
+meemmeemisolatedarabic\
+meetorusquare\
+mehiragana\
+meizierasquare\
+mekatakana\
+mekatakanahalfwidth\
+mem\
+memdagesh\
+memdageshhebrew\
+memhebrew\
+menarmenian\
+merkhahebrew\
+merkhakefulahebrew\
+merkhakefulalefthebrew\
+merkhalefthebrew\
+mhook\
+mhzsquare\
+middledotkatakanahalfwidth\
+middot\
+mieumacirclekorean\
+mieumaparenkorean\
+mieumcirclekorean\
+mieumkorean\
+mieumpansioskorean\
+mieumparenkorean\
+mieumpieupkorean\
+mieumsioskorean\
+mihiragana\
+mikatakana\
+mikatakanahalfwidth\
+minus\
+minusbelowcmb\
+minuscircle\
+minusmod\
+minusplus\
+minute\
+miribaarusquare\
+mirisquare\
+mlonglegturned\
+mlsquare\
+mmcubedsquare\
+mmonospace\
+mmsquaredsquare\
+mohiragana\
+mohmsquare\
+mokatakana\
+mokatakanahalfwidth\
+molsquare\
+momathai\
+moverssquare\
+moverssquaredsquare\
+mparen\
+mpasquare\
+mssquare\
+msuperior\
+mturned\
+mu\
+mu1\
+muasquare\
+muchgreater\
+muchless\
+mufsquare\
+mugreek\
+mugsquare\
+muhiragana\
+mukatakana\
+mukatakanahalfwidth\
+mulsquare\
+multiply\
+mumsquare\
+munahhebrew\
+munahlefthebrew\
+musicalnote\
+musicalnotedbl\
+musicflatsign\
+musicsharpsign\
+mussquare\
+muvsquare\
+muwsquare\
+mvmegasquare\
+mvsquare\
+mwmegasquare\
+mwsquare\
+n\
+nabengali\
+nabla\
+nacute\
+nadeva\
+nagujarati\
+nagurmukhi\
+nahiragana\
+nakatakana\
+nakatakanahalfwidth\
+napostrophe\
+nasquare\
+nbopomofo\
+nbspace\
+ncaron\
+ncedilla\
+ncircle\
+ncircumflexbelow\
+ncommaaccent\
+ndotaccent\
+ndotbelow\
+nehiragana\
+nekatakana\
+nekatakanahalfwidth\
+newsheqelsign\
+nfsquare\
+ngabengali\
+ngadeva\
+ngagujarati\
+ngagurmukhi\
+ngonguthai\
+nhiragana\
+nhookleft\
+nhookretroflex\
+nieunacirclekorean\
+nieunaparenkorean\
+nieuncieuckorean\
+nieuncirclekorean\
+nieunhieuhkorean\
+nieunkorean\
+nieunpansioskorean\
+nieunparenkorean\
+nieunsioskorean\
+nieuntikeutkorean\
+nihiragana\
+nikatakana\
+nikatakanahalfwidth\
+nikhahitleftthai\
+nikhahitthai\
+nine\
+ninearabic\
+ninebengali\
+ninecircle\
+ninecircleinversesansserif\
+ninedeva\
+ninegujarati\
+ninegurmukhi\
+ninehackarabic\
+ninehangzhou\
+nineideographicparen\
+nineinferior\
+ninemonospace\
+nineoldstyle\
+nineparen\
+nineperiod\
+ninepersian\
+nineroman\
+ninesuperior\
+nineteencircle\
+nineteenparen\
+nineteenperiod\
+ninethai\
+nj\
+njecyrillic\
+nkatakana\
+nkatakanahalfwidth\
+nlegrightlong\
+nlinebelow\
+nmonospace\
+nmsquare\
+nnabengali\
+nnadeva\
+nnagujarati\
+nnagurmukhi\
+nnnadeva\
+nohiragana\
+nokatakana\
+nokatakanahalfwidth\
+nonbreakingspace\
+nonenthai\
+nonuthai\
+noonarabic\
+noonfinalarabic\
+noonghunnaarabic\
+noonghunnafinalarabic\
+nooninitialarabic\
+noonjeeminitialarabic\
+noonjeemisolatedarabic\
+noonmedialarabic\
+noonmeeminitialarabic\
+noonmeemisolatedarabic\
+noonnoonfinalarabic\
+notcontains\
+notelement\
+notelementof\
+notequal\
+notgreater\
+notgreaternorequal\
+notgreaternorless\
+notidentical\
+notless\
+notlessnorequal\
+notparallel\
+notprecedes\
+notsubset\
+notsucceeds\
+notsuperset\
+nowarmenian\
+nparen\
+nssquare\
+nsuperior\
+ntilde\
+nu\
+nuhiragana\
+nukatakana\
+nukatakanahalfwidth\
+nuktabengali\
+nuktadeva\
+nuktagujarati\
+nuktagurmukhi\
+numbersign\
+numbersignmonospace\
+numbersignsmall\
+numeralsigngreek\
+numeralsignlowergreek\
+numero\
+nun\
+nundagesh\
+nundageshhebrew\
+nunhebrew\
+nvsquare\
+nwsquare\
+nyabengali\
+nyadeva\
+nyagujarati\
+nyagurmukhi\
+o\
+oacute\
+oangthai\
+obarred\
+obarredcyrillic\
+obarreddieresiscyrillic\
+obengali\
+obopomofo\
+obreve\
+ocandradeva\
+ocandragujarati\
+ocandravowelsigndeva\
+ocandravowelsigngujarati\
+ocaron\
+ocircle\
+ocircumflex\
+ocircumflexacute\
+ocircumflexdotbelow\
+ocircumflexgrave\
+ocircumflexhookabove\
+ocircumflextilde\
+ocyrillic\
+odblacute\
+odblgrave\
+odeva\
+odieresis\
+odieresiscyrillic\
+odotbelow\
+oe\
+oekorean\
+ogonek\
+ogonekcmb\
+ograve\
+ogujarati\
+oharmenian\
+ohiragana\
+ohookabove\
+ohorn\
+ohornacute\
+ohorndotbelow\
+ohorngrave\
+ohornhookabove\
+ohorntilde\
+ohungarumlaut\
+oi\
+oinvertedbreve\
+okatakana\
+okatakanahalfwidth\
+okorean\
+olehebrew\
+omacron\
+omacronacute\
+omacrongrave\
+omdeva\
+omega\
+omega1\
+omegacyrillic\
+omegalatinclosed\
+omegaroundcyrillic\
+omegatitlocyrillic\
+omegatonos\
+omgujarati\
+omicron\
+omicrontonos\
+omonospace\
+one\
+onearabic\
+onebengali\
+onecircle\
+onecircleinversesansserif\
+onedeva\
+onedotenleader\
+oneeighth\
+onefitted\
+onegujarati\
+onegurmukhi\
+onehackarabic\
+onehalf\
+onehangzhou\
+oneideographicparen\
+oneinferior\
+onemonospace\
+onenumeratorbengali\
+oneoldstyle\
+oneparen\
+oneperiod\
+onepersian\
+onequarter\
+oneroman\
+onesuperior\
+onethai\
+onethird\
+oogonek\
+oogonekmacron\
+oogurmukhi\
+oomatragurmukhi\
+oopen\
+oparen\
+openbullet\
+option\
+ordfeminine\
+ordmasculine\
+orthogonal\
+oshortdeva\
+oshortvowelsigndeva\
+oslash\
+oslashacute\
+osmallhiragana\
+osmallkatakana\
+osmallkatakanahalfwidth\
+ostrokeacute\
+osuperior\
+otcyrillic\
+otilde\
+otildeacute\
+otildedieresis\
+oubopomofo\
+overline\
+overlinecenterline\
+overlinecmb\
+overlinedashed\
+overlinedblwavy\
+overlinewavy\
+overscore\
+ovowelsignbengali\
+ovowelsigndeva\
+ovowelsigngujarati\
+p\
+paampssquare\
+paasentosquare\
+pabengali\
+pacute\
+padeva\
+pagedown\
+pageup\
+pagujarati\
+pagurmukhi\
+pahiragana\
+paiyannoithai\
+pakatakana\
+palatalizationcyrilliccmb\
+palochkacyrillic\
+pansioskorean\
+paragraph\
+parallel\
+parenleft\
+parenleftaltonearabic\
+parenleftbt\
+parenleftex\
+parenleftinferior\
+parenleftmonospace\
+parenleftsmall\
+parenleftsuperior\
+parenlefttp\
+parenleftvertical\
+parenright\
+parenrightaltonearabic\
+parenrightbt\
+parenrightex\
+parenrightinferior\
+parenrightmonospace\
+parenrightsmall\
+parenrightsuperior\
+parenrighttp\
+parenrightvertical\
+partialdiff\
+paseqhebrew\
+pashtahebrew\
+pasquare\
+patah\
+patah11\
+patah1d\
+patah2a\
+patahhebrew\
+patahnarrowhebrew\
+patahquarterhebrew\
+patahwidehebrew\
+pazerhebrew\
+pbopomofo\
+pcircle\
+pdotaccent\
+pe\
+pecyrillic\
+pedagesh\
+pedageshhebrew\
+peezisquare\
+pefinaldageshhebrew\
+peharabic\
+peharmenian\
+pehebrew\
+pehfinalarabic\
+pehinitialarabic\
+pehiragana\
+pehmedialarabic\
+pekatakana\
+pemiddlehookcyrillic\
+perafehebrew\
+percent\
+percentarabic\
+percentmonospace\
+percentsmall\
+period\
+periodarmenian\
+periodcentered\
+periodhalfwidth\
+periodinferior\
+periodmonospace\
+periodsmall\
+periodsuperior\
+perispomenigreekcmb\
+perpendicular\
+perthousand\
+peseta\
+pfsquare\
+phabengali\
+phadeva\
+phagujarati\
+phagurmukhi\
+phi\
+phi1\
+phieuphacirclekorean\
+phieuphaparenkorean\
+phieuphcirclekorean\
+phieuphkorean\
+phieuphparenkorean\
+philatin\
+phinthuthai\
+phisymbolgreek\
+phook\
+phophanthai\
+phophungthai\
+phosamphaothai\
+pi\
+pieupacirclekorean\
+pieupaparenkorean\
+pieupcieuckorean\
+pieupcirclekorean\
+pieupkiyeokkorean\
+pieupkorean\
+pieupparenkorean\
+pieupsioskiyeokkorean\
+pieupsioskorean\
+pieupsiostikeutkorean\
+pieupthieuthkorean\
+pieuptikeutkorean\
+pihiragana\
+pikatakana\
+pisymbolgreek\
+piwrarmenian\
+plus\
+plusbelowcmb\
+pluscircle\
+plusminus\
+plusmod\
+plusmonospace\
+plussmall\
+plussuperior\
+pmonospace\
+pmsquare\
+pohiragana\
+pointingindexdownwhite\
+pointingindexleftwhite\
+pointingindexrightwhite\
+pointingindexupwhite\
+pokatakana\
+poplathai\
+postalmark\
+postalmarkface\
+pparen\
+precedes\
+prescription\
+primemod\
+primereversed\
+product\
+projective\
+prolongedkana\
+propellor\
+propersubset\
+propersuperset\
+proportion\
+proportional\
+psi\
+psicyrillic\
+psilipneumatacyrilliccmb\
+pssquare\
+puhiragana\
+pukatakana\
+pvsquare\
+pwsquare\
+q\
+qadeva\
+qadmahebrew\
+qafarabic\
+qaffinalarabic\
+qafinitialarabic\
+qafmedialarabic\
+qamats\
+qamats10\
+qamats1a\
+qamats1c\
+qamats27\
+qamats29\
+qamats33\
+qamatsde\
+qamatshebrew\
+qamatsnarrowhebrew\
+qamatsqatanhebrew\
+qamatsqatannarrowhebrew\
+qamatsqatanquarterhebrew\
+qamatsqatanwidehebrew\
+qamatsquarterhebrew\
+qamatswidehebrew\
+qarneyparahebrew\
+qbopomofo\
+qcircle\
+qhook\
+qmonospace\
+qof\
+qofdagesh\
+qofdageshhebrew\
+qofhebrew\
+qparen\
+quarternote\
+qubuts\
+qubuts18\
+qubuts25\
+qubuts31\
+qubutshebrew\
+qubutsnarrowhebrew\
+qubutsquarterhebrew\
+qubutswidehebrew\
+question\
+questionarabic\
+questionarmenian\
+questiondown\
+questiondownsmall\
+questiongreek\
+questionmonospace\
+questionsmall\
+quotedbl\
+quotedblbase\
+quotedblleft\
+quotedblmonospace\
+quotedblprime\
+quotedblprimereversed\
+quotedblright\
+quoteleft\
+quoteleftreversed\
+quotereversed\
+quoteright\
+quoterightn\
+quotesinglbase\
+quotesingle\
+quotesinglemonospace\
+r\
+raarmenian\
+rabengali\
+racute\
+radeva\
+radical\
+radicalex\
+radoverssquare\
+radoverssquaredsquare\
+radsquare\
+rafe\
+rafehebrew\
+ragujarati\
+ragurmukhi\
+rahiragana\
+rakatakana\
+rakatakanahalfwidth\
+ralowerdiagonalbengali\
+ramiddlediagonalbengali\
+ramshorn\
+ratio\
+rbopomofo\
+rcaron\
+rcedilla\
+rcircle\
+rcommaaccent\
+rdblgrave\
+rdotaccent\
+rdotbelow\
+rdotbelowmacron\
+referencemark\
+reflexsubset\
+reflexsuperset\
+registered\
+registersans\
+registerserif\
+reharabic\
+reharmenian\
+rehfinalarabic\
+rehiragana\
+rekatakana\
+rekatakanahalfwidth\
+resh\
+reshdageshhebrew\
+reshhebrew\
+reversedtilde\
+reviahebrew\
+reviamugrashhebrew\
+revlogicalnot\
+rfishhook\
+rfishhookreversed\
+rhabengali\
+rhadeva\
+rho\
+rhook\
+rhookturned\
+rhookturnedsuperior\
+rhosymbolgreek\
+rhotichookmod\
+rieulacirclekorean\
+rieulaparenkorean\
+rieulcirclekorean\
+rieulhieuhkorean\
+rieulkiyeokkorean\
+rieulkiyeoksioskorean\
+rieulkorean\
+rieulmieumkorean\
+rieulpansioskorean\
+rieulparenkorean\
+rieulphieuphkorean\
+rieulpieupkorean\
+rieulpieupsioskorean\
+rieulsioskorean\
+rieulthieuthkorean\
+rieultikeutkorean\
+rieulyeorinhieuhkorean\
+rightangle\
+righttackbelowcmb\
+righttriangle\
+rihiragana\
+rikatakana\
+rikatakanahalfwidth\
+ring\
+ringbelowcmb\
+ringcmb\
+ringhalfleft\
+ringhalfleftarmenian\
+ringhalfleftbelowcmb\
+ringhalfleftcentered\
+ringhalfright\
+ringhalfrightbelowcmb\
+ringhalfrightcentered\
+rinvertedbreve\
+rittorusquare\
+rlinebelow\
+rlongleg\
+rlonglegturned\
+rmonospace\
+rohiragana\
+rokatakana\
+rokatakanahalfwidth\
+roruathai\
+rparen\
+rrabengali\
+rradeva\
+rragurmukhi\
+rreharabic\
+rrehfinalarabic\
+rrvocalicbengali\
+rrvocalicdeva\
+rrvocalicgujarati\
+rrvocalicvowelsignbengali\
+rrvocalicvowelsigndeva\
+rrvocalicvowelsigngujarati\
+rsuperior\
+rtblock\
+rturned\
+rturnedsuperior\
+ruhiragana\
+rukatakana\
+rukatakanahalfwidth\
+rupeemarkbengali\
+rupeesignbengali\
+rupiah\
+ruthai\
+rvocalicbengali\
+rvocalicdeva\
+rvocalicgujarati\
+rvocalicvowelsignbengali\
+rvocalicvowelsigndeva\
+rvocalicvowelsigngujarati\
+s\
+sabengali\
+sacute\
+sacutedotaccent\
+sadarabic\
+sadeva\
+sadfinalarabic\
+sadinitialarabic\
+sadmedialarabic\
+sagujarati\
+sagurmukhi\
+sahiragana\
+sakatakana\
+sakatakanahalfwidth\
+sallallahoualayhewasallamarabic\
+samekh\
+samekhdagesh\
+samekhdageshhebrew\
+samekhhebrew\
+saraaathai\
+saraaethai\
+saraaimaimalaithai\
+saraaimaimuanthai\
+saraamthai\
+saraathai\
+saraethai\
+saraiileftthai\
+saraiithai\
+saraileftthai\
+saraithai\
+saraothai\
+saraueeleftthai\
+saraueethai\
+saraueleftthai\
+sarauethai\
+sarauthai\
+sarauuthai\
+sbopomofo\
+scaron\
+scarondotaccent\
+scedilla\
+schwa\
+schwacyrillic\
+schwadieresiscyrillic\
+schwahook\
+scircle\
+scircumflex\
+scommaaccent\
+sdotaccent\
+sdotbelow\
+sdotbelowdotaccent\
+seagullbelowcmb\
+second\
+secondtonechinese\
+section\
+seenarabic\
+seenfinalarabic\
+seeninitialarabic\
+seenmedialarabic\
+segol\
+segol13\
+segol1f\
+segol2c\
+segolhebrew\
+segolnarrowhebrew\
+segolquarterhebrew\
+segoltahebrew\
+segolwidehebrew\
+seharmenian\
+sehiragana\
+sekatakana\
+sekatakanahalfwidth\
+semicolon\
+semicolonarabic\
+semicolonmonospace\
+semicolonsmall\
+semivoicedmarkkana\
+semivoicedmarkkanahalfwidth\
+sentisquare\
+sentosquare\
+seven\
+sevenarabic\
+sevenbengali\
+sevencircle\
+sevencircleinversesansserif\
+sevendeva\
+seveneighths\
+sevengujarati\
+sevengurmukhi\
+sevenhackarabic\
+sevenhangzhou\
+sevenideographicparen\
+seveninferior\
+sevenmonospace\
+sevenoldstyle\
+sevenparen\
+sevenperiod\
+sevenpersian\
+sevenroman\
+sevensuperior\
+seventeencircle\
+seventeenparen\
+seventeenperiod\
+seventhai\
+sfthyphen\
+shaarmenian\
+shabengali\
+shacyrillic\
+shaddaarabic\
+shaddadammaarabic\
+shaddadammatanarabic\
+shaddafathaarabic\
+shaddakasraarabic\
+shaddakasratanarabic\
+shade\
+shadedark\
+shadelight\
+shademedium\
+shadeva\
+shagujarati\
+shagurmukhi\
+shalshelethebrew\
+shbopomofo\
+shchacyrillic\
+sheenarabic\
+sheenfinalarabic\
+sheeninitialarabic\
+sheenmedialarabic\
+sheicoptic\
+sheqel\
+sheqelhebrew\
+sheva\
+sheva115\
+sheva15\
+sheva22\
+sheva2e\
+shevahebrew\
+shevanarrowhebrew\
+shevaquarterhebrew\
+shevawidehebrew\
+shhacyrillic\
+shimacoptic\
+shin\
+shindagesh\
+shindageshhebrew\
+shindageshshindot\
+shindageshshindothebrew\
+shindageshsindot\
+shindageshsindothebrew\
+shindothebrew\
+shinhebrew\
+shinshindot\
+shinshindothebrew\
+shinsindot\
+shinsindothebrew\
+shook\
+sigma\
+sigma1\
+sigmafinal\
+sigmalunatesymbolgreek\
+sihiragana\
+sikatakana\
+sikatakanahalfwidth\
+siluqhebrew\
+siluqlefthebrew\
+similar\
+sindothebrew\
+siosacirclekorean\
+siosaparenkorean\
+sioscieuckorean\
+sioscirclekorean\
+sioskiyeokkorean\
+sioskorean\
+siosnieunkorean\
+siosparenkorean\
+siospieupkorean\
+siostikeutkorean\
+six\
+sixarabic\
+sixbengali\
+sixcircle\
+sixcircleinversesansserif\
+sixdeva\
+sixgujarati\
+sixgurmukhi\
+sixhackarabic\
+sixhangzhou\
+sixideographicparen\
+sixinferior\
+sixmonospace\
+sixoldstyle\
+sixparen\
+sixperiod\
+sixpersian\
+sixroman\
+sixsuperior\
+sixteencircle\
+sixteencurrencydenominatorbengali\
+sixteenparen\
+sixteenperiod\
+sixthai\
+slash\
+slashmonospace\
+slong\
+slongdotaccent\
+smileface\
+smonospace\
+sofpasuqhebrew\
+softhyphen\
+softsigncyrillic\
+sohiragana\
+sokatakana\
+sokatakanahalfwidth\
+soliduslongoverlaycmb\
+solidusshortoverlaycmb\
+sorusithai\
+sosalathai\
+sosothai\
+sosuathai\
+space\
+spacehackarabic\
+spade\
+spadesuitblack\
+spadesuitwhite\
+sparen\
+squarebelowcmb\
+squarecc\
+squarecm\
+squarediagonalcrosshatchfill\
+squarehorizontalfill\
+squarekg\
+squarekm\
+squarekmcapital\
+squareln\
+squarelog\
+squaremg\
+squaremil\
+squaremm\
+squaremsquared\
+squareorthogonalcrosshatchfill\
+squareupperlefttolowerrightfill\
+squareupperrighttolowerleftfill\
+squareverticalfill\
+squarewhitewithsmallblack\
+srsquare\
+ssabengali\
+ssadeva\
+ssagujarati\
+ssangcieuckorean\
+ssanghieuhkorean\
+ssangieungkorean\
+ssangkiyeokkorean\
+ssangnieunkorean\
+ssangpieupkorean\
+ssangsioskorean\
+ssangtikeutkorean\
+ssuperior\
+sterling\
+sterlingmonospace\
+strokelongoverlaycmb\
+strokeshortoverlaycmb\
+subset\
+subsetnotequal\
+subsetorequal\
+succeeds\
+suchthat\
+suhiragana\
+sukatakana\
+sukatakanahalfwidth\
+sukunarabic\
+summation\
+sun\
+superset\
+supersetnotequal\
+supersetorequal\
+svsquare\
+syouwaerasquare\
+t\
+tabengali\
+tackdown\
+tackleft\
+tadeva\
+tagujarati\
+tagurmukhi\
+taharabic\
+tahfinalarabic\
+tahinitialarabic\
+tahiragana\
+tahmedialarabic\
+taisyouerasquare\
+takatakana\
+takatakanahalfwidth\
+tatweelarabic\
+tau\
+tav\
+tavdages\
+tavdagesh\
+tavdageshhebrew\
+tavhebrew\
+tbar\
+tbopomofo\
+tcaron\
+tccurl\
+tcedilla\
+tcheharabic\
+tchehfinalarabic\
+tchehinitialarabic\
+tchehmedialarabic\
+tcircle\
+tcircumflexbelow\
+tcommaaccent\
+tdieresis\
+tdotaccent\
+tdotbelow\
+tecyrillic\
+tedescendercyrillic\
+teharabic\
+tehfinalarabic\
+tehhahinitialarabic\
+tehhahisolatedarabic\
+tehinitialarabic\
+tehiragana\
+tehjeeminitialarabic\
+tehjeemisolatedarabic\
+tehmarbutaarabic\
+tehmarbutafinalarabic\
+tehmedialarabic\
+tehmeeminitialarabic\
+tehmeemisolatedarabic\
+tehnoonfinalarabic\
+tekatakana\
+tekatakanahalfwidth\
+telephone\
+telephoneblack\
+telishagedolahebrew\
+telishaqetanahebrew\
+tencircle\
+tenideographicparen\
+tenparen\
+tenperiod\
+tenroman\
+tesh\
+tet\
+tetdagesh\
+tetdageshhebrew\
+tethebrew\
+tetsecyrillic\
+tevirhebrew\
+tevirlefthebrew\
+thabengali\
+thadeva\
+thagujarati\
+thagurmukhi\
+thalarabic\
+thalfinalarabic\
+thanthakhatlowleftthai\
+thanthakhatlowrightthai\
+thanthakhatthai\
+thanthakhatupperleftthai\
+theharabic\
+thehfinalarabic\
+thehinitialarabic\
+thehmedialarabic\
+thereexists\
+therefore\
+theta\
+theta1\
+thetasymbolgreek\
+thieuthacirclekorean\
+thieuthaparenkorean\
+thieuthcirclekorean\
+thieuthkorean\
+thieuthparenkorean\
+thirteencircle\
+thirteenparen\
+thirteenperiod\
+thonangmonthothai\
+thook\
+thophuthaothai\
+thorn\
+thothahanthai\
+thothanthai\
+thothongthai\
+thothungthai\
+thousandcyrillic\
+thousandsseparatorarabic\
+thousandsseparatorpersian\
+three\
+threearabic\
+threebengali\
+threecircle\
+threecircleinversesansserif\
+threedeva\
+threeeighths\
+threegujarati\
+threegurmukhi\
+threehackarabic\
+threehangzhou\
+threeideographicparen\
+threeinferior\
+threemonospace\
+threenumeratorbengali\
+threeoldstyle\
+threeparen\
+threeperiod\
+threepersian\
+threequarters\
+threequartersemdash\
+threeroman\
+threesuperior\
+threethai\
+thzsquare\
+tihiragana\
+tikatakana\
+tikatakanahalfwidth\
+tikeutacirclekorean\
+tikeutaparenkorean\
+tikeutcirclekorean\
+tikeutkorean\
+tikeutparenkorean\
+tilde\
+tildebelowcmb\
+tildecmb\
+tildecomb\
+tildedoublecmb\
+tildeoperator\
+tildeoverlaycmb\
+tildeverticalcmb\
+timescircle\
+tipehahebrew\
+tipehalefthebrew\
+tippigurmukhi\
+titlocyrilliccmb\
+tiwnarmenian\
+tlinebelow\
+tmonospace\
+toarmenian\
+tohiragana\
+tokatakana\
+tokatakanahalfwidth\
+tonebarextrahighmod\
+tonebarextralowmod\
+tonebarhighmod\
+tonebarlowmod\
+tonebarmidmod\
+tonefive\
+tonesix\
+tonetwo\
+tonos\
+tonsquare\
+topatakthai\
+tortoiseshellbracketleft\
+tortoiseshellbracketleftsmall\
+tortoiseshellbracketleftvertical\
+tortoiseshellbracketright\
+tortoiseshellbracketrightsmall\
+tortoiseshellbracketrightvertical\
+totaothai\
+tpalatalhook\
+tparen\
+trademark\
+trademarksans\
+trademarkserif\
+tretroflexhook\
+triagdn\
+triaglf\
+triagrt\
+triagup\
+ts\
+tsadi\
+tsadidagesh\
+tsadidageshhebrew\
+tsadihebrew\
+tsecyrillic\
+tsere\
+tsere12\
+tsere1e\
+tsere2b\
+tserehebrew\
+tserenarrowhebrew\
+tserequarterhebrew\
+tserewidehebrew\
+tshecyrillic\
+tsuperior\
+ttabengali\
+ttadeva\
+ttagujarati\
+ttagurmukhi\
+tteharabic\
+ttehfinalarabic\
+ttehinitialarabic\
+ttehmedialarabic\
+tthabengali\
+tthadeva\
+tthagujarati\
+tthagurmukhi\
+tturned\
+tuhiragana\
+tukatakana\
+tukatakanahalfwidth\
+tusmallhiragana\
+tusmallkatakana\
+tusmallkatakanahalfwidth\
+twelvecircle\
+twelveparen\
+twelveperiod\
+twelveroman\
+twentycircle\
+twentyhangzhou\
+twentyparen\
+twentyperiod\
+two\
+twoarabic\
+twobengali\
+twocircle\
+twocircleinversesansserif\
+twodeva\
+twodotenleader\
+twodotleader\
+twodotleadervertical\
+twogujarati\
+twogurmukhi\
+twohackarabic\
+twohangzhou\
+twoideographicparen\
+twoinferior\
+twomonospace\
+twonumeratorbengali\
+twooldstyle\
+twoparen\
+twoperiod\
+twopersian\
+tworoman\
+twostroke\
+twosuperior\
+twothai\
+twothirds\
+u\
+uacute\
+ubar\
+ubengali\
+ubopomofo\
+ubreve\
+ucaron\
+ucircle\
+ucircumflex\
+ucircumflexbelow\
+ucyrillic\
+udattadeva\
+udblacute\
+udblgrave\
+udeva\
+udieresis\
+udieresisacute\
+udieresisbelow\
+udieresiscaron\
+udieresiscyrillic\
+udieresisgrave\
+udieresismacron\
+udotbelow\
+ugrave\
+ugujarati\
+ugurmukhi\
+uhiragana\
+uhookabove\
+uhorn\
+uhornacute\
+uhorndotbelow\
+uhorngrave\
+uhornhookabove\
+uhorntilde\
+uhungarumlaut\
+uhungarumlautcyrillic\
+uinvertedbreve\
+ukatakana\
+ukatakanahalfwidth\
+ukcyrillic\
+ukorean\
+umacron\
+umacroncyrillic\
+umacrondieresis\
+umatragurmukhi\
+umonospace\
+underscore\
+underscoredbl\
+underscoremonospace\
+underscorevertical\
+underscorewavy\
+union\
+universal\
+uogonek\
+uparen\
+upblock\
+upperdothebrew\
+upsilon\
+upsilondieresis\
+upsilondieresistonos\
+upsilonlatin\
+upsilontonos\
+uptackbelowcmb\
+uptackmod\
+uragurmukhi\
+uring\
+ushortcyrillic\
+usmallhiragana\
+usmallkatakana\
+usmallkatakanahalfwidth\
+ustraightcyrillic\
+ustraightstrokecyrillic\
+utilde\
+utildeacute\
+utildebelow\
+uubengali\
+uudeva\
+uugujarati\
+uugurmukhi\
+uumatragurmukhi\
+uuvowelsignbengali\
+uuvowelsigndeva\
+uuvowelsigngujarati\
+uvowelsignbengali\
+uvowelsigndeva\
+uvowelsigngujarati\
+v\
+vadeva\
+vagujarati\
+vagurmukhi\
+vakatakana\
+vav\
+vavdagesh\
+vavdagesh65\
+vavdageshhebrew\
+vavhebrew\
+vavholam\
+vavholamhebrew\
+vavvavhebrew\
+vavyodhebrew\
+vcircle\
+vdotbelow\
+vecyrillic\
+veharabic\
+vehfinalarabic\
+vehinitialarabic\
+vehmedialarabic\
+vekatakana\
+venus\
+verticalbar\
+verticallineabovecmb\
+verticallinebelowcmb\
+verticallinelowmod\
+verticallinemod\
+vewarmenian\
+vhook\
+vikatakana\
+viramabengali\
+viramadeva\
+viramagujarati\
+visargabengali\
+visargadeva\
+visargagujarati\
+vmonospace\
+voarmenian\
+voicediterationhiragana\
+voicediterationkatakana\
+voicedmarkkana\
+voicedmarkkanahalfwidth\
+vokatakana\
+vparen\
+vtilde\
+vturned\
+vuhiragana\
+vukatakana\
+w\
+wacute\
+waekorean\
+wahiragana\
+wakatakana\
+wakatakanahalfwidth\
+wakorean\
+wasmallhiragana\
+wasmallkatakana\
+wattosquare\
+wavedash\
+wavyunderscorevertical\
+wawarabic\
+wawfinalarabic\
+wawhamzaabovearabic\
+wawhamzaabovefinalarabic\
+wbsquare\
+wcircle\
+wcircumflex\
+wdieresis\
+wdotaccent\
+wdotbelow\
+wehiragana\
+weierstrass\
+wekatakana\
+wekorean\
+weokorean\
+wgrave\
+whitebullet\
+whitecircle\
+whitecircleinverse\
+whitecornerbracketleft\
+whitecornerbracketleftvertical\
+whitecornerbracketright\
+whitecornerbracketrightvertical\
+whitediamond\
+whitediamondcontainingblacksmalldiamond\
+whitedownpointingsmalltriangle\
+whitedownpointingtriangle\
+whiteleftpointingsmalltriangle\
+whiteleftpointingtriangle\
+whitelenticularbracketleft\
+whitelenticularbracketright\
+whiterightpointingsmalltriangle\
+whiterightpointingtriangle\
+whitesmallsquare\
+whitesmilingface\
+whitesquare\
+whitestar\
+whitetelephone\
+whitetortoiseshellbracketleft\
+whitetortoiseshellbracketright\
+whiteuppointingsmalltriangle\
+whiteuppointingtriangle\
+wihiragana\
+wikatakana\
+wikorean\
+wmonospace\
+wohiragana\
+wokatakana\
+wokatakanahalfwidth\
+won\
+wonmonospace\
+wowaenthai\
+wparen\
+wring\
+wsuperior\
+wturned\
+wynn\
+x\
+xabovecmb\
+xbopomofo\
+xcircle\
+xdieresis\
+xdotaccent\
+xeharmenian\
+xi\
+xmonospace\
+xparen\
+xsuperior\
+y\
+yaadosquare\
+yabengali\
+yacute\
+yadeva\
+yaekorean\
+yagujarati\
+yagurmukhi\
+yahiragana\
+yakatakana\
+yakatakanahalfwidth\
+yakorean\
+yamakkanthai\
+yasmallhiragana\
+yasmallkatakana\
+yasmallkatakanahalfwidth\
+yatcyrillic\
+ycircle\
+ycircumflex\
+ydieresis\
+ydotaccent\
+ydotbelow\
+yeharabic\
+yehbarreearabic\
+yehbarreefinalarabic\
+yehfinalarabic\
+yehhamzaabovearabic\
+yehhamzaabovefinalarabic\
+yehhamzaaboveinitialarabic\
+yehhamzaabovemedialarabic\
+yehinitialarabic\
+yehmedialarabic\
+yehmeeminitialarabic\
+yehmeemisolatedarabic\
+yehnoonfinalarabic\
+yehthreedotsbelowarabic\
+yekorean\
+yen\
+yenmonospace\
+yeokorean\
+yeorinhieuhkorean\
+yerahbenyomohebrew\
+yerahbenyomolefthebrew\
+yericyrillic\
+yerudieresiscyrillic\
+yesieungkorean\
+yesieungpansioskorean\
+yesieungsioskorean\
+yetivhebrew\
+ygrave\
+yhook\
+yhookabove\
+yiarmenian\
+yicyrillic\
+yikorean\
+yinyang\
+yiwnarmenian\
+ymonospace\
+yod\
+yoddagesh\
+yoddageshhebrew\
+yodhebrew\
+yodyodhebrew\
+yodyodpatahhebrew\
+yohiragana\
+yoikorean\
+yokatakana\
+yokatakanahalfwidth\
+yokorean\
+yosmallhiragana\
+yosmallkatakana\
+yosmallkatakanahalfwidth\
+yotgreek\
+yoyaekorean\
+yoyakorean\
+yoyakthai\
+yoyingthai\
+yparen\
+ypogegrammeni\
+ypogegrammenigreekcmb\
+yr\
+yring\
+ysuperior\
+ytilde\
+yturned\
+yuhiragana\
+yuikorean\
+yukatakana\
+yukatakanahalfwidth\
+yukorean\
+yusbigcyrillic\
+yusbigiotifiedcyrillic\
+yuslittlecyrillic\
+yuslittleiotifiedcyrillic\
+yusmallhiragana\
+yusmallkatakana\
+yusmallkatakanahalfwidth\
+yuyekorean\
+yuyeokorean\
+yyabengali\
+yyadeva\
+z\
+zaarmenian\
+zacute\
+zadeva\
+zagurmukhi\
+zaharabic\
+zahfinalarabic\
+zahinitialarabic\
+zahiragana\
+zahmedialarabic\
+zainarabic\
+zainfinalarabic\
+zakatakana\
+zaqefgadolhebrew\
+zaqefqatanhebrew\
+zarqahebrew\
+zayin\
+zayindagesh\
+zayindageshhebrew\
+zayinhebrew\
+zbopomofo\
+zcaron\
+zcircle\
+zcircumflex\
+zcurl\
+zdot\
+zdotaccent\
+zdotbelow\
+zecyrillic\
+zedescendercyrillic\
+zedieresiscyrillic\
+zehiragana\
+zekatakana\
+zero\
+zeroarabic\
+zerobengali\
+zerodeva\
+zerogujarati\
+zerogurmukhi\
+zerohackarabic\
+zeroinferior\
+zeromonospace\
+zerooldstyle\
+zeropersian\
+zerosuperior\
+zerothai\
+zerowidthjoiner\
+zerowidthnonjoiner\
+zerowidthspace\
+zeta\
+zhbopomofo\
+zhearmenian\
+zhebrevecyrillic\
+zhecyrillic\
+zhedescendercyrillic\
+zhedieresiscyrillic\
+zihiragana\
+zikatakana\
+zinorhebrew\
+zlinebelow\
+zmonospace\
+zohiragana\
+zokatakana\
+zparen\
+zretroflexhook\
+zstroke\
+zuhiragana\
+zukatakana";
+
+/// Offset in `NAMES` just past each name
+static ENDS: [u16; 4200] = [
+    1, 3, 10, 18, 25, 31, 42, 48, 59, 73, 87, 98, 113, 124, 130, 137, 148, 164, 183, 199, 219, 235,
+    251, 256, 266, 275, 284, 293, 310, 325, 339, 348, 358, 364, 375, 385, 396, 410, 415, 425, 432,
+    442, 449, 454, 464, 474, 484, 490, 496, 507, 518, 519, 526, 536, 545, 555, 566, 570, 575, 585,
+    595, 605, 611, 618, 619, 629, 635, 640, 650, 656, 664, 677, 690, 697, 708, 712, 722, 734, 745,
+    765, 776, 805, 825, 844, 856, 877, 902, 905, 910, 925, 935, 945, 951, 952, 954, 961, 971, 979,
+    985, 993, 1000, 1016, 1022, 1032, 1041, 1051, 1060, 1065, 1075, 1080, 1088, 1101, 1114, 1127,
+    1139, 1150, 1160, 1170, 1184, 1190, 1196, 1203, 1205, 1212, 1232, 1243, 1255, 1256, 1262, 1273,
+    1279, 1285, 1298, 1309, 1316, 1327, 1343, 1359, 1378, 1394, 1414, 1430, 1446, 1455, 1464, 1473,
+    1487, 1491, 1501, 1510, 1520, 1526, 1537, 1547, 1557, 1567, 1581, 1598, 1608, 1619, 1626, 1638,
+    1650, 1660, 1670, 1680, 1699, 1702, 1715, 1729, 1736, 1741, 1748, 1760, 1770, 1779, 1796, 1806,
+    1825, 1828, 1834, 1837, 1847, 1855, 1858, 1866, 1872, 1883, 1887, 1890, 1898, 1909, 1910, 1917,
+    1927, 1938, 1947, 1952, 1964, 1973, 1983, 1992, 1998, 1999, 2007, 2013, 2018, 2030, 2042, 2048,
+    2054, 2062, 2069, 2080, 2092, 2096, 2106, 2116, 2128, 2149, 2166, 2183, 2188, 2199, 2210, 2217,
+    2227, 2232, 2242, 2248, 2258, 2265, 2266, 2272, 2278, 2284, 2290, 2298, 2317, 2336, 2352, 2356,
+    2367, 2375, 2382, 2393, 2402, 2412, 2421, 2431, 2441, 2451, 2457, 2469, 2486, 2494, 2495, 2505,
+    2507, 2517, 2523, 2534, 2540, 2546, 2553, 2564, 2580, 2589, 2598, 2607, 2621, 2638, 2652, 2656,
+    2666, 2675, 2690, 2700, 2708, 2714, 2725, 2735, 2745, 2759, 2774, 2781, 2796, 2806, 2817, 2827,
+    2834, 2838, 2849, 2861, 2870, 2876, 2883, 2889, 2900, 2915, 2938, 2939, 2949, 2956, 2967, 2977,
+    2989, 2999, 3005, 3006, 3014, 3022, 3039, 3045, 3055, 3074, 3088, 3093, 3109, 3133, 3139, 3147,
+    3154, 3166, 3175, 3186, 3197, 3208, 3218, 3223, 3234, 3244, 3254, 3267, 3277, 3288, 3294, 3295,
+    3297, 3299, 3305, 3311, 3317, 3325, 3332, 3348, 3360, 3364, 3374, 3383, 3398, 3410, 3412, 3423,
+    3433, 3443, 3449, 3460, 3466, 3467, 3475, 3481, 3492, 3498, 3505, 3515, 3524, 3535, 3545, 3551,
+    3558, 3560, 3561, 3563, 3569, 3575, 3583, 3590, 3606, 3618, 3628, 3637, 3646, 3655, 3657, 3668,
+    3678, 3688, 3699, 3705, 3711, 3722, 3724, 3725, 3727, 3734, 3740, 3751, 3766, 3789, 3795, 3801,
+    3815, 3822, 3833, 3849, 3868, 3884, 3904, 3920, 3936, 3945, 3954, 3963, 3972, 3989, 4003, 4012,
+    4023, 4029, 4040, 4050, 4053, 4063, 4068, 4078, 4091, 4101, 4115, 4125, 4138, 4140, 4154, 4161,
+    4173, 4185, 4190, 4203, 4213, 4231, 4249, 4259, 4266, 4278, 4288, 4296, 4303, 4316, 4321, 4327,
+    4338, 4349, 4355, 4367, 4377, 4383, 4394, 4408, 4419, 4420, 4426, 4433, 4443, 4453, 4464, 4484,
+    4487, 4492, 4494, 4506, 4516, 4519, 4530, 4536, 4537, 4544, 4554, 4560, 4561, 4571, 4577, 4583,
+    4591, 4598, 4610, 4619, 4629, 4638, 4653, 4664, 4672, 4675, 4684, 4698, 4708, 4718, 4724, 4738,
+    4760, 4761, 4769, 4777, 4785, 4793, 4801, 4809, 4817, 4825, 4833, 4841, 4849, 4857, 4865, 4873,
+    4881, 4889, 4897, 4905, 4913, 4921, 4929, 4937, 4945, 4953, 4961, 4969, 4977, 4985, 4993, 5001,
+    5009, 5017, 5025, 5033, 5041, 5049, 5057, 5065, 5073, 5081, 5087, 5102, 5112, 5118, 5133, 5144,
+    5152, 5157, 5170, 5191, 5198, 5209, 5221, 5231, 5240, 5258, 5269, 5279, 5290, 5301, 5314, 5324,
+    5336, 5347, 5352, 5360, 5370, 5386, 5392, 5403, 5404, 5407, 5411, 5417, 5425, 5432, 5448, 5460,
+    5470, 5479, 5489, 5508, 5516, 5529, 5534, 5539, 5544, 5554, 5564, 5574, 5586, 5596, 5606, 5616,
+    5624, 5631, 5638, 5652, 5663, 5675, 5681, 5692, 5700, 5701, 5707, 5718, 5724, 5730, 5737, 5748,
+    5764, 5780, 5789, 5798, 5807, 5816, 5830, 5844, 5858, 5875, 5889, 5904, 5918, 5927, 5933, 5944,
+    5954, 5959, 5969, 5982, 5992, 6006, 6016, 6029, 6050, 6064, 6074, 6081, 6096, 6111, 6121, 6128,
+    6135, 6143, 6170, 6184, 6199, 6229, 6246, 6258, 6263, 6277, 6283, 6300, 6323, 6329, 6340, 6351,
+    6352, 6359, 6368, 6378, 6389, 6394, 6404, 6414, 6420, 6426, 6427, 6433, 6440, 6451, 6460, 6470,
+    6479, 6485, 6495, 6501, 6502, 6509, 6518, 6528, 6539, 6541, 6551, 6557, 6558, 6564, 6575, 6586,
+    6593, 6604, 6613, 6627, 6637, 6646, 6658, 6678, 6684, 6689, 6699, 6709, 6719, 6731, 6741, 6747,
+    6753, 6767, 6789, 6806, 6831, 6832, 6842, 6848, 6854, 6865, 6872, 6883, 6887, 6897, 6906, 6916,
+    6935, 6953, 6957, 6968, 6984, 6995, 7015, 7034, 7044, 7054, 7060, 7067, 7068, 7077, 7083, 7089,
+    7099, 7109, 7124, 7134, 7152, 7167, 7186, 7210, 7230, 7238, 7247, 7253, 7264, 7278, 7292, 7303,
+    7318, 7329, 7335, 7342, 7353, 7369, 7388, 7404, 7424, 7440, 7445, 7458, 7466, 7475, 7484, 7495,
+    7507, 7516, 7525, 7538, 7543, 7552, 7569, 7584, 7593, 7603, 7605, 7612, 7620, 7628, 7637, 7646,
+    7655, 7664, 7673, 7682, 7691, 7700, 7709, 7718, 7727, 7736, 7745, 7754, 7763, 7772, 7781, 7790,
+    7799, 7808, 7817, 7826, 7835, 7844, 7853, 7862, 7871, 7880, 7889, 7898, 7907, 7916, 7925, 7934,
+    7943, 7952, 7961, 7970, 7979, 7988, 7997, 8006, 8015, 8024, 8033, 8042, 8051, 8060, 8069, 8078,
+    8087, 8096, 8105, 8114, 8123, 8132, 8141, 8150, 8159, 8168, 8177, 8186, 8195, 8204, 8213, 8222,
+    8231, 8240, 8249, 8258, 8267, 8276, 8285, 8294, 8303, 8312, 8321, 8330, 8339, 8348, 8357, 8366,
+    8375, 8384, 8393, 8402, 8411, 8420, 8429, 8438, 8447, 8456, 8465, 8474, 8483, 8492, 8501, 8510,
+    8519, 8528, 8537, 8546, 8555, 8564, 8573, 8582, 8591, 8600, 8607, 8614, 8621, 8630, 8639, 8648,
+    8657, 8666, 8675, 8684, 8693, 8702, 8711, 8720, 8729, 8738, 8747, 8756, 8765, 8774, 8783, 8792,
+    8801, 8810, 8819, 8828, 8837, 8846, 8855, 8864, 8873, 8882, 8891, 8900, 8909, 8918, 8927, 8936,
+    8945, 8954, 8963, 8972, 8981, 8990, 8999, 9008, 9017, 9026, 9035, 9044, 9053, 9062, 9071, 9080,
+    9089, 9098, 9107, 9116, 9125, 9134, 9143, 9152, 9161, 9170, 9179, 9188, 9197, 9206, 9215, 9224,
+    9233, 9242, 9251, 9260, 9269, 9278, 9287, 9296, 9305, 9314, 9323, 9332, 9341, 9350, 9359, 9368,
+    9377, 9386, 9395, 9404, 9413, 9422, 9431, 9440, 9449, 9458, 9467, 9476, 9485, 9494, 9503, 9512,
+    9521, 9530, 9539, 9548, 9557, 9566, 9575, 9584, 9593, 9602, 9611, 9620, 9629, 9638, 9647, 9656,
+    9665, 9674, 9683, 9692, 9701, 9710, 9719, 9728, 9737, 9746, 9755, 9764, 9773, 9782, 9791, 9800,
+    9809, 9818, 9827, 9836, 9842, 9851, 9860, 9869, 9879, 9888, 9898, 9904, 9915, 9925, 9935, 9950,
+    9959, 9973, 9989, 10004, 10018, 10036, 10051, 10070, 10079, 10097, 10104, 10108, 10118, 10134,
+    10149, 10169, 10194, 10214, 10239, 10249, 10264, 10284, 10309, 10326, 10348, 10372, 10395,
+    10410, 10426, 10431, 10439, 10444, 10454, 10461, 10471, 10480, 10498, 10512, 10520, 10530,
+    10541, 10555, 10560, 10576, 10600, 10617, 10642, 10651, 10661, 10669, 10678, 10690, 10705,
+    10717, 10733, 10740, 10752, 10758, 10776, 10789, 10794, 10804, 10815, 10833, 10851, 10863,
+    10874, 10877, 10891, 10896, 10906, 10916, 10925, 10938, 10951, 10965, 10976, 10988, 11000,
+    11012, 11025, 11035, 11044, 11057, 11071, 11085, 11101, 11117, 11134, 11148, 11160, 11169,
+    11181, 11199, 11217, 11231, 11241, 11260, 11275, 11293, 11308, 11320, 11333, 11340, 11349,
+    11361, 11376, 11387, 11404, 11416, 11428, 11439, 11450, 11470, 11480, 11499, 11506, 11519,
+    11533, 11547, 11570, 11578, 11598, 11612, 11624, 11641, 11654, 11662, 11671, 11690, 11692,
+    11698, 11709, 11716, 11723, 11732, 11742, 11748, 11758, 11768, 11787, 11802, 11820, 11835,
+    11854, 11866, 11877, 11881, 11897, 11907, 11908, 11917, 11926, 11944, 11950, 11960, 11970,
+    11980, 11988, 11998, 12001, 12013, 12022, 12029, 12039, 12048, 12068, 12075, 12085, 12094,
+    12108, 12124, 12134, 12149, 12169, 12190, 12208, 12218, 12229, 12232, 12236, 12251, 12260,
+    12275, 12284, 12297, 12307, 12314, 12325, 12336, 12341, 12351, 12361, 12374, 12387, 12397,
+    12408, 12420, 12445, 12469, 12494, 12520, 12554, 12581, 12616, 12638, 12661, 12675, 12700,
+    12726, 12742, 12758, 12769, 12778, 12800, 12823, 12851, 12874, 12879, 12889, 12894, 12904,
+    12916, 12926, 12936, 12942, 12950, 12957, 12966, 12977, 12989, 13007, 13021, 13032, 13049,
+    13059, 13071, 13084, 13103, 13118, 13130, 13148, 13159, 13172, 13185, 13205, 13218, 13230,
+    13244, 13258, 13279, 13293, 13298, 13311, 13319, 13340, 13356, 13378, 13392, 13414, 13423,
+    13430, 13439, 13446, 13456, 13466, 13472, 13485, 13499, 13507, 13508, 13518, 13527, 13533,
+    13539, 13549, 13559, 13568, 13586, 13600, 13615, 13634, 13642, 13648, 13653, 13666, 13674,
+    13688, 13697, 13703, 13711, 13724, 13731, 13742, 13747, 13751, 13761, 13769, 13776, 13786,
+    13790, 13800, 13812, 13825, 13837, 13849, 13860, 13870, 13877, 13888, 13899, 13909, 13929,
+    13938, 13949, 13978, 13998, 14017, 14029, 14050, 14075, 14078, 14098, 14117, 14136, 14149,
+    14167, 14179, 14190, 14202, 14213, 14218, 14236, 14253, 14270, 14281, 14297, 14314, 14320,
+    14334, 14342, 14352, 14368, 14391, 14415, 14425, 14443, 14456, 14461, 14474, 14485, 14497,
+    14511, 14515, 14528, 14541, 14554, 14564, 14579, 14589, 14594, 14607, 14621, 14630, 14640,
+    14662, 14680, 14685, 14698, 14716, 14727, 14738, 14751, 14764, 14778, 14799, 14815, 14825,
+    14838, 14857, 14871, 14878, 14887, 14902, 14909, 14919, 14929, 14938, 14948, 14957, 14967,
+    14977, 14987, 14997, 15007, 15017, 15026, 15036, 15046, 15056, 15066, 15076, 15085, 15094,
+    15103, 15112, 15121, 15131, 15140, 15149, 15158, 15168, 15178, 15188, 15198, 15207, 15216,
+    15225, 15238, 15252, 15269, 15295, 15320, 15338, 15365, 15391, 15408, 15416, 15429, 15435,
+    15443, 15453, 15461, 15468, 15476, 15484, 15491, 15499, 15506, 15507, 15517, 15526, 15535,
+    15541, 15555, 15571, 15586, 15592, 15604, 15610, 15619, 15629, 15639, 15649, 15659, 15668,
+    15673, 15684, 15701, 15712, 15726, 15737, 15751, 15771, 15785, 15790, 15801, 15816, 15840,
+    15848, 15867, 15894, 15914, 15942, 15965, 15977, 15990, 15998, 16006, 16017, 16028, 16038,
+    16051, 16065, 16076, 16090, 16113, 16122, 16130, 16136, 16144, 16151, 16167, 16173, 16183,
+    16190, 16201, 16212, 16222, 16237, 16246, 16257, 16265, 16277, 16289, 16299, 16308, 16330,
+    16353, 16363, 16369, 16379, 16389, 16398, 16408, 16418, 16429, 16434, 16445, 16480, 16484,
+    16494, 16501, 16512, 16523, 16528, 16542, 16559, 16566, 16582, 16590, 16603, 16619, 16630,
+    16643, 16656, 16666, 16676, 16685, 16691, 16698, 16711, 16722, 16729, 16739, 16747, 16754,
+    16764, 16771, 16782, 16791, 16801, 16811, 16817, 16831, 16846, 16860, 16871, 16885, 16889,
+    16899, 16908, 16920, 16931, 16943, 16954, 16962, 16970, 16988, 16995, 17007, 17021, 17041,
+    17057, 17068, 17074, 17083, 17088, 17095, 17105, 17115, 17117, 17125, 17132, 17138, 17158,
+    17169, 17181, 17182, 17188, 17193, 17201, 17210, 17216, 17227, 17242, 17262, 17286, 17292,
+    17305, 17316, 17331, 17338, 17349, 17365, 17381, 17400, 17416, 17436, 17452, 17461, 17470,
+    17475, 17484, 17488, 17498, 17507, 17517, 17532, 17542, 17548, 17557, 17567, 17577, 17586,
+    17596, 17606, 17611, 17622, 17634, 17645, 17672, 17681, 17695, 17708, 17722, 17735, 17748,
+    17763, 17776, 17792, 17813, 17826, 17840, 17853, 17863, 17874, 17886, 17896, 17909, 17918,
+    17932, 17949, 17958, 17976, 17991, 17998, 18008, 18015, 18027, 18038, 18050, 18061, 18069,
+    18085, 18092, 18104, 18116, 18126, 18132, 18146, 18156, 18176, 18184, 18194, 18204, 18210,
+    18224, 18243, 18246, 18257, 18270, 18284, 18291, 18298, 18306, 18311, 18322, 18335, 18354,
+    18371, 18377, 18384, 18396, 18401, 18415, 18425, 18438, 18449, 18459, 18469, 18478, 18495,
+    18505, 18524, 18527, 18534, 18544, 18563, 18578, 18594, 18608, 18622, 18645, 18654, 18663,
+    18666, 18676, 18684, 18687, 18693, 18704, 18722, 18744, 18757, 18774, 18781, 18789, 18793,
+    18810, 18824, 18842, 18848, 18862, 18871, 18881, 18896, 18911, 18922, 18933, 18936, 18944,
+    18951, 18962, 18969, 18970, 18976, 18986, 18996, 19007, 19021, 19035, 19044, 19051, 19061,
+    19070, 19081, 19095, 19111, 19126, 19135, 19141, 19143, 19146, 19149, 19151, 19164, 19176,
+    19189, 19199, 19208, 19218, 19226, 19240, 19260, 19274, 19282, 19296, 19304, 19318, 19325,
+    19338, 19348, 19364, 19380, 19387, 19399, 19403, 19413, 19424, 19434, 19460, 19468, 19479,
+    19491, 19503, 19517, 19529, 19549, 19561, 19574, 19586, 19595, 19605, 19616, 19625, 19637,
+    19645, 19647, 19653, 19663, 19671, 19680, 19688, 19699, 19705, 19709, 19719, 19730, 19740,
+    19766, 19774, 19786, 19798, 19812, 19824, 19844, 19856, 19869, 19889, 19901, 19910, 19920,
+    19931, 19940, 19952, 19966, 19979, 19993, 20001, 20018, 20024, 20032, 20037, 20038, 20047,
+    20053, 20059, 20068, 20082, 20098, 20113, 20123, 20133, 20143, 20153, 20158, 20173, 20186,
+    20198, 20207, 20213, 20219, 20227, 20234, 20245, 20257, 20261, 20271, 20281, 20291, 20301,
+    20319, 20337, 20349, 20367, 20377, 20398, 20413, 20421, 20431, 20443, 20450, 20461, 20472,
+    20483, 20499, 20517, 20534, 20555, 20572, 20589, 20597, 20609, 20614, 20623, 20633, 20643,
+    20654, 20659, 20670, 20687, 20698, 20709, 20730, 20741, 20760, 20774, 20793, 20815, 20842,
+    20859, 20884, 20891, 20901, 20911, 20921, 20927, 20936, 20944, 20949, 20962, 20970, 20979,
+    20988, 20999, 21013, 21025, 21032, 21044, 21062, 21078, 21097, 21110, 21126, 21138, 21145,
+    21152, 21162, 21175, 21189, 21202, 21216, 21226, 21238, 21246, 21247, 21266, 21280, 21289,
+    21308, 21314, 21324, 21334, 21343, 21357, 21373, 21383, 21398, 21409, 21419, 21438, 21452,
+    21463, 21477, 21489, 21505, 21522, 21540, 21548, 21558, 21570, 21582, 21594, 21610, 21632,
+    21655, 21675, 21686, 21699, 21712, 21725, 21742, 21765, 21789, 21810, 21820, 21832, 21844,
+    21856, 21872, 21894, 21917, 21937, 21941, 21950, 21961, 21969, 21976, 21987, 21996, 22006,
+    22015, 22017, 22022, 22036, 22050, 22058, 22072, 22087, 22096, 22104, 22124, 22144, 22158,
+    22182, 22209, 22231, 22247, 22257, 22278, 22293, 22308, 22318, 22337, 22352, 22360, 22372,
+    22375, 22384, 22389, 22402, 22420, 22437, 22454, 22465, 22481, 22491, 22501, 22520, 22525,
+    22532, 22539, 22546, 22557, 22574, 22592, 22607, 22617, 22627, 22637, 22646, 22656, 22666,
+    22685, 22690, 22697, 22704, 22711, 22722, 22739, 22757, 22772, 22784, 22797, 22804, 22827,
+    22848, 22858, 22868, 22881, 22888, 22898, 22903, 22909, 22918, 22925, 22935, 22947, 22957,
+    22976, 22988, 23003, 23005, 23011, 23025, 23040, 23051, 23065, 23074, 23075, 23081, 23091,
+    23099, 23108, 23114, 23120, 23127, 23138, 23147, 23156, 23176, 23195, 23219, 23239, 23262,
+    23278, 23294, 23314, 23344, 23368, 23389, 23415, 23441, 23465, 23491, 23516, 23536, 23556,
+    23577, 23601, 23623, 23644, 23665, 23685, 23710, 23731, 23751, 23771, 23788, 23810, 23831,
+    23856, 23880, 23902, 23925, 23945, 23968, 23984, 24007, 24028, 24049, 24068, 24093, 24114,
+    24134, 24149, 24169, 24188, 24207, 24225, 24245, 24264, 24269, 24278, 24292, 24309, 24318,
+    24333, 24343, 24361, 24378, 24395, 24406, 24422, 24428, 24437, 24446, 24455, 24465, 24474,
+    24484, 24490, 24500, 24510, 24525, 24539, 24554, 24572, 24587, 24606, 24608, 24617, 24635,
+    24642, 24646, 24656, 24663, 24678, 24703, 24717, 24727, 24736, 24744, 24755, 24763, 24777,
+    24787, 24797, 24808, 24818, 24830, 24840, 24849, 24858, 24870, 24880, 24887, 24891, 24903,
+    24920, 24929, 24938, 24944, 24955, 24969, 24983, 25006, 25019, 25026, 25035, 25052, 25069,
+    25075, 25086, 25096, 25106, 25123, 25137, 25155, 25170, 25193, 25194, 25204, 25213, 25219,
+    25229, 25239, 25248, 25254, 25261, 25272, 25284, 25298, 25308, 25318, 25333, 25350, 25366,
+    25375, 25389, 25399, 25406, 25417, 25428, 25440, 25443, 25453, 25459, 25468, 25469, 25486,
+    25495, 25501, 25511, 25530, 25536, 25539, 25548, 25557, 25572, 25586, 25595, 25611, 25626,
+    25639, 25649, 25659, 25669, 25683, 25693, 25712, 25717, 25733, 25752, 25773, 25792, 25816,
+    25829, 25846, 25876, 25891, 25899, 25910, 25924, 25940, 25968, 25992, 26001, 26011, 26017,
+    26025, 26032, 26044, 26053, 26064, 26074, 26084, 26103, 26114, 26129, 26141, 26151, 26162,
+    26169, 26180, 26191, 26201, 26216, 26233, 26249, 26259, 26267, 26279, 26299, 26318, 26337,
+    26350, 26368, 26379, 26390, 26402, 26414, 26424, 26429, 26443, 26452, 26462, 26472, 26491,
+    26507, 26524, 26534, 26553, 26571, 26589, 26601, 26618, 26634, 26645, 26655, 26663, 26676,
+    26686, 26701, 26711, 26721, 26730, 26740, 26759, 26770, 26783, 26803, 26813, 26819, 26828,
+    26839, 26847, 26854, 26864, 26874, 26893, 26901, 26909, 26910, 26919, 26925, 26931, 26941,
+    26951, 26966, 26984, 27012, 27043, 27071, 27102, 27123, 27151, 27182, 27191, 27197, 27209,
+    27214, 27225, 27242, 27253, 27267, 27286, 27302, 27322, 27342, 27365, 27380, 27403, 27423,
+    27434, 27438, 27443, 27452, 27458, 27466, 27473, 27489, 27501, 27505, 27515, 27524, 27539,
+    27556, 27572, 27576, 27585, 27603, 27616, 27632, 27645, 27658, 27667, 27671, 27678, 27692,
+    27696, 27708, 27710, 27721, 27723, 27730, 27741, 27751, 27759, 27775, 27788, 27813, 27835,
+    27847, 27857, 27865, 27876, 27886, 27896, 27914, 27923, 27933, 27938, 27955, 27965, 27978,
+    27985, 27991, 27997, 28004, 28013, 28020, 28026, 28041, 28053, 28077, 28098, 28106, 28107,
+    28116, 28122, 28136, 28145, 28157, 28172, 28178, 28184, 28194, 28204, 28218, 28236, 28246,
+    28268, 28291, 28306, 28330, 28346, 28363, 28372, 28390, 28408, 28422, 28439, 28452, 28469,
+    28487, 28497, 28516, 28533, 28551, 28561, 28580, 28592, 28602, 28621, 28625, 28638, 28649,
+    28653, 28671, 28679, 28688, 28696, 28703, 28715, 28725, 28734, 28744, 28759, 28776, 28792,
+    28813, 28835, 28848, 28858, 28872, 28882, 28901, 28904, 28913, 28928, 28937, 28948, 28960,
+    28978, 29000, 29016, 29021, 29030, 29056, 29062, 29080, 29097, 29114, 29125, 29143, 29159,
+    29175, 29190, 29200, 29210, 29229, 29234, 29247, 29258, 29266, 29275, 29281, 29296, 29306,
+    29320, 29328, 29341, 29351, 29366, 29376, 29386, 29396, 29415, 29424, 29432, 29444, 29463,
+    29469, 29478, 29486, 29495, 29502, 29504, 29507, 29516, 29527, 29535, 29544, 29551, 29560,
+    29570, 29580, 29599, 29608, 29616, 29625, 29636, 29651, 29662, 29676, 29689, 29703, 29712,
+    29721, 29730, 29742, 29750, 29762, 29770, 29771, 29780, 29785, 29791, 29797, 29807, 29817,
+    29827, 29837, 29856, 29867, 29875, 29884, 29891, 29897, 29905, 29912, 29928, 29940, 29950,
+    29959, 29969, 29979, 29998, 30011, 30019, 30029, 30036, 30047, 30058, 30068, 30077, 30086,
+    30100, 30118, 30135, 30151, 30168, 30184, 30195, 30213, 30229, 30244, 30261, 30271, 30281,
+    30300, 30316, 30328, 30332, 30342, 30353, 30363, 30389, 30397, 30409, 30421, 30435, 30447,
+    30467, 30479, 30492, 30504, 30513, 30523, 30534, 30543, 30555, 30569, 30582, 30596, 30604,
+    30606, 30617, 30626, 30644, 30657, 30667, 30677, 30685, 30695, 30702, 30713, 30724, 30732,
+    30742, 30752, 30771, 30787, 30796, 30804, 30814, 30829, 30845, 30866, 30883, 30904, 30926,
+    30942, 30963, 30985, 31004, 31015, 31025, 31037, 31045, 31055, 31073, 31090, 31102, 31109,
+    31124, 31135, 31146, 31155, 31166, 31177, 31188, 31194, 31202, 31211, 31217, 31219, 31229,
+    31239, 31258, 31270, 31279, 31292, 31305, 31315, 31334, 31349, 31365, 31386, 31392, 31395,
+    31404, 31419, 31428, 31436, 31444, 31454, 31461, 31472, 31483, 31484, 31490, 31498, 31505,
+    31520, 31543, 31551, 31560, 31566, 31577, 31592, 31612, 31636, 31642, 31649, 31660, 31676,
+    31695, 31711, 31731, 31747, 31756, 31765, 31774, 31779, 31788, 31805, 31814, 31816, 31824,
+    31830, 31839, 31845, 31854, 31864, 31873, 31883, 31888, 31898, 31911, 31921, 31935, 31945,
+    31958, 31960, 31974, 31983, 32001, 32008, 32017, 32024, 32036, 32048, 32054, 32059, 32065,
+    32078, 32094, 32112, 32130, 32140, 32150, 32157, 32169, 32179, 32182, 32191, 32201, 32210,
+    32235, 32242, 32256, 32265, 32274, 32285, 32296, 32309, 32316, 32327, 32346, 32357, 32369,
+    32388, 32399, 32407, 32416, 32426, 32436, 32444, 32455, 32462, 32470, 32477, 32490, 32500,
+    32515, 32520, 32526, 32536, 32542, 32553, 32565, 32575, 32585, 32604, 32610, 32621, 32635,
+    32649, 32672, 32684, 32693, 32703, 32709, 32720, 32734, 32744, 32752, 32770, 32781, 32795,
+    32810, 32822, 32831, 32848, 32862, 32880, 32881, 32893, 32907, 32916, 32922, 32928, 32936,
+    32942, 32952, 32962, 32972, 32985, 32995, 33020, 33036, 33049, 33058, 33066, 33075, 33096,
+    33107, 33118, 33135, 33153, 33167, 33184, 33195, 33212, 33222, 33244, 33256, 33268, 33286,
+    33305, 33320, 33338, 33350, 33368, 33379, 33390, 33402, 33410, 33415, 33422, 33429, 33436,
+    33447, 33464, 33482, 33497, 33508, 33517, 33524, 33534, 33536, 33546, 33554, 33568, 33579,
+    33598, 33607, 33618, 33626, 33640, 33656, 33666, 33681, 33691, 33711, 33723, 33730, 33743,
+    33759, 33771, 33777, 33791, 33805, 33820, 33834, 33849, 33860, 33874, 33893, 33906, 33917,
+    33923, 33931, 33941, 33948, 33959, 33970, 33973, 33977, 33997, 34016, 34035, 34048, 34066,
+    34074, 34085, 34099, 34104, 34115, 34127, 34141, 34143, 34161, 34178, 34194, 34211, 34228,
+    34239, 34255, 34276, 34291, 34312, 34330, 34347, 34357, 34367, 34380, 34392, 34396, 34408,
+    34418, 34427, 34434, 34447, 34456, 34468, 34478, 34486, 34496, 34518, 34540, 34563, 34583,
+    34593, 34602, 34612, 34626, 34632, 34640, 34652, 34660, 34673, 34680, 34690, 34703, 34712,
+    34724, 34738, 34748, 34760, 34763, 34774, 34798, 34806, 34816, 34826, 34834, 34842, 34843,
+    34849, 34860, 34869, 34883, 34899, 34914, 34920, 34928, 34936, 34944, 34952, 34960, 34968,
+    34976, 34988, 35006, 35023, 35046, 35070, 35091, 35110, 35126, 35142, 35151, 35158, 35163,
+    35173, 35176, 35185, 35200, 35209, 35215, 35226, 35232, 35240, 35248, 35256, 35268, 35286,
+    35305, 35321, 35329, 35343, 35359, 35371, 35388, 35401, 35418, 35431, 35439, 35451, 35463,
+    35480, 35493, 35514, 35527, 35536, 35553, 35566, 35576, 35587, 35601, 35612, 35632, 35633,
+    35643, 35652, 35658, 35664, 35671, 35680, 35694, 35715, 35724, 35728, 35738, 35748, 35758,
+    35768, 35778, 35797, 35819, 35842, 35850, 35855, 35864, 35870, 35878, 35885, 35897, 35906,
+    35916, 35925, 35940, 35953, 35965, 35979, 35989, 36001, 36014, 36023, 36034, 36048, 36058,
+    36068, 36087, 36091, 36107, 36117, 36130, 36141, 36159, 36172, 36181, 36198, 36208, 36215,
+    36218, 36223, 36234, 36253, 36267, 36280, 36298, 36315, 36332, 36348, 36365, 36386, 36397,
+    36413, 36431, 36447, 36465, 36481, 36501, 36516, 36534, 36551, 36573, 36583, 36600, 36613,
+    36623, 36633, 36652, 36656, 36668, 36675, 36687, 36707, 36727, 36747, 36760, 36781, 36802,
+    36816, 36829, 36839, 36847, 36861, 36871, 36881, 36891, 36910, 36919, 36925, 36935, 36942,
+    36953, 36963, 36978, 36994, 37007, 37024, 37049, 37071, 37097, 37106, 37113, 37120, 37135,
+    37145, 37155, 37174, 37190, 37206, 37212, 37218, 37233, 37245, 37261, 37285, 37306, 37331,
+    37332, 37341, 37347, 37362, 37371, 37377, 37391, 37407, 37422, 37432, 37442, 37452, 37462,
+    37481, 37512, 37518, 37530, 37548, 37560, 37570, 37580, 37598, 37615, 37625, 37634, 37643,
+    37657, 37667, 37680, 37689, 37698, 37713, 37724, 37738, 37748, 37757, 37767, 37776, 37782,
+    37797, 37805, 37810, 37823, 37844, 37853, 37860, 37871, 37883, 37893, 37902, 37920, 37935,
+    37941, 37958, 37965, 37975, 37990, 38007, 38023, 38028, 38035, 38042, 38049, 38060, 38077,
+    38095, 38108, 38123, 38134, 38144, 38154, 38173, 38182, 38197, 38215, 38229, 38247, 38274,
+    38285, 38296, 38301, 38312, 38324, 38335, 38362, 38371, 38383, 38396, 38409, 38424, 38437,
+    38458, 38471, 38485, 38498, 38508, 38519, 38531, 38541, 38554, 38569, 38583, 38598, 38607,
+    38616, 38627, 38637, 38648, 38660, 38677, 38697, 38714, 38731, 38751, 38756, 38765, 38775,
+    38786, 38793, 38804, 38815, 38831, 38841, 38854, 38865, 38881, 38899, 38916, 38926, 38932,
+    38944, 38949, 38957, 38964, 38971, 38978, 38989, 39006, 39024, 39039, 39051, 39062, 39066,
+    39076, 39092, 39109, 39132, 39148, 39170, 39183, 39193, 39204, 39221, 39231, 39247, 39252,
+    39257, 39263, 39273, 39295, 39305, 39315, 39334, 39345, 39360, 39367, 39379, 39396, 39412,
+    39427, 39443, 39459, 39469, 39484, 39499, 39514, 39530, 39533, 39542, 39552, 39561, 39586,
+    39593, 39604, 39615, 39628, 39639, 39658, 39669, 39681, 39692, 39700, 39709, 39719, 39727,
+    39738, 39751, 39784, 39796, 39809, 39816, 39821, 39835, 39840, 39854, 39863, 39873, 39887,
+    39897, 39913, 39923, 39933, 39952, 39973, 39995, 40005, 40015, 40023, 40032, 40037, 40052,
+    40057, 40071, 40085, 40091, 40105, 40113, 40121, 40149, 40169, 40177, 40185, 40200, 40208,
+    40217, 40225, 40234, 40242, 40256, 40286, 40317, 40348, 40366, 40391, 40399, 40409, 40416,
+    40427, 40443, 40459, 40475, 40492, 40508, 40524, 40539, 40556, 40565, 40573, 40590, 40610,
+    40631, 40637, 40651, 40664, 40672, 40680, 40690, 40700, 40719, 40730, 40739, 40742, 40750,
+    40766, 40781, 40789, 40804, 40805, 40814, 40822, 40830, 40836, 40846, 40856, 40865, 40879,
+    40895, 40905, 40920, 40936, 40946, 40965, 40978, 40981, 40984, 40992, 41001, 41016, 41025,
+    41029, 41038, 41044, 41050, 41058, 41069, 41085, 41103, 41120, 41127, 41143, 41155, 41164,
+    41174, 41183, 41193, 41212, 41221, 41235, 41254, 41274, 41290, 41300, 41320, 41341, 41357,
+    41378, 41393, 41413, 41434, 41452, 41462, 41481, 41490, 41504, 41523, 41542, 41551, 41570,
+    41578, 41587, 41595, 41599, 41602, 41611, 41626, 41635, 41648, 41659, 41674, 41684, 41691,
+    41702, 41713, 41723, 41738, 41760, 41783, 41798, 41822, 41832, 41847, 41864, 41880, 41891,
+    41900, 41905, 41911, 41927, 41947, 41966, 41985, 41998, 42016, 42030, 42043, 42057, 42074,
+    42079, 42093, 42098, 42111, 42122, 42134, 42146, 42162, 42186, 42211, 42216, 42227, 42239,
+    42250, 42277, 42286, 42298, 42311, 42324, 42339, 42352, 42373, 42386, 42400, 42421, 42434,
+    42444, 42455, 42467, 42480, 42499, 42509, 42522, 42531, 42540, 42550, 42560, 42579, 42598,
+    42616, 42634, 42646, 42663, 42668, 42681, 42689, 42698, 42712, 42725, 42740, 42756, 42767,
+    42779, 42795, 42808, 42824, 42836, 42846, 42856, 42866, 42876, 42886, 42905, 42924, 42942,
+    42956, 42969, 42982, 42990, 42997, 43004, 43009, 43018, 43029, 43053, 43082, 43114, 43139,
+    43169, 43202, 43211, 43223, 43229, 43238, 43251, 43265, 43279, 43286, 43293, 43300, 43307,
+    43309, 43314, 43325, 43342, 43353, 43364, 43369, 43376, 43383, 43390, 43401, 43418, 43436,
+    43451, 43463, 43472, 43482, 43489, 43500, 43511, 43521, 43536, 43553, 43569, 43580, 43588,
+    43600, 43612, 43619, 43629, 43639, 43658, 43673, 43688, 43712, 43724, 43735, 43747, 43758,
+    43770, 43784, 43795, 43807, 43810, 43819, 43829, 43838, 43863, 43870, 43884, 43896, 43916,
+    43927, 43938, 43951, 43962, 43981, 43992, 44004, 44023, 44034, 44042, 44051, 44061, 44069,
+    44078, 44089, 44096, 44105, 44106, 44112, 44116, 44124, 44133, 44139, 44145, 44152, 44163,
+    44179, 44188, 44198, 44207, 44216, 44221, 44230, 44244, 44258, 44272, 44289, 44303, 44318,
+    44327, 44333, 44342, 44351, 44360, 44370, 44375, 44385, 44398, 44408, 44422, 44432, 44445,
+    44466, 44480, 44489, 44507, 44517, 44524, 44531, 44546, 44561, 44575, 44585, 44595, 44608,
+    44627, 44645, 44659, 44664, 44673, 44680, 44686, 44693, 44707, 44714, 44729, 44749, 44761,
+    44773, 44787, 44796, 44807, 44812, 44826, 44840, 44854, 44877, 44894, 44917, 44923, 44934,
+    44945, 44954, 44960, 44970, 44980, 44995, 45013, 45028, 45047, 45064, 45078, 45096, 45097,
+    45103, 45113, 45123, 45133, 45136, 45145, 45156, 45171, 45180, 45188, 45202, 45214, 45226,
+    45233, 45242, 45252, 45261, 45275, 45291, 45306, 45316, 45321, 45332, 45352, 45372, 45390,
+    45405, 45416, 45421, 45431, 45444, 45454, 45468, 45482, 45493, 45508, 45518, 45528, 45551,
+    45574, 45588, 45611, 45621, 45627, 45633, 45640, 45650, 45660, 45661, 45667, 45676, 45686,
+    45696, 45715, 45723, 45738, 45753, 45764, 45772, 45794, 45803, 45817, 45836, 45860, 45868,
+    45875, 45886, 45895, 45905, 45914, 45924, 45935, 45945, 45953, 45962, 45968, 45979, 45990,
+    46008, 46030, 46060, 46083, 46114, 46126, 46165, 46195, 46220, 46250, 46275, 46301, 46328,
+    46359, 46385, 46401, 46417, 46428, 46437, 46451, 46480, 46510, 46538, 46561, 46571, 46581,
+    46589, 46599, 46609, 46619, 46638, 46641, 46653, 46663, 46669, 46674, 46683, 46690, 46694,
+    46695, 46704, 46713, 46720, 46729, 46739, 46750, 46752, 46762, 46768, 46777, 46778, 46789,
+    46798, 46804, 46810, 46819, 46829, 46839, 46849, 46859, 46878, 46886, 46898, 46913, 46928,
+    46952, 46963, 46970, 46981, 46990, 47000, 47009, 47018, 47033, 47053, 47067, 47086, 47110,
+    47136, 47161, 47177, 47192, 47212, 47233, 47251, 47274, 47282, 47285, 47297, 47306, 47323,
+    47341, 47363, 47375, 47395, 47409, 47430, 47448, 47459, 47465, 47470, 47480, 47490, 47500,
+    47508, 47515, 47527, 47537, 47540, 47549, 47564, 47573, 47585, 47602, 47612, 47621, 47631,
+    47650, 47658, 47673, 47688, 47712, 47720, 47731, 47741, 47750, 47760, 47766, 47779, 47800,
+    47802, 47807, 47816, 47822, 47829, 47839, 47848, 47858, 47877, 47885, 47899, 47921, 47938,
+    47963, 47978, 47993, 48017, 48027, 48038, 48048, 48055, 48056, 48066, 48072, 48078, 48088,
+    48097, 48111, 48127, 48137, 48152, 48162, 48177, 48187, 48203, 48219, 48230, 48235, 48246,
+    48263, 48274, 48283, 48289, 48296, 48307, 48312, 48316, 48326, 48335, 48345, 48364, 48382,
+    48392, 48402, 48406, 48416, 48427, 48435, 48447, 48459, 48473, 48485, 48498, 48510, 48521,
+    48533, 48541, 48556, 48574, 48588, 48592, 48602, 48613, 48629, 48640, 48660, 48679, 48689,
+    48699, 48710, 48720, 48730, 48740, 48750, 48756, 48770, 48777, 48787, 48797,
+];
+
+/// Code point of each name
+static UNICODE: [u16; 4200] = [
+    0x0041, 0x00C6, 0x01FC, 0x01E2, 0xF7E6, 0x00C1, 0xF7E1, 0x0102, 0x1EAE, 0x04D0, 0x1EB6, 0x1EB0,
+    0x1EB2, 0x1EB4, 0x01CD, 0x24B6, 0x00C2, 0x1EA4, 0x1EAC, 0x1EA6, 0x1EA8, 0xF7E2, 0x1EAA, 0xF6C9,
+    0xF7B4, 0x0410, 0x0200, 0x00C4, 0x04D2, 0x01DE, 0xF7E4, 0x1EA0, 0x01E0, 0x00C0, 0xF7E0, 0x1EA2,
+    0x04D4, 0x0202, 0x0391, 0x0386, 0x0100, 0xFF21, 0x0104, 0x00C5, 0x01FA, 0x1E00, 0xF7E5, 0xF761,
+    0x00C3, 0xF7E3, 0x0531, 0x0042, 0x24B7, 0x1E02, 0x1E04, 0x0411, 0x0532, 0x0392, 0x0181, 0x1E06,
+    0xFF22, 0xF6F4, 0xF762, 0x0182, 0x0043, 0x053E, 0x0106, 0xF6CA, 0xF6F5, 0x010C, 0x00C7, 0x1E08,
+    0xF7E7, 0x24B8, 0x0108, 0x010A, 0x010A, 0xF7B8, 0x0549, 0x04BC, 0x0427, 0x04BE, 0x04B6, 0x04F4,
+    0x0543, 0x04CB, 0x04B8, 0x03A7, 0x0187, 0xF6F6, 0xFF23, 0x0551, 0xF763, 0x0044, 0x01F1, 0x01C4,
+    0x0534, 0x0189, 0x010E, 0x1E10, 0x24B9, 0x1E12, 0x0110, 0x1E0A, 0x1E0C, 0x0414, 0x03EE, 0x2206,
+    0x0394, 0x018A, 0xF6CB, 0xF6CC, 0xF6CD, 0xF7A8, 0x03DC, 0x0402, 0x1E0E, 0xFF24, 0xF6F7, 0x0110,
+    0xF764, 0x018B, 0x01F2, 0x01C5, 0x04E0, 0x0405, 0x040F, 0x0045, 0x00C9, 0xF7E9, 0x0114, 0x011A,
+    0x1E1C, 0x0535, 0x24BA, 0x00CA, 0x1EBE, 0x1E18, 0x1EC6, 0x1EC0, 0x1EC2, 0xF7EA, 0x1EC4, 0x0404,
+    0x0204, 0x00CB, 0xF7EB, 0x0116, 0x0116, 0x1EB8, 0x0424, 0x00C8, 0xF7E8, 0x0537, 0x1EBA, 0x2167,
+    0x0206, 0x0464, 0x041B, 0x216A, 0x0112, 0x1E16, 0x1E14, 0x041C, 0xFF25, 0x041D, 0x04A2, 0x014A,
+    0x04A4, 0x04C7, 0x0118, 0x0190, 0x0395, 0x0388, 0x0420, 0x018E, 0x042D, 0x0421, 0x04AA, 0x01A9,
+    0xF765, 0x0397, 0x0538, 0x0389, 0x00D0, 0xF7F0, 0x1EBC, 0x1E1A, 0x20AC, 0x01B7, 0x01EE, 0x01B8,
+    0x0046, 0x24BB, 0x1E1E, 0x0556, 0x03E4, 0x0191, 0x0472, 0x2164, 0xFF26, 0x2163, 0xF766, 0x0047,
+    0x3387, 0x01F4, 0x0393, 0x0194, 0x03EA, 0x011E, 0x01E6, 0x0122, 0x24BC, 0x011C, 0x0122, 0x0120,
+    0x0120, 0x0413, 0x0542, 0x0494, 0x0492, 0x0490, 0x0193, 0x0533, 0x0403, 0x1E20, 0xFF27, 0xF6CE,
+    0xF760, 0xF767, 0x029B, 0x01E4, 0x0048, 0x25CF, 0x25AA, 0x25AB, 0x25A1, 0x33CB, 0x04A8, 0x04B2,
+    0x042A, 0x0126, 0x1E2A, 0x1E28, 0x24BD, 0x0124, 0x1E26, 0x1E22, 0x1E24, 0xFF28, 0x0540, 0x03E8,
+    0xF768, 0xF6CF, 0xF6F8, 0x3390, 0x0049, 0x042F, 0x0132, 0x042E, 0x00CD, 0xF7ED, 0x012C, 0x01CF,
+    0x24BE, 0x00CE, 0xF7EE, 0x0406, 0x0208, 0x00CF, 0x1E2E, 0x04E4, 0xF7EF, 0x0130, 0x0130, 0x1ECA,
+    0x04D6, 0x0415, 0x2111, 0x00CC, 0xF7EC, 0x1EC8, 0x0418, 0x020A, 0x0419, 0x012A, 0x04E2, 0xFF29,
+    0x053B, 0x0401, 0x012E, 0x0399, 0x0196, 0x03AA, 0x038A, 0xF769, 0x0197, 0x0128, 0x1E2C, 0x0474,
+    0x0476, 0x004A, 0x0541, 0x24BF, 0x0134, 0x0408, 0x054B, 0xFF2A, 0xF76A, 0x004B, 0x3385, 0x33CD,
+    0x04A0, 0x1E30, 0x041A, 0x049A, 0x04C3, 0x039A, 0x049E, 0x049C, 0x01E8, 0x0136, 0x24C0, 0x0136,
+    0x1E32, 0x0554, 0x053F, 0x0425, 0x03E6, 0x0198, 0x040C, 0x1E34, 0xFF2B, 0x0480, 0x03DE, 0x046E,
+    0xF76B, 0x004C, 0x01C7, 0xF6BF, 0x0139, 0x039B, 0x013D, 0x013B, 0x24C1, 0x1E3C, 0x013B, 0x013F,
+    0x013F, 0x1E36, 0x1E38, 0x053C, 0x01C8, 0x0409, 0x1E3A, 0xFF2C, 0x0141, 0xF6F9, 0xF76C, 0x004D,
+    0x3386, 0xF6D0, 0xF7AF, 0x1E3E, 0x24C2, 0x1E40, 0x1E42, 0x0544, 0xFF2D, 0xF76D, 0x019C, 0x039C,
+    0x004E, 0x01CA, 0x0143, 0x0147, 0x0145, 0x24C3, 0x1E4A, 0x0145, 0x1E44, 0x1E46, 0x019D, 0x2168,
+    0x01CB, 0x040A, 0x1E48, 0xFF2E, 0x0546, 0xF76E, 0x00D1, 0xF7F1, 0x039D, 0x004F, 0x0152, 0xF6FA,
+    0x00D3, 0xF7F3, 0x04E8, 0x04EA, 0x014E, 0x01D1, 0x019F, 0x24C4, 0x00D4, 0x1ED0, 0x1ED8, 0x1ED2,
+    0x1ED4, 0xF7F4, 0x1ED6, 0x041E, 0x0150, 0x020C, 0x00D6, 0x04E6, 0xF7F6, 0x1ECC, 0xF6FB, 0x00D2,
+    0xF7F2, 0x0555, 0x2126, 0x1ECE, 0x01A0, 0x1EDA, 0x1EE2, 0x1EDC, 0x1EDE, 0x1EE0, 0x0150, 0x01A2,
+    0x020E, 0x014C, 0x1E52, 0x1E50, 0x2126, 0x0460, 0x03A9, 0x047A, 0x047C, 0x038F, 0x039F, 0x038C,
+    0xFF2F, 0x2160, 0x01EA, 0x01EC, 0x0186, 0x00D8, 0x01FE, 0xF7F8, 0xF76F, 0x01FE, 0x047E, 0x00D5,
+    0x1E4C, 0x1E4E, 0xF7F5, 0x0050, 0x1E54, 0x24C5, 0x1E56, 0x041F, 0x054A, 0x04A6, 0x03A6, 0x01A4,
+    0x03A0, 0x0553, 0xFF30, 0x03A8, 0x0470, 0xF770, 0x0051, 0x24C6, 0xFF31, 0xF771, 0x0052, 0x054C,
+    0x0154, 0x0158, 0x0156, 0x24C7, 0x0156, 0x0210, 0x1E58, 0x1E5A, 0x1E5C, 0x0550, 0x211C, 0x03A1,
+    0xF6FC, 0x0212, 0x1E5E, 0xFF32, 0xF772, 0x0281, 0x02B6, 0x0053, 0x250C, 0x2514, 0x2510, 0x2518,
+    0x253C, 0x252C, 0x2534, 0x251C, 0x2524, 0x2500, 0x2502, 0x2561, 0x2562, 0x2556, 0x2555, 0x2563,
+    0x2551, 0x2557, 0x255D, 0x255C, 0x255B, 0x255E, 0x255F, 0x255A, 0x2554, 0x2569, 0x2566, 0x2560,
+    0x2550, 0x256C, 0x2567, 0x2568, 0x2564, 0x2565, 0x2559, 0x2558, 0x2552, 0x2553, 0x256B, 0x256A,
+    0x015A, 0x1E64, 0x03E0, 0x0160, 0x1E66, 0xF6FD, 0x015E, 0x018F, 0x04D8, 0x04DA, 0x24C8, 0x015C,
+    0x0218, 0x1E60, 0x1E62, 0x1E68, 0x054D, 0x2166, 0x0547, 0x0428, 0x0429, 0x03E2, 0x04BA, 0x03EC,
+    0x03A3, 0x2165, 0xFF33, 0x042C, 0xF773, 0x03DA, 0x0054, 0x03A4, 0x0166, 0x0164, 0x0162, 0x24C9,
+    0x1E70, 0x0162, 0x1E6A, 0x1E6C, 0x0422, 0x04AC, 0x2169, 0x04B4, 0x0398, 0x01AC, 0x00DE, 0xF7FE,
+    0x2162, 0xF6FE, 0x054F, 0x1E6E, 0xFF34, 0x0539, 0x01BC, 0x0184, 0x01A7, 0x01AE, 0x0426, 0x040B,
+    0xF774, 0x216B, 0x2161, 0x0055, 0x00DA, 0xF7FA, 0x016C, 0x01D3, 0x24CA, 0x00DB, 0x1E76, 0xF7FB,
+    0x0423, 0x0170, 0x0214, 0x00DC, 0x01D7, 0x1E72, 0x01D9, 0x04F0, 0x01DB, 0x01D5, 0xF7FC, 0x1EE4,
+    0x00D9, 0xF7F9, 0x1EE6, 0x01AF, 0x1EE8, 0x1EF0, 0x1EEA, 0x1EEC, 0x1EEE, 0x0170, 0x04F2, 0x0216,
+    0x0478, 0x016A, 0x04EE, 0x1E7A, 0xFF35, 0x0172, 0x03A5, 0x03D2, 0x03D3, 0x01B1, 0x03AB, 0x03D4,
+    0x03D2, 0x038E, 0x016E, 0x040E, 0xF775, 0x04AE, 0x04B0, 0x0168, 0x1E78, 0x1E74, 0x0056, 0x24CB,
+    0x1E7E, 0x0412, 0x054E, 0x01B2, 0xFF36, 0x0548, 0xF776, 0x1E7C, 0x0057, 0x1E82, 0x24CC, 0x0174,
+    0x1E84, 0x1E86, 0x1E88, 0x1E80, 0xFF37, 0xF777, 0x0058, 0x24CD, 0x1E8C, 0x1E8A, 0x053D, 0x039E,
+    0xFF38, 0xF778, 0x0059, 0x00DD, 0xF7FD, 0x0462, 0x24CE, 0x0176, 0x0178, 0xF7FF, 0x1E8E, 0x1EF4,
+    0x042B, 0x04F8, 0x1EF2, 0x01B3, 0x1EF6, 0x0545, 0x0407, 0x0552, 0xFF39, 0xF779, 0x1EF8, 0x046A,
+    0x046C, 0x0466, 0x0468, 0x005A, 0x0536, 0x0179, 0x017D, 0xF6FF, 0x24CF, 0x1E90, 0x017B, 0x017B,
+    0x1E92, 0x0417, 0x0498, 0x04DE, 0x0396, 0x053A, 0x04C1, 0x0416, 0x0496, 0x04DC, 0x1E94, 0xFF3A,
+    0xF77A, 0x01B5, 0x0061, 0x0986, 0x00E1, 0x0906, 0x0A86, 0x0A06, 0x0A3E, 0x3303, 0x09BE, 0x093E,
+    0x0ABE, 0x055F, 0x0970, 0x0985, 0x311A, 0x0103, 0x1EAF, 0x04D1, 0x1EB7, 0x1EB1, 0x1EB3, 0x1EB5,
+    0x01CE, 0x24D0, 0x00E2, 0x1EA5, 0x1EAD, 0x1EA7, 0x1EA9, 0x1EAB, 0x00B4, 0x0317, 0x0301, 0x0301,
+    0x0954, 0x02CF, 0x0341, 0x0430, 0x0201, 0x0A71, 0x0905, 0x00E4, 0x04D3, 0x01DF, 0x1EA1, 0x01E1,
+    0x00E6, 0x01FD, 0x3150, 0x01E3, 0x2015, 0x20A4, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415,
+    0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420,
+    0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C,
+    0x042D, 0x042E, 0x042F, 0x0490, 0x0402, 0x0403, 0x0404, 0x0405, 0x0406, 0x0407, 0x0408, 0x0409,
+    0x040A, 0x040B, 0x040C, 0x040E, 0xF6C4, 0xF6C5, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435,
+    0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E, 0x043F, 0x0440,
+    0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044A, 0x044B, 0x044C,
+    0x044D, 0x044E, 0x044F, 0x0491, 0x0452, 0x0453, 0x0454, 0x0455, 0x0456, 0x0457, 0x0458, 0x0459,
+    0x045A, 0x045B, 0x045C, 0x045E, 0x040F, 0x0462, 0x0472, 0x0474, 0xF6C6, 0x045F, 0x0463, 0x0473,
+    0x0475, 0xF6C7, 0xF6C8, 0x04D9, 0x200E, 0x200F, 0x200D, 0x066A, 0x060C, 0x0660, 0x0661, 0x0662,
+    0x0663, 0x0664, 0x0665, 0x0666, 0x0667, 0x0668, 0x0669, 0x061B, 0x061F, 0x0621, 0x0622, 0x0623,
+    0x0624, 0x0625, 0x0626, 0x0627, 0x0628, 0x0629, 0x062A, 0x062B, 0x062C, 0x062D, 0x062E, 0x062F,
+    0x0630, 0x0631, 0x0632, 0x0633, 0x0634, 0x0635, 0x0636, 0x0637, 0x0638, 0x0639, 0x063A, 0x0640,
+    0x0641, 0x0642, 0x0643, 0x0644, 0x0645, 0x0646, 0x0648, 0x0649, 0x064A, 0x064B, 0x064C, 0x064D,
+    0x064E, 0x064F, 0x0650, 0x0651, 0x0652, 0x0647, 0x06A4, 0x067E, 0x0686, 0x0698, 0x06AF, 0x0679,
+    0x0688, 0x0691, 0x06BA, 0x06D2, 0x06D5, 0x20AA, 0x05BE, 0x05C3, 0x05D0, 0x05D1, 0x05D2, 0x05D3,
+    0x05D4, 0x05D5, 0x05D6, 0x05D7, 0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF,
+    0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7, 0x05E8, 0x05E9, 0x05EA, 0xFB2A,
+    0xFB2B, 0xFB4B, 0xFB1F, 0x05F0, 0x05F1, 0x05F2, 0xFB35, 0x05B4, 0x05B5, 0x05B6, 0x05BB, 0x05B8,
+    0x05B7, 0x05B0, 0x05B2, 0x05B1, 0x05B3, 0x05C2, 0x05C1, 0x05B9, 0x05BC, 0x05BD, 0x05BF, 0x05C0,
+    0x02BC, 0x2105, 0x2113, 0x2116, 0x202C, 0x202D, 0x202E, 0x200C, 0x066D, 0x02BD, 0x00E0, 0x0A85,
+    0x0A05, 0x3042, 0x1EA3, 0x0990, 0x311E, 0x0910, 0x04D5, 0x0A90, 0x0A10, 0x0A48, 0x0639, 0xFECA,
+    0xFECB, 0xFECC, 0x0203, 0x09C8, 0x0948, 0x0AC8, 0x30A2, 0xFF71, 0x314F, 0x05D0, 0x0627, 0xFB30,
+    0xFE8E, 0x0623, 0xFE84, 0x0625, 0xFE88, 0x05D0, 0xFB4F, 0x0622, 0xFE82, 0x0649, 0xFEF0, 0xFEF3,
+    0xFEF4, 0xFB2E, 0xFB2F, 0x2135, 0x224C, 0x03B1, 0x03AC, 0x0101, 0xFF41, 0x0026, 0xFF06, 0xF726,
+    0x33C2, 0x3122, 0x3124, 0x0E5A, 0x2220, 0x3008, 0xFE3F, 0x3009, 0xFE40, 0x2329, 0x232A, 0x212B,
+    0x0387, 0x0952, 0x0982, 0x0902, 0x0A82, 0x0105, 0x3300, 0x249C, 0x055A, 0x02BC, 0xF8FF, 0x2250,
+    0x2248, 0x2252, 0x2245, 0x318E, 0x318D, 0x2312, 0x1E9A, 0x00E5, 0x01FB, 0x1E01, 0x2194, 0x21E3,
+    0x21E0, 0x21E2, 0x21E1, 0x21D4, 0x21D3, 0x21D0, 0x21D2, 0x21D1, 0x2193, 0x2199, 0x2198, 0x21E9,
+    0x02C5, 0x02C2, 0x02C3, 0x02C4, 0xF8E7, 0x2190, 0x21D0, 0x21CD, 0x21C6, 0x21E6, 0x2192, 0x21CF,
+    0x279E, 0x21C4, 0x21E8, 0x21E4, 0x21E5, 0x2191, 0x2195, 0x21A8, 0x21A8, 0x2196, 0x21C5, 0x2197,
+    0x21E7, 0xF8E6, 0x005E, 0xFF3E, 0x007E, 0xFF5E, 0x0251, 0x0252, 0x3041, 0x30A1, 0xFF67, 0x002A,
+    0x066D, 0x066D, 0x2217, 0xFF0A, 0xFE61, 0x2042, 0xF6E9, 0x2243, 0x0040, 0x00E3, 0xFF20, 0xFE6B,
+    0x0250, 0x0994, 0x3120, 0x0914, 0x0A94, 0x0A14, 0x09D7, 0x0A4C, 0x09CC, 0x094C, 0x0ACC, 0x093D,
+    0x0561, 0x05E2, 0xFB20, 0x05E2, 0x0062, 0x09AC, 0x005C, 0xFF3C, 0x092C, 0x0AAC, 0x0A2C, 0x3070,
+    0x0E3F, 0x30D0, 0x007C, 0xFF5C, 0x3105, 0x24D1, 0x1E03, 0x1E05, 0x266C, 0x2235, 0x0431, 0x0628,
+    0xFE90, 0xFE91, 0x3079, 0xFE92, 0xFC9F, 0xFC08, 0xFC6D, 0x30D9, 0x0562, 0x05D1, 0x03B2, 0x03D0,
+    0xFB31, 0xFB31, 0x05D1, 0xFB4C, 0x09AD, 0x092D, 0x0AAD, 0x0A2D, 0x0253, 0x3073, 0x30D3, 0x0298,
+    0x0A02, 0x3331, 0x25CF, 0x25C6, 0x25BC, 0x25C4, 0x25C0, 0x3010, 0xFE3B, 0x3011, 0xFE3C, 0x25E3,
+    0x25E2, 0x25AC, 0x25BA, 0x25B6, 0x25AA, 0x263B, 0x25A0, 0x2605, 0x25E4, 0x25E5, 0x25B4, 0x25B2,
+    0x2423, 0x1E07, 0x2588, 0xFF42, 0x0E1A, 0x307C, 0x30DC, 0x249D, 0x33C3, 0xF8F4, 0x007B, 0xF8F3,
+    0xF8F2, 0xFF5B, 0xFE5B, 0xF8F1, 0xFE37, 0x007D, 0xF8FE, 0xF8FD, 0xFF5D, 0xFE5C, 0xF8FC, 0xFE38,
+    0x005B, 0xF8F0, 0xF8EF, 0xFF3B, 0xF8EE, 0x005D, 0xF8FB, 0xF8FA, 0xFF3D, 0xF8F9, 0x02D8, 0x032E,
+    0x0306, 0x032F, 0x0311, 0x0361, 0x032A, 0x033A, 0x00A6, 0x0180, 0xF6EA, 0x0183, 0x3076, 0x30D6,
+    0x2022, 0x25D8, 0x2219, 0x25CE, 0x0063, 0x056E, 0x099A, 0x0107, 0x091A, 0x0A9A, 0x0A1A, 0x3388,
+    0x0981, 0x0310, 0x0901, 0x0A81, 0x21EA, 0x2105, 0x02C7, 0x032C, 0x030C, 0x21B5, 0x3118, 0x010D,
+    0x00E7, 0x1E09, 0x24D2, 0x0109, 0x0255, 0x010B, 0x010B, 0x33C5, 0x00B8, 0x0327, 0x00A2, 0x2103,
+    0xF6DF, 0xFFE0, 0xF7A2, 0xF6E0, 0x0579, 0x099B, 0x091B, 0x0A9B, 0x0A1B, 0x3114, 0x04BD, 0x2713,
+    0x0447, 0x04BF, 0x04B7, 0x04F5, 0x0573, 0x04CC, 0x04B9, 0x03C7, 0x3277, 0x3217, 0x3269, 0x314A,
+    0x3209, 0x0E0A, 0x0E08, 0x0E09, 0x0E0C, 0x0188, 0x3276, 0x3216, 0x3268, 0x3148, 0x3208, 0x321C,
+    0x25CB, 0x2297, 0x2299, 0x2295, 0x3036, 0x25D0, 0x25D1, 0x02C6, 0x032D, 0x0302, 0x2327, 0x01C2,
+    0x01C0, 0x01C1, 0x01C3, 0x2663, 0x2663, 0x2667, 0x33A4, 0xFF43, 0x33A0, 0x0581, 0x003A, 0x20A1,
+    0xFF1A, 0x20A1, 0xFE55, 0x02D1, 0x02D0, 0x002C, 0x0313, 0x0315, 0xF6C3, 0x060C, 0x055D, 0xF6E1,
+    0xFF0C, 0x0314, 0x02BD, 0xFE50, 0xF6E2, 0x0312, 0x02BB, 0x263C, 0x2245, 0x222E, 0x2303, 0x0006,
+    0x0007, 0x0008, 0x0018, 0x000D, 0x0011, 0x0012, 0x0013, 0x0014, 0x007F, 0x0010, 0x0019, 0x0005,
+    0x0004, 0x001B, 0x0017, 0x0003, 0x000C, 0x001C, 0x001D, 0x0009, 0x000A, 0x0015, 0x001E, 0x000F,
+    0x000E, 0x0002, 0x0001, 0x001A, 0x0016, 0x001F, 0x000B, 0x00A9, 0xF8E9, 0xF6D9, 0x300C, 0xFF62,
+    0xFE41, 0x300D, 0xFF63, 0xFE42, 0x337F, 0x33C7, 0x33C6, 0x249E, 0x20A2, 0x0297, 0x22CF, 0x22CE,
+    0x00A4, 0xF6D1, 0xF6D2, 0xF6D4, 0xF6D5, 0x0064, 0x0564, 0x09A6, 0x0636, 0x0926, 0xFEBE, 0xFEBF,
+    0xFEC0, 0x05BC, 0x05BC, 0x2020, 0x2021, 0x0AA6, 0x0A26, 0x3060, 0x30C0, 0x062F, 0x05D3, 0xFB33,
+    0xFB33, 0x05D3, 0xFEAA, 0x064F, 0x064F, 0x064C, 0x064C, 0x0964, 0x05A7, 0x05A7, 0x0485, 0xF6D3,
+    0x300A, 0xFE3D, 0x300B, 0xFE3E, 0x032B, 0x21D4, 0x21D2, 0x0965, 0xF6D6, 0x030F, 0x222C, 0x2017,
+    0x0333, 0x033F, 0x02BA, 0x2016, 0x030E, 0x3109, 0x33C8, 0x010F, 0x1E11, 0x24D3, 0x1E13, 0x0111,
+    0x09A1, 0x0921, 0x0AA1, 0x0A21, 0x0688, 0xFB89, 0x095C, 0x09A2, 0x0922, 0x0AA2, 0x0A22, 0x1E0B,
+    0x1E0D, 0x066B, 0x066B, 0x0434, 0x00B0, 0x05AD, 0x3067, 0x03EF, 0x30C7, 0x232B, 0x2326, 0x03B4,
+    0x018D, 0x09F8, 0x02A4, 0x09A7, 0x0927, 0x0AA7, 0x0A27, 0x0257, 0x0385, 0x0344, 0x2666, 0x2662,
+    0x00A8, 0xF6D7, 0x0324, 0x0308, 0xF6D8, 0x0385, 0x3062, 0x30C2, 0x3003, 0x00F7, 0x2223, 0x2215,
+    0x0452, 0x2593, 0x1E0F, 0x3397, 0x0111, 0xFF44, 0x2584, 0x0E0E, 0x0E14, 0x3069, 0x30C9, 0x0024,
+    0xF6E3, 0xFF04, 0xF724, 0xFE69, 0xF6E4, 0x20AB, 0x3326, 0x02D9, 0x0307, 0x0323, 0x0323, 0x30FB,
+    0x0131, 0xF6BE, 0x0284, 0x22C5, 0x25CC, 0xFB1F, 0xFB1F, 0x031E, 0x02D5, 0x249F, 0xF6EB, 0x0256,
+    0x018C, 0x3065, 0x30C5, 0x01F3, 0x02A3, 0x01C6, 0x02A5, 0x04E1, 0x0455, 0x045F, 0x0065, 0x00E9,
+    0x2641, 0x098F, 0x311C, 0x0115, 0x090D, 0x0A8D, 0x0945, 0x0AC5, 0x011B, 0x1E1D, 0x0565, 0x0587,
+    0x24D4, 0x00EA, 0x1EBF, 0x1E19, 0x1EC7, 0x1EC1, 0x1EC3, 0x1EC5, 0x0454, 0x0205, 0x090F, 0x00EB,
+    0x0117, 0x0117, 0x1EB9, 0x0A0F, 0x0A47, 0x0444, 0x00E8, 0x0A8F, 0x0567, 0x311D, 0x3048, 0x1EBB,
+    0x311F, 0x0038, 0x0668, 0x09EE, 0x2467, 0x2791, 0x096E, 0x2471, 0x2485, 0x2499, 0x0AEE, 0x0A6E,
+    0x0668, 0x3028, 0x266B, 0x3227, 0x2088, 0xFF18, 0xF738, 0x247B, 0x248F, 0x06F8, 0x2177, 0x2078,
+    0x0E58, 0x0207, 0x0465, 0x30A8, 0xFF74, 0x0A74, 0x3154, 0x043B, 0x2208, 0x246A, 0x247E, 0x2492,
+    0x217A, 0x2026, 0x22EE, 0x0113, 0x1E17, 0x1E15, 0x043C, 0x2014, 0xFE31, 0xFF45, 0x055B, 0x2205,
+    0x3123, 0x043D, 0x2013, 0xFE32, 0x04A3, 0x014B, 0x3125, 0x04A5, 0x04C8, 0x2002, 0x0119, 0x3153,
+    0x025B, 0x029A, 0x025C, 0x025E, 0x025D, 0x24A0, 0x03B5, 0x03AD, 0x003D, 0xFF1D, 0xFE66, 0x207C,
+    0x2261, 0x3126, 0x0440, 0x0258, 0x044D, 0x0441, 0x04AB, 0x0283, 0x0286, 0x090E, 0x0946, 0x01AA,
+    0x0285, 0x3047, 0x30A7, 0xFF6A, 0x212E, 0xF6EC, 0x03B7, 0x0568, 0x03AE, 0x00F0, 0x1EBD, 0x1E1B,
+    0x0591, 0x0591, 0x0591, 0x0591, 0x01DD, 0x3161, 0x20AC, 0x09C7, 0x0947, 0x0AC7, 0x0021, 0x055C,
+    0x203C, 0x00A1, 0xF7A1, 0xFF01, 0xF721, 0x2203, 0x0292, 0x01EF, 0x0293, 0x01B9, 0x01BA, 0x0066,
+    0x095E, 0x0A5E, 0x2109, 0x064E, 0x064E, 0x064B, 0x3108, 0x24D5, 0x1E1F, 0x0641, 0x0586, 0xFED2,
+    0xFED3, 0xFED4, 0x03E5, 0x2640, 0xFB00, 0xFB03, 0xFB04, 0xFB01, 0x246E, 0x2482, 0x2496, 0x2012,
+    0x25A0, 0x25AC, 0x05DA, 0xFB3A, 0xFB3A, 0x05DA, 0x05DD, 0x05DD, 0x05DF, 0x05DF, 0x05E3, 0x05E3,
+    0x05E5, 0x05E5, 0x02C9, 0x25C9, 0x0473, 0x0035, 0x0665, 0x09EB, 0x2464, 0x278E, 0x096B, 0x215D,
+    0x0AEB, 0x0A6B, 0x0665, 0x3025, 0x3224, 0x2085, 0xFF15, 0xF735, 0x2478, 0x248C, 0x06F5, 0x2174,
+    0x2075, 0x0E55, 0xFB02, 0x0192, 0xFF46, 0x3399, 0x0E1F, 0x0E1D, 0x0E4F, 0x2200, 0x0034, 0x0664,
+    0x09EA, 0x2463, 0x278D, 0x096A, 0x0AEA, 0x0A6A, 0x0664, 0x3024, 0x3223, 0x2084, 0xFF14, 0x09F7,
+    0xF734, 0x2477, 0x248B, 0x06F4, 0x2173, 0x2074, 0x246D, 0x2481, 0x2495, 0x0E54, 0x02CB, 0x24A1,
+    0x2044, 0x20A3, 0x0067, 0x0997, 0x01F5, 0x0917, 0x06AF, 0xFB93, 0xFB94, 0xFB95, 0x0A97, 0x0A17,
+    0x304C, 0x30AC, 0x03B3, 0x0263, 0x02E0, 0x03EB, 0x310D, 0x011F, 0x01E7, 0x0123, 0x24D6, 0x011D,
+    0x0123, 0x0121, 0x0121, 0x0433, 0x3052, 0x30B2, 0x2251, 0x059C, 0x05F3, 0x059D, 0x00DF, 0x059E,
+    0x05F4, 0x3013, 0x0998, 0x0572, 0x0918, 0x0A98, 0x0A18, 0x063A, 0xFECE, 0xFECF, 0xFED0, 0x0495,
+    0x0493, 0x0491, 0x095A, 0x0A5A, 0x0260, 0x3393, 0x304E, 0x30AE, 0x0563, 0x05D2, 0xFB32, 0xFB32,
+    0x05D2, 0x0453, 0x01BE, 0x0294, 0x0296, 0x02C0, 0x0295, 0x02C1, 0x02E4, 0x02A1, 0x02A2, 0x1E21,
+    0xFF47, 0x3054, 0x30B4, 0x24A2, 0x33AC, 0x2207, 0x0060, 0x0316, 0x0300, 0x0300, 0x0953, 0x02CE,
+    0xFF40, 0x0340, 0x003E, 0x2265, 0x22DB, 0xFF1E, 0x2273, 0x2277, 0x2267, 0xFE65, 0x0261, 0x01E5,
+    0x3050, 0x00AB, 0x00BB, 0x2039, 0x203A, 0x30B0, 0x3318, 0x33C9, 0x0068, 0x04A9, 0x06C1, 0x09B9,
+    0x04B3, 0x0939, 0x0AB9, 0x0A39, 0x062D, 0xFEA2, 0xFEA3, 0x306F, 0xFEA4, 0x332A, 0x30CF, 0xFF8A,
+    0x0A4D, 0x0621, 0x0621, 0x3164, 0x044A, 0x21BC, 0x21C0, 0x33CA, 0x05B2, 0x05B2, 0x05B2, 0x05B2,
+    0x05B2, 0x05B2, 0x05B2, 0x05B2, 0x05B3, 0x05B3, 0x05B3, 0x05B3, 0x05B3, 0x05B3, 0x05B3, 0x05B3,
+    0x05B1, 0x05B1, 0x05B1, 0x05B1, 0x05B1, 0x05B1, 0x05B1, 0x05B1, 0x0127, 0x310F, 0x1E2B, 0x1E29,
+    0x24D7, 0x0125, 0x1E27, 0x1E23, 0x1E25, 0x05D4, 0x2665, 0x2665, 0x2661, 0xFB34, 0xFB34, 0x06C1,
+    0x0647, 0x05D4, 0xFBA7, 0xFEEA, 0xFEEA, 0xFBA5, 0xFBA4, 0xFBA8, 0xFEEB, 0x3078, 0xFBA9, 0xFEEC,
+    0x337B, 0x30D8, 0xFF8D, 0x3336, 0x0267, 0x3339, 0x05D7, 0x05D7, 0x0266, 0x02B1, 0x327B, 0x321B,
+    0x326D, 0x314E, 0x320D, 0x3072, 0x30D2, 0xFF8B, 0x05B4, 0x05B4, 0x05B4, 0x05B4, 0x05B4, 0x05B4,
+    0x05B4, 0x05B4, 0x1E96, 0xFF48, 0x0570, 0x0E2B, 0x307B, 0x30DB, 0xFF8E, 0x05B9, 0x05B9, 0x05B9,
+    0x05B9, 0x05B9, 0x05B9, 0x05B9, 0x05B9, 0x0E2E, 0x0309, 0x0309, 0x0321, 0x0322, 0x3342, 0x03E9,
+    0x2015, 0x031B, 0x2668, 0x2302, 0x24A3, 0x02B0, 0x0265, 0x3075, 0x3333, 0x30D5, 0xFF8C, 0x02DD,
+    0x030B, 0x0195, 0x002D, 0xF6E5, 0xFF0D, 0xFE63, 0xF6E6, 0x2010, 0x0069, 0x00ED, 0x044F, 0x0987,
+    0x3127, 0x012D, 0x01D0, 0x24D8, 0x00EE, 0x0456, 0x0209, 0x328F, 0x328B, 0x323F, 0x323A, 0x32A5,
+    0x3006, 0x3001, 0xFF64, 0x3237, 0x32A3, 0x322F, 0x323D, 0x329D, 0x3240, 0x3296, 0x3236, 0x322B,
+    0x3232, 0x32A4, 0x3005, 0x3298, 0x3238, 0x32A7, 0x32A6, 0x32A9, 0x322E, 0x322A, 0x3234, 0x3002,
+    0x329E, 0x3243, 0x3239, 0x323E, 0x32A8, 0x3299, 0x3242, 0x3233, 0x3000, 0x3235, 0x3231, 0x323B,
+    0x3230, 0x323C, 0x322C, 0x322D, 0x3007, 0x328E, 0x328A, 0x3294, 0x3290, 0x328C, 0x328D, 0x0907,
+    0x00EF, 0x1E2F, 0x04E5, 0x1ECB, 0x04D7, 0x0435, 0x3275, 0x3215, 0x3267, 0x3147, 0x3207, 0x00EC,
+    0x0A87, 0x0A07, 0x3044, 0x1EC9, 0x0988, 0x0438, 0x0908, 0x0A88, 0x0A08, 0x0A40, 0x020B, 0x0439,
+    0x09C0, 0x0940, 0x0AC0, 0x0133, 0x30A4, 0xFF72, 0x3163, 0x02DC, 0x05AC, 0x012B, 0x04E3, 0x2253,
+    0x0A3F, 0xFF49, 0x2206, 0x221E, 0x056B, 0x222B, 0x2321, 0x2321, 0xF8F5, 0x2320, 0x2320, 0x2229,
+    0x3305, 0x25D8, 0x25D9, 0x263B, 0x0451, 0x012F, 0x03B9, 0x03CA, 0x0390, 0x0269, 0x03AF, 0x24A4,
+    0x0A72, 0x3043, 0x30A3, 0xFF68, 0x09FA, 0x0268, 0xF6ED, 0x309D, 0x30FD, 0x0129, 0x1E2D, 0x3129,
+    0x044E, 0x09BF, 0x093F, 0x0ABF, 0x0475, 0x0477, 0x006A, 0x0571, 0x099C, 0x091C, 0x0A9C, 0x0A1C,
+    0x3110, 0x01F0, 0x24D9, 0x0135, 0x029D, 0x025F, 0x0458, 0x062C, 0xFE9E, 0xFE9F, 0xFEA0, 0x0698,
+    0xFB8B, 0x099D, 0x091D, 0x0A9D, 0x0A1D, 0x057B, 0x3004, 0xFF4A, 0x24A5, 0x02B2, 0x006B, 0x04A1,
+    0x0995, 0x1E31, 0x043A, 0x049B, 0x0915, 0x05DB, 0x0643, 0xFB3B, 0xFB3B, 0xFEDA, 0x05DB, 0xFEDB,
+    0xFEDC, 0xFB4D, 0x0A95, 0x0A15, 0x304B, 0x04C4, 0x30AB, 0xFF76, 0x03BA, 0x03F0, 0x3171, 0x3184,
+    0x3178, 0x3179, 0x330D, 0x0640, 0x0640, 0x30F5, 0x3384, 0x0650, 0x064D, 0x049F, 0xFF70, 0x049D,
+    0x310E, 0x3389, 0x01E9, 0x0137, 0x24DA, 0x0137, 0x1E33, 0x0584, 0x3051, 0x30B1, 0xFF79, 0x056F,
+    0x30F6, 0x0138, 0x0996, 0x0445, 0x0916, 0x0A96, 0x0A16, 0x062E, 0xFEA6, 0xFEA7, 0xFEA8, 0x03E7,
+    0x0959, 0x0A59, 0x3278, 0x3218, 0x326A, 0x314B, 0x320A, 0x0E02, 0x0E05, 0x0E03, 0x0E04, 0x0E5B,
+    0x0199, 0x0E06, 0x3391, 0x304D, 0x30AD, 0xFF77, 0x3315, 0x3316, 0x3314, 0x326E, 0x320E, 0x3260,
+    0x3131, 0x3200, 0x3133, 0x045C, 0x1E35, 0x3398, 0x33A6, 0xFF4B, 0x33A2, 0x3053, 0x33C0, 0x0E01,
+    0x30B3, 0xFF7A, 0x331E, 0x0481, 0x327F, 0x0343, 0x24A6, 0x33AA, 0x046F, 0x33CF, 0x029E, 0x304F,
+    0x30AF, 0xFF78, 0x33B8, 0x33BE, 0x006C, 0x09B2, 0x013A, 0x0932, 0x0AB2, 0x0A32, 0x0E45, 0xFEFC,
+    0xFEF8, 0xFEF7, 0xFEFA, 0xFEF9, 0xFEFB, 0xFEF6, 0xFEF5, 0x0644, 0x03BB, 0x019B, 0x05DC, 0xFB3C,
+    0xFB3C, 0x05DC, 0xFEDE, 0xFCCA, 0xFEDF, 0xFCC9, 0xFCCB, 0xFDF2, 0xFEE0, 0xFD88, 0xFCCC, 0x25EF,
+    0x019A, 0x026C, 0x310C, 0x013E, 0x013C, 0x24DB, 0x1E3D, 0x013C, 0x0140, 0x0140, 0x1E37, 0x1E39,
+    0x031A, 0x0318, 0x003C, 0x2264, 0x22DA, 0xFF1C, 0x2272, 0x2276, 0x2266, 0xFE64, 0x026E, 0x258C,
+    0x026D, 0x20A4, 0x056C, 0x01C9, 0x0459, 0xF6C0, 0x0933, 0x0AB3, 0x1E3B, 0x0934, 0x09E1, 0x0961,
+    0x09E3, 0x0963, 0x026B, 0xFF4C, 0x33D0, 0x0E2C, 0x2227, 0x00AC, 0x2310, 0x2228, 0x0E25, 0x017F,
+    0xFE4E, 0x0332, 0xFE4D, 0x25CA, 0x24A7, 0x0142, 0x2113, 0xF6EE, 0x2591, 0x0E26, 0x098C, 0x090C,
+    0x09E2, 0x0962, 0x33D3, 0x006D, 0x09AE, 0x00AF, 0x0331, 0x0304, 0x02CD, 0xFFE3, 0x1E3F, 0x092E,
+    0x0AAE, 0x0A2E, 0x05A4, 0x05A4, 0x307E, 0xF895, 0xF894, 0x0E4B, 0xF893, 0xF88C, 0xF88B, 0x0E48,
+    0xF88A, 0xF884, 0x0E31, 0xF889, 0x0E47, 0xF88F, 0xF88E, 0x0E49, 0xF88D, 0xF892, 0xF891, 0x0E4A,
+    0xF890, 0x0E46, 0x30DE, 0xFF8F, 0x2642, 0x3347, 0x05BE, 0x2642, 0x05AF, 0x3383, 0x3107, 0x33D4,
+    0x24DC, 0x33A5, 0x1E41, 0x1E43, 0x0645, 0xFEE2, 0xFEE3, 0xFEE4, 0xFCD1, 0xFC48, 0x334D, 0x3081,
+    0x337E, 0x30E1, 0xFF92, 0x05DE, 0xFB3E, 0xFB3E, 0x05DE, 0x0574, 0x05A5, 0x05A6, 0x05A6, 0x05A5,
+    0x0271, 0x3392, 0xFF65, 0x00B7, 0x3272, 0x3212, 0x3264, 0x3141, 0x3170, 0x3204, 0x316E, 0x316F,
+    0x307F, 0x30DF, 0xFF90, 0x2212, 0x0320, 0x2296, 0x02D7, 0x2213, 0x2032, 0x334A, 0x3349, 0x0270,
+    0x3396, 0x33A3, 0xFF4D, 0x339F, 0x3082, 0x33C1, 0x30E2, 0xFF93, 0x33D6, 0x0E21, 0x33A7, 0x33A8,
+    0x24A8, 0x33AB, 0x33B3, 0xF6EF, 0x026F, 0x00B5, 0x00B5, 0x3382, 0x226B, 0x226A, 0x338C, 0x03BC,
+    0x338D, 0x3080, 0x30E0, 0xFF91, 0x3395, 0x00D7, 0x339B, 0x05A3, 0x05A3, 0x266A, 0x266B, 0x266D,
+    0x266F, 0x33B2, 0x33B6, 0x33BC, 0x33B9, 0x33B7, 0x33BF, 0x33BD, 0x006E, 0x09A8, 0x2207, 0x0144,
+    0x0928, 0x0AA8, 0x0A28, 0x306A, 0x30CA, 0xFF85, 0x0149, 0x3381, 0x310B, 0x00A0, 0x0148, 0x0146,
+    0x24DD, 0x1E4B, 0x0146, 0x1E45, 0x1E47, 0x306D, 0x30CD, 0xFF88, 0x20AA, 0x338B, 0x0999, 0x0919,
+    0x0A99, 0x0A19, 0x0E07, 0x3093, 0x0272, 0x0273, 0x326F, 0x320F, 0x3135, 0x3261, 0x3136, 0x3134,
+    0x3168, 0x3201, 0x3167, 0x3166, 0x306B, 0x30CB, 0xFF86, 0xF899, 0x0E4D, 0x0039, 0x0669, 0x09EF,
+    0x2468, 0x2792, 0x096F, 0x0AEF, 0x0A6F, 0x0669, 0x3029, 0x3228, 0x2089, 0xFF19, 0xF739, 0x247C,
+    0x2490, 0x06F9, 0x2178, 0x2079, 0x2472, 0x2486, 0x249A, 0x0E59, 0x01CC, 0x045A, 0x30F3, 0xFF9D,
+    0x019E, 0x1E49, 0xFF4E, 0x339A, 0x09A3, 0x0923, 0x0AA3, 0x0A23, 0x0929, 0x306E, 0x30CE, 0xFF89,
+    0x00A0, 0x0E13, 0x0E19, 0x0646, 0xFEE6, 0x06BA, 0xFB9F, 0xFEE7, 0xFCD2, 0xFC4B, 0xFEE8, 0xFCD5,
+    0xFC4E, 0xFC8D, 0x220C, 0x2209, 0x2209, 0x2260, 0x226F, 0x2271, 0x2279, 0x2262, 0x226E, 0x2270,
+    0x2226, 0x2280, 0x2284, 0x2281, 0x2285, 0x0576, 0x24A9, 0x33B1, 0x207F, 0x00F1, 0x03BD, 0x306C,
+    0x30CC, 0xFF87, 0x09BC, 0x093C, 0x0ABC, 0x0A3C, 0x0023, 0xFF03, 0xFE5F, 0x0374, 0x0375, 0x2116,
+    0x05E0, 0xFB40, 0xFB40, 0x05E0, 0x33B5, 0x33BB, 0x099E, 0x091E, 0x0A9E, 0x0A1E, 0x006F, 0x00F3,
+    0x0E2D, 0x0275, 0x04E9, 0x04EB, 0x0993, 0x311B, 0x014F, 0x0911, 0x0A91, 0x0949, 0x0AC9, 0x01D2,
+    0x24DE, 0x00F4, 0x1ED1, 0x1ED9, 0x1ED3, 0x1ED5, 0x1ED7, 0x043E, 0x0151, 0x020D, 0x0913, 0x00F6,
+    0x04E7, 0x1ECD, 0x0153, 0x315A, 0x02DB, 0x0328, 0x00F2, 0x0A93, 0x0585, 0x304A, 0x1ECF, 0x01A1,
+    0x1EDB, 0x1EE3, 0x1EDD, 0x1EDF, 0x1EE1, 0x0151, 0x01A3, 0x020F, 0x30AA, 0xFF75, 0x3157, 0x05AB,
+    0x014D, 0x1E53, 0x1E51, 0x0950, 0x03C9, 0x03D6, 0x0461, 0x0277, 0x047B, 0x047D, 0x03CE, 0x0AD0,
+    0x03BF, 0x03CC, 0xFF4F, 0x0031, 0x0661, 0x09E7, 0x2460, 0x278A, 0x0967, 0x2024, 0x215B, 0xF6DC,
+    0x0AE7, 0x0A67, 0x0661, 0x00BD, 0x3021, 0x3220, 0x2081, 0xFF11, 0x09F4, 0xF731, 0x2474, 0x2488,
+    0x06F1, 0x00BC, 0x2170, 0x00B9, 0x0E51, 0x2153, 0x01EB, 0x01ED, 0x0A13, 0x0A4B, 0x0254, 0x24AA,
+    0x25E6, 0x2325, 0x00AA, 0x00BA, 0x221F, 0x0912, 0x094A, 0x00F8, 0x01FF, 0x3049, 0x30A9, 0xFF6B,
+    0x01FF, 0xF6F0, 0x047F, 0x00F5, 0x1E4D, 0x1E4F, 0x3121, 0x203E, 0xFE4A, 0x0305, 0xFE49, 0xFE4C,
+    0xFE4B, 0x00AF, 0x09CB, 0x094B, 0x0ACB, 0x0070, 0x3380, 0x332B, 0x09AA, 0x1E55, 0x092A, 0x21DF,
+    0x21DE, 0x0AAA, 0x0A2A, 0x3071, 0x0E2F, 0x30D1, 0x0484, 0x04C0, 0x317F, 0x00B6, 0x2225, 0x0028,
+    0xFD3E, 0xF8ED, 0xF8EC, 0x208D, 0xFF08, 0xFE59, 0x207D, 0xF8EB, 0xFE35, 0x0029, 0xFD3F, 0xF8F8,
+    0xF8F7, 0x208E, 0xFF09, 0xFE5A, 0x207E, 0xF8F6, 0xFE36, 0x2202, 0x05C0, 0x0599, 0x33A9, 0x05B7,
+    0x05B7, 0x05B7, 0x05B7, 0x05B7, 0x05B7, 0x05B7, 0x05B7, 0x05A1, 0x3106, 0x24DF, 0x1E57, 0x05E4,
+    0x043F, 0xFB44, 0xFB44, 0x333B, 0xFB43, 0x067E, 0x057A, 0x05E4, 0xFB57, 0xFB58, 0x307A, 0xFB59,
+    0x30DA, 0x04A7, 0xFB4E, 0x0025, 0x066A, 0xFF05, 0xFE6A, 0x002E, 0x0589, 0x00B7, 0xFF61, 0xF6E7,
+    0xFF0E, 0xFE52, 0xF6E8, 0x0342, 0x22A5, 0x2030, 0x20A7, 0x338A, 0x09AB, 0x092B, 0x0AAB, 0x0A2B,
+    0x03C6, 0x03D5, 0x327A, 0x321A, 0x326C, 0x314D, 0x320C, 0x0278, 0x0E3A, 0x03D5, 0x01A5, 0x0E1E,
+    0x0E1C, 0x0E20, 0x03C0, 0x3273, 0x3213, 0x3176, 0x3265, 0x3172, 0x3142, 0x3205, 0x3174, 0x3144,
+    0x3175, 0x3177, 0x3173, 0x3074, 0x30D4, 0x03D6, 0x0583, 0x002B, 0x031F, 0x2295, 0x00B1, 0x02D6,
+    0xFF0B, 0xFE62, 0x207A, 0xFF50, 0x33D8, 0x307D, 0x261F, 0x261C, 0x261E, 0x261D, 0x30DD, 0x0E1B,
+    0x3012, 0x3020, 0x24AB, 0x227A, 0x211E, 0x02B9, 0x2035, 0x220F, 0x2305, 0x30FC, 0x2318, 0x2282,
+    0x2283, 0x2237, 0x221D, 0x03C8, 0x0471, 0x0486, 0x33B0, 0x3077, 0x30D7, 0x33B4, 0x33BA, 0x0071,
+    0x0958, 0x05A8, 0x0642, 0xFED6, 0xFED7, 0xFED8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8,
+    0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x05B8, 0x059F, 0x3111,
+    0x24E0, 0x02A0, 0xFF51, 0x05E7, 0xFB47, 0xFB47, 0x05E7, 0x24AC, 0x2669, 0x05BB, 0x05BB, 0x05BB,
+    0x05BB, 0x05BB, 0x05BB, 0x05BB, 0x05BB, 0x003F, 0x061F, 0x055E, 0x00BF, 0xF7BF, 0x037E, 0xFF1F,
+    0xF73F, 0x0022, 0x201E, 0x201C, 0xFF02, 0x301E, 0x301D, 0x201D, 0x2018, 0x201B, 0x201B, 0x2019,
+    0x0149, 0x201A, 0x0027, 0xFF07, 0x0072, 0x057C, 0x09B0, 0x0155, 0x0930, 0x221A, 0xF8E5, 0x33AE,
+    0x33AF, 0x33AD, 0x05BF, 0x05BF, 0x0AB0, 0x0A30, 0x3089, 0x30E9, 0xFF97, 0x09F1, 0x09F0, 0x0264,
+    0x2236, 0x3116, 0x0159, 0x0157, 0x24E1, 0x0157, 0x0211, 0x1E59, 0x1E5B, 0x1E5D, 0x203B, 0x2286,
+    0x2287, 0x00AE, 0xF8E8, 0xF6DA, 0x0631, 0x0580, 0xFEAE, 0x308C, 0x30EC, 0xFF9A, 0x05E8, 0xFB48,
+    0x05E8, 0x223D, 0x0597, 0x0597, 0x2310, 0x027E, 0x027F, 0x09DD, 0x095D, 0x03C1, 0x027D, 0x027B,
+    0x02B5, 0x03F1, 0x02DE, 0x3271, 0x3211, 0x3263, 0x3140, 0x313A, 0x3169, 0x3139, 0x313B, 0x316C,
+    0x3203, 0x313F, 0x313C, 0x316B, 0x313D, 0x313E, 0x316A, 0x316D, 0x221F, 0x0319, 0x22BF, 0x308A,
+    0x30EA, 0xFF98, 0x02DA, 0x0325, 0x030A, 0x02BF, 0x0559, 0x031C, 0x02D3, 0x02BE, 0x0339, 0x02D2,
+    0x0213, 0x3351, 0x1E5F, 0x027C, 0x027A, 0xFF52, 0x308D, 0x30ED, 0xFF9B, 0x0E23, 0x24AD, 0x09DC,
+    0x0931, 0x0A5C, 0x0691, 0xFB8D, 0x09E0, 0x0960, 0x0AE0, 0x09C4, 0x0944, 0x0AC4, 0xF6F1, 0x2590,
+    0x0279, 0x02B4, 0x308B, 0x30EB, 0xFF99, 0x09F2, 0x09F3, 0xF6DD, 0x0E24, 0x098B, 0x090B, 0x0A8B,
+    0x09C3, 0x0943, 0x0AC3, 0x0073, 0x09B8, 0x015B, 0x1E65, 0x0635, 0x0938, 0xFEBA, 0xFEBB, 0xFEBC,
+    0x0AB8, 0x0A38, 0x3055, 0x30B5, 0xFF7B, 0xFDFA, 0x05E1, 0xFB41, 0xFB41, 0x05E1, 0x0E32, 0x0E41,
+    0x0E44, 0x0E43, 0x0E33, 0x0E30, 0x0E40, 0xF886, 0x0E35, 0xF885, 0x0E34, 0x0E42, 0xF888, 0x0E37,
+    0xF887, 0x0E36, 0x0E38, 0x0E39, 0x3119, 0x0161, 0x1E67, 0x015F, 0x0259, 0x04D9, 0x04DB, 0x025A,
+    0x24E2, 0x015D, 0x0219, 0x1E61, 0x1E63, 0x1E69, 0x033C, 0x2033, 0x02CA, 0x00A7, 0x0633, 0xFEB2,
+    0xFEB3, 0xFEB4, 0x05B6, 0x05B6, 0x05B6, 0x05B6, 0x05B6, 0x05B6, 0x05B6, 0x0592, 0x05B6, 0x057D,
+    0x305B, 0x30BB, 0xFF7E, 0x003B, 0x061B, 0xFF1B, 0xFE54, 0x309C, 0xFF9F, 0x3322, 0x3323, 0x0037,
+    0x0667, 0x09ED, 0x2466, 0x2790, 0x096D, 0x215E, 0x0AED, 0x0A6D, 0x0667, 0x3027, 0x3226, 0x2087,
+    0xFF17, 0xF737, 0x247A, 0x248E, 0x06F7, 0x2176, 0x2077, 0x2470, 0x2484, 0x2498, 0x0E57, 0x00AD,
+    0x0577, 0x09B6, 0x0448, 0x0651, 0xFC61, 0xFC5E, 0xFC60, 0xFC62, 0xFC5F, 0x2592, 0x2593, 0x2591,
+    0x2592, 0x0936, 0x0AB6, 0x0A36, 0x0593, 0x3115, 0x0449, 0x0634, 0xFEB6, 0xFEB7, 0xFEB8, 0x03E3,
+    0x20AA, 0x20AA, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x05B0, 0x04BB,
+    0x03ED, 0x05E9, 0xFB49, 0xFB49, 0xFB2C, 0xFB2C, 0xFB2D, 0xFB2D, 0x05C1, 0x05E9, 0xFB2A, 0xFB2A,
+    0xFB2B, 0xFB2B, 0x0282, 0x03C3, 0x03C2, 0x03C2, 0x03F2, 0x3057, 0x30B7, 0xFF7C, 0x05BD, 0x05BD,
+    0x223C, 0x05C2, 0x3274, 0x3214, 0x317E, 0x3266, 0x317A, 0x3145, 0x317B, 0x3206, 0x317D, 0x317C,
+    0x0036, 0x0666, 0x09EC, 0x2465, 0x278F, 0x096C, 0x0AEC, 0x0A6C, 0x0666, 0x3026, 0x3225, 0x2086,
+    0xFF16, 0xF736, 0x2479, 0x248D, 0x06F6, 0x2175, 0x2076, 0x246F, 0x09F9, 0x2483, 0x2497, 0x0E56,
+    0x002F, 0xFF0F, 0x017F, 0x1E9B, 0x263A, 0xFF53, 0x05C3, 0x00AD, 0x044C, 0x305D, 0x30BD, 0xFF7F,
+    0x0338, 0x0337, 0x0E29, 0x0E28, 0x0E0B, 0x0E2A, 0x0020, 0x0020, 0x2660, 0x2660, 0x2664, 0x24AE,
+    0x033B, 0x33C4, 0x339D, 0x25A9, 0x25A4, 0x338F, 0x339E, 0x33CE, 0x33D1, 0x33D2, 0x338E, 0x33D5,
+    0x339C, 0x33A1, 0x25A6, 0x25A7, 0x25A8, 0x25A5, 0x25A3, 0x33DB, 0x09B7, 0x0937, 0x0AB7, 0x3149,
+    0x3185, 0x3180, 0x3132, 0x3165, 0x3143, 0x3146, 0x3138, 0xF6F2, 0x00A3, 0xFFE1, 0x0336, 0x0335,
+    0x2282, 0x228A, 0x2286, 0x227B, 0x220B, 0x3059, 0x30B9, 0xFF7D, 0x0652, 0x2211, 0x263C, 0x2283,
+    0x228B, 0x2287, 0x33DC, 0x337C, 0x0074, 0x09A4, 0x22A4, 0x22A3, 0x0924, 0x0AA4, 0x0A24, 0x0637,
+    0xFEC2, 0xFEC3, 0x305F, 0xFEC4, 0x337D, 0x30BF, 0xFF80, 0x0640, 0x03C4, 0x05EA, 0xFB4A, 0xFB4A,
+    0xFB4A, 0x05EA, 0x0167, 0x310A, 0x0165, 0x02A8, 0x0163, 0x0686, 0xFB7B, 0xFB7C, 0xFB7D, 0x24E3,
+    0x1E71, 0x0163, 0x1E97, 0x1E6B, 0x1E6D, 0x0442, 0x04AD, 0x062A, 0xFE96, 0xFCA2, 0xFC0C, 0xFE97,
+    0x3066, 0xFCA1, 0xFC0B, 0x0629, 0xFE94, 0xFE98, 0xFCA4, 0xFC0E, 0xFC73, 0x30C6, 0xFF83, 0x2121,
+    0x260E, 0x05A0, 0x05A9, 0x2469, 0x3229, 0x247D, 0x2491, 0x2179, 0x02A7, 0x05D8, 0xFB38, 0xFB38,
+    0x05D8, 0x04B5, 0x059B, 0x059B, 0x09A5, 0x0925, 0x0AA5, 0x0A25, 0x0630, 0xFEAC, 0xF898, 0xF897,
+    0x0E4C, 0xF896, 0x062B, 0xFE9A, 0xFE9B, 0xFE9C, 0x2203, 0x2234, 0x03B8, 0x03D1, 0x03D1, 0x3279,
+    0x3219, 0x326B, 0x314C, 0x320B, 0x246C, 0x2480, 0x2494, 0x0E11, 0x01AD, 0x0E12, 0x00FE, 0x0E17,
+    0x0E10, 0x0E18, 0x0E16, 0x0482, 0x066C, 0x066C, 0x0033, 0x0663, 0x09E9, 0x2462, 0x278C, 0x0969,
+    0x215C, 0x0AE9, 0x0A69, 0x0663, 0x3023, 0x3222, 0x2083, 0xFF13, 0x09F6, 0xF733, 0x2476, 0x248A,
+    0x06F3, 0x00BE, 0xF6DE, 0x2172, 0x00B3, 0x0E53, 0x3394, 0x3061, 0x30C1, 0xFF81, 0x3270, 0x3210,
+    0x3262, 0x3137, 0x3202, 0x02DC, 0x0330, 0x0303, 0x0303, 0x0360, 0x223C, 0x0334, 0x033E, 0x2297,
+    0x0596, 0x0596, 0x0A70, 0x0483, 0x057F, 0x1E6F, 0xFF54, 0x0569, 0x3068, 0x30C8, 0xFF84, 0x02E5,
+    0x02E9, 0x02E6, 0x02E8, 0x02E7, 0x01BD, 0x0185, 0x01A8, 0x0384, 0x3327, 0x0E0F, 0x3014, 0xFE5D,
+    0xFE39, 0x3015, 0xFE5E, 0xFE3A, 0x0E15, 0x01AB, 0x24AF, 0x2122, 0xF8EA, 0xF6DB, 0x0288, 0x25BC,
+    0x25C4, 0x25BA, 0x25B2, 0x02A6, 0x05E6, 0xFB46, 0xFB46, 0x05E6, 0x0446, 0x05B5, 0x05B5, 0x05B5,
+    0x05B5, 0x05B5, 0x05B5, 0x05B5, 0x05B5, 0x045B, 0xF6F3, 0x099F, 0x091F, 0x0A9F, 0x0A1F, 0x0679,
+    0xFB67, 0xFB68, 0xFB69, 0x09A0, 0x0920, 0x0AA0, 0x0A20, 0x0287, 0x3064, 0x30C4, 0xFF82, 0x3063,
+    0x30C3, 0xFF6F, 0x246B, 0x247F, 0x2493, 0x217B, 0x2473, 0x5344, 0x2487, 0x249B, 0x0032, 0x0662,
+    0x09E8, 0x2461, 0x278B, 0x0968, 0x2025, 0x2025, 0xFE30, 0x0AE8, 0x0A68, 0x0662, 0x3022, 0x3221,
+    0x2082, 0xFF12, 0x09F5, 0xF732, 0x2475, 0x2489, 0x06F2, 0x2171, 0x01BB, 0x00B2, 0x0E52, 0x2154,
+    0x0075, 0x00FA, 0x0289, 0x0989, 0x3128, 0x016D, 0x01D4, 0x24E4, 0x00FB, 0x1E77, 0x0443, 0x0951,
+    0x0171, 0x0215, 0x0909, 0x00FC, 0x01D8, 0x1E73, 0x01DA, 0x04F1, 0x01DC, 0x01D6, 0x1EE5, 0x00F9,
+    0x0A89, 0x0A09, 0x3046, 0x1EE7, 0x01B0, 0x1EE9, 0x1EF1, 0x1EEB, 0x1EED, 0x1EEF, 0x0171, 0x04F3,
+    0x0217, 0x30A6, 0xFF73, 0x0479, 0x315C, 0x016B, 0x04EF, 0x1E7B, 0x0A41, 0xFF55, 0x005F, 0x2017,
+    0xFF3F, 0xFE33, 0xFE4F, 0x222A, 0x2200, 0x0173, 0x24B0, 0x2580, 0x05C4, 0x03C5, 0x03CB, 0x03B0,
+    0x028A, 0x03CD, 0x031D, 0x02D4, 0x0A73, 0x016F, 0x045E, 0x3045, 0x30A5, 0xFF69, 0x04AF, 0x04B1,
+    0x0169, 0x1E79, 0x1E75, 0x098A, 0x090A, 0x0A8A, 0x0A0A, 0x0A42, 0x09C2, 0x0942, 0x0AC2, 0x09C1,
+    0x0941, 0x0AC1, 0x0076, 0x0935, 0x0AB5, 0x0A35, 0x30F7, 0x05D5, 0xFB35, 0xFB35, 0xFB35, 0x05D5,
+    0xFB4B, 0xFB4B, 0x05F0, 0x05F1, 0x24E5, 0x1E7F, 0x0432, 0x06A4, 0xFB6B, 0xFB6C, 0xFB6D, 0x30F9,
+    0x2640, 0x007C, 0x030D, 0x0329, 0x02CC, 0x02C8, 0x057E, 0x028B, 0x30F8, 0x09CD, 0x094D, 0x0ACD,
+    0x0983, 0x0903, 0x0A83, 0xFF56, 0x0578, 0x309E, 0x30FE, 0x309B, 0xFF9E, 0x30FA, 0x24B1, 0x1E7D,
+    0x028C, 0x3094, 0x30F4, 0x0077, 0x1E83, 0x3159, 0x308F, 0x30EF, 0xFF9C, 0x3158, 0x308E, 0x30EE,
+    0x3357, 0x301C, 0xFE34, 0x0648, 0xFEEE, 0x0624, 0xFE86, 0x33DD, 0x24E6, 0x0175, 0x1E85, 0x1E87,
+    0x1E89, 0x3091, 0x2118, 0x30F1, 0x315E, 0x315D, 0x1E81, 0x25E6, 0x25CB, 0x25D9, 0x300E, 0xFE43,
+    0x300F, 0xFE44, 0x25C7, 0x25C8, 0x25BF, 0x25BD, 0x25C3, 0x25C1, 0x3016, 0x3017, 0x25B9, 0x25B7,
+    0x25AB, 0x263A, 0x25A1, 0x2606, 0x260F, 0x3018, 0x3019, 0x25B5, 0x25B3, 0x3090, 0x30F0, 0x315F,
+    0xFF57, 0x3092, 0x30F2, 0xFF66, 0x20A9, 0xFFE6, 0x0E27, 0x24B2, 0x1E98, 0x02B7, 0x028D, 0x01BF,
+    0x0078, 0x033D, 0x3112, 0x24E7, 0x1E8D, 0x1E8B, 0x056D, 0x03BE, 0xFF58, 0x24B3, 0x02E3, 0x0079,
+    0x334E, 0x09AF, 0x00FD, 0x092F, 0x3152, 0x0AAF, 0x0A2F, 0x3084, 0x30E4, 0xFF94, 0x3151, 0x0E4E,
+    0x3083, 0x30E3, 0xFF6C, 0x0463, 0x24E8, 0x0177, 0x00FF, 0x1E8F, 0x1EF5, 0x064A, 0x06D2, 0xFBAF,
+    0xFEF2, 0x0626, 0xFE8A, 0xFE8B, 0xFE8C, 0xFEF3, 0xFEF4, 0xFCDD, 0xFC58, 0xFC94, 0x06D1, 0x3156,
+    0x00A5, 0xFFE5, 0x3155, 0x3186, 0x05AA, 0x05AA, 0x044B, 0x04F9, 0x3181, 0x3183, 0x3182, 0x059A,
+    0x1EF3, 0x01B4, 0x1EF7, 0x0575, 0x0457, 0x3162, 0x262F, 0x0582, 0xFF59, 0x05D9, 0xFB39, 0xFB39,
+    0x05D9, 0x05F2, 0xFB1F, 0x3088, 0x3189, 0x30E8, 0xFF96, 0x315B, 0x3087, 0x30E7, 0xFF6E, 0x03F3,
+    0x3188, 0x3187, 0x0E22, 0x0E0D, 0x24B4, 0x037A, 0x0345, 0x01A6, 0x1E99, 0x02B8, 0x1EF9, 0x028E,
+    0x3086, 0x318C, 0x30E6, 0xFF95, 0x3160, 0x046B, 0x046D, 0x0467, 0x0469, 0x3085, 0x30E5, 0xFF6D,
+    0x318B, 0x318A, 0x09DF, 0x095F, 0x007A, 0x0566, 0x017A, 0x095B, 0x0A5B, 0x0638, 0xFEC6, 0xFEC7,
+    0x3056, 0xFEC8, 0x0632, 0xFEB0, 0x30B6, 0x0595, 0x0594, 0x0598, 0x05D6, 0xFB36, 0xFB36, 0x05D6,
+    0x3117, 0x017E, 0x24E9, 0x1E91, 0x0291, 0x017C, 0x017C, 0x1E93, 0x0437, 0x0499, 0x04DF, 0x305C,
+    0x30BC, 0x0030, 0x0660, 0x09E6, 0x0966, 0x0AE6, 0x0A66, 0x0660, 0x2080, 0xFF10, 0xF730, 0x06F0,
+    0x2070, 0x0E50, 0xFEFF, 0x200C, 0x200B, 0x03B6, 0x3113, 0x056A, 0x04C2, 0x0436, 0x0497, 0x04DD,
+    0x3058, 0x30B8, 0x05AE, 0x1E95, 0xFF5A, 0x305E, 0x30BE, 0x24B5, 0x0290, 0x01B6, 0x305A, 0x30BA,
+];
+
+/// Glyph names mapped to several code points, sorted
+static SEQUENCES: &[(&str, &str)] = &[
+    ("dalethatafpatah", "\u{05D3}\u{05B2}"),
+    ("dalethatafpatahhebrew", "\u{05D3}\u{05B2}"),
+    ("dalethatafsegol", "\u{05D3}\u{05B1}"),
+    ("dalethatafsegolhebrew", "\u{05D3}\u{05B1}"),
+    ("dalethiriq", "\u{05D3}\u{05B4}"),
+    ("dalethiriqhebrew", "\u{05D3}\u{05B4}"),
+    ("daletholam", "\u{05D3}\u{05B9}"),
+    ("daletholamhebrew", "\u{05D3}\u{05B9}"),
+    ("daletpatah", "\u{05D3}\u{05B7}"),
+    ("daletpatahhebrew", "\u{05D3}\u{05B7}"),
+    ("daletqamats", "\u{05D3}\u{05B8}"),
+    ("daletqamatshebrew", "\u{05D3}\u{05B8}"),
+    ("daletqubuts", "\u{05D3}\u{05BB}"),
+    ("daletqubutshebrew", "\u{05D3}\u{05BB}"),
+    ("daletsegol", "\u{05D3}\u{05B6}"),
+    ("daletsegolhebrew", "\u{05D3}\u{05B6}"),
+    ("daletsheva", "\u{05D3}\u{05B0}"),
+    ("daletshevahebrew", "\u{05D3}\u{05B0}"),
+    ("dalettsere", "\u{05D3}\u{05B5}"),
+    ("dalettserehebrew", "\u{05D3}\u{05B5}"),
+    ("finalkafqamats", "\u{05DA}\u{05B8}"),
+    ("finalkafqamatshebrew", "\u{05DA}\u{05B8}"),
+    ("finalkafsheva", "\u{05DA}\u{05B0}"),
+    ("finalkafshevahebrew", "\u{05DA}\u{05B0}"),
+    ("hamzadammaarabic", "\u{0621}\u{064F}"),
+    ("hamzadammatanarabic", "\u{0621}\u{064C}"),
+    ("hamzafathaarabic", "\u{0621}\u{064E}"),
+    ("hamzafathatanarabic", "\u{0621}\u{064B}"),
+    ("hamzalowkasraarabic", "\u{0621}\u{0650}"),
+    ("hamzalowkasratanarabic", "\u{0621}\u{064D}"),
+    ("hamzasukunarabic", "\u{0621}\u{0652}"),
+    ("lamedholam", "\u{05DC}\u{05B9}"),
+    ("lamedholamdagesh", "\u{05DC}\u{05B9}\u{05BC}"),
+    ("lamedholamdageshhebrew", "\u{05DC}\u{05B9}\u{05BC}"),
+    ("lamedholamhebrew", "\u{05DC}\u{05B9}"),
+    ("lammeemjeeminitialarabic", "\u{FEDF}\u{FEE4}\u{FEA0}"),
+    ("lammeemkhahinitialarabic", "\u{FEDF}\u{FEE4}\u{FEA8}"),
+    ("noonhehinitialarabic", "\u{FEE7}\u{FEEC}"),
+    ("qofhatafpatah", "\u{05E7}\u{05B2}"),
+    ("qofhatafpatahhebrew", "\u{05E7}\u{05B2}"),
+    ("qofhatafsegol", "\u{05E7}\u{05B1}"),
+    ("qofhatafsegolhebrew", "\u{05E7}\u{05B1}"),
+    ("qofhiriq", "\u{05E7}\u{05B4}"),
+    ("qofhiriqhebrew", "\u{05E7}\u{05B4}"),
+    ("qofholam", "\u{05E7}\u{05B9}"),
+    ("qofholamhebrew", "\u{05E7}\u{05B9}"),
+    ("qofpatah", "\u{05E7}\u{05B7}"),
+    ("qofpatahhebrew", "\u{05E7}\u{05B7}"),
+    ("qofqamats", "\u{05E7}\u{05B8}"),
+    ("qofqamatshebrew", "\u{05E7}\u{05B8}"),
+    ("qofqubuts", "\u{05E7}\u{05BB}"),
+    ("qofqubutshebrew", "\u{05E7}\u{05BB}"),
+    ("qofsegol", "\u{05E7}\u{05B6}"),
+    ("qofsegolhebrew", "\u{05E7}\u{05B6}"),
+    ("qofsheva", "\u{05E7}\u{05B0}"),
+    ("qofshevahebrew", "\u{05E7}\u{05B0}"),
+    ("qoftsere", "\u{05E7}\u{05B5}"),
+    ("qoftserehebrew", "\u{05E7}\u{05B5}"),
+    ("rehyehaleflamarabic", "\u{0631}\u{FEF3}\u{FE8E}\u{0644}"),
+    ("reshhatafpatah", "\u{05E8}\u{05B2}"),
+    ("reshhatafpatahhebrew", "\u{05E8}\u{05B2}"),
+    ("reshhatafsegol", "\u{05E8}\u{05B1}"),
+    ("reshhatafsegolhebrew", "\u{05E8}\u{05B1}"),
+    ("reshhiriq", "\u{05E8}\u{05B4}"),
+    ("reshhiriqhebrew", "\u{05E8}\u{05B4}"),
+    ("reshholam", "\u{05E8}\u{05B9}"),
+    ("reshholamhebrew", "\u{05E8}\u{05B9}"),
+    ("reshpatah", "\u{05E8}\u{05B7}"),
+    ("reshpatahhebrew", "\u{05E8}\u{05B7}"),
+    ("reshqamats", "\u{05E8}\u{05B8}"),
+    ("reshqamatshebrew", "\u{05E8}\u{05B8}"),
+    ("reshqubuts", "\u{05E8}\u{05BB}"),
+    ("reshqubutshebrew", "\u{05E8}\u{05BB}"),
+    ("reshsegol", "\u{05E8}\u{05B6}"),
+    ("reshsegolhebrew", "\u{05E8}\u{05B6}"),
+    ("reshsheva", "\u{05E8}\u{05B0}"),
+    ("reshshevahebrew", "\u{05E8}\u{05B0}"),
+    ("reshtsere", "\u{05E8}\u{05B5}"),
+    ("reshtserehebrew", "\u{05E8}\u{05B5}"),
+    ("shaddafathatanarabic", "\u{0651}\u{064B}"),
+    ("tchehmeeminitialarabic", "\u{FB7C}\u{FEE4}"),
+];
+
+/// Unicode text of a glyph name (Adobe Glyph List Specification, section
+/// "Mapping a glyph name to a Unicode character sequence"): drop any suffix
+/// after a period, split ligature components at underscores and map each
+/// through the glyph list, `uniXXXX...` or `uXXXX[XX]`. Hex digits of either
+/// case are accepted. `None` when no component maps.
+pub(crate) fn glyph_to_unicode(name: &str) -> Option<String> {
+    let base = name.split('.').next().unwrap_or_default();
+    let mut text = String::new();
+    for component in base.split('_') {
+        push_component(component, &mut text);
+    }
+    (!text.is_empty()).then_some(text)
+}
+
+fn push_component(component: &str, text: &mut String) {
+    if let Some(code) = lookup(component) {
+        text.extend(char::from_u32(code as u32));
+    } else if let Ok(index) = SEQUENCES.binary_search_by(|(name, _)| (*name).cmp(component)) {
+        text.push_str(SEQUENCES[index].1);
+    } else if let Some(digits) = component.strip_prefix("uni") {
+        // Slicing by four bytes needs ASCII, which any hex digits are
+        if digits.is_ascii() && !digits.is_empty() && digits.len() % 4 == 0 {
+            let units: Option<String> = (0..digits.len())
+                .step_by(4)
+                .map(|i| scalar(&digits[i..i + 4]).filter(|&ch| (ch as u32) <= 0xFFFF))
+                .collect();
+            text.extend(units);
+        }
+    } else if let Some(digits) = component.strip_prefix('u') {
+        if (4..=6).contains(&digits.len()) {
+            text.extend(scalar(digits));
+        }
+    }
+}
+
+/// A Unicode scalar value written in hex, surrogates excluded
+fn scalar(digits: &str) -> Option<char> {
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+}
+
+/// Code point of a name in the glyph list
+fn lookup(name: &str) -> Option<u16> {
+    let (mut low, mut high) = (0, ENDS.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        let start = if mid == 0 { 0 } else { ENDS[mid - 1] as usize };
+        match NAMES[start..ENDS[mid] as usize].cmp(name) {
+            core::cmp::Ordering::Less => low = mid + 1,
+            core::cmp::Ordering::Greater => high = mid,
+            core::cmp::Ordering::Equal => return Some(UNICODE[mid]),
+        }
+    }
+    None
+}
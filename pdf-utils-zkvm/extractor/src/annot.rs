@@ -0,0 +1,340 @@
+//! Annotation text (PDF 32000-1 section 12.5).
+//!
+//! Annotations are listed in the page's `/Annots` and drawn apart from its
+//! content streams. Markup annotations (notes, free text, stamps, ...) carry
+//! their text in `/Contents` and, as XHTML, in `/RC`. Widgets and free text
+//! annotations also draw it in their normal appearance stream (`/AP /N`),
+//! a form XObject placed on the annotation rectangle.
+
+use crate::document::Document;
+use crate::font::PdfFont;
+use crate::marked::MarkedContentStack;
+use crate::page::{PageContent, Rect};
+use crate::parser::{decode_text_string, resolve, resolve_reference, PdfObj, PdfStream};
+use crate::position::{Matrix, TextRun, TextSource};
+use crate::text::{form_text, ExtractOptions};
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// `/F` flags of annotations that are not shown
+const HIDDEN: u32 = 1 << 1;
+const NO_VIEW: u32 = 1 << 5;
+
+/// Annotation subtypes whose `/Contents` and `/RC` are their text
+const MARKUP: &[&str] = &[
+    "Text",
+    "FreeText",
+    "Line",
+    "Square",
+    "Circle",
+    "Polygon",
+    "PolyLine",
+    "Highlight",
+    "Underline",
+    "Squiggly",
+    "StrikeOut",
+    "Stamp",
+    "Caret",
+    "Ink",
+    "FileAttachment",
+    "Sound",
+    "Redact",
+];
+
+/// A piece of text carried by an annotation
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotationText {
+    /// Annotation subtype, e.g. `Text`, `FreeText`, `Stamp` or `Widget`
+    pub subtype: String,
+    /// Where in the annotation the text was found
+    pub source: TextSource,
+    pub text: String,
+    /// Annotation rectangle (`/Rect`) in user space
+    pub rect: Option<Rect>,
+}
+
+/// Text of every shown annotation of `page`, in `/Annots` order
+pub fn extract_annotations_from_page_content(
+    page: &PageContent,
+    objects: &Document,
+    options: &ExtractOptions,
+) -> Vec<AnnotationText> {
+    let annots = annotations(page, objects);
+    if annots.is_empty() {
+        return Vec::new();
+    }
+    let fonts = page.fonts(objects);
+    let mut pieces = Vec::new();
+    for annot in annots {
+        pieces.extend(annotation_text(annot, &fonts, objects, options, None));
+    }
+    pieces
+}
+
+/// Append the text of the annotations of `page` to `text`, one piece per
+/// annotation: its appearance, else its `/Contents`, else its `/RC`. Runs
+/// drawn by appearance streams go to `runs`; the other pieces become a run
+/// spanning the annotation rectangle.
+pub(crate) fn append_annotations(
+    page: &PageContent,
+    fonts: &BTreeMap<String, Rc<PdfFont>>,
+    objects: &Document,
+    options: &ExtractOptions,
+    text: &mut String,
+    mut runs: Option<&mut Vec<TextRun>>,
+) {
+    for annot in annotations(page, objects) {
+        let pieces = annotation_text(annot, fonts, objects, options, runs.as_deref_mut());
+        let Some(piece) = pieces.into_iter().next() else {
+            continue;
+        };
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&piece.text);
+        if let (Some(runs), Some(rect)) = (runs.as_deref_mut(), piece.rect) {
+            if piece.source != TextSource::AnnotationAppearance {
+                runs.push(TextRun {
+                    text: piece.text,
+                    x: rect.x0,
+                    y: rect.y0,
+                    width: rect.width(),
+                    font_size: rect.height(),
+                    font_name: String::new(),
+                    mcid: None,
+                    source: piece.source,
+                });
+            }
+        }
+    }
+}
+
+/// Annotation dictionaries of `page` that a viewer shows
+fn annotations<'a>(page: &PageContent, objects: &'a Document) -> Vec<&'a BTreeMap<String, PdfObj>> {
+    let Some(PdfObj::Dictionary(page_dict)) =
+        page.id.and_then(|id| resolve_reference(objects, &id))
+    else {
+        return Vec::new();
+    };
+    let Some(PdfObj::Array(annots)) = resolve(page_dict.get("Annots"), objects) else {
+        return Vec::new();
+    };
+    annots
+        .iter()
+        .filter_map(|annot| match resolve(Some(annot), objects) {
+            Some(PdfObj::Dictionary(annot)) => Some(annot),
+            _ => None,
+        })
+        .filter(|annot| match resolve(annot.get("F"), objects) {
+            Some(PdfObj::Integer(flags)) => flags & i64::from(HIDDEN | NO_VIEW) == 0,
+            _ => true,
+        })
+        .collect()
+}
+
+/// Non-empty text pieces of one annotation, appearance first
+fn annotation_text(
+    annot: &BTreeMap<String, PdfObj>,
+    fonts: &BTreeMap<String, Rc<PdfFont>>,
+    objects: &Document,
+    options: &ExtractOptions,
+    runs: Option<&mut Vec<TextRun>>,
+) -> Vec<AnnotationText> {
+    let subtype = match annot.get("Subtype") {
+        Some(PdfObj::Name(subtype)) => subtype.as_str(),
+        _ => "",
+    };
+    let rect = resolve(annot.get("Rect"), objects).and_then(Rect::from_obj);
+    let mut pieces = Vec::new();
+    let mut push = |source, text: String| {
+        if !text.trim().is_empty() {
+            pieces.push(AnnotationText {
+                subtype: String::from(subtype),
+                source,
+                text,
+                rect,
+            });
+        }
+    };
+
+    if matches!(subtype, "Widget" | "FreeText") {
+        let appearance = appearance(annot, objects)
+            .filter(|appearance| rect.is_some() && objects.enter_form(appearance.id));
+        if let (Some(appearance), Some(rect)) = (appearance, rect) {
+            let start = runs.as_ref().map_or(0, |runs| runs.len());
+            let mut runs = runs;
+            let text = form_text(
+                appearance,
+                fonts,
+                objects,
+                appearance_matrix(appearance, &rect),
+                runs.as_deref_mut(),
+                &mut MarkedContentStack::new(*options),
+            );
+            objects.leave_form();
+            if let Some(runs) = runs {
+                for run in &mut runs[start..] {
+                    run.source = TextSource::AnnotationAppearance;
+                }
+            }
+            push(TextSource::AnnotationAppearance, text.unwrap_or_default());
+        }
+    }
+    if MARKUP.contains(&subtype) {
+        if let Some(PdfObj::String(contents)) = resolve(annot.get("Contents"), objects) {
+            push(TextSource::AnnotationContents, decode_text_string(contents));
+        }
+        let rich_text = match resolve(annot.get("RC"), objects) {
+            Some(PdfObj::String(rc)) => Some(decode_text_string(rc)),
+            Some(PdfObj::Stream(rc)) => objects
+                .decode_stream(rc)
+                .ok()
+                .map(|data| decode_text_string(&data)),
+            _ => None,
+        };
+        if let Some(rich_text) = rich_text {
+            push(TextSource::AnnotationRichText, strip_markup(&rich_text));
+        }
+    }
+    pieces
+}
+
+/// Normal appearance stream, picked by `/AS` when there is one per state
+fn appearance<'a>(
+    annot: &'a BTreeMap<String, PdfObj>,
+    objects: &'a Document,
+) -> Option<&'a PdfStream> {
+    let Some(PdfObj::Dictionary(ap)) = resolve(annot.get("AP"), objects) else {
+        return None;
+    };
+    match resolve(ap.get("N"), objects)? {
+        PdfObj::Stream(stream) => Some(stream),
+        PdfObj::Dictionary(states) => {
+            let Some(PdfObj::Name(state)) = annot.get("AS") else {
+                return None;
+            };
+            match resolve(states.get(state), objects)? {
+                PdfObj::Stream(stream) => Some(stream),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Matrix placing an appearance on the annotation rectangle (PDF 32000-1
+/// section 12.5.5): its `/BBox`, transformed by `/Matrix`, is scaled and
+/// moved onto `rect`. [`form_text`] applies `/Matrix` itself.
+fn appearance_matrix(appearance: &PdfStream, rect: &Rect) -> Matrix {
+    let Some(bbox) = appearance.dict.get("BBox").and_then(Rect::from_obj) else {
+        return Matrix::translate(rect.x0, rect.y0);
+    };
+    let matrix = appearance
+        .dict
+        .get("Matrix")
+        .and_then(Matrix::from_obj)
+        .unwrap_or(Matrix::IDENTITY);
+
+    let corners = [
+        matrix.transform_point(bbox.x0, bbox.y0),
+        matrix.transform_point(bbox.x1, bbox.y0),
+        matrix.transform_point(bbox.x0, bbox.y1),
+        matrix.transform_point(bbox.x1, bbox.y1),
+    ];
+    let mut placed = Rect::new(corners[0].0, corners[0].1, corners[3].0, corners[3].1);
+    for (x, y) in corners {
+        placed = Rect::new(
+            placed.x0.min(x),
+            placed.y0.min(y),
+            placed.x1.max(x),
+            placed.y1.max(y),
+        );
+    }
+
+    let scale = |target: f32, source: f32| if source > 0.0 { target / source } else { 1.0 };
+    let sx = scale(rect.width(), placed.width());
+    let sy = scale(rect.height(), placed.height());
+    Matrix::new(
+        sx,
+        0.0,
+        0.0,
+        sy,
+        rect.x0 - placed.x0 * sx,
+        rect.y0 - placed.y0 * sy,
+    )
+}
+
+/// Plain text of an XHTML rich text string: tags dropped, the predefined and
+/// numeric character references decoded, paragraphs separated by spaces
+fn strip_markup(xhtml: &str) -> String {
+    let mut text = String::new();
+    let mut rest = xhtml;
+    while let Some(start) = rest.find(['<', '&']) {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with('<') {
+            // An unterminated tag runs to the end of the text
+            let (tag, after) = rest[1..].split_once('>').unwrap_or((&rest[1..], ""));
+            if tag.starts_with("/p") || tag.starts_with("br") {
+                text.push(' ');
+            }
+            rest = after;
+        } else {
+            let end = rest.find(';').filter(|&end| end <= 10);
+            let decoded = end.and_then(|end| match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                reference => {
+                    let number = reference.strip_prefix('#')?;
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+                    char::from_u32(code)
+                }
+            });
+            match (decoded, end) {
+                (Some(ch), Some(end)) => {
+                    text.push(ch);
+                    rest = &rest[end + 1..];
+                }
+                _ => {
+                    text.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+    }
+    text.push_str(rest);
+    text.trim().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_markup_tags_and_references() {
+        assert_eq!(
+            strip_markup("<body><p>Total:</p><p>5&#160;&amp;&#x41;&lt;</p></body>"),
+            "Total: 5\u{a0}&A<"
+        );
+        assert_eq!(
+            strip_markup("a<br/>b &unknown; c & d"),
+            "a b &unknown; c & d"
+        );
+    }
+
+    #[test]
+    fn strip_markup_unterminated_tag() {
+        assert_eq!(strip_markup("x<é"), "x");
+        assert_eq!(strip_markup("x</p"), "x");
+        assert_eq!(strip_markup("é<"), "é");
+        assert_eq!(strip_markup("x&é"), "x&é");
+    }
+}
@@ -0,0 +1,274 @@
+//! Predefined CJK CMaps (PDF 32000-1 section 9.7.5.2).
+//!
+//! The Unicode CMaps (`UniJIS-UCS2-H`, `UniGB-UTF16-H`, ...) carry Unicode in
+//! the character codes and need no data. The legacy encodings (Shift-JIS, EUC,
+//! GBK, Big5, UHC) map codes through two-byte tables generated from the
+//! matching Microsoft code pages by `data/gen_cjk_tables.py`. Each table sits
+//! behind a feature so the guest only pays for the scripts it needs:
+//! `cjk-japanese`, `cjk-chinese-simplified`, `cjk-chinese-traditional` and
+//! `cjk-korean`.
+//!
+//! Codes of these CMaps are mapped straight to Unicode, without going
+//! through CIDs. Fonts with an `Identity` encoding show CIDs directly, which
+//! [`CidOrdering`] maps to Unicode for the Adobe character collections named
+//! by the CIDFont's `/CIDSystemInfo`. Only CIDs 1 to 95, proportional ASCII
+//! in all four collections, are known; the rest need a `ToUnicode` map.
+
+use crate::cmap::{CMap, CodespaceRange};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// How a predefined CMap turns character codes into Unicode
+#[derive(Debug, Clone, Copy)]
+pub enum CodeMapping {
+    /// Codes are UCS-2 code units
+    Ucs2,
+    /// Codes are UTF-16BE, with surrogate pairs as four-byte codes
+    Utf16,
+    /// JIS X 0208 row and cell (the `H` and `V` CMaps)
+    #[cfg(feature = "cjk-japanese")]
+    Jis,
+    /// EUC-JP: JIS X 0208 with the high bit set, half-width katakana after 0x8E
+    #[cfg(feature = "cjk-japanese")]
+    EucJp,
+    /// Single bytes as ASCII, two-byte codes through a table
+    Table(&'static CodeTable),
+}
+
+/// Unicode values of two-byte codes `lead << 8 | trail`, row-major and
+/// little-endian, 0 where the code is unmapped
+#[derive(Debug)]
+pub struct CodeTable {
+    lead: (u8, u8),
+    trail: (u8, u8),
+    data: &'static [u8],
+    /// Half-width katakana at 0xA1..=0xDF (Shift-JIS)
+    katakana: bool,
+}
+
+impl CodeTable {
+    fn get(&self, code: u32) -> Option<char> {
+        if code <= 0x7F {
+            return char::from_u32(code);
+        }
+        if code <= 0xFF {
+            return match code {
+                0xA1..=0xDF if self.katakana => katakana(code as u8),
+                _ => None,
+            };
+        }
+        let (lead, trail) = ((code >> 8) as u8, code as u8);
+        if code > 0xFFFF
+            || !(self.lead.0..=self.lead.1).contains(&lead)
+            || !(self.trail.0..=self.trail.1).contains(&trail)
+        {
+            return None;
+        }
+        let columns = (self.trail.1 - self.trail.0) as usize + 1;
+        let index = (lead - self.lead.0) as usize * columns + (trail - self.trail.0) as usize;
+        let unit = u16::from_le_bytes([*self.data.get(2 * index)?, *self.data.get(2 * index + 1)?]);
+        if unit == 0 {
+            None
+        } else {
+            char::from_u32(unit as u32)
+        }
+    }
+}
+
+#[cfg(feature = "cjk-japanese")]
+static SHIFT_JIS: CodeTable = CodeTable {
+    lead: (0x81, 0xFC),
+    trail: (0x40, 0xFC),
+    data: include_bytes!("../data/cp932.bin"),
+    katakana: true,
+};
+
+#[cfg(feature = "cjk-chinese-simplified")]
+static GBK: CodeTable = CodeTable {
+    lead: (0x81, 0xFE),
+    trail: (0x40, 0xFE),
+    data: include_bytes!("../data/gbk.bin"),
+    katakana: false,
+};
+
+#[cfg(feature = "cjk-chinese-traditional")]
+static BIG5: CodeTable = CodeTable {
+    lead: (0x81, 0xFE),
+    trail: (0x40, 0xFE),
+    data: include_bytes!("../data/cp950.bin"),
+    katakana: false,
+};
+
+#[cfg(feature = "cjk-korean")]
+static UHC: CodeTable = CodeTable {
+    lead: (0x81, 0xFE),
+    trail: (0x41, 0xFE),
+    data: include_bytes!("../data/cp949.bin"),
+    katakana: false,
+};
+
+impl CodeMapping {
+    pub fn get(&self, code: u32) -> Option<char> {
+        match self {
+            CodeMapping::Ucs2 => char::from_u32(code),
+            CodeMapping::Utf16 if code > 0xFFFF => {
+                let (high, low) = (code >> 16, code & 0xFFFF);
+                char::decode_utf16([high as u16, low as u16]).next()?.ok()
+            }
+            CodeMapping::Utf16 => char::from_u32(code),
+            #[cfg(feature = "cjk-japanese")]
+            CodeMapping::Jis => jis_to_shift_jis(code).and_then(|code| SHIFT_JIS.get(code)),
+            #[cfg(feature = "cjk-japanese")]
+            CodeMapping::EucJp => match code {
+                0..=0x7F => char::from_u32(code),
+                0x8EA1..=0x8EDF => katakana(code as u8),
+                0xA1A1..=0xFEFE => {
+                    jis_to_shift_jis(code & 0x7F7F).and_then(|code| SHIFT_JIS.get(code))
+                }
+                _ => None,
+            },
+            CodeMapping::Table(table) => table.get(code),
+        }
+    }
+}
+
+fn katakana(byte: u8) -> Option<char> {
+    char::from_u32(0xFF61 + (byte - 0xA1) as u32)
+}
+
+/// Adobe character collection of a CIDFont, from the `/Registry` and
+/// `/Ordering` of its `/CIDSystemInfo`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CidOrdering {
+    Japan1,
+    Gb1,
+    Cns1,
+    Korea1,
+}
+
+impl CidOrdering {
+    pub fn from_system_info(registry: &str, ordering: &str) -> Option<Self> {
+        if registry != "Adobe" {
+            return None;
+        }
+        match ordering {
+            "Japan1" => Some(CidOrdering::Japan1),
+            "GB1" => Some(CidOrdering::Gb1),
+            "CNS1" => Some(CidOrdering::Cns1),
+            "Korea1" => Some(CidOrdering::Korea1),
+            _ => None,
+        }
+    }
+
+    /// Unicode for `cid`, `None` outside the ASCII CIDs
+    pub fn get(&self, cid: u32) -> Option<char> {
+        if (1..=95).contains(&cid) {
+            char::from_u32(cid + 0x1F)
+        } else {
+            None
+        }
+    }
+}
+
+/// Convert a JIS X 0208 row/cell code to the Shift-JIS code of the same character
+#[cfg(feature = "cjk-japanese")]
+fn jis_to_shift_jis(code: u32) -> Option<u32> {
+    let (row, cell) = (code >> 8, code & 0xFF);
+    if !(0x21..=0x7E).contains(&row) || !(0x21..=0x7E).contains(&cell) {
+        return None;
+    }
+    let lead = ((row + 1) >> 1) + if row <= 0x5E { 0x70 } else { 0xB0 };
+    let trail = if row % 2 == 1 {
+        cell + if cell >= 0x60 { 0x20 } else { 0x1F }
+    } else {
+        cell + 0x7E
+    };
+    Some(lead << 8 | trail)
+}
+
+/// Code space `low..=high` for each `(low, high)` pair
+fn codespace(ranges: &[(&[u8], &[u8])]) -> Vec<CodespaceRange> {
+    ranges
+        .iter()
+        .map(|&(low, high)| CodespaceRange {
+            low: low.into(),
+            high: high.into(),
+        })
+        .collect()
+}
+
+const UCS2: &[(&[u8], &[u8])] = &[(&[0x00, 0x00], &[0xFF, 0xFF])];
+const UTF16: &[(&[u8], &[u8])] = &[
+    (&[0x00, 0x00], &[0xD7, 0xFF]),
+    (&[0xE0, 0x00], &[0xFF, 0xFF]),
+    (&[0xD8, 0x00, 0xDC, 0x00], &[0xDB, 0xFF, 0xDF, 0xFF]),
+];
+#[cfg(feature = "cjk-japanese")]
+const RKSJ: &[(&[u8], &[u8])] = &[
+    (&[0x00], &[0x80]),
+    (&[0x81, 0x40], &[0x9F, 0xFC]),
+    (&[0xA0], &[0xDF]),
+    (&[0xE0, 0x40], &[0xFC, 0xFC]),
+];
+#[cfg(feature = "cjk-japanese")]
+const JIS: &[(&[u8], &[u8])] = &[(&[0x21, 0x21], &[0x7E, 0x7E])];
+#[cfg(feature = "cjk-japanese")]
+const EUC_JP: &[(&[u8], &[u8])] = &[
+    (&[0x00], &[0x80]),
+    (&[0x8E, 0xA0], &[0x8E, 0xDF]),
+    (&[0xA1, 0xA1], &[0xFE, 0xFE]),
+];
+#[cfg(any(feature = "cjk-chinese-simplified", feature = "cjk-korean"))]
+const EUC: &[(&[u8], &[u8])] = &[(&[0x00], &[0x80]), (&[0xA1, 0xA1], &[0xFE, 0xFE])];
+#[cfg(feature = "cjk-chinese-simplified")]
+const GBK_EUC: &[(&[u8], &[u8])] = &[(&[0x00], &[0x80]), (&[0x81, 0x40], &[0xFE, 0xFE])];
+#[cfg(feature = "cjk-chinese-traditional")]
+const B5: &[(&[u8], &[u8])] = &[(&[0x00], &[0x80]), (&[0xA1, 0x40], &[0xFE, 0xFE])];
+#[cfg(feature = "cjk-korean")]
+const UHC_CODES: &[(&[u8], &[u8])] = &[(&[0x00], &[0x80]), (&[0x81, 0x41], &[0xFE, 0xFE])];
+
+/// The predefined CMap called `name`, if its encoding is supported
+pub fn predefined(name: &str) -> Option<CMap> {
+    let (ranges, mapping) = match name {
+        "UniJIS-UCS2-H" | "UniJIS-UCS2-V" | "UniJIS-UCS2-HW-H" | "UniJIS-UCS2-HW-V"
+        | "UniGB-UCS2-H" | "UniGB-UCS2-V" | "UniCNS-UCS2-H" | "UniCNS-UCS2-V" | "UniKS-UCS2-H"
+        | "UniKS-UCS2-V" => (UCS2, CodeMapping::Ucs2),
+        "UniJIS-UTF16-H" | "UniJIS-UTF16-V" | "UniGB-UTF16-H" | "UniGB-UTF16-V"
+        | "UniCNS-UTF16-H" | "UniCNS-UTF16-V" | "UniKS-UTF16-H" | "UniKS-UTF16-V" => {
+            (UTF16, CodeMapping::Utf16)
+        }
+        #[cfg(feature = "cjk-japanese")]
+        "83pv-RKSJ-H" | "90ms-RKSJ-H" | "90ms-RKSJ-V" | "90msp-RKSJ-H" | "90msp-RKSJ-V"
+        | "90pv-RKSJ-H" | "Add-RKSJ-H" | "Add-RKSJ-V" | "Ext-RKSJ-H" | "Ext-RKSJ-V" => {
+            (RKSJ, CodeMapping::Table(&SHIFT_JIS))
+        }
+        #[cfg(feature = "cjk-japanese")]
+        "H" | "V" => (JIS, CodeMapping::Jis),
+        #[cfg(feature = "cjk-japanese")]
+        "EUC-H" | "EUC-V" => (EUC_JP, CodeMapping::EucJp),
+        #[cfg(feature = "cjk-chinese-simplified")]
+        "GB-EUC-H" | "GB-EUC-V" | "GBpc-EUC-H" | "GBpc-EUC-V" => (EUC, CodeMapping::Table(&GBK)),
+        #[cfg(feature = "cjk-chinese-simplified")]
+        "GBK-EUC-H" | "GBK-EUC-V" | "GBKp-EUC-H" | "GBKp-EUC-V" => {
+            (GBK_EUC, CodeMapping::Table(&GBK))
+        }
+        #[cfg(feature = "cjk-chinese-traditional")]
+        "B5pc-H" | "B5pc-V" | "ETen-B5-H" | "ETen-B5-V" | "ETenms-B5-H" | "ETenms-B5-V" => {
+            (B5, CodeMapping::Table(&BIG5))
+        }
+        #[cfg(feature = "cjk-korean")]
+        "KSC-EUC-H" | "KSC-EUC-V" | "KSCpc-EUC-H" => (EUC, CodeMapping::Table(&UHC)),
+        #[cfg(feature = "cjk-korean")]
+        "KSCms-UHC-H" | "KSCms-UHC-V" | "KSCms-UHC-HW-H" | "KSCms-UHC-HW-V" => {
+            (UHC_CODES, CodeMapping::Table(&UHC))
+        }
+        _ => return None,
+    };
+
+    Some(CMap {
+        codespace: codespace(ranges),
+        unicode: BTreeMap::new(),
+        ranges: Vec::new(),
+        mapping: Some(mapping),
+    })
+}
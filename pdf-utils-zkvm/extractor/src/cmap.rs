@@ -0,0 +1,303 @@
+//! CMap parsing (PDF 32000-1 sections 9.7.5 and 9.10.3).
+//!
+//! A CMap is a PostScript program. It is tokenised like a content stream and
+//! only the operators that define code space ranges and Unicode mappings are
+//! interpreted, so entries may be laid out on lines in any way. `usecmap` is
+//! resolved against the CMaps known by name through [`CMap::predefined`].
+
+use crate::cjk::CodeMapping;
+use crate::token::{Token, TokenParser};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Longest character code a CMap may define, in bytes
+const MAX_CODE_BYTES: usize = 4;
+
+/// Codes of `low.len()` bytes whose every byte lies between the bytes of
+/// `low` and `high` at the same position
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodespaceRange {
+    pub low: Vec<u8>,
+    pub high: Vec<u8>,
+}
+
+impl CodespaceRange {
+    fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() == self.low.len()
+            && bytes
+                .iter()
+                .zip(self.low.iter().zip(&self.high))
+                .all(|(byte, (low, high))| low <= byte && byte <= high)
+    }
+}
+
+/// Codes `low..=high` of a `bfrange` mapped to consecutive Unicode values:
+/// `low` to `start`, the next code to `start` with its last UTF-16 unit
+/// incremented, and so on. Kept as a range so that a range over the whole
+/// code space costs no more than any other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BfRange {
+    pub low: u32,
+    pub high: u32,
+    /// UTF-16BE destination of `low`
+    pub start: Vec<u8>,
+}
+
+impl BfRange {
+    fn get(&self, code: u32) -> Option<String> {
+        if code < self.low || code > self.high {
+            return None;
+        }
+        let Some(mut units) = utf16_units(&self.start) else {
+            return Some(decode_utf16be(&self.start));
+        };
+        if let Some(last) = units.last_mut() {
+            *last = last.wrapping_add((code - self.low) as u16);
+        }
+        Some(
+            char::decode_utf16(units)
+                .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CMap {
+    pub codespace: Vec<CodespaceRange>,
+    /// Character code to Unicode text, from `bfchar` and the array form of
+    /// `bfrange`
+    pub unicode: BTreeMap<u32, String>,
+    /// `bfrange` entries with a single destination string, later entries
+    /// taking precedence
+    pub ranges: Vec<BfRange>,
+    /// Built-in code to Unicode conversion of a predefined CMap
+    pub mapping: Option<CodeMapping>,
+}
+
+/// Operand of a CMap operator
+enum Operand {
+    Bytes(Vec<u8>),
+    Name(String),
+    Array(Vec<Vec<u8>>),
+}
+
+impl CMap {
+    /// Parse a CMap stream. Malformed entries are skipped.
+    pub fn parse(data: &[u8]) -> Self {
+        let mut cmap = CMap::default();
+        let mut operands: Vec<Operand> = Vec::new();
+        let mut array: Option<Vec<Vec<u8>>> = None;
+
+        for token in TokenParser::new(data).parse_all() {
+            match token {
+                Token::String(bytes) => match array.as_mut() {
+                    Some(items) => items.push(bytes),
+                    None => operands.push(Operand::Bytes(bytes)),
+                },
+                Token::Name(name) => operands.push(Operand::Name(name)),
+                Token::ArrayStart => array = Some(Vec::new()),
+                Token::ArrayEnd => {
+                    if let Some(items) = array.take() {
+                        operands.push(Operand::Array(items));
+                    }
+                }
+                Token::Operator(op) => {
+                    match op.as_str() {
+                        "endcodespacerange" => cmap.add_codespace(&operands),
+                        "endbfchar" => cmap.add_bfchar(&operands),
+                        "endbfrange" => cmap.add_bfrange(&operands),
+                        "usecmap" => {
+                            if let Some(Operand::Name(name)) = operands.last() {
+                                if let Some(base) = CMap::predefined(name) {
+                                    cmap.use_cmap(base);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                    operands.clear();
+                    array = None;
+                }
+                Token::Integer(_) | Token::Real(_) | Token::DictStart | Token::DictEnd => {}
+            }
+        }
+
+        cmap
+    }
+
+    /// CMaps referenced by name rather than embedded in the document
+    pub fn predefined(name: &str) -> Option<Self> {
+        match name {
+            "Identity-H" | "Identity-V" => Some(CMap {
+                codespace: alloc::vec![CodespaceRange {
+                    low: alloc::vec![0x00, 0x00],
+                    high: alloc::vec![0xFF, 0xFF],
+                }],
+                unicode: BTreeMap::new(),
+                ranges: Vec::new(),
+                mapping: None,
+            }),
+            _ => crate::cjk::predefined(name),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unicode.is_empty() && self.ranges.is_empty()
+    }
+
+    pub fn get(&self, code: u32) -> Option<Cow<'_, str>> {
+        if let Some(text) = self.unicode.get(&code) {
+            return Some(Cow::Borrowed(text));
+        }
+        self.ranges
+            .iter()
+            .rev()
+            .find_map(|range| range.get(code))
+            .map(Cow::Owned)
+    }
+
+    /// Unicode for `code` through the built-in mapping of a predefined CMap
+    pub fn decode(&self, code: u32) -> Option<char> {
+        self.mapping?.get(code)
+    }
+
+    /// Split the character code at the start of `bytes`, returning the code
+    /// and its length in bytes. Bytes outside every code space range are
+    /// consumed as a code of the shortest defined length. `None` when the
+    /// CMap has no code space or `bytes` is empty.
+    pub fn next_code(&self, bytes: &[u8]) -> Option<(u32, usize)> {
+        let shortest = self.codespace.iter().map(|range| range.low.len()).min()?;
+        if bytes.is_empty() {
+            return None;
+        }
+        let len = (1..=bytes.len().min(MAX_CODE_BYTES))
+            .find(|&len| {
+                self.codespace
+                    .iter()
+                    .any(|range| range.matches(&bytes[..len]))
+            })
+            .unwrap_or(shortest.min(bytes.len()));
+        Some((code_value(&bytes[..len])?, len))
+    }
+
+    /// Inherit the code space and mappings of `base`, keeping our own entries
+    fn use_cmap(&mut self, base: CMap) {
+        let mut codespace = base.codespace;
+        codespace.append(&mut self.codespace);
+        self.codespace = codespace;
+        self.mapping = self.mapping.or(base.mapping);
+        for (code, text) in base.unicode {
+            self.unicode.entry(code).or_insert(text);
+        }
+        let mut ranges = base.ranges;
+        ranges.append(&mut self.ranges);
+        self.ranges = ranges;
+    }
+
+    fn add_codespace(&mut self, operands: &[Operand]) {
+        for pair in operands.chunks_exact(2) {
+            if let [Operand::Bytes(low), Operand::Bytes(high)] = pair {
+                if !low.is_empty() && low.len() == high.len() && low.len() <= MAX_CODE_BYTES {
+                    self.codespace.push(CodespaceRange {
+                        low: low.clone(),
+                        high: high.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn add_bfchar(&mut self, operands: &[Operand]) {
+        for pair in operands.chunks_exact(2) {
+            let Operand::Bytes(src) = &pair[0] else {
+                continue;
+            };
+            let Some(code) = code_value(src) else {
+                continue;
+            };
+            match &pair[1] {
+                Operand::Bytes(dst) => {
+                    self.unicode.insert(code, decode_utf16be(dst));
+                }
+                Operand::Name(glyph) => {
+                    self.unicode
+                        .insert(code, crate::font::glyph_to_unicode(glyph));
+                }
+                Operand::Array(_) => {}
+            }
+        }
+    }
+
+    fn add_bfrange(&mut self, operands: &[Operand]) {
+        for triple in operands.chunks_exact(3) {
+            let [Operand::Bytes(low), Operand::Bytes(high), dst] = triple else {
+                continue;
+            };
+            let (Some(low), Some(high)) = (code_value(low), code_value(high)) else {
+                continue;
+            };
+            if high < low {
+                continue;
+            }
+
+            match dst {
+                Operand::Bytes(start) => {
+                    // Earlier single codes in the range are overridden
+                    let overridden: Vec<u32> = self
+                        .unicode
+                        .range(low..=high)
+                        .map(|(&code, _)| code)
+                        .collect();
+                    for code in overridden {
+                        self.unicode.remove(&code);
+                    }
+                    self.ranges.push(BfRange {
+                        low,
+                        high,
+                        start: start.clone(),
+                    });
+                }
+                Operand::Array(items) => {
+                    for (code, item) in (low..=high).zip(items) {
+                        self.unicode.insert(code, decode_utf16be(item));
+                    }
+                }
+                Operand::Name(_) => {}
+            }
+        }
+    }
+}
+
+/// Big-endian value of a character code of at most four bytes
+fn code_value(bytes: &[u8]) -> Option<u32> {
+    if bytes.is_empty() || bytes.len() > MAX_CODE_BYTES {
+        return None;
+    }
+    Some(bytes.iter().fold(0, |code, &b| (code << 8) | b as u32))
+}
+
+fn utf16_units(bytes: &[u8]) -> Option<Vec<u16>> {
+    if !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+    Some(
+        bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect(),
+    )
+}
+
+/// Destination strings are UTF-16BE. Some producers write single bytes, which
+/// are taken as Latin-1.
+fn decode_utf16be(bytes: &[u8]) -> String {
+    match utf16_units(bytes) {
+        Some(units) => char::decode_utf16(units)
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect(),
+        None => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
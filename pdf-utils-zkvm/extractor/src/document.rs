@@ -0,0 +1,992 @@
+//! Lazily resolved document objects.
+//!
+//! A [`Document`] keeps the bytes of the file and an index of where each
+//! object lives, read from the cross-reference tables and streams (PDF
+//! 32000-1 sections 7.5.4 and 7.5.8) from `startxref` back through `/Prev`.
+//! An object is parsed the first time it is resolved and kept for the life of
+//! the document, so images, fonts and pages nobody asks for are never read.
+//! Stream data is not copied into the cache: it stays in the file and is
+//! decrypted and decoded each time it is needed, so the cache holds parsed
+//! dictionaries, bounded by the size of the file, plus decoded object streams,
+//! kept up to a byte budget. A page's content streams and fonts are read when
+//! the page is extracted, see [`PageContent::content_streams`]. Files whose
+//! cross-reference data is missing or points at the wrong places are indexed
+//! by scanning for object headers instead.
+//! Strings of an encrypted document are decrypted as objects are parsed and
+//! stream data as it is decoded, see
+//! [`crate::security`]. Decoding and nesting are bounded by the document's
+//! [`ParseLimits`].
+
+use crate::font::{parse_font, PdfFont};
+use crate::limits::ParseLimits;
+use crate::page::PageContent;
+use crate::parser::{parse_page_tree, Parser, PdfObj, PdfStream};
+use crate::security::SecurityHandler;
+use crate::stream::handle_stream_filters;
+use crate::PdfError;
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::{Cell, OnceCell, RefCell};
+use core::str;
+
+/// Bytes of decoded streams kept by [`Document::parse`]
+pub const DEFAULT_CACHE_BUDGET: usize = 4 << 20;
+
+/// Object number and generation of an indirect object
+pub type ObjRef = (u32, u16);
+
+/// Stands in for the catalog, which parsing has checked is a dictionary
+static EMPTY_DICT: BTreeMap<String, PdfObj> = BTreeMap::new();
+
+/// Where an object is stored in the file
+#[derive(Debug, Clone, Copy)]
+enum Location {
+    /// Byte offset of its `n g obj` header
+    Offset(usize),
+    /// Index within the object stream numbered `stream`
+    Compressed { stream: u32, index: usize },
+}
+
+#[derive(Debug)]
+struct Entry {
+    location: Location,
+    /// The parsed object once resolved, `None` when it could not be parsed
+    value: OnceCell<Option<PdfObj>>,
+}
+
+/// A decompressed object stream (PDF 32000-1 section 7.5.7)
+#[derive(Debug)]
+struct ObjectStream {
+    data: Vec<u8>,
+    /// Offset of the first object in `data`
+    first: usize,
+    /// Object number and offset relative to `first` of each object
+    headers: Vec<(u32, usize)>,
+}
+
+impl ObjectStream {
+    /// The object stream `stream`, `data` its decoded contents
+    fn decode(stream: &PdfStream, data: Vec<u8>) -> Option<Self> {
+        let first = stream.dict.get("First")?.as_unsigned::<usize>()?;
+        let count = stream.dict.get("N")?.as_unsigned::<usize>()?;
+
+        let mut parser = Parser::new(&data);
+        let mut headers = Vec::new();
+        for _ in 0..count {
+            parser.skip_whitespace_and_comments();
+            let Some(object) = parse_unsigned(&mut parser) else {
+                break;
+            };
+            parser.skip_whitespace_and_comments();
+            let Some(offset) = parse_unsigned(&mut parser) else {
+                break;
+            };
+            headers.push((u32::try_from(object).ok()?, offset));
+        }
+
+        Some(Self {
+            first,
+            data,
+            headers,
+        })
+    }
+
+    /// Data of object `number`, expected at `index`, through the end of the
+    /// stream
+    fn object_data(&self, number: u32, index: usize) -> Option<&[u8]> {
+        let index = match self.headers.get(index) {
+            Some((found, _)) if *found == number => index,
+            _ => self
+                .headers
+                .iter()
+                .position(|(found, _)| *found == number)?,
+        };
+        let start = self.first + self.headers[index].1;
+        if start >= self.data.len() {
+            return None;
+        }
+        Some(&self.data[start..])
+    }
+}
+
+/// A decoded stream kept in the cache
+#[derive(Debug, Clone)]
+enum Decoded {
+    Object(Rc<ObjectStream>),
+    Data(Rc<[u8]>),
+}
+
+impl Decoded {
+    fn len(&self) -> usize {
+        match self {
+            Decoded::Object(stream) => stream.data.len(),
+            Decoded::Data(data) => data.len(),
+        }
+    }
+}
+
+/// A PDF file: its pages, read when it is parsed, and its other objects,
+/// parsed when first resolved
+pub struct Document<'a> {
+    data: &'a [u8],
+    /// Version from the `%PDF-` header
+    header_version: Option<String>,
+    trailer: BTreeMap<String, PdfObj>,
+    catalog: ObjRef,
+    pages: Vec<PageContent>,
+    entries: BTreeMap<(u32, u16), Entry>,
+    /// Objects being parsed, so a stream whose `/Length` refers back to it
+    /// cannot loop
+    resolving: RefCell<BTreeSet<(u32, u16)>>,
+    /// Decoded streams by object, least recently used first, up to
+    /// `cache_budget` bytes
+    decoded_streams: RefCell<Vec<(ObjRef, Decoded)>>,
+    cache_budget: usize,
+    /// Fonts parsed so far by font dictionary. A font is parsed from streams
+    /// counted against the decoded size limit, which so bounds them.
+    fonts: RefCell<BTreeMap<ObjRef, Option<Rc<PdfFont>>>>,
+    /// Decrypts the objects of an encrypted document
+    security: Option<SecurityHandler>,
+    limits: ParseLimits,
+    /// Bytes decoded from streams so far, each stream counted once
+    decoded: Cell<usize>,
+    /// Streams counted in `decoded`
+    counted: RefCell<BTreeSet<ObjRef>>,
+    /// Form XObjects being interpreted, innermost last
+    forms: RefCell<Vec<ObjRef>>,
+    /// Form XObjects interpreted so far
+    form_invocations: Cell<usize>,
+    /// The first limit exceeded, or page content that failed to decode.
+    /// Once set, no more streams are decoded.
+    error: RefCell<Option<PdfError>>,
+}
+
+impl<'a> Document<'a> {
+    /// Parse `data`: index its objects and read its catalog and page tree,
+    /// keeping up to [`DEFAULT_CACHE_BUDGET`] bytes of decoded object streams.
+    /// An encrypted document is opened with the empty user password.
+    pub fn parse(data: &'a [u8]) -> Result<Self, PdfError> {
+        Self::open(data, b"", DEFAULT_CACHE_BUDGET, ParseLimits::default())
+    }
+
+    /// Like [`Document::parse`], opening an encrypted document with
+    /// `password`, either its user or its owner password. The password is
+    /// ignored when the document is not encrypted.
+    pub fn with_password(data: &'a [u8], password: &[u8]) -> Result<Self, PdfError> {
+        Self::open(data, password, DEFAULT_CACHE_BUDGET, ParseLimits::default())
+    }
+
+    /// Like [`Document::parse`], keeping up to `cache_budget` bytes of
+    /// decoded streams
+    pub fn with_cache_budget(data: &'a [u8], cache_budget: usize) -> Result<Self, PdfError> {
+        Self::open(data, b"", cache_budget, ParseLimits::default())
+    }
+
+    /// Like [`Document::with_password`], within `limits` rather than the
+    /// default ones
+    pub fn with_limits(
+        data: &'a [u8],
+        password: &[u8],
+        limits: ParseLimits,
+    ) -> Result<Self, PdfError> {
+        Self::open(data, password, DEFAULT_CACHE_BUDGET, limits)
+    }
+
+    fn open(
+        data: &'a [u8],
+        password: &[u8],
+        cache_budget: usize,
+        limits: ParseLimits,
+    ) -> Result<Self, PdfError> {
+        let mut document = Self::index(data, password, cache_budget, limits)?;
+
+        document.catalog = match document.trailer.get("Root") {
+            Some(PdfObj::Reference(r)) => *r,
+            _ => {
+                return Err(PdfError::ParseError(alloc::format!(
+                    "No Root in trailer. Trailer: {:?}",
+                    document.trailer
+                )))
+            }
+        };
+        // An object nested too deep fails to resolve; report the limit
+        // rather than the missing object
+        let pages = parse_page_tree(&document, &document.catalog);
+        document.check_limits()?;
+        document.pages = pages?;
+        if document.limits.strict_page_count {
+            let declared = document.declared_page_count();
+            if declared != Some(document.pages.len()) {
+                return Err(PdfError::ParseError(alloc::format!(
+                    "Page tree /Count is {declared:?} but it holds {} pages",
+                    document.pages.len()
+                )));
+            }
+        }
+        Ok(document)
+    }
+
+    /// Index the objects of `data`, find its trailer and open its encryption
+    /// with `password`
+    fn index(
+        data: &'a [u8],
+        password: &[u8],
+        cache_budget: usize,
+        limits: ParseLimits,
+    ) -> Result<Self, PdfError> {
+        let (mut index, trailer) = match read_xref(data, &limits) {
+            Some((index, trailer)) => (index, Some(trailer)),
+            None => (BTreeMap::new(), None),
+        };
+        let has_trailer = trailer.is_some();
+
+        // Trust the cross-reference data only if every offset holds the
+        // object it names
+        let valid = !index.is_empty()
+            && index.iter().all(|(id, location)| match location {
+                Location::Offset(offset) => object_header(data, *offset) == Some(*id),
+                Location::Compressed { .. } => true,
+            });
+        if !valid {
+            let mut scanned = scan_objects(data);
+            for (id, location) in index {
+                if let Location::Compressed { .. } = location {
+                    scanned.entry(id).or_insert(location);
+                }
+            }
+            index = scanned;
+        }
+
+        let mut document = Self {
+            data,
+            header_version: header_version(data),
+            trailer: trailer.unwrap_or_default(),
+            catalog: (0, 0),
+            pages: Vec::new(),
+            entries: index
+                .into_iter()
+                .map(|(id, location)| {
+                    let value = OnceCell::new();
+                    (id, Entry { location, value })
+                })
+                .collect(),
+            resolving: RefCell::new(BTreeSet::new()),
+            decoded_streams: RefCell::new(Vec::new()),
+            cache_budget,
+            fonts: RefCell::new(BTreeMap::new()),
+            security: None,
+            limits,
+            decoded: Cell::new(0),
+            counted: RefCell::new(BTreeSet::new()),
+            forms: RefCell::new(Vec::new()),
+            form_invocations: Cell::new(0),
+            error: RefCell::new(None),
+        };
+        document.check_object_count()?;
+        if !has_trailer {
+            document.trailer = document.find_trailer().ok_or_else(|| {
+                PdfError::ParseError(alloc::format!(
+                    "No trailer or cross-reference stream found. Indexed {} objects",
+                    document.entries.len()
+                ))
+            })?;
+        }
+        // Object streams are encrypted, so they are read once the security
+        // handler is known
+        document.security = document.security_handler(password)?;
+        if !valid {
+            document.index_object_streams();
+            document.check_object_count()?;
+        }
+        Ok(document)
+    }
+
+    /// Security handler for the trailer's `/Encrypt`, `None` when the
+    /// document is not encrypted
+    fn security_handler(&self, password: &[u8]) -> Result<Option<SecurityHandler>, PdfError> {
+        let (encrypt, reference) = match self.trailer.get("Encrypt") {
+            None | Some(PdfObj::Null) => return Ok(None),
+            Some(PdfObj::Reference(r)) => (self.get(*r), Some(*r)),
+            Some(encrypt) => (Some(encrypt), None),
+        };
+        let encrypt = encrypt.and_then(PdfObj::as_dict).ok_or_else(|| {
+            PdfError::EncryptionError(String::from("Encryption dictionary not found"))
+        })?;
+        let id = match self.trailer.get("ID") {
+            Some(PdfObj::Array(ids)) => ids.first().and_then(PdfObj::as_bytes),
+            _ => None,
+        };
+        SecurityHandler::new(encrypt, id.unwrap_or_default(), password, reference).map(Some)
+    }
+
+    /// Whether the document is encrypted. Its objects are decrypted as they
+    /// are resolved.
+    pub fn is_encrypted(&self) -> bool {
+        self.security.is_some()
+    }
+
+    /// The limits the document is parsed within
+    pub fn limits(&self) -> &ParseLimits {
+        &self.limits
+    }
+
+    /// [`PdfError::LimitExceeded`] when a limit was exceeded since the
+    /// document was parsed, e.g. while decoding a font or form XObject, or
+    /// the error of a page content stream that could not be decoded. What
+    /// was extracted after that may be incomplete.
+    pub fn check_limits(&self) -> Result<(), PdfError> {
+        match &*self.error.borrow() {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Record that a limit was exceeded, keeping the first such error
+    fn exceed(&self, error: PdfError) -> PdfError {
+        if let PdfError::LimitExceeded(_) = &error {
+            self.error.borrow_mut().get_or_insert_with(|| error.clone());
+        }
+        error
+    }
+
+    /// Record an error that fails the extraction, such as page content that
+    /// does not decode, keeping the first one
+    pub(crate) fn fail(&self, error: PdfError) {
+        self.error.borrow_mut().get_or_insert(error);
+    }
+
+    fn check_object_count(&self) -> Result<(), PdfError> {
+        if self.entries.len() > self.limits.max_objects {
+            return Err(PdfError::LimitExceeded(alloc::format!(
+                "Document has {} objects, more than {}",
+                self.entries.len(),
+                self.limits.max_objects
+            )));
+        }
+        Ok(())
+    }
+
+    /// The decoded data of `stream`, from the cache or decoded afresh. A
+    /// stream counts against the total decoded size limit once, however
+    /// often it is decoded.
+    pub fn decode_stream(&self, stream: &PdfStream) -> Result<Rc<[u8]>, PdfError> {
+        if let Some(Decoded::Data(data)) = self.cached(stream.id) {
+            return Ok(data);
+        }
+        let data: Rc<[u8]> = self.decode_data(stream)?.into();
+        self.cache(stream.id, Decoded::Data(data.clone()));
+        Ok(data)
+    }
+
+    /// Decode `stream`, counted against the stream size limits
+    fn decode_data(&self, stream: &PdfStream) -> Result<Vec<u8>, PdfError> {
+        self.check_limits()?;
+        let counted = self.counted.borrow().contains(&stream.id);
+        let decoded = self.decoded.get();
+        let remaining = match counted {
+            true => usize::MAX,
+            false => self.limits.max_total_decoded_size.saturating_sub(decoded),
+        };
+        let max_size = self.limits.max_stream_size.min(remaining);
+        let raw = self.data.get(stream.span.clone()).unwrap_or_default();
+        let raw = match &self.security {
+            Some(security) => Cow::Owned(security.decrypt_stream(stream.id, &stream.dict, raw)),
+            None => Cow::Borrowed(raw),
+        };
+        match handle_stream_filters(&stream.dict, &raw, max_size) {
+            Ok(data) => {
+                if !counted {
+                    self.decoded.set(decoded + data.len());
+                    self.counted.borrow_mut().insert(stream.id);
+                }
+                Ok(data)
+            }
+            Err(PdfError::LimitExceeded(_)) if max_size < self.limits.max_stream_size => Err(self
+                .exceed(PdfError::LimitExceeded(alloc::format!(
+                    "Streams decode to more than {} bytes in total",
+                    self.limits.max_total_decoded_size
+                )))),
+            Err(error) => Err(self.exceed(error)),
+        }
+    }
+
+    /// The decoded stream `id` when it is cached, marked most recently used
+    fn cached(&self, id: ObjRef) -> Option<Decoded> {
+        let mut cache = self.decoded_streams.borrow_mut();
+        let index = cache.iter().position(|(cached, _)| *cached == id)?;
+        let entry = cache.remove(index);
+        let decoded = entry.1.clone();
+        cache.push(entry);
+        Some(decoded)
+    }
+
+    /// Keep the decoded stream `id`, dropping the least recently used ones
+    /// to stay within the cache budget
+    fn cache(&self, id: ObjRef, decoded: Decoded) {
+        if decoded.len() > self.cache_budget {
+            return;
+        }
+        let mut cache = self.decoded_streams.borrow_mut();
+        let mut used: usize = cache.iter().map(|(_, cached)| cached.len()).sum();
+        while used + decoded.len() > self.cache_budget {
+            used -= cache.remove(0).1.len();
+        }
+        cache.push((id, decoded));
+    }
+
+    /// The font of the font dictionary `id`, parsed the first time it is
+    /// asked for
+    pub(crate) fn font(&self, id: ObjRef) -> Option<Rc<PdfFont>> {
+        if let Some(font) = self.fonts.borrow().get(&id) {
+            return font.clone();
+        }
+        let font = match self.get(id) {
+            Some(PdfObj::Dictionary(dict)) => parse_font(dict, self).map(Rc::new),
+            _ => None,
+        };
+        self.fonts.borrow_mut().insert(id, font.clone());
+        font
+    }
+
+    /// Start interpreting the form XObject `id`. `false` when it is already
+    /// being interpreted, so draws itself, or is nested too deep; otherwise
+    /// [`Document::leave_form`] must follow.
+    pub(crate) fn enter_form(&self, id: ObjRef) -> bool {
+        let mut forms = self.forms.borrow_mut();
+        if forms.contains(&id) {
+            return false;
+        }
+        if forms.len() >= self.limits.max_xobject_depth {
+            self.exceed(PdfError::LimitExceeded(alloc::format!(
+                "Form XObjects nested deeper than {}",
+                self.limits.max_xobject_depth
+            )));
+            return false;
+        }
+        let invocations = self.form_invocations.get() + 1;
+        if invocations > self.limits.max_form_invocations {
+            self.exceed(PdfError::LimitExceeded(alloc::format!(
+                "More than {} form XObjects drawn",
+                self.limits.max_form_invocations
+            )));
+            return false;
+        }
+        self.form_invocations.set(invocations);
+        forms.push(id);
+        true
+    }
+
+    pub(crate) fn leave_form(&self) {
+        self.forms.borrow_mut().pop();
+    }
+
+    /// The PDF version, e.g. `1.7`: the header's, or the catalog's
+    /// `/Version` when an update raised it
+    pub fn version(&self) -> Option<&str> {
+        let header = self.header_version.as_deref();
+        let catalog = self.catalog().get("Version").and_then(PdfObj::as_name);
+        let parse = |version: &str| {
+            let (major, minor) = version.split_once('.')?;
+            Some((major.parse::<u32>().ok()?, minor.parse::<u32>().ok()?))
+        };
+        match (header, catalog) {
+            (Some(header), Some(catalog)) if parse(catalog) > parse(header) => Some(catalog),
+            (None, catalog) => catalog,
+            (header, _) => header,
+        }
+    }
+
+    /// The trailer dictionary of the newest revision
+    pub fn trailer(&self) -> &BTreeMap<String, PdfObj> {
+        &self.trailer
+    }
+
+    /// The document catalog (`/Root`)
+    pub fn catalog(&self) -> &BTreeMap<String, PdfObj> {
+        self.get(self.catalog)
+            .and_then(PdfObj::as_dict)
+            .unwrap_or(&EMPTY_DICT)
+    }
+
+    /// Reference of the document catalog
+    pub fn catalog_ref(&self) -> ObjRef {
+        self.catalog
+    }
+
+    /// The document information dictionary (`/Info`): title, author,
+    /// producer, dates
+    pub fn info(&self) -> Option<&BTreeMap<String, PdfObj>> {
+        self.trailer()
+            .get("Info")
+            .and_then(|info| self.resolve(info))
+            .and_then(PdfObj::as_dict)
+    }
+
+    /// The pages, in page tree order
+    pub fn pages(&self) -> &[PageContent] {
+        &self.pages
+    }
+
+    /// Number of pages the document claims, the `/Count` of its page tree
+    /// root. Writers get it wrong often enough that it is only checked with
+    /// [`ParseLimits::strict_page_count`], and a page listed twice in the tree
+    /// counts twice here but is in [`Document::pages`] once, so it may differ
+    /// from `pages().len()`.
+    pub fn declared_page_count(&self) -> Option<usize> {
+        self.catalog()
+            .get("Pages")
+            .and_then(|pages| self.resolve(pages))
+            .and_then(|pages| pages.as_dict()?.get("Count"))
+            .and_then(|count| self.resolve(count)?.as_unsigned())
+    }
+
+    /// Page `index`, counting from 0
+    pub fn page(&self, index: usize) -> Option<&PageContent> {
+        self.pages.get(index)
+    }
+
+    /// Hand the pages over to the caller, for [`crate::parse_pdf`]
+    pub(crate) fn take_pages(&mut self) -> Vec<PageContent> {
+        core::mem::take(&mut self.pages)
+    }
+
+    /// `obj` itself, or the object it refers to
+    pub fn resolve<'b>(&'b self, obj: &'b PdfObj) -> Option<&'b PdfObj> {
+        match obj {
+            PdfObj::Reference(r) => self.get(*r),
+            obj => Some(obj),
+        }
+    }
+
+    /// The object `id`, parsed on first use. `None` when the document has no
+    /// such object or it cannot be parsed.
+    pub fn get(&self, id: ObjRef) -> Option<&PdfObj> {
+        let entry = self.entries.get(&id)?;
+        if let Some(value) = entry.value.get() {
+            return value.as_ref();
+        }
+        if !self.resolving.borrow_mut().insert(id) {
+            return None;
+        }
+        let value = self.load(id, entry.location);
+        self.resolving.borrow_mut().remove(&id);
+        entry.value.get_or_init(|| value).as_ref()
+    }
+
+    fn load(&self, id: ObjRef, location: Location) -> Option<PdfObj> {
+        match location {
+            Location::Offset(offset) => self.parse_at(id, offset),
+            Location::Compressed { stream, index } => {
+                let stream = self.object_stream(stream)?;
+                let mut parser = self.parser(stream.object_data(id.0, index)?);
+                self.parse_object(&mut parser)
+            }
+        }
+    }
+
+    /// Parse the indirect object `id` whose header is at `offset`, decrypted
+    fn parse_at(&self, id: ObjRef, offset: usize) -> Option<PdfObj> {
+        let mut value = self.read_at(id, offset)?;
+        if let Some(security) = &self.security {
+            security.decrypt_object(id, &mut value);
+        }
+        Some(value)
+    }
+
+    fn read_at(&self, id: ObjRef, offset: usize) -> Option<PdfObj> {
+        let mut parser = self.parser(self.data);
+        parser.pos = offset;
+        read_header(&mut parser)?;
+        let dict = match self.parse_object(&mut parser)? {
+            PdfObj::Dictionary(dict) => dict,
+            value => return Some(value),
+        };
+        parser.skip_whitespace_and_comments();
+        if !parser.remaining_starts_with(b"stream") {
+            return Some(PdfObj::Dictionary(dict));
+        }
+
+        let length = match dict.get("Length") {
+            Some(PdfObj::Reference(r)) => self.get(*r).and_then(PdfObj::as_unsigned),
+            length => length.and_then(PdfObj::as_unsigned),
+        };
+        let span = parser.parse_stream_data(length).ok()?;
+        Some(PdfObj::Stream(PdfStream { dict, id, span }))
+    }
+
+    /// A parser over `data` within the nesting limit
+    fn parser<'b>(&self, data: &'b [u8]) -> Parser<'b> {
+        let mut parser = Parser::new(data);
+        parser.max_depth = self.limits.max_nesting_depth;
+        parser
+    }
+
+    /// The object at the parser's position, recording a nesting too deep
+    fn parse_object(&self, parser: &mut Parser) -> Option<PdfObj> {
+        match parser.parse_object() {
+            Ok(value) => Some(value),
+            Err(msg) => {
+                if parser.depth_exceeded {
+                    self.exceed(PdfError::LimitExceeded(msg));
+                }
+                None
+            }
+        }
+    }
+
+    /// The decoded object stream numbered `number`, from the cache or read
+    /// afresh. The stream object itself is not kept.
+    fn object_stream(&self, number: u32) -> Option<Rc<ObjectStream>> {
+        let id = (number, 0);
+        if let Some(Decoded::Object(cached)) = self.cached(id) {
+            return Some(cached);
+        }
+
+        let Location::Offset(offset) = self.entries.get(&id)?.location else {
+            return None;
+        };
+        if !self.resolving.borrow_mut().insert(id) {
+            return None;
+        }
+        let stream = self.parse_at(id, offset);
+        self.resolving.borrow_mut().remove(&id);
+        let Some(PdfObj::Stream(stream)) = stream else {
+            return None;
+        };
+        let data = self.decode_data(&stream).ok()?;
+        let decoded = Rc::new(ObjectStream::decode(&stream, data)?);
+        self.cache(id, Decoded::Object(decoded.clone()));
+        Some(decoded)
+    }
+
+    /// Add the objects held in object streams, when the index came from a
+    /// scan and so only knows the objects written out in the file
+    fn index_object_streams(&mut self) {
+        let streams: Vec<u32> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| match entry.location {
+                Location::Offset(offset) => matches!(
+                    dictionary_at(self.data, offset).as_ref().and_then(|dict| dict.get("Type")),
+                    Some(PdfObj::Name(kind)) if kind == "ObjStm"
+                ),
+                Location::Compressed { .. } => false,
+            })
+            .map(|(id, _)| id.0)
+            .collect();
+
+        let mut found = Vec::new();
+        for stream in streams {
+            let Some(decoded) = self.object_stream(stream) else {
+                continue;
+            };
+            for (index, (number, _)) in decoded.headers.iter().enumerate() {
+                found.push(((*number, 0), Location::Compressed { stream, index }));
+            }
+        }
+        for (id, location) in found {
+            self.entries.entry(id).or_insert(Entry {
+                location,
+                value: OnceCell::new(),
+            });
+        }
+    }
+
+    /// Trailer of a file whose cross-reference data could not be read: the
+    /// last `trailer` dictionary, else the newest cross-reference stream's
+    fn find_trailer(&self) -> Option<BTreeMap<String, PdfObj>> {
+        if let Some(position) = rfind(self.data, b"trailer") {
+            let mut parser = Parser::new(self.data);
+            parser.pos = position + 7;
+            if let Ok(dict) = parser.parse_dictionary() {
+                return Some(dict);
+            }
+        }
+
+        let mut offsets: Vec<usize> = self
+            .entries
+            .values()
+            .filter_map(|entry| match entry.location {
+                Location::Offset(offset) => Some(offset),
+                Location::Compressed { .. } => None,
+            })
+            .collect();
+        offsets.sort_unstable();
+        offsets.into_iter().rev().find_map(|offset| {
+            let dict = dictionary_at(self.data, offset)?;
+            match dict.get("Type") {
+                Some(PdfObj::Name(kind)) if kind == "XRef" => Some(dict),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Unsigned decimal integer at the parser's position. Offsets are read this
+/// way because large ones are not exact as `f32`.
+fn parse_unsigned(parser: &mut Parser) -> Option<usize> {
+    let start = parser.pos;
+    while parser.pos < parser.len && parser.data[parser.pos].is_ascii_digit() {
+        parser.pos += 1;
+    }
+    if parser.pos == start {
+        return None;
+    }
+    str::from_utf8(&parser.data[start..parser.pos])
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// An `n g obj` header, leaving the parser after `obj`
+fn read_header(parser: &mut Parser) -> Option<(u32, u16)> {
+    parser.skip_whitespace_and_comments();
+    let number = parse_unsigned(parser)?;
+    parser.skip_whitespace();
+    let generation = parse_unsigned(parser)?;
+    parser.skip_whitespace();
+    if !parser.remaining_starts_with(b"obj") {
+        return None;
+    }
+    parser.pos += 3;
+    Some((u32::try_from(number).ok()?, u16::try_from(generation).ok()?))
+}
+
+fn object_header(data: &[u8], offset: usize) -> Option<(u32, u16)> {
+    if offset >= data.len() {
+        return None;
+    }
+    let mut parser = Parser::new(data);
+    parser.pos = offset;
+    read_header(&mut parser)
+}
+
+/// Dictionary of the indirect object at `offset`, without reading its stream
+fn dictionary_at(data: &[u8], offset: usize) -> Option<BTreeMap<String, PdfObj>> {
+    let mut parser = Parser::new(data);
+    parser.pos = offset;
+    read_header(&mut parser)?;
+    parser.skip_whitespace_and_comments();
+    parser.parse_dictionary().ok()
+}
+
+/// Version in the `%PDF-1.7` header, which may follow some junk bytes
+fn header_version(data: &[u8]) -> Option<String> {
+    let head = &data[..data.len().min(1024)];
+    let start = head.windows(5).position(|window| window == b"%PDF-")? + 5;
+    let version: String = head[start..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit() || **byte == b'.')
+        .map(|byte| *byte as char)
+        .collect();
+    (!version.is_empty()).then_some(version)
+}
+
+fn rfind(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len())
+        .rposition(|window| window == pattern)
+}
+
+type XrefIndex = BTreeMap<(u32, u16), Location>;
+
+/// Index and trailer from the cross-reference sections, newest first. An
+/// object number listed in use by a newer section hides older entries.
+fn read_xref(data: &[u8], limits: &ParseLimits) -> Option<(XrefIndex, BTreeMap<String, PdfObj>)> {
+    let mut parser = Parser::new(data);
+    parser.pos = rfind(data, b"startxref")? + 9;
+    parser.skip_whitespace_and_comments();
+    let mut pending = alloc::vec![parse_unsigned(&mut parser)?];
+
+    let mut index = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    let mut visited = BTreeSet::new();
+    let mut trailer = None;
+    while let Some(offset) = pending.pop() {
+        if offset >= data.len() || !visited.insert(offset) {
+            continue;
+        }
+        let mut parser = Parser::new(data);
+        parser.pos = offset;
+        parser.skip_whitespace_and_comments();
+        let section = if parser.remaining_starts_with(b"xref") {
+            read_xref_table(&mut parser, &mut index, &mut seen)
+        } else {
+            read_xref_stream(&mut parser, &mut index, &mut seen, limits)
+        };
+        let Some(section) = section else {
+            // The newest section must be readable, an older one is skipped
+            trailer.as_ref()?;
+            continue;
+        };
+
+        // A hybrid file's `/XRefStm` belongs to the same revision, so it is
+        // read before the older `/Prev` section
+        if let Some(prev) = section.get("Prev").and_then(PdfObj::as_unsigned) {
+            pending.push(prev);
+        }
+        if let Some(xref_stream) = section.get("XRefStm").and_then(PdfObj::as_unsigned) {
+            pending.push(xref_stream);
+        }
+        trailer.get_or_insert(section);
+    }
+    Some((index, trailer?))
+}
+
+/// A cross-reference table and its trailer, the parser at `xref`
+fn read_xref_table(
+    parser: &mut Parser,
+    index: &mut XrefIndex,
+    seen: &mut BTreeSet<u32>,
+) -> Option<BTreeMap<String, PdfObj>> {
+    parser.pos += 4;
+    let mut section_seen = Vec::new();
+    loop {
+        parser.skip_whitespace_and_comments();
+        if parser.remaining_starts_with(b"trailer") {
+            parser.pos += 7;
+            seen.extend(section_seen);
+            return parser.parse_dictionary().ok();
+        }
+        let start = parse_unsigned(parser)?;
+        parser.skip_whitespace();
+        let count = parse_unsigned(parser)?;
+        for number in start..start.checked_add(count)? {
+            parser.skip_whitespace();
+            let offset = parse_unsigned(parser)?;
+            parser.skip_whitespace();
+            let generation = parse_unsigned(parser)?;
+            parser.skip_whitespace();
+            let kind = parser.data.get(parser.pos).copied()?;
+            parser.pos += 1;
+
+            let number = u32::try_from(number).ok()?;
+            if kind == b'n' && !seen.contains(&number) {
+                let id = (number, u16::try_from(generation).ok()?);
+                index.insert(id, Location::Offset(offset));
+                section_seen.push(number);
+            }
+        }
+    }
+}
+
+/// A cross-reference stream (PDF 32000-1 section 7.5.8), the parser at its
+/// object header. Returns its dictionary, which is also the trailer.
+fn read_xref_stream(
+    parser: &mut Parser,
+    index: &mut XrefIndex,
+    seen: &mut BTreeSet<u32>,
+    limits: &ParseLimits,
+) -> Option<BTreeMap<String, PdfObj>> {
+    read_header(parser)?;
+    parser.skip_whitespace_and_comments();
+    let dict = parser.parse_dictionary().ok()?;
+    match dict.get("Type") {
+        Some(PdfObj::Name(kind)) if kind == "XRef" => {}
+        _ => return None,
+    }
+    parser.skip_whitespace_and_comments();
+    let length = dict.get("Length").and_then(PdfObj::as_unsigned);
+    let span = parser.parse_stream_data(length).ok()?;
+    let data = handle_stream_filters(&dict, &parser.data[span], limits.max_stream_size).ok()?;
+
+    // Get the W array which describes field widths
+    let widths: Vec<usize> = match dict.get("W") {
+        Some(PdfObj::Array(widths)) if widths.len() == 3 => widths
+            .iter()
+            .map(|width| width.as_unsigned().unwrap_or(0))
+            .collect(),
+        _ => return None,
+    };
+    if widths.iter().any(|width| *width > 8) {
+        return None;
+    }
+
+    // Get the Index array (if present) or use default [0, Size]
+    let subsections: Vec<(u32, u32)> = match dict.get("Index") {
+        Some(PdfObj::Array(items)) => items
+            .chunks_exact(2)
+            .filter_map(|pair| Some((pair[0].as_unsigned()?, pair[1].as_unsigned()?)))
+            .collect(),
+        _ => alloc::vec![(0, dict.get("Size")?.as_unsigned()?)],
+    };
+
+    let entry_size: usize = widths.iter().sum();
+    if entry_size == 0 {
+        return None;
+    }
+    let mut entries = data.chunks_exact(entry_size);
+    let mut section_seen = Vec::new();
+    for (start, count) in subsections {
+        for number in start..start.saturating_add(count) {
+            let Some(entry) = entries.next() else {
+                break;
+            };
+            let mut fields = [0u64; 3];
+            let mut pos = 0;
+            for (field, width) in fields.iter_mut().zip(&widths) {
+                for byte in &entry[pos..pos + width] {
+                    *field = (*field << 8) | u64::from(*byte);
+                }
+                pos += width;
+            }
+            // The type defaults to 1 when its width is 0
+            let kind = if widths[0] == 0 { 1 } else { fields[0] };
+
+            // Fields that do not fit their type make the entry unusable
+            let location = match kind {
+                1 => match (u16::try_from(fields[2]), usize::try_from(fields[1])) {
+                    (Ok(generation), Ok(offset)) => {
+                        ((number, generation), Location::Offset(offset))
+                    }
+                    _ => continue,
+                },
+                2 => match (u32::try_from(fields[1]), usize::try_from(fields[2])) {
+                    (Ok(stream), Ok(index)) => {
+                        ((number, 0), Location::Compressed { stream, index })
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !seen.contains(&number) {
+                index.insert(location.0, location.1);
+                section_seen.push(number);
+            }
+        }
+    }
+    seen.extend(section_seen);
+    Some(dict)
+}
+
+/// Index of every `n g obj` header in the file at the start of a line or
+/// after whitespace, later definitions replacing earlier ones as in an
+/// incremental update
+fn scan_objects(data: &[u8]) -> XrefIndex {
+    let mut index = BTreeMap::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let starts_token = pos == 0 || data[pos - 1].is_ascii_whitespace();
+        if starts_token && data[pos].is_ascii_digit() {
+            if let Some(id) = object_header(data, pos) {
+                index.insert(id, Location::Offset(pos));
+            }
+        }
+        pos += 1;
+    }
+    index
+}
+
+impl core::fmt::Debug for Document<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Document")
+            .field("len", &self.data.len())
+            .field("objects", &self.entries.len())
+            .field("pages", &self.pages.len())
+            .field("trailer", &self.trailer)
+            .field("encrypted", &self.is_encrypted())
+            .finish()
+    }
+}
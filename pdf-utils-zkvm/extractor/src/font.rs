@@ -1,30 +1,140 @@
-use crate::parser::{resolve_reference, PdfObj};
+use crate::afm::StandardMetrics;
+use crate::cjk::CidOrdering;
+use crate::cmap::CMap;
+use crate::document::Document;
+use crate::fontfile::{self, CodeToGlyph};
+use crate::parser::{resolve, resolve_reference, PdfObj};
 use alloc::collections::BTreeMap;
-use alloc::string::{String, ToString};
+use alloc::rc::Rc;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 #[derive(Debug, Clone)]
 pub struct PdfFont {
     pub base_font: String,
     pub subtype: String,
+    /// Encoding name, empty when a simple font uses its built-in encoding
     pub encoding: String,
-    pub to_unicode: Option<BTreeMap<u32, String>>,
-    pub differences: Option<BTreeMap<u32, String>>,
+    pub to_unicode: Option<CMap>,
+    /// Encoding CMap of a composite font, when embedded or predefined
+    pub encoding_cmap: Option<CMap>,
+    /// Code to Unicode mapping read from the embedded font program, used when
+    /// there is no `ToUnicode` map
+    pub embedded_unicode: Option<BTreeMap<u32, String>>,
+    /// Character collection of an `Identity`-encoded CIDFont, whose codes
+    /// are CIDs in that collection
+    pub cid_ordering: Option<CidOrdering>,
+    /// Unicode text of the codes `/Differences` assigns glyph names to,
+    /// `None` where the name is not recognised
+    pub differences: Option<BTreeMap<u32, Option<String>>>,
+    pub widths: Option<GlyphWidths>,
 }
 
+/// Advance widths of a font, in thousandths of an em
+#[derive(Debug, Clone, Default)]
+pub struct GlyphWidths {
+    /// Widths of consecutive codes starting at `first_char` (simple fonts)
+    pub first_char: u32,
+    pub widths: Vec<f32>,
+    /// `(first, last, width)` CID ranges sorted by `first` (CID fonts)
+    pub ranges: Vec<(u32, u32, f32)>,
+    /// Width of any other code: `/MissingWidth` or `/DW`
+    pub default_width: f32,
+}
+
+impl GlyphWidths {
+    pub fn get(&self, code: u32) -> f32 {
+        if let Some(width) = code
+            .checked_sub(self.first_char)
+            .and_then(|index| self.widths.get(index as usize))
+        {
+            return *width;
+        }
+        let candidates = self.ranges.partition_point(|&(first, _, _)| first <= code);
+        match candidates.checked_sub(1).map(|i| self.ranges[i]) {
+            Some((_, last, width)) if code <= last => width,
+            _ => self.default_width,
+        }
+    }
+
+    fn scale(&mut self, factor: f32) {
+        for width in self.widths.iter_mut() {
+            *width *= factor;
+        }
+        for (_, _, width) in self.ranges.iter_mut() {
+            *width *= factor;
+        }
+        self.default_width *= factor;
+    }
+}
+
+/// Advance used for glyphs whose width is unknown, in thousandths of an em
+pub(crate) const DEFAULT_GLYPH_WIDTH: f32 = 500.0;
+
+impl PdfFont {
+    /// Composite fonts use two-byte character codes
+    pub fn is_cid(&self) -> bool {
+        self.subtype == "Type0" || self.encoding == "Identity-H" || self.encoding == "Identity-V"
+    }
+
+    /// Split a string operand into character codes. Simple fonts use single
+    /// bytes. Composite fonts follow the code space of the encoding CMap, or
+    /// of the `ToUnicode` CMap when the encoding is unknown, and fall back to
+    /// two bytes per code.
+    pub fn char_codes(&self, bytes: &[u8]) -> Vec<u32> {
+        if !self.is_cid() {
+            return bytes.iter().map(|&b| b as u32).collect();
+        }
+        let cmap = [&self.encoding_cmap, &self.to_unicode]
+            .into_iter()
+            .flatten()
+            .find(|cmap| !cmap.codespace.is_empty());
+        let Some(cmap) = cmap else {
+            return bytes
+                .chunks(2)
+                .map(|pair| pair.iter().fold(0, |code, &b| (code << 8) | b as u32))
+                .collect();
+        };
+
+        let mut codes = Vec::new();
+        let mut rest = bytes;
+        while let Some((code, len)) = cmap.next_code(rest) {
+            codes.push(code);
+            rest = &rest[len..];
+        }
+        codes
+    }
+
+    /// Horizontal advance of a character code, in thousandths of an em.
+    /// Composite widths are indexed by CID, so fonts whose predefined encoding
+    /// maps codes straight to Unicode get the default width.
+    pub fn glyph_width(&self, code: u32) -> f32 {
+        let Some(widths) = &self.widths else {
+            return DEFAULT_GLYPH_WIDTH;
+        };
+        if self
+            .encoding_cmap
+            .as_ref()
+            .is_some_and(|cmap| cmap.mapping.is_some())
+        {
+            return widths.default_width;
+        }
+        widths.get(code)
+    }
+}
+
+/// Fonts of a `/Font` resource dictionary by resource name. Fonts given by
+/// reference are parsed once per document, see [`Document::font`].
 pub fn extract_fonts(
     font_dict: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
-) -> BTreeMap<String, PdfFont> {
+    objects: &Document,
+) -> BTreeMap<String, Rc<PdfFont>> {
     let mut fonts = BTreeMap::new();
 
     for (name, font_obj) in font_dict {
         let font = match font_obj {
-            PdfObj::Reference(font_ref) => match resolve_reference(objects, font_ref) {
-                Some(PdfObj::Dictionary(dict)) => parse_font(dict, objects),
-                _ => None,
-            },
-            PdfObj::Dictionary(dict) => parse_font(dict, objects),
+            PdfObj::Reference(font_ref) => objects.font(*font_ref),
+            PdfObj::Dictionary(dict) => parse_font(dict, objects).map(Rc::new),
             _ => None,
         };
 
@@ -36,11 +146,12 @@ pub fn extract_fonts(
     fonts
 }
 
-fn parse_font(
+pub(crate) fn parse_font(
     font_dict: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
 ) -> Option<PdfFont> {
-    let base_font = match font_dict.get("BaseFont") {
+    // Type3 fonts have no `/BaseFont`, only an optional `/Name`
+    let base_font = match font_dict.get("BaseFont").or_else(|| font_dict.get("Name")) {
         Some(PdfObj::Name(name)) => name.clone(),
         _ => String::from("Unknown"),
     };
@@ -50,45 +161,292 @@ fn parse_font(
         _ => String::from("Type1"),
     };
 
-    let encoding = extract_encoding(font_dict, objects);
+    let encoding = extract_encoding(font_dict, objects, &subtype);
     let to_unicode = extract_to_unicode(font_dict, objects);
+    let encoding_cmap = extract_encoding_cmap(font_dict, objects, &subtype, &encoding);
     let differences = extract_differences(font_dict, objects);
 
-    Some(PdfFont {
+    let mut font = PdfFont {
         base_font,
         subtype,
         encoding,
         to_unicode,
+        encoding_cmap,
+        embedded_unicode: None,
+        cid_ordering: None,
         differences,
+        widths: None,
+    };
+    if font.to_unicode.is_none() {
+        font.embedded_unicode = extract_embedded_unicode(font_dict, objects, &font);
+        font.cid_ordering = extract_cid_ordering(font_dict, objects, &font);
+    }
+    font.widths = if font.subtype == "Type0" {
+        extract_cid_widths(font_dict, objects)
+    } else {
+        extract_simple_widths(font_dict, objects).or_else(|| standard_widths(&font))
+    };
+    if font.subtype == "Type3" {
+        // Type3 widths are in glyph space, mapped to text space by `/FontMatrix`.
+        // The glyph procedures in `/CharProcs` are never run as page content.
+        if let Some(widths) = font.widths.as_mut() {
+            widths.scale(type3_glyph_scale(font_dict, objects));
+        }
+    }
+
+    Some(font)
+}
+
+fn number(obj: Option<&PdfObj>, objects: &Document) -> Option<f32> {
+    resolve(obj, objects)?.as_number()
+}
+
+/// A non-negative integer such as a character code or CID
+fn code(obj: Option<&PdfObj>, objects: &Document) -> Option<u32> {
+    resolve(obj, objects)?.as_unsigned()
+}
+
+/// `/FirstChar` and `/Widths`, with `/MissingWidth` from the font descriptor
+fn extract_simple_widths(
+    font_dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+) -> Option<GlyphWidths> {
+    let PdfObj::Array(values) = resolve(font_dict.get("Widths"), objects)? else {
+        return None;
+    };
+    let first_char = code(font_dict.get("FirstChar"), objects).unwrap_or(0);
+    let default_width = match resolve(font_dict.get("FontDescriptor"), objects) {
+        Some(PdfObj::Dictionary(descriptor)) => {
+            number(descriptor.get("MissingWidth"), objects).unwrap_or(0.0)
+        }
+        _ => 0.0,
+    };
+
+    Some(GlyphWidths {
+        first_char,
+        widths: values
+            .iter()
+            .map(|value| number(Some(value), objects).unwrap_or(default_width))
+            .collect(),
+        ranges: Vec::new(),
+        default_width,
+    })
+}
+
+/// Factor taking Type3 glyph space widths to thousandths of an em: the
+/// horizontal scale of `/FontMatrix` times 1000
+fn type3_glyph_scale(font_dict: &BTreeMap<String, PdfObj>, objects: &Document) -> f32 {
+    match resolve(font_dict.get("FontMatrix"), objects) {
+        Some(PdfObj::Array(matrix)) => number(matrix.first(), objects).map_or(1.0, |a| a * 1000.0),
+        _ => 1.0,
+    }
+}
+
+/// The CIDFont of a Type0 font
+fn descendant_font<'a>(
+    font_dict: &'a BTreeMap<String, PdfObj>,
+    objects: &'a Document,
+) -> Option<&'a BTreeMap<String, PdfObj>> {
+    let PdfObj::Array(descendants) = resolve(font_dict.get("DescendantFonts"), objects)? else {
+        return None;
+    };
+    match resolve(descendants.first(), objects)? {
+        PdfObj::Dictionary(cid_font) => Some(cid_font),
+        _ => None,
+    }
+}
+
+/// Character collection of the descendant CIDFont, when the font's codes are
+/// CIDs (an `Identity` encoding)
+fn extract_cid_ordering(
+    font_dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+    font: &PdfFont,
+) -> Option<CidOrdering> {
+    if font.subtype != "Type0" || !matches!(font.encoding.as_str(), "Identity-H" | "Identity-V") {
+        return None;
+    }
+    let cid_font = descendant_font(font_dict, objects)?;
+    let PdfObj::Dictionary(info) = resolve(cid_font.get("CIDSystemInfo"), objects)? else {
+        return None;
+    };
+    let text = |key: &str| {
+        let bytes = resolve(info.get(key), objects)?.as_bytes()?;
+        core::str::from_utf8(bytes).ok()
+    };
+    CidOrdering::from_system_info(text("Registry")?, text("Ordering")?)
+}
+
+/// `/W` and `/DW` of the descendant CIDFont
+fn extract_cid_widths(
+    font_dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+) -> Option<GlyphWidths> {
+    let cid_font = descendant_font(font_dict, objects)?;
+    let default_width = number(cid_font.get("DW"), objects).unwrap_or(1000.0);
+    let mut ranges = Vec::new();
+    if let Some(PdfObj::Array(w)) = resolve(cid_font.get("W"), objects) {
+        // Entries are either `c [w1 w2 ...]` or `c_first c_last w`
+        let mut i = 0;
+        while i < w.len() {
+            let first = code(w.get(i), objects);
+            match (first, resolve(w.get(i + 1), objects)) {
+                (Some(first), Some(PdfObj::Array(widths))) => {
+                    for (offset, width) in widths.iter().enumerate() {
+                        let cid = u32::try_from(offset)
+                            .ok()
+                            .and_then(|offset| first.checked_add(offset));
+                        if let (Some(cid), Some(width)) = (cid, number(Some(width), objects)) {
+                            ranges.push((cid, cid, width));
+                        }
+                    }
+                    i += 2;
+                }
+                (Some(first), Some(last)) if last.as_int().is_some() => {
+                    if let (Some(last), Some(width)) =
+                        (last.as_unsigned(), number(w.get(i + 2), objects))
+                    {
+                        ranges.push((first, last, width));
+                    }
+                    i += 3;
+                }
+                _ => break,
+            }
+        }
+    }
+    ranges.sort_by_key(|&(first, _, _)| first);
+
+    Some(GlyphWidths {
+        first_char: 0,
+        widths: Vec::new(),
+        ranges,
+        default_width,
+    })
+}
+
+/// Unicode mapping of the font program in `/FontFile2` or `/FontFile3`. CID
+/// fonts qualify only when their codes are CIDs, i.e. with an Identity encoding.
+fn extract_embedded_unicode(
+    font_dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+    font: &PdfFont,
+) -> Option<BTreeMap<u32, String>> {
+    let cid_font = if font.subtype == "Type0" {
+        if font.encoding != "Identity-H" && font.encoding != "Identity-V" {
+            return None;
+        }
+        Some(descendant_font(font_dict, objects)?)
+    } else {
+        None
+    };
+    let PdfObj::Dictionary(descriptor) =
+        resolve(cid_font.unwrap_or(font_dict).get("FontDescriptor"), objects)?
+    else {
+        return None;
+    };
+    let stream_data = |obj: &PdfObj| match obj {
+        PdfObj::Stream(stream) => objects.decode_stream(stream).ok(),
+        _ => None,
+    };
+
+    if let Some(program) = resolve(descriptor.get("FontFile2"), objects) {
+        let data = stream_data(program)?;
+        // A `/CIDToGIDMap` stream; the name `/Identity` leaves it `None`
+        let cid_to_gid = cid_font
+            .and_then(|cid_font| resolve(cid_font.get("CIDToGIDMap"), objects))
+            .and_then(stream_data);
+        let codes = match cid_font {
+            None => CodeToGlyph::Simple,
+            Some(_) => CodeToGlyph::Cid(cid_to_gid.as_deref()),
+        };
+        return fontfile::truetype_unicode(&data, codes);
+    }
+
+    let program = resolve(descriptor.get("FontFile3"), objects)?;
+    let PdfObj::Stream(stream) = program else {
+        return None;
+    };
+    let data = stream_data(program)?;
+    match stream.dict.get("Subtype") {
+        Some(PdfObj::Name(subtype)) if subtype == "OpenType" => {
+            let codes = match cid_font.map(|cid_font| cid_font.get("Subtype")) {
+                None => CodeToGlyph::Simple,
+                // A TrueType-based CIDFont in an OpenType wrapper: CIDs are
+                // glyph ids, as `/CIDToGIDMap` is only read from `/FontFile2`
+                Some(Some(PdfObj::Name(subtype))) if subtype == "CIDFontType2" => {
+                    CodeToGlyph::Cid(None)
+                }
+                // CFF-based CIDFonts select glyphs through their charset,
+                // which is not read
+                Some(_) => return None,
+            };
+            fontfile::truetype_unicode(&data, codes)
+        }
+        Some(PdfObj::Name(subtype)) if subtype == "Type1C" && cid_font.is_none() => {
+            fontfile::cff_unicode(&data)
+        }
+        _ => None,
+    }
+}
+
+/// Widths of an unembedded standard 14 font from the built-in AFM metrics,
+/// resolved through the font's encoding
+fn standard_widths(font: &PdfFont) -> Option<GlyphWidths> {
+    if font.is_cid() {
+        return None;
+    }
+    let metrics = StandardMetrics::for_base_font(&font.base_font)?;
+    let widths = (0..=255u8)
+        .map(|code| {
+            crate::text::decode_with_font(&[code], font)
+                .chars()
+                .next()
+                .and_then(|ch| metrics.width(ch))
+                .map_or(DEFAULT_GLYPH_WIDTH, f32::from)
+        })
+        .collect();
+
+    Some(GlyphWidths {
+        first_char: 0,
+        widths,
+        ranges: Vec::new(),
+        default_width: DEFAULT_GLYPH_WIDTH,
     })
 }
 
+/// Name of the font's encoding. Without one, Type0 fonts default to
+/// `Identity-H` and simple fonts to an empty name: their built-in encoding.
 fn extract_encoding(
     font_dict: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
+    subtype: &str,
 ) -> String {
+    let default = || {
+        if subtype == "Type0" {
+            String::from("Identity-H")
+        } else {
+            String::new()
+        }
+    };
     match font_dict.get("Encoding") {
         Some(PdfObj::Name(name)) => name.clone(),
         Some(PdfObj::Reference(enc_ref)) => match resolve_reference(objects, enc_ref) {
             Some(PdfObj::Name(name)) => name.clone(),
             Some(PdfObj::Dictionary(dict)) => match dict.get("BaseEncoding") {
                 Some(PdfObj::Name(name)) => name.clone(),
-                _ => String::from("Identity-H"),
+                _ => default(),
             },
-            _ => String::from("Identity-H"),
+            _ => default(),
         },
         Some(PdfObj::Dictionary(dict)) => match dict.get("BaseEncoding") {
             Some(PdfObj::Name(name)) => name.clone(),
-            _ => String::from("Identity-H"),
+            _ => default(),
         },
-        _ => String::from("Identity-H"),
+        _ => default(),
     }
 }
 
-fn extract_to_unicode(
-    font_dict: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
-) -> Option<BTreeMap<u32, String>> {
+fn extract_to_unicode(font_dict: &BTreeMap<String, PdfObj>, objects: &Document) -> Option<CMap> {
     let stream = match font_dict.get("ToUnicode") {
         Some(PdfObj::Reference(ref_)) => match resolve_reference(objects, ref_) {
             Some(PdfObj::Stream(stream)) => stream,
@@ -99,18 +457,38 @@ fn extract_to_unicode(
     };
 
     // Decompress stream
-    let data = match crate::stream::handle_stream_filters(&stream.dict, &stream.data) {
+    let data = match objects.decode_stream(stream) {
         Ok(data) => data,
         Err(_) => return None,
     };
 
-    parse_cmap(&data)
+    let cmap = CMap::parse(&data);
+    (!cmap.is_empty()).then_some(cmap)
+}
+
+/// The `/Encoding` CMap of a Type0 font: an embedded stream or a known name
+fn extract_encoding_cmap(
+    font_dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+    subtype: &str,
+    encoding: &str,
+) -> Option<CMap> {
+    if subtype != "Type0" {
+        return None;
+    }
+    match resolve(font_dict.get("Encoding"), objects) {
+        Some(PdfObj::Stream(stream)) => {
+            let data = objects.decode_stream(stream).ok()?;
+            Some(CMap::parse(&data))
+        }
+        _ => CMap::predefined(encoding),
+    }
 }
 
 fn extract_differences(
     font_dict: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
-) -> Option<BTreeMap<u32, String>> {
+    objects: &Document,
+) -> Option<BTreeMap<u32, Option<String>>> {
     let encoding = match font_dict.get("Encoding") {
         Some(PdfObj::Dictionary(dict)) => dict,
         Some(PdfObj::Reference(enc_ref)) => match resolve_reference(objects, enc_ref) {
@@ -134,10 +512,10 @@ fn extract_differences(
 
     for item in differences {
         match item {
-            PdfObj::Number(n) => current_code = *n as u32,
+            PdfObj::Integer(n) => current_code = u32::try_from(*n).unwrap_or(u32::MAX),
             PdfObj::Name(name) => {
-                result.insert(current_code, glyph_to_unicode(name));
-                current_code += 1;
+                result.insert(current_code, crate::agl::glyph_to_unicode(name));
+                current_code = current_code.saturating_add(1);
             }
             _ => {}
         }
@@ -146,246 +524,7 @@ fn extract_differences(
     Some(result)
 }
 
-fn parse_cmap(data: &[u8]) -> Option<BTreeMap<u32, String>> {
-    let content = match core::str::from_utf8(data) {
-        Ok(s) => s,
-        Err(_) => {
-            return None;
-        }
-    };
-    let mut map = BTreeMap::new();
-
-    // Simple CMap parser for bfchar and bfrange
-    let lines: Vec<&str> = content.lines().collect();
-    let mut i = 0;
-
-    while i < lines.len() {
-        let line = lines[i].trim();
-
-        if line.ends_with("beginbfchar") {
-            i += 1;
-            while i < lines.len() && !lines[i].trim_end().ends_with("endbfchar") {
-                let l = lines[i].trim();
-                if l.starts_with('<') {
-                    let parts: Vec<&str> = l.split_ascii_whitespace().collect();
-                    if parts.len() >= 2 {
-                        if let (Some(src), Some(dst)) =
-                            (parse_hex_u32(parts[0]), parse_hex_string(parts[1]))
-                        {
-                            map.insert(src, dst);
-                        }
-                    }
-                }
-                i += 1;
-            }
-        } else if line.ends_with("beginbfrange") {
-            i += 1;
-            while i < lines.len() && !lines[i].trim_end().ends_with("endbfrange") {
-                let l = lines[i].trim();
-                if l.starts_with('<') {
-                    let parts: Vec<&str> = l.split_ascii_whitespace().collect();
-                    if parts.len() >= 3 {
-                        let start_hex = parts[0].trim_matches(|c| c == '<' || c == '>');
-                        let end_hex = parts[1].trim_matches(|c| c == '<' || c == '>');
-                        if let (Ok(start_code), Ok(end_code)) = (
-                            u32::from_str_radix(start_hex, 16),
-                            u32::from_str_radix(end_hex, 16),
-                        ) {
-                            if parts[2].starts_with('[') {
-                                // Array format - not implemented yet in simplified version
-                            } else {
-                                // Range mapping
-                                let dest_start_hex =
-                                    parts[2].trim_matches(|c| c == '<' || c == '>');
-                                if let Some(dest_start_str) = parse_hex_string(dest_start_hex) {
-                                    let mut dest_start_codes: Vec<u32> =
-                                        dest_start_str.chars().map(|ch| ch as u32).collect();
-                                    for code in start_code..=end_code {
-                                        let dest_string: String = dest_start_codes
-                                            .iter()
-                                            .map(|&u| char::from_u32(u).unwrap_or('?'))
-                                            .collect();
-                                        map.insert(code, dest_string);
-                                        if let Some(last) = dest_start_codes.last_mut() {
-                                            *last += 1;
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                i += 1;
-            }
-        }
-
-        i += 1;
-    }
-
-    if map.is_empty() {
-        None
-    } else {
-        Some(map)
-    }
-}
-
-fn parse_hex_u32(s: &str) -> Option<u32> {
-    let s = s.trim_start_matches('<').trim_end_matches('>');
-    u32::from_str_radix(s, 16).ok()
-}
-
-fn parse_hex_string(hex: &str) -> Option<String> {
-    let hex = hex.trim_start_matches('<').trim_end_matches('>');
-
-    if hex.is_empty() {
-        return Some(String::new());
-    }
-    if hex.len() % 4 != 0 {
-        return None;
-    }
-
-    let chunks: Vec<&[u8]> = hex.as_bytes().chunks(4).collect();
-    let mut out = String::new();
-    let mut i = 0;
-
-    while i < chunks.len() {
-        let chunk = chunks[i];
-        if chunk.len() < 4 {
-            break;
-        }
-        let part = core::str::from_utf8(chunk).ok()?;
-        let code = u16::from_str_radix(part, 16).ok()?;
-
-        if (0xD800..=0xDBFF).contains(&code) {
-            if i + 1 < chunks.len() {
-                let next_part = core::str::from_utf8(chunks[i + 1]).ok()?;
-                if let Ok(low) = u16::from_str_radix(next_part, 16) {
-                    if (0xDC00..=0xDFFF).contains(&low) {
-                        let combined =
-                            0x10000 + (((code - 0xD800) as u32) << 10) + ((low - 0xDC00) as u32);
-                        if let Some(ch) = char::from_u32(combined) {
-                            out.push(ch);
-                            i += 2;
-                            continue;
-                        }
-                    }
-                }
-            }
-            out.push('�');
-            i += 1;
-            continue;
-        } else if (0xDC00..=0xDFFF).contains(&code) {
-            out.push('�');
-        } else if let Some(ch) = char::from_u32(code as u32) {
-            out.push(ch);
-        } else {
-            out.push('�');
-        }
-        i += 1;
-    }
-
-    Some(out)
-}
-
-fn glyph_to_unicode(glyph_name: &str) -> String {
-    // Common glyph name mappings
-    match glyph_name {
-        "space" => " ",
-        "exclam" => "!",
-        "quotedbl" => "\"",
-        "numbersign" => "#",
-        "dollar" => "$",
-        "percent" => "%",
-        "ampersand" => "&",
-        "quotesingle" => "'",
-        "parenleft" => "(",
-        "parenright" => ")",
-        "asterisk" => "*",
-        "plus" => "+",
-        "comma" => ",",
-        "hyphen" | "minus" => "-",
-        "period" => ".",
-        "slash" => "/",
-        "zero" => "0",
-        "one" => "1",
-        "two" => "2",
-        "three" => "3",
-        "four" => "4",
-        "five" => "5",
-        "six" => "6",
-        "seven" => "7",
-        "eight" => "8",
-        "nine" => "9",
-        "colon" => ":",
-        "semicolon" => ";",
-        "less" => "<",
-        "equal" => "=",
-        "greater" => ">",
-        "question" => "?",
-        "at" => "@",
-        "A" => "A",
-        "B" => "B",
-        "C" => "C",
-        "D" => "D",
-        "E" => "E",
-        "F" => "F",
-        "G" => "G",
-        "H" => "H",
-        "I" => "I",
-        "J" => "J",
-        "K" => "K",
-        "L" => "L",
-        "M" => "M",
-        "N" => "N",
-        "O" => "O",
-        "P" => "P",
-        "Q" => "Q",
-        "R" => "R",
-        "S" => "S",
-        "T" => "T",
-        "U" => "U",
-        "V" => "V",
-        "W" => "W",
-        "X" => "X",
-        "Y" => "Y",
-        "Z" => "Z",
-        "bracketleft" => "[",
-        "backslash" => "\\",
-        "bracketright" => "]",
-        "asciicircum" => "^",
-        "underscore" => "_",
-        "grave" => "`",
-        "a" => "a",
-        "b" => "b",
-        "c" => "c",
-        "d" => "d",
-        "e" => "e",
-        "f" => "f",
-        "g" => "g",
-        "h" => "h",
-        "i" => "i",
-        "j" => "j",
-        "k" => "k",
-        "l" => "l",
-        "m" => "m",
-        "n" => "n",
-        "o" => "o",
-        "p" => "p",
-        "q" => "q",
-        "r" => "r",
-        "s" => "s",
-        "t" => "t",
-        "u" => "u",
-        "v" => "v",
-        "w" => "w",
-        "x" => "x",
-        "y" => "y",
-        "z" => "z",
-        "braceleft" => "{",
-        "bar" => "|",
-        "braceright" => "}",
-        "asciitilde" => "~",
-        _ => "?", // Unknown glyph
-    }
-    .to_string()
+/// Unicode text of a glyph name, `?` when the name is not recognised
+pub(crate) fn glyph_to_unicode(glyph_name: &str) -> String {
+    crate::agl::glyph_to_unicode(glyph_name).unwrap_or_else(|| String::from("?"))
 }
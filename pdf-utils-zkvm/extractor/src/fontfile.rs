@@ -0,0 +1,1160 @@
+//! Character mappings recovered from embedded font programs.
+//!
+//! Used when a font has no `ToUnicode` map. TrueType and OpenType fonts
+//! (`/FontFile2`, `/FontFile3` of subtype `OpenType`) are read through their
+//! `cmap` and `post` tables, bare CFF fonts (`/FontFile3` of subtype `Type1C`)
+//! through their built-in encoding and charset glyph names. CID-keyed CFF
+//! fonts carry no glyph names and give nothing.
+
+use crate::agl;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Most character code to glyph pairs read from one `cmap` subtable
+const MAX_CMAP_ENTRIES: usize = 0x20000;
+
+/// How character codes select glyphs of the font program
+pub enum CodeToGlyph<'a> {
+    /// Simple fonts: single-byte codes through the font's built-in encoding
+    Simple,
+    /// CIDFontType2: codes are CIDs, mapped to glyph ids by a `/CIDToGIDMap`
+    /// stream of big-endian `u16`, or identity when `None`
+    Cid(Option<&'a [u8]>),
+}
+
+/// Character code to Unicode text from a TrueType or OpenType font program
+pub fn truetype_unicode(data: &[u8], codes: CodeToGlyph) -> Option<BTreeMap<u32, String>> {
+    let font = TrueType::parse(data)?;
+    let glyphs = font.glyph_unicode();
+
+    let mut map = BTreeMap::new();
+    match codes {
+        CodeToGlyph::Simple => {
+            // Symbolic fonts map codes into 0xF000..=0xF0FF of the (3,0) subtable
+            if let Some(entries) = font.cmap(3, 0) {
+                let entries: BTreeMap<u32, u16> = entries.into_iter().collect();
+                for code in 0..=0xFF {
+                    let glyph = [code, 0xF000 | code, 0xF100 | code, 0xF200 | code]
+                        .iter()
+                        .find_map(|code| entries.get(code));
+                    if let Some(text) = glyph.and_then(|glyph| glyphs.get(glyph)) {
+                        map.insert(code, text.clone());
+                    }
+                }
+            } else {
+                for (code, glyph) in font.cmap(1, 0)? {
+                    if let Some(text) = glyphs.get(&glyph) {
+                        map.insert(code, text.clone());
+                    }
+                }
+            }
+        }
+        CodeToGlyph::Cid(None) => {
+            map.extend(glyphs.into_iter().map(|(glyph, text)| (glyph as u32, text)));
+        }
+        CodeToGlyph::Cid(Some(cid_to_gid)) => {
+            for (cid, pair) in cid_to_gid.chunks_exact(2).enumerate() {
+                let glyph = u16::from_be_bytes([pair[0], pair[1]]);
+                if let Some(text) = glyphs.get(&glyph) {
+                    map.insert(cid as u32, text.clone());
+                }
+            }
+        }
+    }
+
+    (!map.is_empty()).then_some(map)
+}
+
+/// Character code to Unicode text from a bare CFF font program, through its
+/// built-in encoding and glyph names
+pub fn cff_unicode(data: &[u8]) -> Option<BTreeMap<u32, String>> {
+    let cff = Cff::parse(data)?;
+    let sids = cff.charset()?;
+    let glyph_of_sid: BTreeMap<u16, u16> = sids
+        .iter()
+        .enumerate()
+        .map(|(glyph, &sid)| (sid, glyph as u16))
+        .collect();
+    let codes = cff.encoding(&glyph_of_sid)?;
+
+    let map: BTreeMap<u32, String> = codes
+        .into_iter()
+        .filter_map(|(code, glyph)| {
+            let name = cff.string(*sids.get(glyph as usize)?)?;
+            Some((code as u32, agl::glyph_to_unicode(name)?))
+        })
+        .collect();
+    (!map.is_empty()).then_some(map)
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([
+        *data.get(offset)?,
+        *data.get(offset.checked_add(1)?)?,
+    ]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+struct TrueType<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TrueType<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        match u32_at(data, 0)? {
+            0x0001_0000 | 0x7472_7565 | 0x4F54_544F => Some(Self { data }), // 1.0, 'true', 'OTTO'
+            _ => None,
+        }
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> Option<&'a [u8]> {
+        let count = u16_at(self.data, 4)? as usize;
+        (0..count).find_map(|i| {
+            let record = 12 + 16 * i;
+            if self.data.get(record..record + 4)? != tag {
+                return None;
+            }
+            let offset = u32_at(self.data, record + 8)? as usize;
+            let length = u32_at(self.data, record + 12)? as usize;
+            self.data.get(offset..offset.checked_add(length)?)
+        })
+    }
+
+    /// `(code, glyph)` pairs of the `cmap` subtable for a platform and encoding
+    fn cmap(&self, platform: u16, encoding: u16) -> Option<Vec<(u32, u16)>> {
+        let cmap = self.table(b"cmap")?;
+        let count = u16_at(cmap, 2)? as usize;
+        let offset = (0..count).find_map(|i| {
+            let record = 4 + 8 * i;
+            (u16_at(cmap, record)? == platform && u16_at(cmap, record + 2)? == encoding)
+                .then(|| u32_at(cmap, record + 4))
+                .flatten()
+        })? as usize;
+        let subtable = cmap.get(offset..)?;
+
+        let mut entries = Vec::new();
+        // False once the subtable has given `MAX_CMAP_ENTRIES` pairs
+        let mut push = |code: u32, glyph: u16| {
+            if entries.len() >= MAX_CMAP_ENTRIES {
+                return false;
+            }
+            if glyph != 0 {
+                entries.push((code, glyph));
+            }
+            true
+        };
+        match u16_at(subtable, 0)? {
+            0 => {
+                for (code, &glyph) in subtable.get(6..262)?.iter().enumerate() {
+                    push(code as u32, glyph as u16);
+                }
+            }
+            4 => {
+                let segments = u16_at(subtable, 6)? as usize / 2;
+                let ends = 14;
+                let starts = ends + 2 * segments + 2;
+                let deltas = starts + 2 * segments;
+                let range_offsets = deltas + 2 * segments;
+                'segments: for i in 0..segments {
+                    let end = u16_at(subtable, ends + 2 * i)?;
+                    let start = u16_at(subtable, starts + 2 * i)?;
+                    let delta = u16_at(subtable, deltas + 2 * i)?;
+                    let range_offset = u16_at(subtable, range_offsets + 2 * i)? as usize;
+                    for code in start..=end.min(0xFFFE) {
+                        let glyph = if range_offset == 0 {
+                            code.wrapping_add(delta)
+                        } else {
+                            let index =
+                                range_offsets + 2 * i + range_offset + 2 * (code - start) as usize;
+                            match u16_at(subtable, index) {
+                                Some(0) | None => continue,
+                                Some(glyph) => glyph.wrapping_add(delta),
+                            }
+                        };
+                        if !push(code as u32, glyph) {
+                            break 'segments;
+                        }
+                    }
+                }
+            }
+            6 => {
+                let first = u16_at(subtable, 6)? as u32;
+                let count = u16_at(subtable, 8)? as usize;
+                for i in 0..count {
+                    if !push(first + i as u32, u16_at(subtable, 10 + 2 * i)?) {
+                        break;
+                    }
+                }
+            }
+            12 => {
+                let groups = u32_at(subtable, 12)? as usize;
+                'groups: for i in 0..groups.min(MAX_CMAP_ENTRIES) {
+                    let group = 16 + 12 * i;
+                    let first = u32_at(subtable, group)?;
+                    let last = u32_at(subtable, group + 4)?;
+                    let glyph = u32_at(subtable, group + 8)?;
+                    for offset in 0..=last.saturating_sub(first).min(MAX_CMAP_ENTRIES as u32) {
+                        let (Some(code), Some(glyph)) =
+                            (first.checked_add(offset), glyph.checked_add(offset))
+                        else {
+                            continue 'groups;
+                        };
+                        let Ok(glyph) = u16::try_from(glyph) else {
+                            continue 'groups;
+                        };
+                        if !push(code, glyph) {
+                            break 'groups;
+                        }
+                    }
+                }
+            }
+            _ => return None,
+        }
+        Some(entries)
+    }
+
+    /// Glyph names from the `post` table, indexed by glyph id
+    fn glyph_names(&self) -> Option<Vec<&'a str>> {
+        let post = self.table(b"post")?;
+        match u32_at(post, 0)? {
+            0x0001_0000 => Some(MACINTOSH_NAMES.to_vec()),
+            0x0002_0000 => {
+                let count = u16_at(post, 32)? as usize;
+                let mut custom = Vec::new();
+                let mut pos = 34 + 2 * count;
+                while let Some(&len) = post.get(pos) {
+                    let name = post.get(pos + 1..pos + 1 + len as usize)?;
+                    custom.push(core::str::from_utf8(name).unwrap_or(""));
+                    pos += 1 + len as usize;
+                }
+                (0..count)
+                    .map(|glyph| {
+                        let index = u16_at(post, 34 + 2 * glyph)? as usize;
+                        Some(match index.checked_sub(MACINTOSH_NAMES.len()) {
+                            None => MACINTOSH_NAMES[index],
+                            Some(custom_index) => custom.get(custom_index).copied().unwrap_or(""),
+                        })
+                    })
+                    .collect()
+            }
+            _ => None,
+        }
+    }
+
+    /// Unicode text of each glyph: the Unicode `cmap` subtable, inverted, then
+    /// the glyph names of the `post` table
+    fn glyph_unicode(&self) -> BTreeMap<u16, String> {
+        let mut glyphs = BTreeMap::new();
+        let unicode = [(3, 10), (3, 1), (0, 4), (0, 3)]
+            .iter()
+            .find_map(|&(platform, encoding)| self.cmap(platform, encoding));
+        for (code, glyph) in unicode.unwrap_or_default() {
+            if let Some(ch) = char::from_u32(code) {
+                glyphs.entry(glyph).or_insert_with(|| String::from(ch));
+            }
+        }
+        for (glyph, name) in self
+            .glyph_names()
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(text) = agl::glyph_to_unicode(name) {
+                glyphs.entry(glyph as u16).or_insert(text);
+            }
+        }
+        glyphs
+    }
+}
+
+/// Compact Font Format (Adobe Technical Note #5176)
+struct Cff<'a> {
+    data: &'a [u8],
+    top: BTreeMap<u16, Vec<i32>>,
+    strings: Vec<&'a [u8]>,
+    glyph_count: usize,
+}
+
+/// Top DICT operators
+const CHARSET: u16 = 15;
+const ENCODING: u16 = 16;
+const CHAR_STRINGS: u16 = 17;
+const ROS: u16 = 1230;
+
+impl<'a> Cff<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let header_size = *data.get(2)? as usize;
+        let (_, names_end) = index(data, header_size)?;
+        let (top_dicts, top_end) = index(data, names_end)?;
+        let (strings, _) = index(data, top_end)?;
+        let top = dict(top_dicts.first()?)?;
+        if top.contains_key(&ROS) {
+            return None;
+        }
+        let char_strings = *top.get(&CHAR_STRINGS)?.first()? as usize;
+        let glyph_count = u16_at(data, char_strings)? as usize;
+        Some(Self {
+            data,
+            top,
+            strings,
+            glyph_count,
+        })
+    }
+
+    fn offset(&self, op: u16) -> usize {
+        self.top
+            .get(&op)
+            .and_then(|operands| operands.first())
+            .map_or(0, |&offset| offset.max(0) as usize)
+    }
+
+    fn string(&self, sid: u16) -> Option<&'a str> {
+        match (sid as usize).checked_sub(CFF_STANDARD_STRINGS.len()) {
+            None => Some(CFF_STANDARD_STRINGS[sid as usize]),
+            Some(index) => core::str::from_utf8(self.strings.get(index)?).ok(),
+        }
+    }
+
+    /// SID of each glyph
+    fn charset(&self) -> Option<Vec<u16>> {
+        let mut sids = alloc::vec![0];
+        match self.offset(CHARSET) {
+            // ISOAdobe: glyph ids are SIDs
+            0 => sids.extend(1..self.glyph_count.min(229) as u16),
+            // Expert and ExpertSubset
+            1 | 2 => return None,
+            offset => {
+                let format = *self.data.get(offset)?;
+                let mut pos = offset + 1;
+                while sids.len() < self.glyph_count {
+                    match format {
+                        0 => {
+                            sids.push(u16_at(self.data, pos)?);
+                            pos += 2;
+                        }
+                        1 | 2 => {
+                            let first = u16_at(self.data, pos)?;
+                            let left = if format == 1 {
+                                *self.data.get(pos + 2)? as u16
+                            } else {
+                                u16_at(self.data, pos + 2)?
+                            };
+                            pos += if format == 1 { 3 } else { 4 };
+                            sids.extend((0..=left).map(|i| first.wrapping_add(i)));
+                        }
+                        _ => return None,
+                    }
+                }
+                sids.truncate(self.glyph_count);
+            }
+        }
+        Some(sids)
+    }
+
+    /// `(code, glyph)` pairs of the built-in encoding
+    fn encoding(&self, glyph_of_sid: &BTreeMap<u16, u16>) -> Option<Vec<(u8, u16)>> {
+        let mut codes = Vec::new();
+        match self.offset(ENCODING) {
+            0 => {
+                for (code, &sid) in CFF_STANDARD_ENCODING.iter().enumerate() {
+                    if let Some(&glyph) = glyph_of_sid.get(&(sid as u16)).filter(|_| sid != 0) {
+                        codes.push((code as u8, glyph));
+                    }
+                }
+            }
+            // Expert
+            1 => return None,
+            offset => {
+                let format = *self.data.get(offset)?;
+                let count = *self.data.get(offset + 1)? as usize;
+                let mut pos = offset + 2;
+                match format & 0x7F {
+                    0 => {
+                        for glyph in 1..=count {
+                            codes.push((*self.data.get(pos)?, glyph as u16));
+                            pos += 1;
+                        }
+                    }
+                    1 => {
+                        let mut glyph = 1;
+                        for _ in 0..count {
+                            let first = *self.data.get(pos)?;
+                            let left = *self.data.get(pos + 1)?;
+                            pos += 2;
+                            for code in first..=first.saturating_add(left) {
+                                codes.push((code, glyph));
+                                glyph += 1;
+                            }
+                        }
+                    }
+                    _ => return None,
+                }
+                // Supplements: extra codes for glyphs given by SID
+                if format & 0x80 != 0 {
+                    let supplements = *self.data.get(pos)? as usize;
+                    for i in 0..supplements {
+                        let code = *self.data.get(pos + 1 + 3 * i)?;
+                        let sid = u16_at(self.data, pos + 2 + 3 * i)?;
+                        if let Some(&glyph) = glyph_of_sid.get(&sid) {
+                            codes.push((code, glyph));
+                        }
+                    }
+                }
+            }
+        }
+        Some(codes)
+    }
+}
+
+/// Items of the INDEX at `pos` and the offset just past it
+fn index(data: &[u8], pos: usize) -> Option<(Vec<&[u8]>, usize)> {
+    let count = u16_at(data, pos)? as usize;
+    if count == 0 {
+        return Some((Vec::new(), pos + 2));
+    }
+    let offset_size = *data.get(pos + 2)? as usize;
+    if !(1..=4).contains(&offset_size) {
+        return None;
+    }
+    let offset = |i: usize| {
+        let start = pos + 3 + i * offset_size;
+        let bytes = data.get(start..start + offset_size)?;
+        Some(
+            bytes
+                .iter()
+                .fold(0usize, |value, &b| (value << 8) | b as usize),
+        )
+    };
+    // Offsets count from the byte before the item data
+    let base = pos + 2 + (count + 1) * offset_size;
+    let item_pos = |i: usize| base.checked_add(offset(i)?);
+    let mut items = Vec::with_capacity(count);
+    for i in 0..count {
+        items.push(data.get(item_pos(i)?..item_pos(i + 1)?)?);
+    }
+    Some((items, item_pos(count)?))
+}
+
+/// Integer operands of each operator of a DICT; real numbers read as 0
+fn dict(data: &[u8]) -> Option<BTreeMap<u16, Vec<i32>>> {
+    let mut entries = BTreeMap::new();
+    let mut operands = Vec::new();
+    let mut pos = 0;
+    while let Some(&b0) = data.get(pos) {
+        pos += 1;
+        match b0 {
+            0..=21 => {
+                let op = if b0 == 12 {
+                    pos += 1;
+                    1200 + *data.get(pos - 1)? as u16
+                } else {
+                    b0 as u16
+                };
+                entries.insert(op, core::mem::take(&mut operands));
+            }
+            28 => {
+                operands.push(u16_at(data, pos)? as i16 as i32);
+                pos += 2;
+            }
+            29 => {
+                operands.push(u32_at(data, pos)? as i32);
+                pos += 4;
+            }
+            30 => {
+                while (*data.get(pos)? & 0x0F) != 0x0F && (*data.get(pos)? >> 4) != 0x0F {
+                    pos += 1;
+                }
+                pos += 1;
+                operands.push(0);
+            }
+            32..=246 => operands.push(b0 as i32 - 139),
+            247..=250 => {
+                operands.push((b0 as i32 - 247) * 256 + *data.get(pos)? as i32 + 108);
+                pos += 1;
+            }
+            251..=254 => {
+                operands.push(-(b0 as i32 - 251) * 256 - *data.get(pos)? as i32 - 108);
+                pos += 1;
+            }
+            _ => return None,
+        }
+    }
+    Some(entries)
+}
+
+/// The 258 standard Macintosh glyph names of `post` formats 1 and 2
+static MACINTOSH_NAMES: [&str; 258] = [
+    ".notdef",
+    ".null",
+    "nonmarkingreturn",
+    "space",
+    "exclam",
+    "quotedbl",
+    "numbersign",
+    "dollar",
+    "percent",
+    "ampersand",
+    "quotesingle",
+    "parenleft",
+    "parenright",
+    "asterisk",
+    "plus",
+    "comma",
+    "hyphen",
+    "period",
+    "slash",
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "colon",
+    "semicolon",
+    "less",
+    "equal",
+    "greater",
+    "question",
+    "at",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "bracketleft",
+    "backslash",
+    "bracketright",
+    "asciicircum",
+    "underscore",
+    "grave",
+    "a",
+    "b",
+    "c",
+    "d",
+    "e",
+    "f",
+    "g",
+    "h",
+    "i",
+    "j",
+    "k",
+    "l",
+    "m",
+    "n",
+    "o",
+    "p",
+    "q",
+    "r",
+    "s",
+    "t",
+    "u",
+    "v",
+    "w",
+    "x",
+    "y",
+    "z",
+    "braceleft",
+    "bar",
+    "braceright",
+    "asciitilde",
+    "Adieresis",
+    "Aring",
+    "Ccedilla",
+    "Eacute",
+    "Ntilde",
+    "Odieresis",
+    "Udieresis",
+    "aacute",
+    "agrave",
+    "acircumflex",
+    "adieresis",
+    "atilde",
+    "aring",
+    "ccedilla",
+    "eacute",
+    "egrave",
+    "ecircumflex",
+    "edieresis",
+    "iacute",
+    "igrave",
+    "icircumflex",
+    "idieresis",
+    "ntilde",
+    "oacute",
+    "ograve",
+    "ocircumflex",
+    "odieresis",
+    "otilde",
+    "uacute",
+    "ugrave",
+    "ucircumflex",
+    "udieresis",
+    "dagger",
+    "degree",
+    "cent",
+    "sterling",
+    "section",
+    "bullet",
+    "paragraph",
+    "germandbls",
+    "registered",
+    "copyright",
+    "trademark",
+    "acute",
+    "dieresis",
+    "notequal",
+    "AE",
+    "Oslash",
+    "infinity",
+    "plusminus",
+    "lessequal",
+    "greaterequal",
+    "yen",
+    "mu",
+    "partialdiff",
+    "summation",
+    "product",
+    "pi",
+    "integral",
+    "ordfeminine",
+    "ordmasculine",
+    "Omega",
+    "ae",
+    "oslash",
+    "questiondown",
+    "exclamdown",
+    "logicalnot",
+    "radical",
+    "florin",
+    "approxequal",
+    "Delta",
+    "guillemotleft",
+    "guillemotright",
+    "ellipsis",
+    "nonbreakingspace",
+    "Agrave",
+    "Atilde",
+    "Otilde",
+    "OE",
+    "oe",
+    "endash",
+    "emdash",
+    "quotedblleft",
+    "quotedblright",
+    "quoteleft",
+    "quoteright",
+    "divide",
+    "lozenge",
+    "ydieresis",
+    "Ydieresis",
+    "fraction",
+    "currency",
+    "guilsinglleft",
+    "guilsinglright",
+    "fi",
+    "fl",
+    "daggerdbl",
+    "periodcentered",
+    "quotesinglbase",
+    "quotedblbase",
+    "perthousand",
+    "Acircumflex",
+    "Ecircumflex",
+    "Aacute",
+    "Edieresis",
+    "Egrave",
+    "Iacute",
+    "Icircumflex",
+    "Idieresis",
+    "Igrave",
+    "Oacute",
+    "Ocircumflex",
+    "apple",
+    "Ograve",
+    "Uacute",
+    "Ucircumflex",
+    "Ugrave",
+    "dotlessi",
+    "circumflex",
+    "tilde",
+    "macron",
+    "breve",
+    "dotaccent",
+    "ring",
+    "cedilla",
+    "hungarumlaut",
+    "ogonek",
+    "caron",
+    "Lslash",
+    "lslash",
+    "Scaron",
+    "scaron",
+    "Zcaron",
+    "zcaron",
+    "brokenbar",
+    "Eth",
+    "eth",
+    "Yacute",
+    "yacute",
+    "Thorn",
+    "thorn",
+    "minus",
+    "multiply",
+    "onesuperior",
+    "twosuperior",
+    "threesuperior",
+    "onehalf",
+    "onequarter",
+    "threequarters",
+    "franc",
+    "Gbreve",
+    "gbreve",
+    "Idotaccent",
+    "Scedilla",
+    "scedilla",
+    "Cacute",
+    "cacute",
+    "Ccaron",
+    "ccaron",
+    "dcroat",
+];
+
+/// Predefined CFF strings, SIDs 0 to 390 (Adobe TN 5176, appendix A)
+static CFF_STANDARD_STRINGS: [&str; 391] = [
+    ".notdef",
+    "space",
+    "exclam",
+    "quotedbl",
+    "numbersign",
+    "dollar",
+    "percent",
+    "ampersand",
+    "quoteright",
+    "parenleft",
+    "parenright",
+    "asterisk",
+    "plus",
+    "comma",
+    "hyphen",
+    "period",
+    "slash",
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "colon",
+    "semicolon",
+    "less",
+    "equal",
+    "greater",
+    "question",
+    "at",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "bracketleft",
+    "backslash",
+    "bracketright",
+    "asciicircum",
+    "underscore",
+    "quoteleft",
+    "a",
+    "b",
+    "c",
+    "d",
+    "e",
+    "f",
+    "g",
+    "h",
+    "i",
+    "j",
+    "k",
+    "l",
+    "m",
+    "n",
+    "o",
+    "p",
+    "q",
+    "r",
+    "s",
+    "t",
+    "u",
+    "v",
+    "w",
+    "x",
+    "y",
+    "z",
+    "braceleft",
+    "bar",
+    "braceright",
+    "asciitilde",
+    "exclamdown",
+    "cent",
+    "sterling",
+    "fraction",
+    "yen",
+    "florin",
+    "section",
+    "currency",
+    "quotesingle",
+    "quotedblleft",
+    "guillemotleft",
+    "guilsinglleft",
+    "guilsinglright",
+    "fi",
+    "fl",
+    "endash",
+    "dagger",
+    "daggerdbl",
+    "periodcentered",
+    "paragraph",
+    "bullet",
+    "quotesinglbase",
+    "quotedblbase",
+    "quotedblright",
+    "guillemotright",
+    "ellipsis",
+    "perthousand",
+    "questiondown",
+    "grave",
+    "acute",
+    "circumflex",
+    "tilde",
+    "macron",
+    "breve",
+    "dotaccent",
+    "dieresis",
+    "ring",
+    "cedilla",
+    "hungarumlaut",
+    "ogonek",
+    "caron",
+    "emdash",
+    "AE",
+    "ordfeminine",
+    "Lslash",
+    "Oslash",
+    "OE",
+    "ordmasculine",
+    "ae",
+    "dotlessi",
+    "lslash",
+    "oslash",
+    "oe",
+    "germandbls",
+    "onesuperior",
+    "logicalnot",
+    "mu",
+    "trademark",
+    "Eth",
+    "onehalf",
+    "plusminus",
+    "Thorn",
+    "onequarter",
+    "divide",
+    "brokenbar",
+    "degree",
+    "thorn",
+    "threequarters",
+    "twosuperior",
+    "registered",
+    "minus",
+    "eth",
+    "multiply",
+    "threesuperior",
+    "copyright",
+    "Aacute",
+    "Acircumflex",
+    "Adieresis",
+    "Agrave",
+    "Aring",
+    "Atilde",
+    "Ccedilla",
+    "Eacute",
+    "Ecircumflex",
+    "Edieresis",
+    "Egrave",
+    "Iacute",
+    "Icircumflex",
+    "Idieresis",
+    "Igrave",
+    "Ntilde",
+    "Oacute",
+    "Ocircumflex",
+    "Odieresis",
+    "Ograve",
+    "Otilde",
+    "Scaron",
+    "Uacute",
+    "Ucircumflex",
+    "Udieresis",
+    "Ugrave",
+    "Yacute",
+    "Ydieresis",
+    "Zcaron",
+    "aacute",
+    "acircumflex",
+    "adieresis",
+    "agrave",
+    "aring",
+    "atilde",
+    "ccedilla",
+    "eacute",
+    "ecircumflex",
+    "edieresis",
+    "egrave",
+    "iacute",
+    "icircumflex",
+    "idieresis",
+    "igrave",
+    "ntilde",
+    "oacute",
+    "ocircumflex",
+    "odieresis",
+    "ograve",
+    "otilde",
+    "scaron",
+    "uacute",
+    "ucircumflex",
+    "udieresis",
+    "ugrave",
+    "yacute",
+    "ydieresis",
+    "zcaron",
+    "exclamsmall",
+    "Hungarumlautsmall",
+    "dollaroldstyle",
+    "dollarsuperior",
+    "ampersandsmall",
+    "Acutesmall",
+    "parenleftsuperior",
+    "parenrightsuperior",
+    "twodotenleader",
+    "onedotenleader",
+    "zerooldstyle",
+    "oneoldstyle",
+    "twooldstyle",
+    "threeoldstyle",
+    "fouroldstyle",
+    "fiveoldstyle",
+    "sixoldstyle",
+    "sevenoldstyle",
+    "eightoldstyle",
+    "nineoldstyle",
+    "commasuperior",
+    "threequartersemdash",
+    "periodsuperior",
+    "questionsmall",
+    "asuperior",
+    "bsuperior",
+    "centsuperior",
+    "dsuperior",
+    "esuperior",
+    "isuperior",
+    "lsuperior",
+    "msuperior",
+    "nsuperior",
+    "osuperior",
+    "rsuperior",
+    "ssuperior",
+    "tsuperior",
+    "ff",
+    "ffi",
+    "ffl",
+    "parenleftinferior",
+    "parenrightinferior",
+    "Circumflexsmall",
+    "hyphensuperior",
+    "Gravesmall",
+    "Asmall",
+    "Bsmall",
+    "Csmall",
+    "Dsmall",
+    "Esmall",
+    "Fsmall",
+    "Gsmall",
+    "Hsmall",
+    "Ismall",
+    "Jsmall",
+    "Ksmall",
+    "Lsmall",
+    "Msmall",
+    "Nsmall",
+    "Osmall",
+    "Psmall",
+    "Qsmall",
+    "Rsmall",
+    "Ssmall",
+    "Tsmall",
+    "Usmall",
+    "Vsmall",
+    "Wsmall",
+    "Xsmall",
+    "Ysmall",
+    "Zsmall",
+    "colonmonetary",
+    "onefitted",
+    "rupiah",
+    "Tildesmall",
+    "exclamdownsmall",
+    "centoldstyle",
+    "Lslashsmall",
+    "Scaronsmall",
+    "Zcaronsmall",
+    "Dieresissmall",
+    "Brevesmall",
+    "Caronsmall",
+    "Dotaccentsmall",
+    "Macronsmall",
+    "figuredash",
+    "hypheninferior",
+    "Ogoneksmall",
+    "Ringsmall",
+    "Cedillasmall",
+    "questiondownsmall",
+    "oneeighth",
+    "threeeighths",
+    "fiveeighths",
+    "seveneighths",
+    "onethird",
+    "twothirds",
+    "zerosuperior",
+    "foursuperior",
+    "fivesuperior",
+    "sixsuperior",
+    "sevensuperior",
+    "eightsuperior",
+    "ninesuperior",
+    "zeroinferior",
+    "oneinferior",
+    "twoinferior",
+    "threeinferior",
+    "fourinferior",
+    "fiveinferior",
+    "sixinferior",
+    "seveninferior",
+    "eightinferior",
+    "nineinferior",
+    "centinferior",
+    "dollarinferior",
+    "periodinferior",
+    "commainferior",
+    "Agravesmall",
+    "Aacutesmall",
+    "Acircumflexsmall",
+    "Atildesmall",
+    "Adieresissmall",
+    "Aringsmall",
+    "AEsmall",
+    "Ccedillasmall",
+    "Egravesmall",
+    "Eacutesmall",
+    "Ecircumflexsmall",
+    "Edieresissmall",
+    "Igravesmall",
+    "Iacutesmall",
+    "Icircumflexsmall",
+    "Idieresissmall",
+    "Ethsmall",
+    "Ntildesmall",
+    "Ogravesmall",
+    "Oacutesmall",
+    "Ocircumflexsmall",
+    "Otildesmall",
+    "Odieresissmall",
+    "OEsmall",
+    "Oslashsmall",
+    "Ugravesmall",
+    "Uacutesmall",
+    "Ucircumflexsmall",
+    "Udieresissmall",
+    "Yacutesmall",
+    "Thornsmall",
+    "Ydieresissmall",
+    "001.000",
+    "001.001",
+    "001.002",
+    "001.003",
+    "Black",
+    "Bold",
+    "Book",
+    "Light",
+    "Medium",
+    "Regular",
+    "Roman",
+    "Semibold",
+];
+
+/// SID of each code in the CFF Standard Encoding (Adobe TN 5176, appendix B)
+static CFF_STANDARD_ENCODING: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74,
+    75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 0, 111, 112, 113, 114, 0,
+    115, 116, 117, 118, 119, 120, 121, 122, 0, 123, 0, 124, 125, 126, 127, 128, 129, 130, 131, 0,
+    132, 133, 0, 134, 135, 136, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 139,
+    0, 0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0, 0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0,
+    0, 0, 0,
+];
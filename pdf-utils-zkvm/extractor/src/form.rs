@@ -0,0 +1,200 @@
+//! Interactive form fields (PDF 32000-1 section 12.7).
+//!
+//! The catalog's `/AcroForm` lists the root fields in `/Fields`. Fields form
+//! a tree through `/Kids`: a terminal field has no kids, or only widget
+//! annotations (kids without a partial name `/T`), which place it on pages.
+//! The field type `/FT` and value `/V` are inheritable, so they are carried
+//! down from the ancestors of each terminal field.
+
+use crate::document::Document;
+use crate::page::PageContent;
+use crate::parser::{decode_text_string, resolve, resolve_reference, PdfObj};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Deepest nesting of fields followed
+const MAX_DEPTH: usize = 32;
+
+/// A terminal form field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    /// Fully qualified name: the partial names of the field and its
+    /// ancestors joined with `.`, e.g. `applicant.name`
+    pub name: String,
+    /// Field type: `Tx` (text), `Btn` (button), `Ch` (choice) or `Sig`
+    /// (signature), empty when the document gives none
+    pub kind: String,
+    /// Value as text. Buttons give their state name (`Yes`, `Off`, ...) and
+    /// multiple choices are joined with `, `. `None` when unset or for
+    /// signed signature fields.
+    pub value: Option<String>,
+    /// Index of the page of the field's first widget annotation
+    pub page: Option<usize>,
+}
+
+/// Entries a field inherits from its ancestors
+#[derive(Clone, Copy, Default)]
+struct Inherited<'a> {
+    kind: Option<&'a str>,
+    value: Option<&'a PdfObj>,
+}
+
+/// Every terminal field of the form of `document`, in field tree order
+pub fn extract_form_fields(document: &Document) -> Vec<FormField> {
+    let roots = resolve(document.catalog().get("AcroForm"), document)
+        .and_then(|acro_form| acro_form.get_resolved("Fields", document))
+        .and_then(PdfObj::as_array);
+    let Some(roots) = roots else {
+        return Vec::new();
+    };
+
+    let mut collector = FieldCollector {
+        pages: document.pages(),
+        objects: document,
+        visited: BTreeSet::new(),
+        fields: Vec::new(),
+    };
+    for root in roots {
+        collector.collect(root, "", Inherited::default(), 0);
+    }
+    collector.fields
+}
+
+struct FieldCollector<'a> {
+    pages: &'a [PageContent],
+    objects: &'a Document<'a>,
+    /// Field and widget dictionaries already seen, so a malformed tree cannot loop
+    visited: BTreeSet<(u32, u16)>,
+    fields: Vec<FormField>,
+}
+
+impl<'a> FieldCollector<'a> {
+    fn collect(
+        &mut self,
+        obj: &'a PdfObj,
+        parent_name: &str,
+        inherited: Inherited<'a>,
+        depth: usize,
+    ) {
+        if depth >= MAX_DEPTH {
+            return;
+        }
+        if let PdfObj::Reference(r) = obj {
+            if !self.visited.insert(*r) {
+                return;
+            }
+        }
+        let Some(PdfObj::Dictionary(dict)) = resolve(Some(obj), self.objects) else {
+            return;
+        };
+
+        let name = match resolve(dict.get("T"), self.objects) {
+            Some(PdfObj::String(partial)) if parent_name.is_empty() => decode_text_string(partial),
+            Some(PdfObj::String(partial)) => {
+                alloc::format!("{parent_name}.{}", decode_text_string(partial))
+            }
+            _ => String::from(parent_name),
+        };
+        let inherited = Inherited {
+            kind: match resolve(dict.get("FT"), self.objects) {
+                Some(PdfObj::Name(kind)) => Some(kind.as_str()),
+                _ => inherited.kind,
+            },
+            value: resolve(dict.get("V"), self.objects).or(inherited.value),
+        };
+
+        let kids: Vec<&'a PdfObj> = match resolve(dict.get("Kids"), self.objects) {
+            Some(PdfObj::Array(kids)) => kids.iter().collect(),
+            _ => Vec::new(),
+        };
+        let child_fields: Vec<&'a PdfObj> = kids
+            .iter()
+            .copied()
+            .filter(|kid| match kid {
+                PdfObj::Reference(r) if self.visited.contains(r) => false,
+                kid => match resolve(Some(kid), self.objects) {
+                    Some(PdfObj::Dictionary(kid)) => kid.contains_key("T"),
+                    _ => false,
+                },
+            })
+            .collect();
+
+        if !child_fields.is_empty() {
+            for kid in child_fields {
+                self.collect(kid, &name, inherited, depth + 1);
+            }
+            return;
+        }
+
+        // A terminal field: merged with its only widget, or the parent of its widgets
+        let page = if kids.is_empty() {
+            self.widget_page(obj, dict)
+        } else {
+            kids.iter()
+                .find_map(|kid| match resolve(Some(kid), self.objects) {
+                    Some(PdfObj::Dictionary(widget)) => self.widget_page(kid, widget),
+                    _ => None,
+                })
+        };
+        self.fields.push(FormField {
+            name,
+            kind: String::from(inherited.kind.unwrap_or_default()),
+            value: inherited.value.and_then(|value| self.value_text(value)),
+            page,
+        });
+    }
+
+    /// Page of a widget annotation: its `/P`, or the page whose `/Annots` lists it
+    fn widget_page(&self, obj: &PdfObj, widget: &BTreeMap<String, PdfObj>) -> Option<usize> {
+        if let Some(PdfObj::Reference(page_ref)) = widget.get("P") {
+            if let Some(index) = self
+                .pages
+                .iter()
+                .position(|page| page.id == Some(*page_ref))
+            {
+                return Some(index);
+            }
+        }
+
+        let PdfObj::Reference(widget_ref) = obj else {
+            return None;
+        };
+        self.pages.iter().position(|page| {
+            let Some(PdfObj::Dictionary(page_dict)) =
+                page.id.and_then(|id| resolve_reference(self.objects, &id))
+            else {
+                return false;
+            };
+            match resolve(page_dict.get("Annots"), self.objects) {
+                Some(PdfObj::Array(annots)) => annots
+                    .iter()
+                    .any(|annot| matches!(annot, PdfObj::Reference(r) if r == widget_ref)),
+                _ => false,
+            }
+        })
+    }
+
+    fn value_text(&self, value: &PdfObj) -> Option<String> {
+        match value {
+            PdfObj::String(bytes) => Some(decode_text_string(bytes)),
+            PdfObj::Name(state) => Some(state.clone()),
+            PdfObj::Integer(n) => Some(alloc::format!("{n}")),
+            PdfObj::Real(n) => Some(alloc::format!("{n}")),
+            // Text fields may hold long values in a stream
+            PdfObj::Stream(stream) => self
+                .objects
+                .decode_stream(stream)
+                .ok()
+                .map(|data| decode_text_string(&data)),
+            PdfObj::Array(items) => {
+                let values: Vec<String> = items
+                    .iter()
+                    .filter_map(|item| self.value_text(resolve(Some(item), self.objects)?))
+                    .collect();
+                Some(values.join(", "))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Reading-order reconstruction from positioned text runs.
+//!
+//! Runs on a shared baseline form a line, kept in content stream order. A line
+//! is split into segments where the text position jumps by more than a column
+//! gutter. Segments stacked at a regular line spacing form blocks (paragraphs
+//! or column fragments), and blocks are ordered with an XY-cut: full width
+//! gaps first, then gutters, so a two-column body is read column by column
+//! while a header and footer stay in place. The cut pieces wait on a work stack
+//! rather than being ordered by recursion. Thresholds are fractions of the font
+//! size and rely on the glyph widths the font provides.
+//!
+//! Columns whose paragraph breaks line up exactly are read row by row, and
+//! key/value tables with a wide gutter are read column by column.
+
+use crate::position::TextRun;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Baselines closer than this many font sizes belong to the same line
+pub(crate) const SAME_LINE: f32 = 0.3;
+/// A gap wider than this many font sizes separates words
+pub(crate) const WORD_GAP: f32 = 0.15;
+/// A gap wider than this many font sizes separates columns
+const COLUMN_GAP: f32 = 1.5;
+/// Lines further apart than this many font sizes start a new block
+const BLOCK_GAP: f32 = 2.0;
+
+/// Part of a line between column gutters
+#[derive(Debug)]
+struct Segment {
+    text: String,
+    x0: f32,
+    x1: f32,
+    y: f32,
+    font_size: f32,
+}
+
+#[derive(Debug)]
+struct Block {
+    segments: Vec<Segment>,
+    x0: f32,
+    x1: f32,
+    /// Top of the first line and baseline of the last line
+    top: f32,
+    bottom: f32,
+}
+
+impl Block {
+    fn new(segment: Segment) -> Self {
+        Self {
+            x0: segment.x0,
+            x1: segment.x1,
+            top: segment.y + segment.font_size,
+            bottom: segment.y,
+            segments: alloc::vec![segment],
+        }
+    }
+
+    /// Whether `segment` continues this block on the next line
+    fn accepts(&self, segment: &Segment) -> bool {
+        let last = &self.segments[self.segments.len() - 1];
+        let spacing = last.y - segment.y;
+        let size = last.font_size.max(segment.font_size).max(1.0);
+        spacing > SAME_LINE * size
+            && spacing <= BLOCK_GAP * size
+            && segment.x0 < self.x1
+            && self.x0 < segment.x1
+    }
+
+    fn push(&mut self, segment: Segment) {
+        self.x0 = self.x0.min(segment.x0);
+        self.x1 = self.x1.max(segment.x1);
+        self.bottom = segment.y;
+        self.segments.push(segment);
+    }
+}
+
+/// Rebuild the text of a page in reading order.
+///
+/// Lines are separated by `\n` and blocks by an empty line.
+pub fn layout_text(runs: &[TextRun]) -> String {
+    let mut blocks: Vec<Block> = Vec::new();
+    for segment in segments(runs) {
+        match blocks
+            .iter_mut()
+            .rev()
+            .find(|block| block.accepts(&segment))
+        {
+            Some(block) => block.push(segment),
+            None => blocks.push(Block::new(segment)),
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(blocks.len());
+    order_blocks(blocks, &mut ordered);
+
+    let mut text = String::new();
+    for block in ordered {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        for (i, segment) in block.segments.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+            text.push_str(&segment.text);
+        }
+    }
+    text
+}
+
+/// Group runs into lines by baseline and split lines at column gutters,
+/// top to bottom and left to right
+fn segments(runs: &[TextRun]) -> Vec<Segment> {
+    let mut lines: Vec<Vec<&TextRun>> = Vec::new();
+    for run in runs {
+        let line = lines
+            .iter_mut()
+            .find(|line| (line[0].y - run.y).abs() <= SAME_LINE * line_size(line[0], run));
+        match line {
+            Some(line) => line.push(run),
+            None => lines.push(alloc::vec![run]),
+        }
+    }
+    lines.sort_by(|a, b| b[0].y.total_cmp(&a[0].y));
+
+    let mut segments = Vec::new();
+    for line in lines {
+        let line_start = segments.len();
+        let mut current: Option<Segment> = None;
+        let mut pen = 0.0;
+        for run in line {
+            let size = run.font_size.max(1.0);
+            if let Some(segment) = current.as_mut() {
+                let gap = run.x - pen;
+                if gap.abs() <= COLUMN_GAP * size {
+                    let spaced = segment.text.ends_with(char::is_whitespace)
+                        || run.text.starts_with(char::is_whitespace);
+                    if gap > WORD_GAP * size && !spaced {
+                        segment.text.push(' ');
+                    }
+                    segment.text.push_str(&run.text);
+                    segment.x0 = segment.x0.min(run.x);
+                    segment.x1 = segment.x1.max(run.x + run.width);
+                    segment.font_size = segment.font_size.max(run.font_size);
+                    pen = run.x + run.width;
+                    continue;
+                }
+            }
+            segments.extend(current.take());
+            current = Some(Segment {
+                text: run.text.clone(),
+                x0: run.x,
+                x1: run.x + run.width,
+                y: run.y,
+                font_size: run.font_size,
+            });
+            pen = run.x + run.width;
+        }
+        segments.extend(current);
+        segments[line_start..].sort_by(|a, b| a.x0.total_cmp(&b.x0));
+    }
+
+    for segment in segments.iter_mut() {
+        let trimmed = segment.text.trim();
+        if trimmed.len() != segment.text.len() {
+            segment.text = String::from(trimmed);
+        }
+    }
+    segments.retain(|segment| !segment.text.is_empty());
+    segments
+}
+
+fn line_size(a: &TextRun, b: &TextRun) -> f32 {
+    a.font_size.max(b.font_size).max(1.0)
+}
+
+/// XY-cut: split at every horizontal gap if there is one, otherwise at a
+/// vertical gutter, otherwise read top to bottom and left to right. Pieces
+/// wait on an explicit stack, so deeply cut pages cannot exhaust the call
+/// stack.
+fn order_blocks(blocks: Vec<Block>, ordered: &mut Vec<Block>) {
+    let mut pending = alloc::vec![blocks];
+    while let Some(mut blocks) = pending.pop() {
+        if blocks.len() <= 1 {
+            ordered.extend(blocks);
+            continue;
+        }
+
+        // Horizontal cuts: gaps no block crosses
+        blocks.sort_by(|a, b| b.top.total_cmp(&a.top));
+        let mut bottom_edge = blocks[0].bottom;
+        let mut splits = Vec::new();
+        for (split, block) in blocks.iter().enumerate().skip(1) {
+            if block.top < bottom_edge {
+                splits.push(split);
+                bottom_edge = block.bottom;
+            }
+            bottom_edge = bottom_edge.min(block.bottom);
+        }
+
+        if splits.is_empty() {
+            // Vertical cut: the first gutter no block crosses. The right
+            // part may have horizontal gaps of its own, so it is cut again
+            // from scratch
+            blocks.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+            let mut right_edge = blocks[0].x1;
+            for (split, block) in blocks.iter().enumerate().skip(1) {
+                if block.x0 > right_edge {
+                    splits.push(split);
+                    break;
+                }
+                right_edge = right_edge.max(block.x1);
+            }
+        }
+
+        if splits.is_empty() {
+            blocks.sort_by(|a, b| b.top.total_cmp(&a.top).then(a.x0.total_cmp(&b.x0)));
+            ordered.extend(blocks);
+            continue;
+        }
+
+        // Push the last piece first so the first one is ordered next
+        for &split in splits.iter().rev() {
+            pending.push(blocks.split_off(split));
+        }
+        pending.push(blocks);
+    }
+}
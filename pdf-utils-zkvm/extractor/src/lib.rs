@@ -3,25 +3,51 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
 
+mod afm;
+mod agl;
+mod annot;
+mod cjk;
+mod cmap;
+mod document;
 mod font;
+mod fontfile;
+mod form;
+mod layout;
+mod limits;
+mod marked;
 mod page;
 mod parser;
+mod position;
+mod security;
 mod stream;
+mod structure;
 mod text;
 mod token;
 
-pub use page::PageContent;
+pub use annot::AnnotationText;
+pub use document::{Document, ObjRef, DEFAULT_CACHE_BUDGET};
+pub use form::FormField;
+pub use layout::layout_text;
+pub use limits::ParseLimits;
+pub use page::{PageContent, Rect};
 pub use parser::{parse_pdf, PdfObj};
+pub use position::{TextRun, TextSource};
+pub use structure::StructElement;
+pub use text::ExtractOptions;
 
 #[derive(Debug, Clone)]
 pub enum PdfError {
     ParseError(String),
     DecompressionError(String),
+    /// The document is encrypted with an unsupported method, or the password
+    /// does not open it
+    EncryptionError(String),
+    /// The document exceeds one of its [`ParseLimits`]
+    LimitExceeded(String),
 }
 
 impl fmt::Display for PdfError {
@@ -29,18 +55,36 @@ impl fmt::Display for PdfError {
         match self {
             PdfError::ParseError(msg) => write!(f, "Parse error: {msg}"),
             PdfError::DecompressionError(msg) => write!(f, "Decompression error: {msg}"),
+            PdfError::EncryptionError(msg) => write!(f, "Encryption error: {msg}"),
+            PdfError::LimitExceeded(msg) => write!(f, "Limit exceeded: {msg}"),
         }
     }
 }
 
-pub fn extract_text(pdf_bytes: Vec<u8>) -> Result<Vec<String>, PdfError> {
-    let (pages, objects) = parse_pdf(&pdf_bytes)?;
-    extract_text_from_document(&pages, &objects).map_err(PdfError::ParseError)
+pub fn extract_text(pdf_bytes: &[u8]) -> Result<Vec<String>, PdfError> {
+    let (pages, objects) = parse_pdf(pdf_bytes)?;
+    let text = extract_text_from_document(&pages, &objects).map_err(PdfError::ParseError)?;
+    objects.check_limits()?;
+    Ok(text)
+}
+
+/// Like [`extract_text`], with control over marked content
+pub fn extract_text_with_options(
+    pdf_bytes: &[u8],
+    options: &ExtractOptions,
+) -> Result<Vec<String>, PdfError> {
+    let (pages, objects) = parse_pdf(pdf_bytes)?;
+    let text = pages
+        .iter()
+        .map(|page| extract_text_from_page_with_options(page, &objects, options))
+        .collect();
+    objects.check_limits()?;
+    Ok(text)
 }
 
 pub fn extract_text_from_document(
     pages: &[PageContent],
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
 ) -> Result<Vec<String>, String> {
     let mut results = Vec::new();
 
@@ -52,9 +96,136 @@ pub fn extract_text_from_document(
     Ok(results)
 }
 
-pub fn extract_text_from_page(
+pub fn extract_text_from_page(page: &PageContent, objects: &Document) -> String {
+    extract_text_from_page_with_options(page, objects, &ExtractOptions::default())
+}
+
+pub fn extract_text_from_page_with_options(
     page: &PageContent,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
+    options: &ExtractOptions,
 ) -> String {
-    text::extract_text_from_page_content(page, objects)
+    text::extract_text_from_page_content(page, objects, options)
+}
+
+/// Extract every page as positioned text runs, see [`TextRun`]
+pub fn extract_text_runs(pdf_bytes: &[u8]) -> Result<Vec<Vec<TextRun>>, PdfError> {
+    extract_text_runs_with_options(pdf_bytes, &ExtractOptions::default())
+}
+
+/// Like [`extract_text_runs`], with control over marked content
+pub fn extract_text_runs_with_options(
+    pdf_bytes: &[u8],
+    options: &ExtractOptions,
+) -> Result<Vec<Vec<TextRun>>, PdfError> {
+    let (pages, objects) = parse_pdf(pdf_bytes)?;
+    let runs = pages
+        .iter()
+        .map(|page| extract_text_runs_from_page_with_options(page, &objects, options))
+        .collect();
+    objects.check_limits()?;
+    Ok(runs)
+}
+
+pub fn extract_text_runs_from_page(page: &PageContent, objects: &Document) -> Vec<TextRun> {
+    extract_text_runs_from_page_with_options(page, objects, &ExtractOptions::default())
+}
+
+pub fn extract_text_runs_from_page_with_options(
+    page: &PageContent,
+    objects: &Document,
+    options: &ExtractOptions,
+) -> Vec<TextRun> {
+    text::extract_text_runs_from_page_content(page, objects, options)
+}
+
+/// Extract every page as text in reading order, reconstructed from run
+/// positions (lines, columns and paragraphs) rather than content stream order
+pub fn extract_text_layout(pdf_bytes: &[u8]) -> Result<Vec<String>, PdfError> {
+    Ok(extract_text_runs(pdf_bytes)?
+        .iter()
+        .map(|runs| layout_text(runs))
+        .collect())
+}
+
+/// Runs of page `page_index` whose bounding boxes lie inside `region`, a
+/// rectangle measured on the displayed page: in points from its lower-left
+/// corner, after the crop box and `/Rotate` are applied. The runs keep their
+/// user space positions. Text outside the visible part of the page (crop box
+/// and media box) is never returned.
+pub fn extract_text_runs_in_region(
+    pdf_bytes: &[u8],
+    page_index: usize,
+    region: &Rect,
+) -> Result<Vec<TextRun>, PdfError> {
+    let (pages, objects) = parse_pdf(pdf_bytes)?;
+    let page = pages.get(page_index).ok_or_else(|| {
+        PdfError::ParseError(alloc::format!(
+            "Page {page_index} out of range, document has {} pages",
+            pages.len()
+        ))
+    })?;
+    let runs = extract_text_runs_in_region_from_page(page, &objects, region);
+    objects.check_limits()?;
+    Ok(runs)
+}
+
+pub fn extract_text_runs_in_region_from_page(
+    page: &PageContent,
+    objects: &Document,
+    region: &Rect,
+) -> Vec<TextRun> {
+    let region = page.display_to_user(region);
+    let Some(region) = page
+        .visible_box()
+        .and_then(|visible| visible.intersection(&region))
+    else {
+        return Vec::new();
+    };
+
+    let mut runs = extract_text_runs_from_page(page, objects);
+    runs.retain(|run| region.contains(&run.bbox()));
+    runs
+}
+
+/// The logical structure tree of a tagged document: its top-level structure
+/// elements, each with the text of its content. Empty for untagged documents.
+pub fn extract_structure(pdf_bytes: &[u8]) -> Result<Vec<StructElement>, PdfError> {
+    let document = Document::parse(pdf_bytes)?;
+    let structure = structure::extract_structure(&document);
+    document.check_limits()?;
+    Ok(structure)
+}
+
+/// Every terminal field of the document's interactive form (`/AcroForm`)
+/// with its value. Empty when the document has no form.
+pub fn extract_form_fields(pdf_bytes: &[u8]) -> Result<Vec<FormField>, PdfError> {
+    let document = Document::parse(pdf_bytes)?;
+    let fields = extract_form_fields_from_document(&document);
+    document.check_limits()?;
+    Ok(fields)
+}
+
+pub fn extract_form_fields_from_document(document: &Document) -> Vec<FormField> {
+    form::extract_form_fields(document)
+}
+
+/// Text of the annotations of every page (notes, free text, stamps, filled-in
+/// widgets, ...), in `/Annots` order. Each annotation gives a piece per
+/// place its text was found, see [`AnnotationText::source`].
+pub fn extract_annotations(pdf_bytes: &[u8]) -> Result<Vec<Vec<AnnotationText>>, PdfError> {
+    let (pages, objects) = parse_pdf(pdf_bytes)?;
+    let annotations = pages
+        .iter()
+        .map(|page| extract_annotations_from_page(page, &objects))
+        .collect();
+    objects.check_limits()?;
+    Ok(annotations)
+}
+
+pub fn extract_annotations_from_page(
+    page: &PageContent,
+    objects: &Document,
+) -> Vec<AnnotationText> {
+    annot::extract_annotations_from_page_content(page, objects, &ExtractOptions::default())
 }
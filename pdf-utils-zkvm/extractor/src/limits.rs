@@ -0,0 +1,45 @@
+//! Resource limits for parsing untrusted documents.
+//!
+//! A small file can expand into far more data than it holds: a compressed
+//! stream of zeros, object streams listing millions of objects, arrays nested
+//! thousands deep, or form XObjects drawing each other over and over. A
+//! [`Document`](crate::Document) stops at the limits of its [`ParseLimits`]
+//! and reports [`PdfError::LimitExceeded`](crate::PdfError::LimitExceeded).
+
+/// Bounds on the work and memory a [`Document`](crate::Document) spends on a
+/// file. The defaults leave room for large real-world documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Largest decoded size of a single stream, in bytes
+    pub max_stream_size: usize,
+    /// Bytes decoded from all streams together, counting each stream once
+    /// however often it is decoded
+    pub max_total_decoded_size: usize,
+    /// Indirect objects the document may hold
+    pub max_objects: usize,
+    /// Deepest nesting of arrays and dictionaries within an object, and of
+    /// `Pages` nodes in the page tree
+    pub max_nesting_depth: usize,
+    /// Deepest nesting of form XObjects drawn by one another
+    pub max_xobject_depth: usize,
+    /// Form XObjects and annotation appearances drawn in all, so forms
+    /// drawing each other many times over cannot multiply the work
+    pub max_form_invocations: usize,
+    /// Reject a document whose page tree `/Count` differs from the number of
+    /// pages found in it. Off by default, as writers get it wrong often enough.
+    pub strict_page_count: bool,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_stream_size: 16 << 20,
+            max_total_decoded_size: 64 << 20,
+            max_objects: 500_000,
+            max_nesting_depth: 64,
+            max_xobject_depth: 16,
+            max_form_invocations: 100_000,
+            strict_page_count: false,
+        }
+    }
+}
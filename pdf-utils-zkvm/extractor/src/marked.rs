@@ -0,0 +1,245 @@
+//! Marked content (PDF 32000-1 section 14.6).
+//!
+//! `BMC`/`BDC` ... `EMC` sequences nest like `q`/`Q`. Their property lists,
+//! inline or named in the `/Properties` resource, can give the text a
+//! sequence stands for (`/ActualText`), an alternate description (`/Alt`) and
+//! the marked-content identifier linking it to the structure tree (`/MCID`).
+//! Sequences tagged `/Artifact` hold pagination and decoration rather than
+//! document content.
+
+use crate::document::Document;
+use crate::parser::{decode_text_string, resolve, PdfObj};
+use crate::position::TextRun;
+use crate::text::ExtractOptions;
+use crate::token::Token;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Entries of a marked-content property list that affect extraction
+#[derive(Debug, Default)]
+struct Properties {
+    mcid: Option<u32>,
+    actual_text: Option<String>,
+    alt: Option<String>,
+}
+
+impl Properties {
+    fn from_dict(dict: &BTreeMap<String, PdfObj>) -> Self {
+        let text = |key| match dict.get(key) {
+            Some(PdfObj::String(bytes)) => Some(decode_text_string(bytes)),
+            _ => None,
+        };
+        Self {
+            mcid: dict.get("MCID").and_then(PdfObj::as_unsigned),
+            actual_text: text("ActualText"),
+            alt: text("Alt"),
+        }
+    }
+
+    /// Property list written inline, the tokens between `<<` and `>>`
+    fn from_tokens(tokens: &[Token]) -> Self {
+        let mut properties = Self::default();
+        let mut iter = tokens.iter();
+        while let Some(token) = iter.next() {
+            let Token::Name(key) = token else {
+                continue;
+            };
+            match (key.as_str(), iter.next()) {
+                ("MCID", Some(Token::Integer(n))) => properties.mcid = u32::try_from(*n).ok(),
+                ("ActualText", Some(Token::String(bytes))) => {
+                    properties.actual_text = Some(decode_text_string(bytes))
+                }
+                ("Alt", Some(Token::String(bytes))) => {
+                    properties.alt = Some(decode_text_string(bytes))
+                }
+                (_, Some(Token::ArrayStart | Token::DictStart)) => {
+                    // Skip nested values so their keys are not read as ours
+                    let mut depth = 1;
+                    for token in iter.by_ref() {
+                        match token {
+                            Token::ArrayStart | Token::DictStart => depth += 1,
+                            Token::ArrayEnd | Token::DictEnd => depth -= 1,
+                            _ => {}
+                        }
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        properties
+    }
+
+    /// Property list named by `name` in the `/Properties` resource
+    fn from_resource(name: &str, resources: &BTreeMap<String, PdfObj>, objects: &Document) -> Self {
+        let Some(PdfObj::Dictionary(properties)) = resolve(resources.get("Properties"), objects)
+        else {
+            return Self::default();
+        };
+        match resolve(properties.get(name), objects) {
+            Some(PdfObj::Dictionary(dict)) => Self::from_dict(dict),
+            _ => Self::default(),
+        }
+    }
+}
+
+/// An open marked-content sequence
+#[derive(Debug)]
+struct MarkedContent {
+    mcid: Option<u32>,
+    artifact: bool,
+    actual_text: Option<String>,
+    alt: Option<String>,
+    /// Whether any text was shown inside the sequence
+    shown: bool,
+    /// Runs shown inside a sequence with `/ActualText`, merged into one
+    run: Option<TextRun>,
+}
+
+/// Text and run that take the place of a sequence when it ends
+pub(crate) type Replacement = (String, Option<TextRun>);
+
+/// The marked-content sequences open at the current point of a content stream
+#[derive(Debug)]
+pub(crate) struct MarkedContentStack {
+    options: ExtractOptions,
+    open: Vec<MarkedContent>,
+}
+
+impl MarkedContentStack {
+    pub fn new(options: ExtractOptions) -> Self {
+        Self {
+            options,
+            open: Vec::new(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    /// `BMC` or `BDC`, the operator at `index` in `tokens`
+    pub fn begin(
+        &mut self,
+        tokens: &[Token],
+        index: usize,
+        resources: &BTreeMap<String, PdfObj>,
+        objects: &Document,
+    ) {
+        let before = |n: usize| index.checked_sub(n).and_then(|i| tokens.get(i));
+        let (tag, properties) = match (tokens.get(index), before(1)) {
+            (Some(Token::Operator(op)), Some(Token::Name(name))) if op == "BDC" => (
+                before(2),
+                Properties::from_resource(name, resources, objects),
+            ),
+            (Some(Token::Operator(op)), Some(Token::DictEnd)) if op == "BDC" => {
+                let mut depth = 0;
+                let start = (0..index - 1).rev().find(|&i| {
+                    match tokens[i] {
+                        Token::DictEnd => depth += 1,
+                        Token::DictStart if depth == 0 => return true,
+                        Token::DictStart => depth -= 1,
+                        _ => {}
+                    }
+                    false
+                });
+                match start {
+                    Some(start) => (
+                        start.checked_sub(1).and_then(|i| tokens.get(i)),
+                        Properties::from_tokens(&tokens[start + 1..index - 1]),
+                    ),
+                    None => (None, Properties::default()),
+                }
+            }
+            _ => (before(1), Properties::default()),
+        };
+
+        self.open.push(MarkedContent {
+            mcid: properties.mcid,
+            artifact: matches!(tag, Some(Token::Name(tag)) if tag == "Artifact"),
+            actual_text: properties.actual_text,
+            alt: properties.alt,
+            shown: false,
+            run: None,
+        });
+    }
+
+    /// `EMC`: close the innermost sequence, returning what replaces its content
+    pub fn end(&mut self) -> Option<Replacement> {
+        let sequence = self.open.pop()?;
+        if self.hidden() || self.replacing() {
+            // Covered by an enclosing artifact or `/ActualText`
+            return None;
+        }
+        if sequence.artifact && self.options.skip_artifacts {
+            return None;
+        }
+        match (sequence.actual_text, sequence.alt) {
+            (Some(text), _) => {
+                let run = sequence.run.map(|run| TextRun {
+                    text: text.clone(),
+                    ..run
+                });
+                Some((text, run))
+            }
+            (None, Some(alt)) if self.options.alt_text && !sequence.shown => Some((alt, None)),
+            _ => None,
+        }
+    }
+
+    /// Innermost marked-content identifier
+    pub fn mcid(&self) -> Option<u32> {
+        self.open.iter().rev().find_map(|sequence| sequence.mcid)
+    }
+
+    /// Whether shown text is left out as an artifact
+    pub fn hidden(&self) -> bool {
+        self.options.skip_artifacts && self.open.iter().any(|sequence| sequence.artifact)
+    }
+
+    /// Whether shown text is being replaced by an `/ActualText`
+    pub fn replacing(&self) -> bool {
+        self.open
+            .iter()
+            .any(|sequence| sequence.actual_text.is_some())
+    }
+
+    /// Whether shown text goes to the output as it is
+    pub fn visible(&self) -> bool {
+        !self.hidden() && !self.replacing()
+    }
+
+    /// Record text shown at the current point. Returns the text and run to
+    /// output now, or `None` when they are hidden or replaced.
+    pub fn show(&mut self, text: String, run: Option<TextRun>) -> Option<Replacement> {
+        for sequence in self.open.iter_mut() {
+            sequence.shown = true;
+        }
+        if self.hidden() {
+            return None;
+        }
+        let mcid = self.mcid();
+        let mut run = run.map(|run| TextRun { mcid, ..run });
+
+        // The outermost `/ActualText` stands for everything inside it
+        match self
+            .open
+            .iter_mut()
+            .find(|sequence| sequence.actual_text.is_some())
+        {
+            Some(sequence) => {
+                if let Some(run) = run.take() {
+                    match sequence.run.as_mut() {
+                        Some(merged) => merged.extend(&run),
+                        None => sequence.run = Some(run),
+                    }
+                }
+                None
+            }
+            None => Some((text, run)),
+        }
+    }
+}
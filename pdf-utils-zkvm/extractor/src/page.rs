@@ -1,22 +1,159 @@
+use crate::document::Document;
 use crate::font::PdfFont;
-use crate::parser::PdfObj;
+use crate::parser::{PdfObj, PdfStream};
+use crate::PdfError;
 use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+/// Rectangle in PDF user space, normalised so that `x0 <= x1` and `y0 <= y1`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x0: f32,
+    pub y0: f32,
+    pub x1: f32,
+    pub y1: f32,
+}
+
+impl Rect {
+    /// US Letter, used when a page has no `/MediaBox`
+    pub const LETTER: Self = Self {
+        x0: 0.0,
+        y0: 0.0,
+        x1: 612.0,
+        y1: 792.0,
+    };
+
+    /// Build a rectangle from any two opposite corners
+    pub fn new(x0: f32, y0: f32, x1: f32, y1: f32) -> Self {
+        Self {
+            x0: x0.min(x1),
+            y0: y0.min(y1),
+            x1: x0.max(x1),
+            y1: y0.max(y1),
+        }
+    }
+
+    /// Parse a PDF rectangle array `[llx lly urx ury]`
+    pub fn from_obj(obj: &PdfObj) -> Option<Self> {
+        match obj {
+            PdfObj::Array(values) if values.len() == 4 => {
+                let mut corners = [0.0; 4];
+                for (corner, value) in corners.iter_mut().zip(values) {
+                    *corner = value.as_number()?;
+                }
+                Some(Self::new(corners[0], corners[1], corners[2], corners[3]))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.x1 - self.x0
+    }
+
+    pub fn height(&self) -> f32 {
+        self.y1 - self.y0
+    }
+
+    pub fn contains(&self, other: &Rect) -> bool {
+        self.x0 <= other.x0 && other.x1 <= self.x1 && self.y0 <= other.y0 && other.y1 <= self.y1
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x0 = self.x0.max(other.x0);
+        let y0 = self.y0.max(other.y0);
+        let x1 = self.x1.min(other.x1);
+        let y1 = self.y1.min(other.y1);
+        (x0 <= x1 && y0 <= y1).then_some(Rect { x0, y0, x1, y1 })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PageContent {
-    pub content_streams: Vec<Vec<u8>>,
-    pub fonts: BTreeMap<String, PdfFont>,
+    /// Object number and generation of the page dictionary
+    pub id: Option<(u32, u16)>,
+    /// `/Contents`: a content stream or an array of them, decoded by
+    /// [`PageContent::content_streams`]
+    pub contents: Option<PdfObj>,
     pub resources: BTreeMap<String, PdfObj>,
+    pub media_box: Rect,
+    /// Visible region of the page, the media box when `/CropBox` is absent
+    pub crop_box: Rect,
+    /// Clockwise rotation applied when displaying the page: 0, 90, 180 or 270
+    pub rotate: u16,
 }
 
 impl PageContent {
     pub fn new() -> Self {
         Self {
-            content_streams: Vec::new(),
-            fonts: BTreeMap::new(),
+            id: None,
+            contents: None,
             resources: BTreeMap::new(),
+            media_box: Rect::LETTER,
+            crop_box: Rect::LETTER,
+            rotate: 0,
         }
     }
+
+    /// Decoded content streams, in order. They are decoded on each call
+    /// rather than kept, so a page's content is in memory only while it is
+    /// extracted.
+    pub fn content_streams(&self, objects: &Document) -> Result<Vec<Rc<[u8]>>, PdfError> {
+        let streams: Vec<&PdfStream> = match self
+            .contents
+            .as_ref()
+            .and_then(|contents| objects.resolve(contents))
+        {
+            Some(PdfObj::Stream(stream)) => alloc::vec![stream],
+            Some(PdfObj::Array(items)) => items
+                .iter()
+                .filter_map(|item| objects.resolve(item)?.as_stream())
+                .collect(),
+            _ => Vec::new(),
+        };
+        streams
+            .into_iter()
+            .map(|stream| objects.decode_stream(stream))
+            .collect()
+    }
+
+    /// Fonts of `/Resources`, read on each call like the content streams
+    pub fn fonts(&self, objects: &Document) -> BTreeMap<String, Rc<PdfFont>> {
+        match self
+            .resources
+            .get("Font")
+            .and_then(|fonts| objects.resolve(fonts))
+        {
+            Some(PdfObj::Dictionary(fonts)) => crate::font::extract_fonts(fonts, objects),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// Part of user space a viewer shows: the crop box clipped to the media box
+    pub fn visible_box(&self) -> Option<Rect> {
+        self.crop_box.intersection(&self.media_box)
+    }
+
+    /// Convert a rectangle measured on the displayed page (origin at its
+    /// lower-left corner, after `/Rotate`) into user space
+    pub fn display_to_user(&self, rect: &Rect) -> Rect {
+        let crop = &self.crop_box;
+        let to_user = |x: f32, y: f32| match self.rotate {
+            90 => (crop.x1 - y, crop.y0 + x),
+            180 => (crop.x1 - x, crop.y1 - y),
+            270 => (crop.x0 + y, crop.y1 - x),
+            _ => (crop.x0 + x, crop.y0 + y),
+        };
+        let (x0, y0) = to_user(rect.x0, rect.y0);
+        let (x1, y1) = to_user(rect.x1, rect.y1);
+        Rect::new(x0, y0, x1, y1)
+    }
+}
+
+/// Normalise a `/Rotate` value to a clockwise multiple of 90 in `0..360`
+pub(crate) fn normalize_rotation(degrees: i64) -> u16 {
+    let quarter_turns = degrees.div_euclid(90).rem_euclid(4);
+    (quarter_turns * 90) as u16
 }
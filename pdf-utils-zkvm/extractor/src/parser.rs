@@ -1,17 +1,19 @@
-use crate::page::PageContent;
-use crate::stream::handle_stream_filters;
+use crate::document::{Document, ObjRef};
+use crate::limits::ParseLimits;
+use crate::page::{normalize_rotation, PageContent, Rect};
 use crate::PdfError;
 use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::{String, ToString};
-use alloc::vec;
 use alloc::vec::Vec;
+use core::ops::Range;
 use core::str;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PdfObj {
     Null,
     Boolean(bool),
-    Number(f32),
+    Integer(i64),
+    Real(f64),
     Name(String),
     String(Vec<u8>),
     Array(Vec<PdfObj>),
@@ -20,22 +22,158 @@ pub enum PdfObj {
     Reference((u32, u16)),
 }
 
+/// A stream object. Its data is left in the file, and read and decrypted
+/// only when [`Document::decode_stream`](crate::Document::decode_stream)
+/// decodes it, so resolving a stream does not copy it.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PdfStream {
     pub dict: BTreeMap<String, PdfObj>,
-    pub data: Vec<u8>,
+    /// The stream object, whose number and generation decryption needs
+    pub(crate) id: (u32, u16),
+    /// Bytes of the encoded data in the file
+    pub(crate) span: Range<usize>,
+}
+
+impl PdfObj {
+    pub fn as_dict(&self) -> Option<&BTreeMap<String, PdfObj>> {
+        match self {
+            PdfObj::Dictionary(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    /// Dictionary of a dictionary or of a stream
+    pub fn dict(&self) -> Option<&BTreeMap<String, PdfObj>> {
+        match self {
+            PdfObj::Dictionary(dict) => Some(dict),
+            PdfObj::Stream(stream) => Some(&stream.dict),
+            _ => None,
+        }
+    }
+
+    pub fn as_stream(&self) -> Option<&PdfStream> {
+        match self {
+            PdfObj::Stream(stream) => Some(stream),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[PdfObj]> {
+        match self {
+            PdfObj::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_name(&self) -> Option<&str> {
+        match self {
+            PdfObj::Name(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Bytes of a string, undecoded
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            PdfObj::String(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// An integer or real as `f32`, precise enough for coordinates and
+    /// sizes but not for offsets or amounts
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            PdfObj::Integer(n) => Some(*n as f32),
+            PdfObj::Real(n) => Some(*n as f32),
+            _ => None,
+        }
+    }
+
+    /// An integer or real as `f64`
+    pub fn as_real(&self) -> Option<f64> {
+        match self {
+            PdfObj::Integer(n) => Some(*n as f64),
+            PdfObj::Real(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            PdfObj::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// A non-negative integer that fits `T`, such as an offset, count or
+    /// object number
+    pub fn as_unsigned<T: TryFrom<i64>>(&self) -> Option<T> {
+        T::try_from(self.as_int()?).ok()
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PdfObj::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_reference(&self) -> Option<ObjRef> {
+        match self {
+            PdfObj::Reference(r) => Some(*r),
+            _ => None,
+        }
+    }
+
+    /// Entry `key` of a dictionary or stream dictionary, following an
+    /// indirect reference
+    pub fn get_resolved<'a>(&'a self, key: &str, document: &'a Document) -> Option<&'a PdfObj> {
+        document.resolve(self.dict()?.get(key)?)
+    }
 }
 
 pub struct Parser<'a> {
     pub data: &'a [u8],
     pub pos: usize,
     pub len: usize,
+    /// Deepest nesting of arrays and dictionaries parsed
+    pub max_depth: usize,
+    /// Set when parsing failed because objects were nested too deep
+    pub depth_exceeded: bool,
+    /// Arrays and dictionaries open at the current position
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
         let len = data.len();
-        Self { data, pos: 0, len }
+        Self {
+            data,
+            pos: 0,
+            len,
+            max_depth: ParseLimits::default().max_nesting_depth,
+            depth_exceeded: false,
+            depth: 0,
+        }
+    }
+
+    /// Parse an array or dictionary nested one level deeper
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<PdfObj, String>,
+    ) -> Result<PdfObj, String> {
+        if self.depth >= self.max_depth {
+            self.depth_exceeded = true;
+            return Err(alloc::format!(
+                "Objects nested deeper than {}",
+                self.max_depth
+            ));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
     }
 
     fn peek(&self) -> Option<u8> {
@@ -93,11 +231,17 @@ impl<'a> Parser<'a> {
         let num_str =
             str::from_utf8(&self.data[start..self.pos]).map_err(|_| "Invalid UTF-8 in number")?;
 
+        // Integers too large for `i64` are kept as reals
+        if !has_dot {
+            if let Ok(num) = num_str.parse::<i64>() {
+                return Ok(PdfObj::Integer(num));
+            }
+        }
         let num = num_str
-            .parse::<f32>()
+            .parse::<f64>()
             .map_err(|_| "Failed to parse number")?;
 
-        Ok(PdfObj::Number(num))
+        Ok(PdfObj::Real(num))
     }
 
     fn parse_name(&mut self) -> Result<PdfObj, String> {
@@ -280,8 +424,9 @@ impl<'a> Parser<'a> {
     fn parse_reference(&mut self, num: u32) -> Result<PdfObj, String> {
         self.skip_whitespace();
 
-        let gen = match self.parse_object()? {
-            PdfObj::Number(n) => n as u16,
+        let gen = match self.parse_number()? {
+            PdfObj::Integer(n) => u16::try_from(n)
+                .map_err(|_| alloc::format!("Generation number {n} out of range"))?,
             _ => return Err("Expected generation number".to_string()),
         };
 
@@ -314,8 +459,67 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub fn parse_value(&mut self) -> Result<PdfObj, String> {
-        self.parse_object()
+    /// Position in the file of the data of a stream whose dictionary was just
+    /// parsed, the parser being at the `stream` keyword. `length` is the
+    /// `/Length` entry when it is known; when it is missing or wrong the data
+    /// ends at the `endstream` keyword.
+    pub fn parse_stream_data(&mut self, length: Option<usize>) -> Result<Range<usize>, String> {
+        if !self.remaining_starts_with(b"stream") {
+            return Err("Expected 'stream'".to_string());
+        }
+        self.pos += 6;
+
+        // Skip EOL after stream
+        if self.peek() == Some(b'\r') {
+            self.advance();
+        }
+        if self.peek() == Some(b'\n') {
+            self.advance();
+        }
+        let stream_start = self.pos;
+
+        let declared_end = length
+            .and_then(|length| stream_start.checked_add(length))
+            .filter(|end| *end <= self.len);
+        if let Some(declared_end) = declared_end {
+            self.pos = declared_end;
+            self.skip_whitespace_and_comments();
+            if self.remaining_starts_with(b"endstream") {
+                self.pos += 9;
+                return Ok(stream_start..declared_end);
+            }
+        }
+
+        let end = self.find_endstream(stream_start)?;
+        let mut data_end = end;
+        // Trim trailing whitespace
+        while data_end > stream_start && self.data[data_end - 1].is_ascii_whitespace() {
+            data_end -= 1;
+        }
+        self.pos = end + 9;
+        Ok(stream_start..data_end)
+    }
+
+    /// Position of the first `endstream` keyword from `start` that stands on
+    /// its own
+    fn find_endstream(&self, start: usize) -> Result<usize, String> {
+        let search_term = b"endstream";
+        let search_len = search_term.len();
+        let mut i = start;
+        while i + search_len <= self.len {
+            if &self.data[i..i + search_len] == search_term {
+                // Check context
+                let prev_ok = i == 0 || self.data[i - 1].is_ascii_whitespace();
+                let next_ok = i + search_len >= self.len
+                    || self.data[i + search_len..].starts_with(b"endobj")
+                    || self.data[i + search_len].is_ascii_whitespace();
+                if prev_ok && next_ok {
+                    return Ok(i);
+                }
+            }
+            i += 1;
+        }
+        Err("Missing 'endstream'".to_string())
     }
 
     pub fn parse_object(&mut self) -> Result<PdfObj, String> {
@@ -339,24 +543,24 @@ impl<'a> Parser<'a> {
             Some(b'(') => self.parse_string(),
             Some(b'<') => {
                 if self.data.get(self.pos + 1) == Some(&b'<') {
-                    self.parse_dictionary().map(PdfObj::Dictionary)
+                    self.nested(|parser| parser.parse_dictionary().map(PdfObj::Dictionary))
                 } else {
                     self.parse_hex_string()
                 }
             }
-            Some(b'[') => self.parse_array(),
+            Some(b'[') => self.nested(Self::parse_array),
             Some(ch) if ch.is_ascii_digit() || ch == b'-' || ch == b'+' || ch == b'.' => {
                 let num_obj = self.parse_number()?;
 
                 // Check if this is a reference
-                if let PdfObj::Number(num) = num_obj {
+                if let Some(num) = num_obj.as_unsigned::<u32>() {
                     let saved_pos = self.pos;
                     self.skip_whitespace();
 
                     if let Some(ch) = self.peek() {
                         if ch.is_ascii_digit() {
                             // Might be a reference
-                            match self.parse_reference(num as u32) {
+                            match self.parse_reference(num) {
                                 Ok(ref_obj) => Ok(ref_obj),
                                 Err(_) => {
                                     self.pos = saved_pos;
@@ -388,336 +592,22 @@ fn hex_digit_value(ch: u8) -> Result<u8, String> {
     }
 }
 
-type PdfParseResult = (Vec<PageContent>, BTreeMap<(u32, u16), PdfObj>);
-
-pub fn parse_pdf(data: &[u8]) -> Result<PdfParseResult, PdfError> {
-    let mut parser = Parser::new(data);
-    let mut objects: BTreeMap<(u32, u16), PdfObj> = BTreeMap::new();
-
-    // Skip PDF header (e.g. %PDF-1.7)
-    if parser.pos < parser.len && parser.remaining_starts_with(b"%PDF") {
-        // find end of line
-        while parser.pos < parser.len
-            && parser.data[parser.pos] != b'\n'
-            && parser.data[parser.pos] != b'\r'
-        {
-            parser.pos += 1;
-        }
-        // skip newline(s)
-        if parser.pos < parser.len && parser.data[parser.pos] == b'\r' {
-            parser.pos += 1;
-            if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-                parser.pos += 1;
-            }
-        } else if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-            parser.pos += 1;
-        }
-    }
-
-    // Parse objects linearly
-    loop {
-        parser.skip_whitespace_and_comments();
-        if parser.pos >= parser.len {
-            break;
-        }
-
-        if parser.remaining_starts_with(b"xref") || parser.remaining_starts_with(b"trailer") {
-            break;
-        }
-
-        if parser.remaining_starts_with(b"startxref") {
-            parser.pos += 9; // len("startxref")
-            parser.skip_whitespace_and_comments();
-            if parser.pos < parser.len {
-                let _ = parser.parse_number();
-            }
-            parser.skip_whitespace_and_comments();
-            if parser.remaining_starts_with(b"%%EOF") {
-                parser.pos += 5;
-            }
-            continue;
-        }
-
-        // Parse object: "<obj_id> <gen_id> obj"
-        let obj_id = match parser.parse_number().map_err(PdfError::ParseError)? {
-            PdfObj::Number(num) => num as u32,
-            _ => return Err(PdfError::ParseError("Invalid object id".to_string())),
-        };
-        parser.skip_whitespace_and_comments();
-
-        let gen_id = match parser.parse_number().map_err(PdfError::ParseError)? {
-            PdfObj::Number(num) => num as u16,
-            _ => {
-                return Err(PdfError::ParseError(
-                    "Invalid generation number".to_string(),
-                ))
-            }
-        };
-        parser.skip_whitespace_and_comments();
-
-        if !parser.remaining_starts_with(b"obj") {
-            return Err(PdfError::ParseError("Missing 'obj' keyword".to_string()));
-        }
-        parser.pos += 3;
-        parser.skip_whitespace_and_comments();
-
-        // Parse object value
-        let obj_value = if parser.pos < parser.len
-            && parser.data[parser.pos] == b'<'
-            && parser.pos + 1 < parser.len
-            && parser.data[parser.pos + 1] == b'<'
-        {
-            // Dictionary object - don't advance, let parse_dictionary handle it
-            let dict_obj = parser.parse_dictionary().map_err(PdfError::ParseError)?;
-
-            parser.skip_whitespace_and_comments();
-            if parser.remaining_starts_with(b"stream") {
-                // Handle stream - this is where we handle it inline
-                parser.pos += 6;
-
-                // Skip EOL after stream
-                if parser.pos < parser.len && parser.data[parser.pos] == b'\r' {
-                    parser.pos += 1;
-                    if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-                        parser.pos += 1;
-                    }
-                } else if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-                    parser.pos += 1;
-                }
-
-                let stream_start = parser.pos;
-
-                // Find endstream
-                let search_term = b"endstream";
-                let search_len = search_term.len();
-
-                // Try to use Length if available
-                let stream_data = if let Some(PdfObj::Number(length)) = dict_obj.get("Length") {
-                    let length = *length as usize;
-                    if stream_start + length <= parser.len {
-                        parser.pos = stream_start + length;
-                        let data_end = stream_start + length;
-
-                        // Skip whitespace before endstream
-                        while parser.pos > stream_start
-                            && parser.data[parser.pos - 1].is_ascii_whitespace()
-                        {
-                            parser.pos -= 1;
-                        }
-                        parser.skip_whitespace_and_comments();
-                        if !parser.remaining_starts_with(search_term) {
-                            return Err(PdfError::ParseError("Missing 'endstream'".to_string()));
-                        }
-                        parser.data[stream_start..data_end].to_vec()
-                    } else {
-                        // Length is wrong, search for endstream
-                        search_for_endstream(&parser, stream_start, search_term)?
-                    }
-                } else {
-                    // No length, search for endstream
-                    search_for_endstream(&parser, stream_start, search_term)?
-                };
-
-                parser.pos += search_len;
-                parser.skip_whitespace_and_comments();
-                if !parser.remaining_starts_with(b"endobj") {
-                    return Err(PdfError::ParseError(
-                        "Missing 'endobj' after stream".to_string(),
-                    ));
-                }
-                parser.pos += 6;
-
-                let stream_obj = PdfStream {
-                    dict: dict_obj,
-                    data: stream_data,
-                };
-
-                // Check if this is an object stream and parse it
-                if let Some(PdfObj::Name(t)) = stream_obj.dict.get("Type") {
-                    if t == "ObjStm" {
-                        if let (Some(PdfObj::Number(first)), Some(PdfObj::Number(n))) =
-                            (stream_obj.dict.get("First"), stream_obj.dict.get("N"))
-                        {
-                            // Decompress and parse the object stream
-                            if let Ok(decompressed) =
-                                handle_stream_filters(&stream_obj.dict, &stream_obj.data)
-                            {
-                                parse_obj_stream(
-                                    &decompressed,
-                                    *first as usize,
-                                    *n as usize,
-                                    &mut objects,
-                                )?;
-                            }
-                        }
-                    }
-                }
-
-                PdfObj::Stream(stream_obj)
-            } else {
-                // Just a dictionary
-                parser.skip_whitespace_and_comments();
-                if !parser.remaining_starts_with(b"endobj") {
-                    return Err(PdfError::ParseError(
-                        "Missing 'endobj' for dictionary object".to_string(),
-                    ));
-                }
-                parser.pos += 6;
-                PdfObj::Dictionary(dict_obj)
-            }
-        } else {
-            // Other value type
-            let value_obj = parser.parse_value().map_err(PdfError::ParseError)?;
-            parser.skip_whitespace_and_comments();
-            if !parser.remaining_starts_with(b"endobj") {
-                return Err(PdfError::ParseError(
-                    "Missing 'endobj' for object".to_string(),
-                ));
-            }
-            parser.pos += 6;
-            value_obj
-        };
-
-        objects.insert((obj_id, gen_id), obj_value);
-    }
-
-    // Find trailer or cross-reference stream
-    let mut trailer_dict = None;
-
-    // First check if we have a traditional trailer
-    if parser.remaining_starts_with(b"trailer") {
-        parser.pos += 7; // Skip "trailer"
-        parser.skip_whitespace_and_comments();
-        trailer_dict = Some(parser.parse_dictionary().map_err(PdfError::ParseError)?);
-    } else {
-        // Search for trailer backwards
-        let data_bytes = parser.data;
-        for i in (0..data_bytes.len().saturating_sub(7)).rev() {
-            if data_bytes[i..].starts_with(b"trailer") {
-                parser.pos = i + 7; // Skip "trailer"
-                parser.skip_whitespace_and_comments();
-                trailer_dict = Some(parser.parse_dictionary().map_err(PdfError::ParseError)?);
-                break;
-            }
-        }
-    }
+type PdfParseResult<'a> = (Vec<PageContent>, Document<'a>);
 
-    // If no traditional trailer found, look for cross-reference stream
-    let trailer_dict = if let Some(dict) = trailer_dict {
-        dict
-    } else {
-        // Look for a cross-reference stream object
-        // These have Type/XRef and contain the trailer dictionary
-        let mut xref_stream_dict = None;
-        let mut xref_stream_data = None;
-
-        for ((_id, _gen), obj) in objects.iter() {
-            if let PdfObj::Stream(stream) = obj {
-                if let Some(PdfObj::Name(type_name)) = stream.dict.get("Type") {
-                    if type_name == "XRef" {
-                        xref_stream_dict = Some(stream.dict.clone());
-                        xref_stream_data = Some((stream.dict.clone(), stream.data.clone()));
-                        break;
-                    }
-                }
-            }
-        }
-
-        // If we found an XRef stream, parse it to get more objects
-        if let Some((xref_dict, xref_data)) = xref_stream_data {
-            // Parse the cross-reference stream to get object offsets
-            let xref_stream = PdfStream {
-                dict: xref_dict,
-                data: xref_data,
-            };
-            parse_xref_stream(&mut objects, parser.data, &xref_stream)?;
-        }
-
-        xref_stream_dict.ok_or(PdfError::ParseError(alloc::format!(
-            "No trailer or cross-reference stream found. Parsed {} objects",
-            objects.len()
-        )))?
-    };
-
-    // Debug: log how many objects we parsed
-    let _obj_count = objects.len();
-
-    // Get root reference
-    let root_ref = match trailer_dict.get("Root") {
-        Some(PdfObj::Reference(r)) => r,
-        _ => {
-            return Err(PdfError::ParseError(alloc::format!(
-                "No Root in trailer. Trailer: {trailer_dict:?}"
-            )))
-        }
-    };
-
-    // Debug: log object count
-    // Only enable for debugging
-    // if obj_count == 0 {
-    //     return Err(PdfError::ParseError(alloc::format!("No objects parsed. Found XRef stream: {}", xref_stream_dict.is_some())));
-    // }
-
-    // Now find pages using the existing page tree parser
-    let pages = parse_page_tree(&objects, root_ref)?;
-
-    Ok((pages, objects))
-}
-
-fn search_for_endstream(
-    parser: &Parser,
-    stream_start: usize,
-    search_term: &[u8],
-) -> Result<Vec<u8>, PdfError> {
-    let search_len = search_term.len();
-    let mut endstream_index = None;
-    let mut i = stream_start;
-
-    while i + search_len <= parser.len {
-        if &parser.data[i..i + search_len] == search_term {
-            // Check context
-            let prev_ok = if i == 0 {
-                true
-            } else {
-                let prev = parser.data[i - 1];
-                prev == b'\n' || prev == b'\r' || prev.is_ascii_whitespace()
-            };
-            let next_ok = if i + search_len >= parser.len
-                || parser.data[i + search_len..].starts_with(b"endobj")
-            {
-                true
-            } else {
-                let next = parser.data[i + search_len];
-                next.is_ascii_whitespace()
-            };
-            if prev_ok && next_ok {
-                endstream_index = Some(i);
-                break;
-            }
-        }
-        i += 1;
-    }
-
-    let end_idx = endstream_index.ok_or(PdfError::ParseError("Missing 'endstream'".to_string()))?;
-    let mut data_end = end_idx;
-
-    // Trim trailing whitespace
-    while data_end > stream_start && parser.data[data_end - 1].is_ascii_whitespace() {
-        data_end -= 1;
-    }
-
-    Ok(parser.data[stream_start..data_end].to_vec())
+/// Pages and objects of a document. [`Document::parse`] keeps them together.
+pub fn parse_pdf(data: &[u8]) -> Result<PdfParseResult<'_>, PdfError> {
+    let mut document = Document::parse(data)?;
+    let pages = document.take_pages();
+    Ok((pages, document))
 }
 
-fn parse_page_tree(
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+pub(crate) fn parse_page_tree(
+    objects: &Document,
     root_ref: &(u32, u16),
 ) -> Result<Vec<PageContent>, PdfError> {
     let root = resolve_reference(objects, root_ref).ok_or_else(|| {
         PdfError::ParseError(alloc::format!(
-            "Could not resolve root reference {:?}. Available objects: {:?}",
-            root_ref,
-            objects.keys().collect::<Vec<_>>()
+            "Could not resolve root reference {root_ref:?}"
         ))
     })?;
 
@@ -739,23 +629,64 @@ fn parse_page_tree(
         pages_ref,
         &mut pages,
         &mut visited,
+        &mut Vec::new(),
         &BTreeMap::new(),
+        &PageBoxes::default(),
     )?;
 
     Ok(pages)
 }
 
+/// Page boundaries and rotation, which a page inherits from its `Pages` ancestors
+#[derive(Debug, Clone, Copy, Default)]
+struct PageBoxes {
+    media_box: Option<Rect>,
+    crop_box: Option<Rect>,
+    rotate: Option<u16>,
+}
+
+impl PageBoxes {
+    /// Override the inherited values with those set on `dict`
+    fn merge(&self, dict: &BTreeMap<String, PdfObj>, objects: &Document) -> Self {
+        let get = |key: &str| match dict.get(key) {
+            Some(PdfObj::Reference(r)) => resolve_reference(objects, r),
+            other => other,
+        };
+        Self {
+            media_box: get("MediaBox").and_then(Rect::from_obj).or(self.media_box),
+            crop_box: get("CropBox").and_then(Rect::from_obj).or(self.crop_box),
+            rotate: match get("Rotate") {
+                Some(PdfObj::Integer(degrees)) => Some(normalize_rotation(*degrees)),
+                _ => self.rotate,
+            },
+        }
+    }
+}
+
 fn collect_pages(
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
     page_ref: &(u32, u16),
     pages: &mut Vec<PageContent>,
     visited: &mut BTreeSet<(u32, u16)>,
+    ancestors: &mut Vec<(u32, u16)>,
     inherited_resources: &BTreeMap<String, PdfObj>,
+    inherited_boxes: &PageBoxes,
 ) -> Result<(), PdfError> {
-    if visited.contains(page_ref) {
+    if ancestors.contains(page_ref) {
+        return Err(PdfError::ParseError(alloc::format!(
+            "Page tree contains a cycle through {page_ref:?}"
+        )));
+    }
+    if !visited.insert(*page_ref) {
+        // Listed twice in the tree, collected the first time
         return Ok(());
     }
-    visited.insert(*page_ref);
+    if ancestors.len() >= objects.limits().max_nesting_depth {
+        return Err(PdfError::LimitExceeded(alloc::format!(
+            "Page tree nested deeper than {}",
+            objects.limits().max_nesting_depth
+        )));
+    }
 
     let page_obj = resolve_reference(objects, page_ref).ok_or_else(|| {
         PdfError::ParseError(alloc::format!(
@@ -768,116 +699,46 @@ fn collect_pages(
         _ => return Err(PdfError::ParseError("Page is not a dictionary".to_string())),
     };
 
+    // Some writers leave out `/Type`; only `Pages` nodes have kids
     let page_type = match page_dict.get("Type") {
         Some(PdfObj::Name(name)) => name.as_str(),
-        _ => "",
+        _ if page_dict.contains_key("Kids") => "Pages",
+        _ => "Page",
     };
 
+    let resources = inherit_resources(inherited_resources, page_dict, objects);
+    let boxes = inherited_boxes.merge(page_dict, objects);
+
     match page_type {
         "Page" => {
             let mut page_content = PageContent::new();
+            page_content.id = Some(*page_ref);
 
-            // Merge inherited and local resources
-            let mut resources = inherited_resources.clone();
-            if let Some(PdfObj::Dictionary(local_res)) = page_dict.get("Resources") {
-                for (k, v) in local_res {
-                    resources.insert(k.clone(), v.clone());
-                }
-            } else if let Some(PdfObj::Reference(res_ref)) = page_dict.get("Resources") {
-                if let Some(PdfObj::Dictionary(res_dict)) = resolve_reference(objects, res_ref) {
-                    for (k, v) in res_dict {
-                        resources.insert(k.clone(), v.clone());
-                    }
-                }
-            }
-
+            page_content.media_box = boxes.media_box.unwrap_or(Rect::LETTER);
+            page_content.crop_box = boxes.crop_box.unwrap_or(page_content.media_box);
+            page_content.rotate = boxes.rotate.unwrap_or(0);
             page_content.resources = resources;
 
-            // Extract fonts
-            match page_content.resources.get("Font") {
-                Some(PdfObj::Dictionary(font_dict)) => {
-                    page_content.fonts = crate::font::extract_fonts(font_dict, objects);
-                }
-                Some(PdfObj::Reference(font_ref)) => {
-                    if let Some(PdfObj::Dictionary(font_dict)) =
-                        resolve_reference(objects, font_ref)
-                    {
-                        page_content.fonts = crate::font::extract_fonts(font_dict, objects);
-                    }
-                }
-                _ => {}
-            }
-
-            // Extract content streams
-            match page_dict.get("Contents") {
-                Some(PdfObj::Reference(content_ref)) => {
-                    if let Some(content_obj) = resolve_reference(objects, content_ref) {
-                        match content_obj {
-                            PdfObj::Stream(stream) => {
-                                let decompressed =
-                                    handle_stream_filters(&stream.dict, &stream.data)
-                                        .map_err(PdfError::ParseError)?;
-                                page_content.content_streams.push(decompressed);
-                            }
-                            PdfObj::Array(arr) => {
-                                for item in arr {
-                                    if let PdfObj::Reference(stream_ref) = item {
-                                        if let Some(PdfObj::Stream(stream)) =
-                                            resolve_reference(objects, stream_ref)
-                                        {
-                                            let decompressed =
-                                                handle_stream_filters(&stream.dict, &stream.data)
-                                                    .map_err(PdfError::ParseError)?;
-                                            page_content.content_streams.push(decompressed);
-                                        }
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                Some(PdfObj::Array(contents)) => {
-                    for content in contents {
-                        if let PdfObj::Reference(content_ref) = content {
-                            if let Some(PdfObj::Stream(stream)) =
-                                resolve_reference(objects, content_ref)
-                            {
-                                let decompressed =
-                                    handle_stream_filters(&stream.dict, &stream.data)
-                                        .map_err(PdfError::ParseError)?;
-                                page_content.content_streams.push(decompressed);
-                            }
-                        }
-                    }
-                }
-                _ => {}
-            }
+            // Content streams and fonts are read when the page is extracted
+            page_content.contents = page_dict.get("Contents").cloned();
 
             pages.push(page_content);
         }
         "Pages" => {
-            // Get resources to inherit
-            let mut new_inherited = inherited_resources.clone();
-            if let Some(PdfObj::Dictionary(res)) = page_dict.get("Resources") {
-                for (k, v) in res {
-                    new_inherited.insert(k.clone(), v.clone());
-                }
-            } else if let Some(PdfObj::Reference(res_ref)) = page_dict.get("Resources") {
-                if let Some(PdfObj::Dictionary(res_dict)) = resolve_reference(objects, res_ref) {
-                    for (k, v) in res_dict {
-                        new_inherited.insert(k.clone(), v.clone());
-                    }
-                }
-            }
-
-            // Process kids
-            if let Some(PdfObj::Array(kids)) = page_dict.get("Kids") {
+            let kids = match page_dict.get("Kids") {
+                Some(PdfObj::Reference(r)) => resolve_reference(objects, r),
+                other => other,
+            };
+            if let Some(PdfObj::Array(kids)) = kids {
+                ancestors.push(*page_ref);
                 for kid in kids {
                     if let PdfObj::Reference(kid_ref) = kid {
-                        collect_pages(objects, kid_ref, pages, visited, &new_inherited)?;
+                        collect_pages(
+                            objects, kid_ref, pages, visited, ancestors, &resources, &boxes,
+                        )?;
                     }
                 }
+                ancestors.pop();
             }
         }
         _ => {}
@@ -886,247 +747,77 @@ fn collect_pages(
     Ok(())
 }
 
-pub fn resolve_reference<'a>(
-    objects: &'a BTreeMap<(u32, u16), PdfObj>,
-    reference: &(u32, u16),
-) -> Option<&'a PdfObj> {
-    objects.get(reference)
-}
-
-fn parse_xref_stream(
-    objects: &mut BTreeMap<(u32, u16), PdfObj>,
-    pdf_data: &[u8],
-    xref_stream: &PdfStream,
-) -> Result<(), PdfError> {
-    // Get the W array which describes field widths
-    let w_array = match xref_stream.dict.get("W") {
-        Some(PdfObj::Array(arr)) => arr,
-        _ => {
-            return Err(PdfError::ParseError(
-                "XRef stream missing W array".to_string(),
-            ))
-        }
+/// Resources of a page tree node: those it inherits, overridden by its own
+/// `/Resources`. The spec has a node's `/Resources` replace the inherited
+/// dictionary as a whole. Categories such as `/Font` are merged by resource
+/// name instead, so a node adding a font keeps the fonts of its ancestors.
+/// Names the node defines itself still win, which gives conforming files the
+/// same resources as replacing would, and lets files that forget to repeat
+/// their ancestors' resources still find them.
+fn inherit_resources(
+    inherited: &BTreeMap<String, PdfObj>,
+    dict: &BTreeMap<String, PdfObj>,
+    objects: &Document,
+) -> BTreeMap<String, PdfObj> {
+    let resolve = |obj: &PdfObj| match obj {
+        PdfObj::Reference(r) => resolve_reference(objects, r).cloned(),
+        obj => Some(obj.clone()),
     };
-
-    if w_array.len() != 3 {
-        return Err(PdfError::ParseError(
-            "XRef stream W array must have 3 elements".to_string(),
-        ));
-    }
-
-    let w: Vec<usize> = w_array
-        .iter()
-        .map(|obj| match obj {
-            PdfObj::Number(n) => *n as usize,
-            _ => 0,
-        })
-        .collect();
-
-    // Get the Index array (if present) or use default [0, Size]
-    let index_array = match xref_stream.dict.get("Index") {
-        Some(PdfObj::Array(arr)) => {
-            let mut indices = Vec::new();
-            for i in (0..arr.len()).step_by(2) {
-                if let (Some(PdfObj::Number(start)), Some(PdfObj::Number(count))) =
-                    (arr.get(i), arr.get(i + 1))
-                {
-                    indices.push((*start as u32, *count as u32));
-                }
-            }
-            indices
-        }
-        _ => {
-            // Default to [0, Size]
-            match xref_stream.dict.get("Size") {
-                Some(PdfObj::Number(size)) => vec![(0, *size as u32)],
-                _ => vec![(0, 0)],
-            }
-        }
+    let mut resources = inherited.clone();
+    let Some(PdfObj::Dictionary(local)) = dict.get("Resources").and_then(resolve) else {
+        return resources;
     };
-
-    // Decompress the stream data
-    let decompressed_data = handle_stream_filters(&xref_stream.dict, &xref_stream.data)
-        .map_err(PdfError::ParseError)?;
-
-    // Parse entries
-    let entry_size = w[0] + w[1] + w[2];
-    let mut data_pos = 0;
-
-    for (start_obj_num, count) in index_array {
-        for i in 0..count {
-            if data_pos + entry_size > decompressed_data.len() {
-                break;
+    for (category, value) in local {
+        let merged = match (resources.get(&category).and_then(resolve), resolve(&value)) {
+            (Some(PdfObj::Dictionary(mut outer)), Some(PdfObj::Dictionary(inner))) => {
+                outer.extend(inner);
+                PdfObj::Dictionary(outer)
             }
-
-            let obj_num = start_obj_num + i;
-            let entry_data = &decompressed_data[data_pos..data_pos + entry_size];
-            data_pos += entry_size;
-
-            // Parse entry fields
-            let mut field_pos = 0;
-
-            // Field 1: Type (default 1 if w[0] == 0)
-            let entry_type = if w[0] == 0 {
-                1
-            } else {
-                let mut val = 0u64;
-                for j in 0..w[0] {
-                    val = (val << 8) | (entry_data[field_pos + j] as u64);
-                }
-                field_pos += w[0];
-                val
-            };
-
-            // Field 2: Offset or object number
-            let mut field2 = 0u64;
-            for j in 0..w[1] {
-                field2 = (field2 << 8) | (entry_data[field_pos + j] as u64);
-            }
-            field_pos += w[1];
-
-            // Field 3: Generation or index
-            let mut field3 = 0u64;
-            for j in 0..w[2] {
-                field3 = (field3 << 8) | (entry_data[field_pos + j] as u64);
-            }
-
-            // Process based on type
-            match entry_type {
-                1 => {
-                    // Type 1: In-use object
-                    let offset = field2 as usize;
-                    let gen = field3 as u16;
-
-                    // Parse the object at this offset
-                    if offset < pdf_data.len() {
-                        let mut obj_parser = Parser::new(&pdf_data[offset..]);
-
-                        // Parse object header
-                        if let Ok(PdfObj::Number(parsed_num)) = obj_parser.parse_number() {
-                            if parsed_num as u32 == obj_num {
-                                obj_parser.skip_whitespace();
-                                if let Ok(PdfObj::Number(_)) = obj_parser.parse_number() {
-                                    obj_parser.skip_whitespace();
-                                    if obj_parser.remaining_starts_with(b"obj") {
-                                        obj_parser.pos += 3;
-                                        obj_parser.skip_whitespace_and_comments();
-
-                                        // Parse the object value
-                                        if let Ok(obj_value) = parse_object_value(&mut obj_parser) {
-                                            objects.insert((obj_num, gen), obj_value);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                2 => {
-                    // Type 2: Compressed object
-                    // These are stored in object streams, not supported yet
-                }
-                _ => {}
-            }
-        }
+            _ => value,
+        };
+        resources.insert(category, merged);
     }
-
-    Ok(())
+    resources
 }
 
-fn parse_object_value(parser: &mut Parser) -> Result<PdfObj, String> {
-    // Check if it's a dictionary that might be a stream
-    if parser.peek() == Some(b'<') && parser.data.get(parser.pos + 1) == Some(&b'<') {
-        let dict = parser.parse_dictionary()?;
-        parser.skip_whitespace_and_comments();
-
-        if parser.remaining_starts_with(b"stream") {
-            // Parse stream
-            parser.pos += 6;
-
-            // Skip EOL after stream
-            if parser.pos < parser.len && parser.data[parser.pos] == b'\r' {
-                parser.pos += 1;
-                if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-                    parser.pos += 1;
-                }
-            } else if parser.pos < parser.len && parser.data[parser.pos] == b'\n' {
-                parser.pos += 1;
-            }
+pub fn resolve_reference<'a>(objects: &'a Document, reference: &(u32, u16)) -> Option<&'a PdfObj> {
+    objects.get(*reference)
+}
 
-            let stream_start = parser.pos;
-
-            // Find endstream
-            let search_term = b"endstream";
-            let stream_data =
-                search_for_endstream(parser, stream_start, search_term).map_err(|e| match e {
-                    PdfError::ParseError(s) => s,
-                    _ => "Stream parsing error".to_string(),
-                })?;
-            parser.pos += search_term.len();
-
-            Ok(PdfObj::Stream(PdfStream {
-                dict,
-                data: stream_data,
-            }))
-        } else {
-            Ok(PdfObj::Dictionary(dict))
-        }
-    } else {
-        parser.parse_object()
-    }
+/// `obj` itself, or the object it refers to
+pub(crate) fn resolve<'a>(obj: Option<&'a PdfObj>, objects: &'a Document) -> Option<&'a PdfObj> {
+    objects.resolve(obj?)
 }
 
-fn parse_obj_stream(
-    data: &[u8],
-    first: usize,
-    count: usize,
-    objects: &mut BTreeMap<(u32, u16), PdfObj>,
-) -> Result<(), PdfError> {
-    let mut parser = Parser::new(data);
-    let mut headers = Vec::new();
-
-    // Parse headers
-    for i in 0..count {
-        parser.skip_whitespace_and_comments();
-        let obj_num = match parser.parse_number() {
-            Ok(PdfObj::Number(n)) => n as u32,
-            _ => {
-                return Err(PdfError::ParseError(alloc::format!(
-                    "Invalid object number in ObjStm at index {}, pos: {}",
-                    i,
-                    parser.pos
-                )))
-            }
-        };
-        parser.skip_whitespace_and_comments();
-        let offset = match parser.parse_number() {
-            Ok(PdfObj::Number(n)) => n as usize,
-            _ => {
-                return Err(PdfError::ParseError(
-                    "Invalid object offset in ObjStm".to_string(),
-                ))
-            }
-        };
-        headers.push((obj_num, offset));
+/// Decode a text string (PDF 32000-1 section 7.9.2.2): UTF-16BE or UTF-8
+/// after a byte order mark, PDFDocEncoding otherwise
+pub(crate) fn decode_text_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+        return char::decode_utf16(units)
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
     }
-
-    // Parse objects
-    for i in 0..count {
-        let start = first + headers[i].1;
-        let end = if i + 1 < count {
-            first + headers[i + 1].1
-        } else {
-            data.len()
-        };
-
-        if start < data.len() && end <= data.len() && start < end {
-            let mut sub_parser = Parser::new(&data[start..end]);
-            if let Ok(value) = sub_parser.parse_value() {
-                // Objects in streams always have generation 0
-                objects.insert((headers[i].0, 0), value);
-            }
-        }
+    if let Some(utf8) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8_lossy(utf8).into_owned();
     }
+    bytes.iter().map(|&b| pdf_doc_char(b)).collect()
+}
 
-    Ok(())
+/// PDFDocEncoding (PDF 32000-1 annex D.2), which differs from Latin-1 only
+/// at 0x18..=0x1F and 0x7F..=0xA0
+fn pdf_doc_char(byte: u8) -> char {
+    const LOW: [char; 8] = ['˘', 'ˇ', 'ˆ', '˙', '˝', '˛', '˚', '˜'];
+    const HIGH: [char; 34] = [
+        '�', '•', '†', '‡', '…', '—', '–', 'ƒ', '⁄', '‹', '›', '−', '‰', '„', '“', '”', '‘', '’',
+        '‚', '™', 'ﬁ', 'ﬂ', 'Ł', 'Œ', 'Š', 'Ÿ', 'Ž', 'ı', 'ł', 'œ', 'š', 'ž', '�', '€',
+    ];
+    match byte {
+        0x18..=0x1F => LOW[(byte - 0x18) as usize],
+        0x7F..=0xA0 => HIGH[(byte - 0x7F) as usize],
+        0xAD => '�',
+        _ => byte as char,
+    }
 }
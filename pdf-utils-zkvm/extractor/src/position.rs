@@ -0,0 +1,265 @@
+//! Text state tracking for positioned extraction.
+//!
+//! Follows the model of PDF 32000-1 section 9.4: the graphics state carries the
+//! CTM and the text state parameters, a text object carries the text matrix and
+//! text line matrix. Every text-showing operator produces a [`TextRun`] located
+//! in default user space.
+
+use crate::font::{PdfFont, DEFAULT_GLYPH_WIDTH};
+use crate::page::Rect;
+use crate::parser::PdfObj;
+use alloc::string::String;
+
+/// A piece of text shown by a single string operand, in user space units
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextRun {
+    pub text: String,
+    /// Origin of the first glyph on the baseline (including text rise)
+    pub x: f32,
+    pub y: f32,
+    /// Distance the text position advanced while showing the run
+    pub width: f32,
+    /// Font size after applying the text matrix and CTM
+    pub font_size: f32,
+    /// `BaseFont` of the font, or its resource name when it could not be resolved
+    pub font_name: String,
+    /// Marked-content identifier of the innermost enclosing sequence that has
+    /// one, linking the run to the structure tree
+    pub mcid: Option<u32>,
+    /// Part of the page the text was found in
+    pub source: TextSource,
+}
+
+/// Where on a page a piece of text comes from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextSource {
+    /// The page's content streams
+    #[default]
+    Page,
+    /// An annotation's `/Contents`
+    AnnotationContents,
+    /// An annotation's rich text (`/RC`), with the markup removed
+    AnnotationRichText,
+    /// An annotation's normal appearance stream
+    AnnotationAppearance,
+}
+
+/// Ascent and descent assumed for run bounding boxes, as fractions of the font size
+const ASCENT: f32 = 0.8;
+const DESCENT: f32 = 0.2;
+
+impl TextRun {
+    /// Approximate bounding box of the run, assuming horizontal text
+    pub fn bbox(&self) -> Rect {
+        Rect::new(
+            self.x,
+            self.y - DESCENT * self.font_size,
+            self.x + self.width,
+            self.y + ASCENT * self.font_size,
+        )
+    }
+
+    /// Grow the run to also cover `other`, shown after it
+    pub(crate) fn extend(&mut self, other: &TextRun) {
+        self.text.push_str(&other.text);
+        if (other.y - self.y).abs() <= f32::EPSILON * self.y.abs().max(1.0) {
+            self.width = self.width.max(other.x + other.width - self.x);
+        } else {
+            self.width += other.width;
+        }
+    }
+}
+
+/// Affine transform `[a b c d e f]` applied to row vectors, as in the PDF spec
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Matrix {
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    pub const fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    /// Parse a PDF matrix array `[a b c d e f]`
+    pub fn from_obj(obj: &PdfObj) -> Option<Self> {
+        match obj {
+            PdfObj::Array(values) if values.len() == 6 => {
+                let mut m = [0.0; 6];
+                for (value, obj) in m.iter_mut().zip(values) {
+                    *value = obj.as_number()?;
+                }
+                Some(Self::new(m[0], m[1], m[2], m[3], m[4], m[5]))
+            }
+            _ => None,
+        }
+    }
+
+    pub const fn translate(tx: f32, ty: f32) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// `self × other`: apply `self` first, then `other`
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            e: self.e * other.a + self.f * other.c + other.e,
+            f: self.e * other.b + self.f * other.d + other.f,
+        }
+    }
+
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            x * self.a + y * self.c + self.e,
+            x * self.b + y * self.d + self.f,
+        )
+    }
+
+    /// Transform a displacement, ignoring the translation part
+    pub fn transform_vector(&self, x: f32, y: f32) -> (f32, f32) {
+        (x * self.a + y * self.c, x * self.b + y * self.d)
+    }
+}
+
+/// Text state parameters (PDF 32000-1 table 104), saved and restored with `q`/`Q`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextState<'a> {
+    pub char_spacing: f32,
+    pub word_spacing: f32,
+    /// `Tz` divided by 100
+    pub horizontal_scaling: f32,
+    pub leading: f32,
+    pub font_size: f32,
+    pub rise: f32,
+    pub font: Option<&'a PdfFont>,
+    pub font_resource: Option<&'a str>,
+}
+
+impl Default for TextState<'_> {
+    fn default() -> Self {
+        Self {
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            horizontal_scaling: 1.0,
+            leading: 0.0,
+            font_size: 0.0,
+            rise: 0.0,
+            font: None,
+            font_resource: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GraphicsState<'a> {
+    pub ctm: Matrix,
+    pub text: TextState<'a>,
+}
+
+impl<'a> GraphicsState<'a> {
+    pub fn new(ctm: Matrix) -> Self {
+        Self {
+            ctm,
+            text: TextState::default(),
+        }
+    }
+}
+
+/// Matrices of the current text object, reset by `BT`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextObject {
+    pub matrix: Matrix,
+    pub line_matrix: Matrix,
+}
+
+impl TextObject {
+    pub fn new() -> Self {
+        Self {
+            matrix: Matrix::IDENTITY,
+            line_matrix: Matrix::IDENTITY,
+        }
+    }
+
+    /// `Tm`
+    pub fn set_matrix(&mut self, matrix: Matrix) {
+        self.matrix = matrix;
+        self.line_matrix = matrix;
+    }
+
+    /// `Td`, also the basis of `TD`, `T*`, `'` and `"`
+    pub fn next_line(&mut self, tx: f32, ty: f32) {
+        self.line_matrix = Matrix::translate(tx, ty).multiply(&self.line_matrix);
+        self.matrix = self.line_matrix;
+    }
+
+    /// Move the text position along the baseline by `tx` text space units
+    pub fn advance(&mut self, tx: f32) {
+        self.matrix = Matrix::translate(tx, 0.0).multiply(&self.matrix);
+    }
+
+    /// Show the glyphs `codes`, decoded as `text`, and advance past them
+    pub fn show(&mut self, text: String, codes: &[u32], gs: &GraphicsState) -> TextRun {
+        let state = &gs.text;
+        let device = self.matrix.multiply(&gs.ctm);
+        let (x, y) = device.transform_point(0.0, state.rise);
+
+        let mut advance = 0.0;
+        for &code in codes {
+            let glyph_width = state
+                .font
+                .map_or(DEFAULT_GLYPH_WIDTH, |font| font.glyph_width(code))
+                / 1000.0;
+            let mut tx = glyph_width * state.font_size + state.char_spacing;
+            if code == 32 && state.font.is_none_or(|font| !font.is_cid()) {
+                tx += state.word_spacing;
+            }
+            advance += tx * state.horizontal_scaling;
+        }
+        self.advance(advance);
+
+        let (dx, dy) = device.transform_vector(advance, 0.0);
+        let (sx, sy) = device.transform_vector(0.0, state.font_size);
+
+        TextRun {
+            text,
+            x,
+            y,
+            width: length(dx, dy),
+            font_size: length(sx, sy),
+            font_name: match (state.font, state.font_resource) {
+                (Some(font), _) => font.base_font.clone(),
+                (None, Some(name)) => String::from(name),
+                (None, None) => String::new(),
+            },
+            mcid: None,
+            source: TextSource::Page,
+        }
+    }
+}
+
+/// Euclidean length without `std`, using Newton's method
+fn length(x: f32, y: f32) -> f32 {
+    let square = x * x + y * y;
+    if square == 0.0 {
+        return 0.0;
+    }
+    let mut root = if square > 1.0 { square / 2.0 } else { 1.0 };
+    for _ in 0..32 {
+        let next = (root + square / root) / 2.0;
+        if (next - root).abs() <= f32::EPSILON * next {
+            return next;
+        }
+        root = next;
+    }
+    root
+}
@@ -0,0 +1,664 @@
+//! Standard security handler (PDF 32000-1 section 7.6, ISO 32000-2 section
+//! 7.6.4).
+//!
+//! An encrypted document names an encryption dictionary in its trailer's
+//! `/Encrypt`. The strings and stream data of every indirect object are
+//! encrypted with a key derived from the file key and the object's number and
+//! generation: RC4 up to revision 4 (`/V` 1 and 2, or crypt filter `/V2`),
+//! AES-128 in CBC mode for the `/AESV2` crypt filter. Revisions 5 and 6 use
+//! AES-256 (`/AESV3`) with the file key itself. The file key is computed from
+//! a password, checked against `/U` (user password) and then `/O` (owner
+//! password). Most documents can be opened with the empty user password.
+//!
+//! Objects inside object streams are not encrypted themselves, the object
+//! stream is. Neither are the encryption dictionary, cross-reference streams
+//! and, when `/EncryptMetadata` is false, metadata streams.
+
+use crate::document::ObjRef;
+use crate::parser::PdfObj;
+use crate::PdfError;
+use aes::cipher::consts::U16;
+use aes::cipher::{Block, BlockCipherDecrypt, BlockCipherEncrypt, BlockSizeUser, KeyInit};
+use aes::{Aes128, Aes256};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use md5::{Digest, Md5};
+use sha2::{Sha256, Sha384, Sha512};
+
+/// Padding appended to passwords up to revision 4 (Algorithm 2, step a)
+const PASSWORD_PADDING: [u8; 32] = [
+    0x28, 0xBF, 0x4E, 0x5E, 0x4E, 0x75, 0x8A, 0x41, 0x64, 0x00, 0x4E, 0x56, 0xFF, 0xFA, 0x01, 0x08,
+    0x2E, 0x2E, 0x00, 0xB6, 0xD0, 0x68, 0x3E, 0x80, 0x2F, 0x0C, 0xA9, 0xFE, 0x64, 0x53, 0x69, 0x7A,
+];
+
+/// Longest password used by revisions 5 and 6, in bytes of UTF-8
+const MAX_PASSWORD_LEN: usize = 127;
+
+/// How a crypt filter encrypts data
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CryptMethod {
+    /// Not encrypted
+    Identity,
+    Rc4,
+    /// AES-128 in CBC mode with a per-object key
+    AesV2,
+    /// AES-256 in CBC mode with the file key
+    AesV3,
+}
+
+/// Decrypts the objects of a document opened with a password
+#[derive(Debug)]
+pub(crate) struct SecurityHandler {
+    /// The file key
+    key: Vec<u8>,
+    /// Method for strings (`/StrF`)
+    strings: CryptMethod,
+    /// Method for streams (`/StmF`)
+    streams: CryptMethod,
+    /// Crypt filters by name, for streams that choose one with a `/Crypt`
+    /// filter
+    filters: BTreeMap<String, CryptMethod>,
+    encrypt_metadata: bool,
+    /// The encryption dictionary, which is not encrypted
+    dictionary: Option<ObjRef>,
+}
+
+impl SecurityHandler {
+    /// Open the encryption dictionary `encrypt` with `password`, tried as the
+    /// user password and then as the owner password. `id` is the first
+    /// element of the trailer's `/ID` and `dictionary` the reference of
+    /// `encrypt` when it is an indirect object.
+    pub(crate) fn new(
+        encrypt: &BTreeMap<String, PdfObj>,
+        id: &[u8],
+        password: &[u8],
+        dictionary: Option<ObjRef>,
+    ) -> Result<Self, PdfError> {
+        match encrypt.get("Filter").and_then(PdfObj::as_name) {
+            Some("Standard") => {}
+            filter => {
+                return Err(PdfError::EncryptionError(alloc::format!(
+                    "Unsupported security handler {filter:?}"
+                )))
+            }
+        }
+        let version = encrypt.get("V").and_then(PdfObj::as_int).unwrap_or(0);
+        let revision = encrypt.get("R").and_then(PdfObj::as_int).unwrap_or(0);
+        let encrypt_metadata = encrypt
+            .get("EncryptMetadata")
+            .and_then(PdfObj::as_bool)
+            .unwrap_or(true);
+
+        let (strings, streams, filters) = match version {
+            1 | 2 => (CryptMethod::Rc4, CryptMethod::Rc4, BTreeMap::new()),
+            4 | 5 => {
+                let filters = crypt_filters(encrypt)?;
+                let method = |key| match encrypt.get(key).and_then(PdfObj::as_name) {
+                    None | Some("Identity") => Ok(CryptMethod::Identity),
+                    Some(name) => filters.get(name).copied().ok_or_else(|| {
+                        PdfError::EncryptionError(alloc::format!("Unknown crypt filter {name}"))
+                    }),
+                };
+                (method("StrF")?, method("StmF")?, filters)
+            }
+            _ => {
+                return Err(PdfError::EncryptionError(alloc::format!(
+                    "Unsupported encryption version {version}"
+                )))
+            }
+        };
+
+        let key = match revision {
+            2..=4 => {
+                let length = match version {
+                    1 => 40,
+                    _ => encrypt
+                        .get("Length")
+                        .and_then(PdfObj::as_int)
+                        .unwrap_or(if version == 4 { 128 } else { 40 }),
+                };
+                if !(40..=128).contains(&length) || length % 8 != 0 {
+                    return Err(PdfError::EncryptionError(alloc::format!(
+                        "Invalid key length {length}"
+                    )));
+                }
+                let standard = Standard {
+                    revision,
+                    key_len: length as usize / 8,
+                    owner: string(encrypt, "O", 32)?,
+                    user: string(encrypt, "U", 32)?,
+                    permissions: encrypt.get("P").and_then(PdfObj::as_int).unwrap_or(0) as u32,
+                    id,
+                    encrypt_metadata,
+                };
+                standard.file_key(password)
+            }
+            5 | 6 => {
+                let advanced = Advanced {
+                    revision,
+                    owner: string(encrypt, "O", 48)?,
+                    user: string(encrypt, "U", 48)?,
+                    owner_key: string(encrypt, "OE", 32)?,
+                    user_key: string(encrypt, "UE", 32)?,
+                };
+                advanced.file_key(&password[..password.len().min(MAX_PASSWORD_LEN)])
+            }
+            _ => {
+                return Err(PdfError::EncryptionError(alloc::format!(
+                    "Unsupported security handler revision {revision}"
+                )))
+            }
+        };
+        let key = key.ok_or_else(|| {
+            PdfError::EncryptionError(String::from("Password does not open the document"))
+        })?;
+
+        Ok(Self {
+            key,
+            strings,
+            streams,
+            filters,
+            encrypt_metadata,
+            dictionary,
+        })
+    }
+
+    /// Decrypt the strings of object `id` in place. Stream data is
+    /// decrypted when it is decoded, see [`SecurityHandler::decrypt_stream`].
+    pub(crate) fn decrypt_object(&self, id: ObjRef, obj: &mut PdfObj) {
+        if self.dictionary == Some(id) {
+            return;
+        }
+        match obj {
+            PdfObj::Stream(stream) => {
+                if stream.dict.get("Type").and_then(PdfObj::as_name) == Some("XRef") {
+                    return;
+                }
+                for value in stream.dict.values_mut() {
+                    self.decrypt_strings(id, value);
+                }
+            }
+            // The signature value is not encrypted, so the signed byte ranges
+            // can be checked without the password
+            PdfObj::Dictionary(dict)
+                if matches!(
+                    dict.get("Type").and_then(PdfObj::as_name),
+                    Some("Sig" | "DocTimeStamp")
+                ) =>
+            {
+                for (key, value) in dict.iter_mut() {
+                    if key != "Contents" {
+                        self.decrypt_strings(id, value);
+                    }
+                }
+            }
+            obj => self.decrypt_strings(id, obj),
+        }
+    }
+
+    /// Decrypted data of the stream object `id` with dictionary `dict`
+    pub(crate) fn decrypt_stream(
+        &self,
+        id: ObjRef,
+        dict: &BTreeMap<String, PdfObj>,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let kind = dict.get("Type").and_then(PdfObj::as_name);
+        let method = match stream_crypt_filter(dict) {
+            _ if kind == Some("XRef") || self.dictionary == Some(id) => CryptMethod::Identity,
+            Some(name) => self.filter(name),
+            None if kind == Some("Metadata") && !self.encrypt_metadata => CryptMethod::Identity,
+            None => self.streams,
+        };
+        self.decrypt(id, method, data)
+    }
+
+    fn decrypt_strings(&self, id: ObjRef, obj: &mut PdfObj) {
+        match obj {
+            PdfObj::String(bytes) => *bytes = self.decrypt(id, self.strings, bytes),
+            PdfObj::Array(items) => {
+                for item in items {
+                    self.decrypt_strings(id, item);
+                }
+            }
+            PdfObj::Dictionary(dict) => {
+                for value in dict.values_mut() {
+                    self.decrypt_strings(id, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn filter(&self, name: &str) -> CryptMethod {
+        match name {
+            "Identity" => CryptMethod::Identity,
+            name => self
+                .filters
+                .get(name)
+                .copied()
+                .unwrap_or(CryptMethod::Identity),
+        }
+    }
+
+    fn decrypt(&self, id: ObjRef, method: CryptMethod, data: &[u8]) -> Vec<u8> {
+        match method {
+            CryptMethod::Identity => data.to_vec(),
+            CryptMethod::Rc4 => rc4(&self.object_key(id, false), data),
+            CryptMethod::AesV2 => aes_decrypt::<Aes128>(&self.object_key(id, true), data),
+            CryptMethod::AesV3 => aes_decrypt::<Aes256>(&self.key, data),
+        }
+    }
+
+    /// Key for the strings and streams of object `id` (Algorithm 1)
+    fn object_key(&self, id: ObjRef, aes: bool) -> Vec<u8> {
+        let mut hasher = Md5::new();
+        hasher.update(&self.key);
+        hasher.update(&id.0.to_le_bytes()[..3]);
+        hasher.update(id.1.to_le_bytes());
+        if aes {
+            hasher.update(b"sAlT");
+        }
+        let digest = hasher.finalize();
+        digest[..(self.key.len() + 5).min(16)].to_vec()
+    }
+}
+
+/// The named crypt filters of `/CF`
+fn crypt_filters(
+    encrypt: &BTreeMap<String, PdfObj>,
+) -> Result<BTreeMap<String, CryptMethod>, PdfError> {
+    let mut filters = BTreeMap::new();
+    let Some(PdfObj::Dictionary(entries)) = encrypt.get("CF") else {
+        return Ok(filters);
+    };
+    for (name, filter) in entries {
+        let cfm = filter
+            .as_dict()
+            .and_then(|filter| filter.get("CFM"))
+            .and_then(PdfObj::as_name);
+        let method = match cfm {
+            None | Some("None") => CryptMethod::Identity,
+            Some("V2") => CryptMethod::Rc4,
+            Some("AESV2") => CryptMethod::AesV2,
+            Some("AESV3") => CryptMethod::AesV3,
+            Some(cfm) => {
+                return Err(PdfError::EncryptionError(alloc::format!(
+                    "Unsupported crypt filter method {cfm}"
+                )))
+            }
+        };
+        filters.insert(name.clone(), method);
+    }
+    Ok(filters)
+}
+
+/// Name of the crypt filter a stream selects with a leading `/Crypt` filter
+fn stream_crypt_filter(dict: &BTreeMap<String, PdfObj>) -> Option<&str> {
+    let (filter, parms) = match dict.get("Filter")? {
+        PdfObj::Name(filter) => (filter.as_str(), dict.get("DecodeParms")),
+        PdfObj::Array(filters) => (
+            filters.first()?.as_name()?,
+            dict.get("DecodeParms")
+                .and_then(PdfObj::as_array)
+                .and_then(|parms| parms.first()),
+        ),
+        _ => return None,
+    };
+    if filter != "Crypt" {
+        return None;
+    }
+    Some(
+        parms
+            .and_then(PdfObj::as_dict)
+            .and_then(|parms| parms.get("Name"))
+            .and_then(PdfObj::as_name)
+            .unwrap_or("Identity"),
+    )
+}
+
+/// A string entry of at least `len` bytes, truncated to `len`
+fn string(dict: &BTreeMap<String, PdfObj>, key: &str, len: usize) -> Result<Vec<u8>, PdfError> {
+    match dict.get(key).and_then(PdfObj::as_bytes) {
+        Some(bytes) if bytes.len() >= len => Ok(bytes[..len].to_vec()),
+        _ => Err(PdfError::EncryptionError(alloc::format!(
+            "Encryption dictionary has no valid /{key}"
+        ))),
+    }
+}
+
+/// Revisions 2 to 4
+struct Standard<'a> {
+    revision: i64,
+    key_len: usize,
+    owner: Vec<u8>,
+    user: Vec<u8>,
+    permissions: u32,
+    id: &'a [u8],
+    encrypt_metadata: bool,
+}
+
+impl Standard<'_> {
+    fn file_key(&self, password: &[u8]) -> Option<Vec<u8>> {
+        self.user_key(password)
+            .or_else(|| self.user_key(&self.owner_to_user(password)))
+    }
+
+    /// File key from the user `password`, when `/U` confirms it
+    /// (Algorithms 2 and 6)
+    fn user_key(&self, password: &[u8]) -> Option<Vec<u8>> {
+        let mut hasher = Md5::new();
+        hasher.update(pad(password));
+        hasher.update(&self.owner);
+        hasher.update(self.permissions.to_le_bytes());
+        hasher.update(self.id);
+        if self.revision >= 4 && !self.encrypt_metadata {
+            hasher.update([0xFF; 4]);
+        }
+        let mut key = hasher.finalize()[..self.key_len].to_vec();
+        if self.revision >= 3 {
+            for _ in 0..50 {
+                key = Md5::digest(&key)[..self.key_len].to_vec();
+            }
+        }
+
+        let valid = if self.revision == 2 {
+            rc4(&key, &PASSWORD_PADDING) == self.user
+        } else {
+            let mut hasher = Md5::new();
+            hasher.update(PASSWORD_PADDING);
+            hasher.update(self.id);
+            let mut check = hasher.finalize().to_vec();
+            for round in 0..20u8 {
+                let round_key: Vec<u8> = key.iter().map(|byte| byte ^ round).collect();
+                check = rc4(&round_key, &check);
+            }
+            check[..16] == self.user[..16]
+        };
+        valid.then_some(key)
+    }
+
+    /// The user password `/O` holds, encrypted with the owner `password`
+    /// (Algorithm 7)
+    fn owner_to_user(&self, password: &[u8]) -> Vec<u8> {
+        let mut key = Md5::digest(pad(password)).to_vec();
+        if self.revision >= 3 {
+            for _ in 0..50 {
+                key = Md5::digest(&key).to_vec();
+            }
+        }
+        key.truncate(if self.revision == 2 { 5 } else { self.key_len });
+
+        if self.revision == 2 {
+            return rc4(&key, &self.owner);
+        }
+        let mut user = self.owner.clone();
+        for round in (0..20u8).rev() {
+            let round_key: Vec<u8> = key.iter().map(|byte| byte ^ round).collect();
+            user = rc4(&round_key, &user);
+        }
+        user
+    }
+}
+
+/// A password padded or truncated to 32 bytes
+fn pad(password: &[u8]) -> [u8; 32] {
+    let mut padded = PASSWORD_PADDING;
+    let len = password.len().min(32);
+    padded[..len].copy_from_slice(&password[..len]);
+    padded[len..].copy_from_slice(&PASSWORD_PADDING[..32 - len]);
+    padded
+}
+
+/// Revisions 5 and 6. `/U` and `/O` hold a 32-byte hash, an 8-byte
+/// validation salt and an 8-byte key salt.
+struct Advanced {
+    revision: i64,
+    owner: Vec<u8>,
+    user: Vec<u8>,
+    owner_key: Vec<u8>,
+    user_key: Vec<u8>,
+}
+
+impl Advanced {
+    /// File key decrypted from `/UE` or `/OE` (Algorithm 2.A)
+    fn file_key(&self, password: &[u8]) -> Option<Vec<u8>> {
+        let (encrypted_key, key) =
+            if self.hash(password, &self.user[32..40], &[]) == self.user[..32] {
+                (&self.user_key, self.hash(password, &self.user[40..48], &[]))
+            } else if self.hash(password, &self.owner[32..40], &self.user) == self.owner[..32] {
+                (
+                    &self.owner_key,
+                    self.hash(password, &self.owner[40..48], &self.user),
+                )
+            } else {
+                return None;
+            };
+        Some(aes_cbc_decrypt::<Aes256>(&key, &[0; 16], encrypted_key))
+    }
+
+    /// Password hash: SHA-256 for revision 5, Algorithm 2.B for revision 6
+    fn hash(&self, password: &[u8], salt: &[u8], user: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(password);
+        hasher.update(salt);
+        hasher.update(user);
+        let mut k = hasher.finalize().to_vec();
+        if self.revision == 5 {
+            return k[..32].try_into().unwrap_or_default();
+        }
+
+        let mut round = 0;
+        loop {
+            let mut k1 = Vec::with_capacity(64 * (password.len() + k.len() + user.len()));
+            for _ in 0..64 {
+                k1.extend_from_slice(password);
+                k1.extend_from_slice(&k);
+                k1.extend_from_slice(user);
+            }
+            let e = aes_cbc_encrypt::<Aes128>(&k[..16], &k[16..32], &k1);
+            // The first 16 bytes of `e` as a big-endian number modulo 3
+            let remainder = e[..16].iter().map(|byte| u32::from(*byte)).sum::<u32>() % 3;
+            k = match remainder {
+                0 => Sha256::digest(&e).to_vec(),
+                1 => Sha384::digest(&e).to_vec(),
+                _ => Sha512::digest(&e).to_vec(),
+            };
+            round += 1;
+            if round >= 64 && u32::from(e[e.len() - 1]) + 32 <= round {
+                break;
+            }
+        }
+        k[..32].try_into().unwrap_or_default()
+    }
+}
+
+fn rc4(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut state: [u8; 256] = core::array::from_fn(|i| i as u8);
+    let mut j = 0u8;
+    for i in 0..256 {
+        j = j.wrapping_add(state[i]).wrapping_add(key[i % key.len()]);
+        state.swap(i, usize::from(j));
+    }
+
+    let (mut i, mut j) = (0u8, 0u8);
+    data.iter()
+        .map(|byte| {
+            i = i.wrapping_add(1);
+            j = j.wrapping_add(state[usize::from(i)]);
+            state.swap(usize::from(i), usize::from(j));
+            let k = state[usize::from(state[usize::from(i)].wrapping_add(state[usize::from(j)]))];
+            byte ^ k
+        })
+        .collect()
+}
+
+/// AES-CBC data whose first block is the initialisation vector, with the
+/// PKCS#5 padding removed. Empty when the data is shorter than a block.
+fn aes_decrypt<C>(key: &[u8], data: &[u8]) -> Vec<u8>
+where
+    C: KeyInit + BlockCipherDecrypt + BlockSizeUser<BlockSize = U16>,
+{
+    if data.len() < 32 {
+        return Vec::new();
+    }
+    let mut plain = aes_cbc_decrypt::<C>(key, &data[..16], &data[16..]);
+    let padding = usize::from(plain.last().copied().unwrap_or(0));
+    if (1..=16).contains(&padding) && padding <= plain.len() {
+        plain.truncate(plain.len() - padding);
+    }
+    plain
+}
+
+/// Decrypt the whole blocks of `data`, without removing padding
+fn aes_cbc_decrypt<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8>
+where
+    C: KeyInit + BlockCipherDecrypt + BlockSizeUser<BlockSize = U16>,
+{
+    let Ok(cipher) = C::new_from_slice(key) else {
+        return Vec::new();
+    };
+    let mut previous = iv;
+    let mut plain = Vec::with_capacity(data.len());
+    for chunk in data.chunks_exact(16) {
+        let mut block = Block::<C>::try_from(chunk).unwrap_or_default();
+        cipher.decrypt_block(&mut block);
+        plain.extend(block.iter().zip(previous).map(|(byte, prev)| byte ^ prev));
+        previous = chunk;
+    }
+    plain
+}
+
+/// Encrypt `data`, a whole number of blocks, without padding
+fn aes_cbc_encrypt<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8>
+where
+    C: KeyInit + BlockCipherEncrypt + BlockSizeUser<BlockSize = U16>,
+{
+    let Ok(cipher) = C::new_from_slice(key) else {
+        return Vec::new();
+    };
+    let mut cipher_text: Vec<u8> = Vec::with_capacity(data.len());
+    let mut previous = Block::<C>::try_from(iv).unwrap_or_default();
+    for chunk in data.chunks_exact(16) {
+        let mut block = Block::<C>::default();
+        for ((out, byte), prev) in block.iter_mut().zip(chunk).zip(previous.iter()) {
+            *out = byte ^ prev;
+        }
+        cipher.encrypt_block(&mut block);
+        cipher_text.extend_from_slice(&block);
+        previous = block;
+    }
+    cipher_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// An encryption dictionary for the user password `user` and owner
+    /// password `owner`, with its file key and the string "Known answer"
+    /// encrypted as part of object 7
+    struct Case {
+        dictionary: &'static str,
+        key: &'static str,
+        string: &'static str,
+    }
+
+    const ID: &str = "0123456789abcdeffedcba9876543210";
+
+    const CASES: [Case; 6] = [
+        // Revision 2, RC4 40-bit
+        Case {
+            dictionary: concat!(
+                "/V 1 /R 2 ",
+                "/O <94e8094419662a774442fb072e3d9f19e9d130ec09a4d0061e78fe920f7ab62f> /U <00960a327fd80c93dd8fdefe3ab7fff0e2e8b39542a5cecc70f05f28755084db>",
+            ),
+            key: "5d4446bedf",
+            string: "07e1577e5149989d5472ee20",
+        },
+        // Revision 3, RC4 128-bit
+        Case {
+            dictionary: concat!(
+                "/V 2 /R 3 /Length 128 ",
+                "/O <0ba3835f88f90388e74e54584125ce142be0de24c6b0d37746e075b891756671> /U <c9bbe2a3956ec521581a87749e0bc91a00000000000000000000000000000000>",
+            ),
+            key: "16edc0dbea01045469e3195f056a94bd",
+            string: "1221c1f2b2ebdd7ef89c0599",
+        },
+        // Revision 4, V2 crypt filter
+        Case {
+            dictionary: concat!(
+                "/V 4 /R 4 /Length 128 /CF << /StdCF << /CFM /V2 >> >> /StmF /StdCF /StrF /StdCF ",
+                "/O <0ba3835f88f90388e74e54584125ce142be0de24c6b0d37746e075b891756671> /U <c9bbe2a3956ec521581a87749e0bc91a00000000000000000000000000000000>",
+            ),
+            key: "16edc0dbea01045469e3195f056a94bd",
+            string: "1221c1f2b2ebdd7ef89c0599",
+        },
+        // Revision 4, AESV2 crypt filter, metadata not encrypted
+        Case {
+            dictionary: concat!(
+                "/V 4 /R 4 /Length 128 /CF << /StdCF << /CFM /AESV2 >> >> /StmF /StdCF /StrF /StdCF /EncryptMetadata false ",
+                "/O <0ba3835f88f90388e74e54584125ce142be0de24c6b0d37746e075b891756671> /U <026ff9eb254a9de965203e046649429200000000000000000000000000000000>",
+            ),
+            key: "8daac4091c894a6df2237667d4935830",
+            string: "000102030405060708090a0b0c0d0e0f6d5265d47b74efc61e22db8c7a7e4189",
+        },
+        // Revision 5, AESV3
+        Case {
+            dictionary: concat!(
+                "/V 5 /R 5 /Length 256 /CF << /StdCF << /CFM /AESV3 >> >> /StmF /StdCF /StrF /StdCF ",
+                "/O <d6a1673513902f9790fdfff606d0447a671c1c182cb05bf51f6770dbc96068126f7673616c7430336f6b73616c743034> /U <2dc98293c04ad10692ecb1811cdd451294ea02571ccc56c66a1cc1dde93f91ab757673616c743031756b73616c743032> /OE <2f6ddd8b298f6304da468224cc54a1ead35defc5f14dbe959f0e0c7cc3c7cf7d> /UE <e64cf190c4d9a6ef7efe9acd5c32d52d387a064c9917daf2b7b0e7cc68e520e1>",
+            ),
+            key: "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f",
+            string: "000102030405060708090a0b0c0d0e0fc819a6d00f8d1b9580f528b51e9c8e0c",
+        },
+        // Revision 6, AESV3
+        Case {
+            dictionary: concat!(
+                "/V 5 /R 6 /Length 256 /CF << /StdCF << /CFM /AESV3 >> >> /StmF /StdCF /StrF /StdCF ",
+                "/O <90ab9f98045985fd337acba9efe3f98cadb1aaf546ecd0828f46ab838cfee2586f7673616c7430336f6b73616c743034> /U <647eea44ca0648aaea2bd4bd30be64c4cfa85b768d03b1522cb09499a54e5b41757673616c743031756b73616c743032> /OE <133b1ffab3a73903ec3de97854052b8b97329b58093cf07e584a8fa66f5138d6> /UE <a010cde9725aa37d3e994503028e299d68abfe906158136a4e3aee8f13706f19>",
+            ),
+            key: "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f",
+            string: "000102030405060708090a0b0c0d0e0fc819a6d00f8d1b9580f528b51e9c8e0c",
+        },
+    ];
+
+    fn open(case: &Case, password: &[u8]) -> Result<SecurityHandler, PdfError> {
+        let text = alloc::format!("<< /Filter /Standard /P -3904 {} >>", case.dictionary);
+        let encrypt = Parser::new(text.as_bytes()).parse_dictionary().unwrap();
+        SecurityHandler::new(&encrypt, &hex(ID), password, None)
+    }
+
+    #[test]
+    fn file_key() {
+        for case in &CASES {
+            for password in [b"user".as_slice(), b"owner"] {
+                let handler = open(case, password).unwrap();
+                assert_eq!(handler.key, hex(case.key), "{}", case.dictionary);
+            }
+            assert!(matches!(
+                open(case, b"wrong"),
+                Err(PdfError::EncryptionError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn decrypt_string() {
+        for case in &CASES {
+            let handler = open(case, b"user").unwrap();
+            let mut string = PdfObj::String(hex(case.string));
+            handler.decrypt_object((7, 0), &mut string);
+            assert_eq!(string.as_bytes(), Some(b"Known answer".as_slice()));
+        }
+    }
+}
@@ -1,71 +1,221 @@
 use crate::parser::PdfObj;
+use crate::PdfError;
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
-use miniz_oxide::inflate::decompress_to_vec_zlib;
+use miniz_oxide::inflate::{decompress_to_vec_zlib_with_limit, TINFLStatus};
 
+/// Decode stream data through its `/Filter` chain, failing with
+/// [`PdfError::LimitExceeded`] once the output would exceed `max_size` bytes.
+/// See [`crate::Document::decode_stream`], which counts what is decoded.
 pub fn handle_stream_filters(
     stream_dict: &BTreeMap<String, PdfObj>,
     data: &[u8],
-) -> Result<Vec<u8>, String> {
-    match stream_dict.get("Filter") {
-        Some(PdfObj::Name(filter)) => {
-            let result = apply_filter(filter, data)?;
-            // Check for DecodeParms
-            if let Some(decode_parms) = stream_dict.get("DecodeParms") {
-                apply_decode_parms(&result, decode_parms)
-            } else {
-                Ok(result)
-            }
+    max_size: usize,
+) -> Result<Vec<u8>, PdfError> {
+    let filters: Vec<&str> = match stream_dict.get("Filter") {
+        Some(PdfObj::Name(filter)) => vec![filter.as_str()],
+        Some(PdfObj::Array(filters)) => filters.iter().filter_map(PdfObj::as_name).collect(),
+        None => return Ok(data.to_vec()),
+        _ => return Err(PdfError::DecompressionError("Invalid Filter type".into())),
+    };
+    // One DecodeParms entry per filter, a single dictionary goes with a single filter
+    let decode_parms: Vec<&PdfObj> = match stream_dict.get("DecodeParms") {
+        Some(PdfObj::Array(parms)) => parms.iter().collect(),
+        Some(parms) => vec![parms],
+        None => Vec::new(),
+    };
+
+    if data.len() > max_size {
+        return Err(too_large(max_size));
+    }
+    let mut result = data.to_vec();
+    for (i, filter_name) in filters.into_iter().enumerate() {
+        // Image codecs are left encoded, along with any filter after them
+        if is_image_filter(filter_name) {
+            break;
         }
-        Some(PdfObj::Array(filters)) => {
-            let mut result = data.to_vec();
-            // Get DecodeParms array if present
-            let decode_parms_array = match stream_dict.get("DecodeParms") {
-                Some(PdfObj::Array(arr)) => Some(arr),
-                _ => None,
-            };
-
-            for (i, filter) in filters.iter().enumerate() {
-                if let PdfObj::Name(filter_name) = filter {
-                    result = apply_filter(filter_name, &result)?;
-
-                    // Apply corresponding DecodeParms if present
-                    if let Some(parms_array) = decode_parms_array {
-                        if let Some(parms) = parms_array.get(i) {
-                            result = apply_decode_parms(&result, parms)?;
-                        }
-                    }
-                }
-            }
-            Ok(result)
+        let parms = decode_parms.get(i).copied();
+        result = apply_filter(filter_name, &result, parms, max_size)?;
+        if let Some(parms) = parms {
+            result = apply_decode_parms(&result, parms).map_err(PdfError::DecompressionError)?;
+        }
+        if result.len() > max_size {
+            return Err(too_large(max_size));
         }
-        None => Ok(data.to_vec()),
-        _ => Err("Invalid Filter type".into()),
     }
+    Ok(result)
+}
+
+fn too_large(max_size: usize) -> PdfError {
+    PdfError::LimitExceeded(alloc::format!(
+        "Stream decodes to more than {max_size} bytes"
+    ))
+}
+
+/// Filters that only encode image samples. Text extraction never needs their
+/// output, so the data is passed through still encoded.
+fn is_image_filter(filter_name: &str) -> bool {
+    matches!(
+        filter_name,
+        "DCTDecode" | "JBIG2Decode" | "CCITTFaxDecode" | "JPXDecode"
+    )
 }
 
-fn apply_filter(filter_name: &str, data: &[u8]) -> Result<Vec<u8>, String> {
-    match filter_name {
+fn apply_filter(
+    filter_name: &str,
+    data: &[u8],
+    parms: Option<&PdfObj>,
+    max_size: usize,
+) -> Result<Vec<u8>, PdfError> {
+    let decoded = match filter_name {
         "FlateDecode" => {
             // Debug: check data size
             if data.is_empty() {
-                return Err("FlateDecode data is empty".to_string());
+                return Err(PdfError::DecompressionError(
+                    "FlateDecode data is empty".to_string(),
+                ));
             }
 
-            decompress_to_vec_zlib(data).map_err(|e| {
-                alloc::format!(
+            return decompress_to_vec_zlib_with_limit(data, max_size).map_err(|e| match e.status {
+                TINFLStatus::HasMoreOutput => too_large(max_size),
+                _ => PdfError::DecompressionError(alloc::format!(
                     "Failed to decompress FlateDecode data: {:?}, data size: {}",
                     e,
                     data.len()
-                )
-            })
+                )),
+            });
         }
+        "LZWDecode" => {
+            let early_change = parms
+                .and_then(PdfObj::as_dict)
+                .and_then(|parms| parms.get("EarlyChange"))
+                .and_then(PdfObj::as_int)
+                .unwrap_or(1);
+            return decode_lzw(data, early_change != 0, max_size);
+        }
+        "RunLengthDecode" => return decode_run_length(data, max_size),
         "ASCIIHexDecode" => decode_ascii_hex(data),
-        "ASCII85Decode" => decode_ascii85(data),
+        "ASCII85Decode" => return decode_ascii85(data, max_size),
+        // The document has already decrypted the stream with the crypt filter
+        // this names, see `SecurityHandler::decrypt_stream`
+        "Crypt" => Ok(data.to_vec()),
         _ => Err(alloc::format!("Unsupported filter: {filter_name}")),
+    };
+    decoded.map_err(PdfError::DecompressionError)
+}
+
+const LZW_CLEAR_TABLE: usize = 256;
+const LZW_END_OF_DATA: usize = 257;
+const LZW_MAX_CODES: usize = 4096;
+
+/// LZW with 9 to 12 bit codes. With `early_change` the code width grows one
+/// code early, as most encoders do.
+fn decode_lzw(data: &[u8], early_change: bool, max_size: usize) -> Result<Vec<u8>, PdfError> {
+    let mut result = Vec::new();
+    let mut table: Vec<Vec<u8>> = (0..=255u8).map(|b| vec![b]).collect();
+    // Placeholders for the clear table and end of data codes
+    table.push(Vec::new());
+    table.push(Vec::new());
+
+    let mut code_len = 9;
+    let mut previous: Option<usize> = None;
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+    let mut bytes = data.iter();
+
+    loop {
+        while buffered_bits < code_len {
+            let Some(&byte) = bytes.next() else {
+                // A missing end of data code is tolerated
+                return Ok(result);
+            };
+            buffer = (buffer << 8) | byte as u32;
+            buffered_bits += 8;
+        }
+        buffered_bits -= code_len;
+        let code = ((buffer >> buffered_bits) & ((1 << code_len) - 1)) as usize;
+
+        match code {
+            LZW_CLEAR_TABLE => {
+                table.truncate(LZW_END_OF_DATA + 1);
+                code_len = 9;
+                previous = None;
+                continue;
+            }
+            LZW_END_OF_DATA => return Ok(result),
+            _ => {}
+        }
+
+        let entry = match (table.get(code), previous) {
+            (Some(entry), _) => entry.clone(),
+            // The code being defined by this very step: previous entry plus its first byte
+            (None, Some(previous)) if code == table.len() => {
+                let mut entry = table[previous].clone();
+                entry.push(entry[0]);
+                entry
+            }
+            _ => {
+                return Err(PdfError::DecompressionError(alloc::format!(
+                    "Invalid LZW code: {code}"
+                )))
+            }
+        };
+        if result.len() + entry.len() > max_size {
+            return Err(too_large(max_size));
+        }
+        result.extend_from_slice(&entry);
+
+        if let Some(previous) = previous {
+            if table.len() < LZW_MAX_CODES {
+                let mut next = table[previous].clone();
+                next.push(entry[0]);
+                table.push(next);
+            }
+        }
+        previous = Some(code);
+
+        code_len = match table.len() + early_change as usize {
+            n if n >= 2048 => 12,
+            n if n >= 1024 => 11,
+            n if n >= 512 => 10,
+            _ => 9,
+        };
+    }
+}
+
+/// Run-length decoding, failing once the output would exceed `max_size`
+/// bytes: a repeat run expands two bytes into up to 128.
+fn decode_run_length(data: &[u8], max_size: usize) -> Result<Vec<u8>, PdfError> {
+    let truncated = || PdfError::DecompressionError("Truncated RunLengthDecode data".into());
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while let Some(&length) = data.get(i) {
+        let run_length = match length {
+            // End of data
+            128 => break,
+            0..=127 => length as usize + 1,
+            _ => 257 - length as usize,
+        };
+        if result.len() + run_length > max_size {
+            return Err(too_large(max_size));
+        }
+        if length < 128 {
+            // Copy the next length + 1 bytes literally
+            let run = data.get(i + 1..i + 1 + run_length).ok_or_else(truncated)?;
+            result.extend_from_slice(run);
+            i += 1 + run_length;
+        } else {
+            // Repeat the next byte 257 - length times
+            let &byte = data.get(i + 1).ok_or_else(truncated)?;
+            result.resize(result.len() + run_length, byte);
+            i += 2;
+        }
     }
+
+    Ok(result)
 }
 
 fn decode_ascii_hex(data: &[u8]) -> Result<Vec<u8>, String> {
@@ -96,7 +246,9 @@ fn decode_ascii_hex(data: &[u8]) -> Result<Vec<u8>, String> {
     Ok(result)
 }
 
-fn decode_ascii85(data: &[u8]) -> Result<Vec<u8>, String> {
+/// ASCII base-85 decoding, failing once the output would exceed `max_size`
+/// bytes: `z` expands one byte into four.
+fn decode_ascii85(data: &[u8], max_size: usize) -> Result<Vec<u8>, PdfError> {
     let mut result = Vec::new();
     let mut tuple = [0u8; 5];
     let mut count = 0;
@@ -113,12 +265,17 @@ fn decode_ascii85(data: &[u8]) -> Result<Vec<u8>, String> {
 
         if byte == b'z' && count == 0 {
             // Special case: z represents four null bytes
+            if result.len() + 4 > max_size {
+                return Err(too_large(max_size));
+            }
             result.extend_from_slice(&[0, 0, 0, 0]);
             continue;
         }
 
         if !(b'!'..=b'u').contains(&byte) {
-            return Err("Invalid ASCII85 character".into());
+            return Err(PdfError::DecompressionError(
+                "Invalid ASCII85 character".into(),
+            ));
         }
 
         tuple[count] = byte - b'!';
@@ -173,25 +330,12 @@ fn hex_digit_value(ch: u8) -> Result<u8, String> {
 fn apply_decode_parms(data: &[u8], decode_parms: &PdfObj) -> Result<Vec<u8>, String> {
     match decode_parms {
         PdfObj::Dictionary(dict) => {
-            // Check for predictor
-            if let Some(PdfObj::Number(predictor)) = dict.get("Predictor") {
-                let predictor = *predictor as i32;
-                if predictor > 1 {
-                    // PNG predictors
-                    if (10..=15).contains(&predictor) {
-                        let columns = match dict.get("Columns") {
-                            Some(PdfObj::Number(n)) => *n as usize,
-                            _ => return Err("Missing Columns for predictor".to_string()),
-                        };
-                        apply_png_predictor(data, predictor, columns)
-                    } else {
-                        Err(alloc::format!("Unsupported predictor: {predictor}"))
-                    }
-                } else {
-                    Ok(data.to_vec())
-                }
-            } else {
-                Ok(data.to_vec())
+            let predictor = dict.get("Predictor").and_then(PdfObj::as_int).unwrap_or(1);
+            match predictor {
+                ..=1 => Ok(data.to_vec()),
+                2 => apply_tiff_predictor(data, &PredictorParams::from_dict(dict)?),
+                10..=15 => apply_png_predictor(data, &PredictorParams::from_dict(dict)?),
+                _ => Err(alloc::format!("Unsupported predictor: {predictor}")),
             }
         }
         PdfObj::Null => Ok(data.to_vec()),
@@ -199,86 +343,142 @@ fn apply_decode_parms(data: &[u8], decode_parms: &PdfObj) -> Result<Vec<u8>, Str
     }
 }
 
-fn apply_png_predictor(data: &[u8], _predictor: i32, columns: usize) -> Result<Vec<u8>, String> {
-    // PNG predictors work on rows
-    let row_size = columns + 1; // +1 for predictor byte
+/// Sample layout of predicted data, from `/Columns`, `/Colors` and
+/// `/BitsPerComponent`
+struct PredictorParams {
+    columns: usize,
+    colors: usize,
+    bits_per_component: usize,
+}
 
-    if data.len() % row_size != 0 {
-        return Err("Invalid data size for predictor".to_string());
+impl PredictorParams {
+    fn from_dict(dict: &BTreeMap<String, PdfObj>) -> Result<Self, String> {
+        let get = |key: &str| match dict.get(key) {
+            None => Ok(1),
+            Some(value) => match value.as_unsigned::<usize>() {
+                Some(value) if value > 0 => Ok(value),
+                _ => Err(alloc::format!("Invalid {key} for predictor")),
+            },
+        };
+        let params = Self {
+            columns: get("Columns")?,
+            colors: get("Colors")?,
+            bits_per_component: match dict.get("BitsPerComponent") {
+                None => 8,
+                Some(bits) => match bits.as_int() {
+                    Some(bits @ (1 | 2 | 4 | 8 | 16)) => bits as usize,
+                    _ => return Err("Invalid BitsPerComponent for predictor".into()),
+                },
+            },
+        };
+        params.row_size()?;
+        Ok(params)
     }
 
-    let mut result = Vec::with_capacity(data.len() - data.len() / row_size);
-    let mut prev_row = vec![0u8; columns];
+    /// Samples per row
+    fn samples(&self) -> usize {
+        self.columns * self.colors
+    }
 
-    for row_data in data.chunks(row_size) {
-        if row_data.len() != row_size {
-            break;
+    /// Bytes per row, checked once in `from_dict`
+    fn row_size(&self) -> Result<usize, String> {
+        self.columns
+            .checked_mul(self.colors)
+            .and_then(|samples| samples.checked_mul(self.bits_per_component))
+            .map(|bits| bits.div_ceil(8))
+            .ok_or_else(|| "Predictor row size overflows".to_string())
+    }
+
+    /// Bytes per pixel, rounded up to one, the distance PNG predictors look back
+    fn bytes_per_pixel(&self) -> usize {
+        (self.colors * self.bits_per_component).div_ceil(8)
+    }
+}
+
+/// TIFF predictor 2: every sample is stored as the difference from the same
+/// component of the pixel to its left
+fn apply_tiff_predictor(data: &[u8], params: &PredictorParams) -> Result<Vec<u8>, String> {
+    let row_size = params.row_size()?;
+    let bits = params.bits_per_component;
+    let mut result = data.to_vec();
+
+    for row in result.chunks_mut(row_size) {
+        // A truncated last row is decoded as far as it goes
+        let samples = params.samples().min(row.len() * 8 / bits);
+        for i in params.colors..samples {
+            let value = sample(row, i, bits) + sample(row, i - params.colors, bits);
+            set_sample(row, i, bits, value);
         }
+    }
+
+    Ok(result)
+}
+
+fn sample(row: &[u8], index: usize, bits: usize) -> u32 {
+    match bits {
+        16 => u16::from_be_bytes([row[2 * index], row[2 * index + 1]]) as u32,
+        8 => row[index] as u32,
+        _ => {
+            let bit = index * bits;
+            let shift = 8 - bits - bit % 8;
+            (row[bit / 8] >> shift) as u32 & ((1 << bits) - 1)
+        }
+    }
+}
+
+/// Store `value` modulo the sample size
+fn set_sample(row: &mut [u8], index: usize, bits: usize, value: u32) {
+    match bits {
+        16 => row[2 * index..2 * index + 2].copy_from_slice(&(value as u16).to_be_bytes()),
+        8 => row[index] = value as u8,
+        _ => {
+            let bit = index * bits;
+            let shift = 8 - bits - bit % 8;
+            let mask = ((1u32 << bits) - 1) as u8;
+            row[bit / 8] = (row[bit / 8] & !(mask << shift)) | ((value as u8 & mask) << shift);
+        }
+    }
+}
+
+/// PNG predictors: every row starts with a byte choosing its own predictor
+fn apply_png_predictor(data: &[u8], params: &PredictorParams) -> Result<Vec<u8>, String> {
+    let row_size = params.row_size()?;
+    let bpp = params.bytes_per_pixel();
 
+    if !data.len().is_multiple_of(row_size + 1) {
+        return Err("Invalid data size for predictor".to_string());
+    }
+
+    let mut result = Vec::with_capacity(data.len() - data.len() / (row_size + 1));
+    let mut prev_row = vec![0u8; row_size];
+
+    for row_data in data.chunks_exact(row_size + 1) {
         let predictor_byte = row_data[0];
         let row = &row_data[1..];
-        let mut decoded_row = vec![0u8; columns];
-
-        match predictor_byte {
-            0 => {
-                // No prediction
-                decoded_row.copy_from_slice(row);
-            }
-            1 => {
-                // Sub: each byte is the sum of itself and the byte to its left
-                decoded_row[0] = row[0];
-                for i in 1..columns {
-                    decoded_row[i] = row[i].wrapping_add(decoded_row[i - 1]);
-                }
-            }
-            2 => {
-                // Up: each byte is the sum of itself and the corresponding byte in the previous row
-                for i in 0..columns {
-                    decoded_row[i] = row[i].wrapping_add(prev_row[i]);
+        let mut decoded_row = vec![0u8; row_size];
+
+        for i in 0..row_size {
+            let left = if i >= bpp { decoded_row[i - bpp] } else { 0 };
+            let up = prev_row[i];
+            let up_left = if i >= bpp { prev_row[i - bpp] } else { 0 };
+            let predicted = match predictor_byte {
+                // None
+                0 => 0,
+                // Sub
+                1 => left,
+                // Up
+                2 => up,
+                // Average of left and up
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                // Paeth
+                4 => paeth_predictor(left, up, up_left),
+                _ => {
+                    return Err(alloc::format!(
+                        "Unsupported predictor algorithm: {predictor_byte}"
+                    ))
                 }
-            }
-            3 => {
-                // Average: each byte is the sum of itself and the average of left and up
-                for i in 0..columns {
-                    let left = if i > 0 { decoded_row[i - 1] } else { 0 };
-                    let up = prev_row[i];
-                    let avg = (left as u16 + up as u16) / 2;
-                    decoded_row[i] = row[i].wrapping_add(avg as u8);
-                }
-            }
-            4 => {
-                // Paeth: complex predictor
-                for i in 0..columns {
-                    let a = if i > 0 { decoded_row[i - 1] } else { 0 };
-                    let b = prev_row[i];
-                    let c = if i > 0 { prev_row[i - 1] } else { 0 };
-                    decoded_row[i] = row[i].wrapping_add(paeth_predictor(a, b, c));
-                }
-            }
-            _ => {
-                // For predictor >= 10, the predictor byte determines the algorithm
-                // In this case, we should use predictor - 10 as the actual algorithm
-                let algo = if predictor_byte >= 10 {
-                    predictor_byte - 10
-                } else {
-                    predictor_byte
-                };
-                match algo {
-                    0 => decoded_row.copy_from_slice(row),
-                    1 => {
-                        decoded_row[0] = row[0];
-                        for i in 1..columns {
-                            decoded_row[i] = row[i].wrapping_add(decoded_row[i - 1]);
-                        }
-                    }
-                    2 => {
-                        for i in 0..columns {
-                            decoded_row[i] = row[i].wrapping_add(prev_row[i]);
-                        }
-                    }
-                    _ => return Err(alloc::format!("Unsupported predictor algorithm: {algo}")),
-                }
-            }
+            };
+            decoded_row[i] = row[i].wrapping_add(predicted);
         }
 
         result.extend_from_slice(&decoded_row);
@@ -302,3 +502,153 @@ fn paeth_predictor(a: u8, b: u8, c: u8) -> u8 {
         c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Decode `data` through `filter` with the given `/DecodeParms` entries
+    fn decode(filter: &str, parms: &[(&str, i64)], data: &[u8]) -> Result<Vec<u8>, PdfError> {
+        let mut dict = BTreeMap::new();
+        dict.insert("Filter".to_string(), PdfObj::Name(filter.to_string()));
+        if !parms.is_empty() {
+            let parms = parms
+                .iter()
+                .map(|(key, value)| (key.to_string(), PdfObj::Integer(*value)))
+                .collect();
+            dict.insert("DecodeParms".to_string(), PdfObj::Dictionary(parms));
+        }
+        handle_stream_filters(&dict, data, 1 << 20)
+    }
+
+    /// Bytes 0 to 255 then 0 to 7, enough codes for the width to reach 10 bits
+    fn lzw_plain() -> Vec<u8> {
+        (0..=255u8).chain(0..8).collect()
+    }
+
+    /// `lzw_plain` encoded with EarlyChange 1
+    const LZW_EARLY: [&str; 7] = [
+        "800000202018100a0603820120a058301a0e078402212098502a160b860321a0d8703a1e0f8804222118904a",
+        "26138a0522a158b05a2e178c06232198d06a361b8e0723a1d8f07a3e1f9008242219108a4623920924a25930",
+        "9a4e27940a25229950aa562b960b25a2d970ba5e2f980c26231990ca66339a0d26a359b0da6e379c0e272399",
+        "d0ea763b9e0f27a3d9f0fa7e3fa01028241a110a8643a21128a45a311a8e47a41229249a512a964ba61329a4",
+        "da713a9e4fa8142a251a914aa653aa152aa55ab15aae57ac162b259ad16ab65bae172ba5daf17abe5fb0182c",
+        "261b118ac663b2192ca65b319ace67b41a2d269b51aad66bb61b2da6db71bade6fb81c2e271b91cae673ba1d",
+        "2ea75bb1daee77bc1e2f279bd1eaf67bbe1f2fa7dbf1fa7f1fe8120883210808",
+    ];
+
+    /// `lzw_plain` encoded with EarlyChange 0
+    const LZW_LATE: [&str; 7] = [
+        "800000202018100a0603820120a058301a0e078402212098502a160b860321a0d8703a1e0f8804222118904a",
+        "26138a0522a158b05a2e178c06232198d06a361b8e0723a1d8f07a3e1f9008242219108a4623920924a25930",
+        "9a4e27940a25229950aa562b960b25a2d970ba5e2f980c26231990ca66339a0d26a359b0da6e379c0e272399",
+        "d0ea763b9e0f27a3d9f0fa7e3fa01028241a110a8643a21128a45a311a8e47a41229249a512a964ba61329a4",
+        "da713a9e4fa8142a251a914aa653aa152aa55ab15aae57ac162b259ad16ab65bae172ba5daf17abe5fb0182c",
+        "261b118ac663b2192ca65b319ace67b41a2d269b51aad66bb61b2da6db71bade6fb81c2e271b91cae673ba1d",
+        "2ea75bb1daee77bc1e2f279bd1eaf67bbe1f2fa7dbf1fafe3fd0241106421010",
+    ];
+
+    #[test]
+    fn lzw() {
+        // The example of PDF 32000-1 section 7.4.4.2
+        let data = hex("800b6050220c0c8501");
+        assert_eq!(decode("LZWDecode", &[], &data).unwrap(), b"-----A---B");
+        assert_eq!(
+            decode("LZWDecode", &[("EarlyChange", 0)], &data).unwrap(),
+            b"-----A---B"
+        );
+
+        let early = hex(&LZW_EARLY.concat());
+        let late = hex(&LZW_LATE.concat());
+        assert_eq!(decode("LZWDecode", &[], &early).unwrap(), lzw_plain());
+        assert_eq!(
+            decode("LZWDecode", &[("EarlyChange", 0)], &late).unwrap(),
+            lzw_plain()
+        );
+        assert_ne!(decode("LZWDecode", &[], &late).ok(), Some(lzw_plain()));
+    }
+
+    #[test]
+    fn run_length() {
+        let data = hex("02616263016465fe7880");
+        assert_eq!(decode("RunLengthDecode", &[], &data).unwrap(), b"abcdexxx");
+        // A repeat run of 128 bytes from two
+        assert!(matches!(
+            decode_run_length(&[129, 0, 128], 127),
+            Err(PdfError::LimitExceeded(_))
+        ));
+        assert_eq!(decode_run_length(&[129, 0, 128], 128).unwrap(), [0; 128]);
+        assert!(decode_run_length(&[4, 1, 2], 100).is_err());
+    }
+
+    #[test]
+    fn ascii85() {
+        assert_eq!(
+            decode("ASCII85Decode", &[], b"87cURD]i,\"Ebo80~>").unwrap(),
+            b"Hello World!"
+        );
+        assert_eq!(decode_ascii85(b"zz~>", 8).unwrap(), [0; 8]);
+        assert!(matches!(
+            decode_ascii85(b"zzz~>", 8),
+            Err(PdfError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn tiff_predictor() {
+        let tiff = |bits, colors, columns, data: &str| {
+            let parms = [
+                ("Predictor", 2),
+                ("BitsPerComponent", bits),
+                ("Colors", colors),
+                ("Columns", columns),
+            ];
+            decode("ASCIIHexDecode", &parms, data.as_bytes()).unwrap()
+        };
+        assert_eq!(tiff(1, 1, 8, "eb"), hex("b2"));
+        assert_eq!(tiff(2, 1, 8, "153f"), hex("1be4"));
+        assert_eq!(tiff(4, 2, 3, "122222"), hex("123456"));
+        assert_eq!(tiff(16, 1, 3, "01000100fdff"), hex("01000200ffff"));
+        // Two rows are predicted separately
+        assert_eq!(tiff(8, 1, 2, "01010101"), hex("01020102"));
+    }
+
+    #[test]
+    fn png_predictor() {
+        let png = |bits, colors, columns, data: &str| {
+            let parms = [
+                ("Predictor", 15),
+                ("BitsPerComponent", bits),
+                ("Colors", colors),
+                ("Columns", columns),
+            ];
+            decode("ASCIIHexDecode", &parms, data.as_bytes())
+        };
+        // Sub, Up, Average and Paeth over RGB pixels
+        assert_eq!(
+            png(
+                8,
+                3,
+                2,
+                "010a141e1e1e1e0201020304050603c359229fbfcc04399ed1f9f9f9"
+            )
+            .unwrap(),
+            hex("0a141e28323c0b16212c3742c86432190c06010203fafbfc")
+        );
+        // 16-bit samples look back two bytes
+        assert_eq!(
+            png(16, 1, 2, "0101000100040080fd7f").unwrap(),
+            hex("010002000180ffff")
+        );
+        // Samples smaller than a byte look back one byte
+        assert_eq!(png(4, 1, 4, "011222").unwrap(), hex("1234"));
+        assert!(png(8, 1, 2, "0501020").is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Logical structure (PDF 32000-1 section 14.7).
+//!
+//! Tagged documents describe their content as a tree of structure elements
+//! under the catalog's `/StructTreeRoot`. The kids of an element are other
+//! elements, marked-content sequences on a page (an MCID, or an `/MCR`
+//! dictionary) and objects such as annotations (`/OBJR`, not followed). Page
+//! content is linked back through the MCIDs of the runs extracted from it, so
+//! every page an element points to is extracted once.
+
+use crate::document::Document;
+use crate::layout::{SAME_LINE, WORD_GAP};
+use crate::page::PageContent;
+use crate::parser::{decode_text_string, resolve, PdfObj};
+use crate::position::TextRun;
+use crate::text::ExtractOptions;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Deepest nesting of structure elements followed
+const MAX_DEPTH: usize = 64;
+/// Longest chain of `/RoleMap` entries followed
+const MAX_ROLE_MAPPINGS: usize = 8;
+
+/// A node of the structure tree with the text of its content
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructElement {
+    /// Structure type after applying `/RoleMap`, e.g. `H1`, `P`, `Table`, `TD`
+    pub kind: String,
+    /// Structure type as written in the document (`/S`)
+    pub role: String,
+    /// Text of the element and its descendants, `/ActualText` when present
+    pub text: String,
+    /// Alternate description (`/Alt`), typically of a `Figure`
+    pub alt: Option<String>,
+    /// Index of the page holding the element's content (`/Pg`)
+    pub page: Option<usize>,
+    pub children: Vec<StructElement>,
+}
+
+impl StructElement {
+    /// Descendants of type `kind`, in document order
+    pub fn find_all(&self, kind: &str) -> Vec<&StructElement> {
+        let mut found = Vec::new();
+        for child in &self.children {
+            if child.kind == kind {
+                found.push(child);
+            }
+            found.extend(child.find_all(kind));
+        }
+        found
+    }
+
+    /// Cell texts of a `Table`, row by row. Rows are the `TR` elements of the
+    /// table, also inside `THead`, `TBody` and `TFoot`; cells are their `TH`
+    /// and `TD` children. Nested tables are not descended into.
+    pub fn table_rows(&self) -> Vec<Vec<&str>> {
+        let mut rows = Vec::new();
+        for child in &self.children {
+            match child.kind.as_str() {
+                "TR" => rows.push(
+                    child
+                        .children
+                        .iter()
+                        .filter(|cell| matches!(cell.kind.as_str(), "TH" | "TD"))
+                        .map(|cell| cell.text.as_str())
+                        .collect(),
+                ),
+                "THead" | "TBody" | "TFoot" => rows.extend(child.table_rows()),
+                _ => {}
+            }
+        }
+        rows
+    }
+}
+
+/// The top-level structure elements of `document`, empty when it is not
+/// tagged
+pub fn extract_structure(document: &Document) -> Vec<StructElement> {
+    let Some(root) =
+        resolve(document.catalog().get("StructTreeRoot"), document).and_then(PdfObj::as_dict)
+    else {
+        return Vec::new();
+    };
+
+    let mut tree = StructTree {
+        pages: document.pages(),
+        objects: document,
+        role_map: resolve(root.get("RoleMap"), document).and_then(PdfObj::as_dict),
+        content: BTreeMap::new(),
+        visited: BTreeSet::new(),
+    };
+    kids(root.get("K"), document)
+        .into_iter()
+        .filter_map(|kid| tree.element(kid, None, 0))
+        .collect()
+}
+
+/// `/K` holds a single kid or an array of them
+fn kids<'a>(obj: Option<&'a PdfObj>, objects: &'a Document) -> Vec<&'a PdfObj> {
+    match resolve(obj, objects) {
+        Some(PdfObj::Array(items)) => items.iter().collect(),
+        Some(_) => obj.into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+struct StructTree<'a> {
+    pages: &'a [PageContent],
+    objects: &'a Document<'a>,
+    role_map: Option<&'a BTreeMap<String, PdfObj>>,
+    /// Text of each marked-content sequence by page index and MCID, filled
+    /// in when a page is first needed
+    content: BTreeMap<usize, BTreeMap<u32, String>>,
+    /// Elements already built, so a malformed tree cannot loop
+    visited: BTreeSet<(u32, u16)>,
+}
+
+impl<'a> StructTree<'a> {
+    fn element(
+        &mut self,
+        obj: &'a PdfObj,
+        page: Option<usize>,
+        depth: usize,
+    ) -> Option<StructElement> {
+        if depth >= MAX_DEPTH {
+            return None;
+        }
+        if let PdfObj::Reference(r) = obj {
+            if !self.visited.insert(*r) {
+                return None;
+            }
+        }
+        let Some(PdfObj::Dictionary(dict)) = resolve(Some(obj), self.objects) else {
+            return None;
+        };
+        let Some(PdfObj::Name(role)) = dict.get("S") else {
+            return None;
+        };
+        let page = self.page_index(dict.get("Pg")).or(page);
+
+        let mut element = StructElement {
+            kind: self.map_role(role),
+            role: role.clone(),
+            text: String::new(),
+            alt: self.text_string(dict.get("Alt")),
+            page,
+            children: Vec::new(),
+        };
+
+        let mut parts = Vec::new();
+        for kid in kids(dict.get("K"), self.objects) {
+            match resolve(Some(kid), self.objects) {
+                Some(PdfObj::Integer(mcid)) => {
+                    parts.extend(self.marked_text(page, *mcid));
+                }
+                Some(PdfObj::Dictionary(kid_dict)) => match kid_dict.get("Type") {
+                    Some(PdfObj::Name(kind)) if kind == "MCR" => {
+                        let page = self.page_index(kid_dict.get("Pg")).or(page);
+                        if let Some(PdfObj::Integer(mcid)) =
+                            resolve(kid_dict.get("MCID"), self.objects)
+                        {
+                            parts.extend(self.marked_text(page, *mcid));
+                        }
+                    }
+                    Some(PdfObj::Name(kind)) if kind == "OBJR" => {}
+                    _ => {
+                        if let Some(child) = self.element(kid, page, depth + 1) {
+                            if !child.text.is_empty() {
+                                parts.push(child.text.clone());
+                            }
+                            element.children.push(child);
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        element.text = match self.text_string(dict.get("ActualText")) {
+            Some(actual_text) => actual_text,
+            None => parts.join(" "),
+        };
+        Some(element)
+    }
+
+    /// Standard structure type for `role`, following `/RoleMap`
+    fn map_role(&self, role: &str) -> String {
+        let mut kind = role;
+        for _ in 0..MAX_ROLE_MAPPINGS {
+            match self.role_map.and_then(|map| map.get(kind)) {
+                Some(PdfObj::Name(mapped)) if mapped != kind => kind = mapped,
+                _ => break,
+            }
+        }
+        String::from(kind)
+    }
+
+    fn text_string(&self, obj: Option<&PdfObj>) -> Option<String> {
+        match resolve(obj, self.objects)? {
+            PdfObj::String(bytes) => Some(decode_text_string(bytes)),
+            _ => None,
+        }
+    }
+
+    fn page_index(&self, obj: Option<&PdfObj>) -> Option<usize> {
+        let PdfObj::Reference(r) = obj? else {
+            return None;
+        };
+        self.pages.iter().position(|page| page.id == Some(*r))
+    }
+
+    /// Text of the marked-content sequence `mcid` on page `page`
+    fn marked_text(&mut self, page: Option<usize>, mcid: i64) -> Option<String> {
+        let page = page?;
+        let mcid = u32::try_from(mcid).ok()?;
+        if !self.content.contains_key(&page) {
+            let runs = crate::text::extract_text_runs_from_page_content(
+                self.pages.get(page)?,
+                self.objects,
+                &ExtractOptions::default(),
+            );
+            self.content.insert(page, group_by_mcid(&runs));
+        }
+        self.content.get(&page)?.get(&mcid).cloned()
+    }
+}
+
+/// Join the runs of each marked-content sequence, with a space where they
+/// are a word gap apart or on different lines
+fn group_by_mcid(runs: &[TextRun]) -> BTreeMap<u32, String> {
+    let mut last: BTreeMap<u32, &TextRun> = BTreeMap::new();
+    let mut content: BTreeMap<u32, String> = BTreeMap::new();
+    for run in runs {
+        let Some(mcid) = run.mcid else {
+            continue;
+        };
+        let text = content.entry(mcid).or_default();
+        if let Some(previous) = last.insert(mcid, run) {
+            let size = previous.font_size.max(run.font_size).max(1.0);
+            let same_line = (previous.y - run.y).abs() <= SAME_LINE * size;
+            let gap = run.x - (previous.x + previous.width);
+            let spaced =
+                text.ends_with(char::is_whitespace) || run.text.starts_with(char::is_whitespace);
+            if (!same_line || gap > WORD_GAP * size) && !spaced {
+                text.push(' ');
+            }
+        }
+        text.push_str(&run.text);
+    }
+    for text in content.values_mut() {
+        let trimmed = text.trim();
+        if trimmed.len() != text.len() {
+            *text = String::from(trimmed);
+        }
+    }
+    content
+}
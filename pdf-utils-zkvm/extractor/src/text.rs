@@ -1,37 +1,104 @@
+use crate::annot::append_annotations;
+use crate::document::Document;
 use crate::font::PdfFont;
+use crate::marked::{MarkedContentStack, Replacement};
 use crate::page::PageContent;
-use crate::parser::{resolve_reference, PdfObj};
-use crate::stream::handle_stream_filters;
+use crate::parser::{resolve_reference, PdfObj, PdfStream};
+use crate::position::{GraphicsState, Matrix, TextObject, TextRun};
 use crate::token::{Token, TokenParser};
 use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+/// Options for text extraction
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExtractOptions {
+    /// Leave out text marked as `/Artifact`: running headers and footers,
+    /// page numbers, watermarks
+    pub skip_artifacts: bool,
+    /// Use the `/Alt` description of marked-content sequences that show no
+    /// text, such as figures
+    pub alt_text: bool,
+    /// Append the text of the page's annotations after its content, see
+    /// [`crate::extract_annotations`]. Their runs carry the
+    /// [`crate::TextSource`] they came from.
+    pub annotations: bool,
+}
+
 pub fn extract_text_from_page_content(
     page: &PageContent,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
+    options: &ExtractOptions,
+) -> String {
+    extract_page(page, objects, options, None)
+}
+
+/// Extract the text of a page as positioned runs, in content stream order
+pub fn extract_text_runs_from_page_content(
+    page: &PageContent,
+    objects: &Document,
+    options: &ExtractOptions,
+) -> Vec<TextRun> {
+    let mut runs = Vec::new();
+    extract_page(page, objects, options, Some(&mut runs));
+    runs
+}
+
+fn extract_page(
+    page: &PageContent,
+    objects: &Document,
+    options: &ExtractOptions,
+    mut runs: Option<&mut Vec<TextRun>>,
 ) -> String {
+    // A content stream that fails to decode fails the extraction, see
+    // `Document::check_limits`
+    let content_streams = page.content_streams(objects).unwrap_or_else(|error| {
+        objects.fail(error);
+        Vec::new()
+    });
+    let fonts = page.fonts(objects);
+
     // Concatenate all content streams first, like the reference implementation
     let mut all_content = Vec::new();
-    for stream_data in page.content_streams.iter() {
+    for stream_data in content_streams.iter() {
         if !all_content.is_empty() {
             all_content.push(b' '); // Add space between streams
         }
         all_content.extend_from_slice(stream_data);
     }
+    drop(content_streams);
 
-    if all_content.is_empty() {
-        return String::new();
+    let mut text = if all_content.is_empty() {
+        String::new()
+    } else {
+        extract_text_from_stream(
+            &all_content,
+            &fonts,
+            &page.resources,
+            objects,
+            Matrix::IDENTITY,
+            runs.as_deref_mut(),
+            &mut MarkedContentStack::new(*options),
+        )
+    };
+    if options.annotations {
+        append_annotations(page, &fonts, objects, options, &mut text, runs);
     }
-
-    extract_text_from_stream(&all_content, &page.fonts, &page.resources, objects)
+    text
 }
 
+/// Interpret a content stream, returning its plain text and appending a
+/// [`TextRun`] per shown string to `runs` when it is set. `marked` holds the
+/// marked-content sequences open where the stream is drawn.
 fn extract_text_from_stream(
     stream_data: &[u8],
-    fonts: &BTreeMap<String, PdfFont>,
+    fonts: &BTreeMap<String, Rc<PdfFont>>,
     resources: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
+    objects: &Document,
+    ctm: Matrix,
+    mut runs: Option<&mut Vec<TextRun>>,
+    marked: &mut MarkedContentStack,
 ) -> String {
     let mut parser = TokenParser::new(stream_data);
     let tokens = parser.parse_all();
@@ -53,17 +120,33 @@ fn extract_text_from_stream(
     }
 
     let mut text = String::new();
-    let mut current_font: Option<&PdfFont> = None;
+    let mut gs = GraphicsState::new(ctm);
+    let mut gs_stack = Vec::new();
+    let mut text_object = TextObject::new();
     let mut i = 0;
     let mut in_text = false;
     let mut text_line = String::new();
+    // Sequences opened by the caller are not closed by this stream
+    let marked_floor = marked.depth();
 
     while i < tokens.len() {
         if let Token::Operator(op) = &tokens[i] {
             match op.as_str() {
+                "q" => gs_stack.push(gs),
+                "Q" => {
+                    if let Some(saved) = gs_stack.pop() {
+                        gs = saved;
+                    }
+                }
+                "cm" => {
+                    if let Some([a, b, c, d, e, f]) = operands(&tokens, i) {
+                        gs.ctm = Matrix::new(a, b, c, d, e, f).multiply(&gs.ctm);
+                    }
+                }
                 "BT" => {
                     in_text = true;
                     text_line.clear();
+                    text_object = TextObject::new();
                 }
                 "ET" => {
                     if !text_line.is_empty() {
@@ -75,103 +158,181 @@ fn extract_text_from_stream(
                     }
                     in_text = false;
                 }
-                "Tf" => {
+                "Tf" if i >= 2 => {
                     // Set font
-                    if i >= 2 {
-                        if let Token::Name(font_name) = &tokens[i - 2] {
-                            current_font = fonts.get(font_name);
-                        }
+                    if let Token::Name(font_name) = &tokens[i - 2] {
+                        gs.text.font = fonts.get(font_name).map(|font| &**font);
+                        gs.text.font_resource = Some(font_name);
+                    }
+                    if let Some(size) = tokens[i - 1].number() {
+                        gs.text.font_size = size;
+                    }
+                }
+                "Tc" => {
+                    if let Some([spacing]) = operands(&tokens, i) {
+                        gs.text.char_spacing = spacing;
+                    }
+                }
+                "Tw" => {
+                    if let Some([spacing]) = operands(&tokens, i) {
+                        gs.text.word_spacing = spacing;
+                    }
+                }
+                "Tz" => {
+                    if let Some([scale]) = operands(&tokens, i) {
+                        gs.text.horizontal_scaling = scale / 100.0;
+                    }
+                }
+                "TL" => {
+                    if let Some([leading]) = operands(&tokens, i) {
+                        gs.text.leading = leading;
+                    }
+                }
+                "Ts" => {
+                    if let Some([rise]) = operands(&tokens, i) {
+                        gs.text.rise = rise;
                     }
                 }
-                "Tj" => {
+                "Td" => {
+                    if let Some([tx, ty]) = operands(&tokens, i) {
+                        text_object.next_line(tx, ty);
+                    }
+                }
+                "TD" => {
+                    if let Some([tx, ty]) = operands(&tokens, i) {
+                        gs.text.leading = -ty;
+                        text_object.next_line(tx, ty);
+                    }
+                }
+                "Tm" => {
+                    if let Some([a, b, c, d, e, f]) = operands(&tokens, i) {
+                        text_object.set_matrix(Matrix::new(a, b, c, d, e, f));
+                    }
+                }
+                "T*" => text_object.next_line(0.0, -gs.text.leading),
+                "Tj" if i >= 1 && in_text => {
                     // Show text
-                    if i >= 1 && in_text {
-                        if let Token::String(bytes) = &tokens[i - 1] {
-                            let decoded = decode_text(bytes, current_font);
-                            text_line.push_str(&decoded);
-                        }
+                    if let Token::String(bytes) = &tokens[i - 1] {
+                        let decoded =
+                            show_text(bytes, &gs, &mut text_object, runs.as_deref_mut(), marked);
+                        text_line.push_str(&decoded);
                     }
                 }
-                "TJ" => {
+                "TJ" if i >= 1 && in_text => {
                     // Show text with individual glyph positioning
-                    if i >= 1 && in_text {
-                        if let Token::ArrayEnd = &tokens[i - 1] {
-                            // Find array end
-                            let mut j = i - 2;
-                            let mut array_items = Vec::new();
-                            let mut depth = 1;
-
-                            while j > 0 && depth > 0 {
-                                match &tokens[j] {
-                                    Token::ArrayEnd => depth += 1,
-                                    Token::ArrayStart => depth -= 1,
-                                    _ if depth == 1 => array_items.push(&tokens[j]),
-                                    _ => {}
-                                }
-                                j -= 1;
+                    if let Token::ArrayEnd = &tokens[i - 1] {
+                        // Find array end
+                        let mut j = i - 2;
+                        let mut array_items = Vec::new();
+                        let mut depth = 1;
+
+                        while j > 0 && depth > 0 {
+                            match &tokens[j] {
+                                Token::ArrayEnd => depth += 1,
+                                Token::ArrayStart => depth -= 1,
+                                _ if depth == 1 => array_items.push(&tokens[j]),
+                                _ => {}
                             }
+                            j -= 1;
+                        }
 
-                            array_items.reverse();
-                            for item in array_items {
-                                match item {
-                                    Token::String(bytes) => {
-                                        let decoded = decode_text(bytes, current_font);
-                                        text_line.push_str(&decoded);
-                                    }
-                                    Token::Number(n) if *n < -200.0 => {
+                        array_items.reverse();
+                        for item in array_items {
+                            match item {
+                                Token::String(bytes) => {
+                                    let decoded = show_text(
+                                        bytes,
+                                        &gs,
+                                        &mut text_object,
+                                        runs.as_deref_mut(),
+                                        marked,
+                                    );
+                                    text_line.push_str(&decoded);
+                                }
+                                token => {
+                                    let Some(n) = token.number() else {
+                                        continue;
+                                    };
+                                    text_object.advance(
+                                        -n / 1000.0
+                                            * gs.text.font_size
+                                            * gs.text.horizontal_scaling,
+                                    );
+                                    if n < -200.0 && marked.visible() {
                                         // Large negative numbers indicate word spacing
                                         text_line.push(' ');
                                     }
-                                    _ => {}
                                 }
                             }
                         }
                     }
                 }
-                "'" => {
+                "'" if i >= 1 && in_text => {
                     // Move to next line and show text
-                    if i >= 1 && in_text {
-                        if !text_line.is_empty() {
-                            if !text.is_empty() {
-                                text.push(' ');
-                            }
-                            text.push_str(&text_line);
-                            text_line.clear();
-                        }
-                        if let Token::String(bytes) = &tokens[i - 1] {
-                            let decoded = decode_text(bytes, current_font);
-                            text_line.push_str(&decoded);
+                    if !text_line.is_empty() {
+                        if !text.is_empty() {
+                            text.push(' ');
                         }
+                        text.push_str(&text_line);
+                        text_line.clear();
+                    }
+                    text_object.next_line(0.0, -gs.text.leading);
+                    if let Token::String(bytes) = &tokens[i - 1] {
+                        let decoded =
+                            show_text(bytes, &gs, &mut text_object, runs.as_deref_mut(), marked);
+                        text_line.push_str(&decoded);
                     }
                 }
-                "\"" => {
+                "\"" if i >= 3 && in_text => {
                     // Set word and char spacing, move to next line, show text
-                    if i >= 3 && in_text {
-                        if !text_line.is_empty() {
-                            if !text.is_empty() {
-                                text.push(' ');
-                            }
-                            text.push_str(&text_line);
-                            text_line.clear();
-                        }
-                        if let Token::String(bytes) = &tokens[i - 1] {
-                            let decoded = decode_text(bytes, current_font);
-                            text_line.push_str(&decoded);
+                    if !text_line.is_empty() {
+                        if !text.is_empty() {
+                            text.push(' ');
                         }
+                        text.push_str(&text_line);
+                        text_line.clear();
+                    }
+                    if let (Some(word), Some(char)) =
+                        (tokens[i - 3].number(), tokens[i - 2].number())
+                    {
+                        gs.text.word_spacing = word;
+                        gs.text.char_spacing = char;
+                    }
+                    text_object.next_line(0.0, -gs.text.leading);
+                    if let Token::String(bytes) = &tokens[i - 1] {
+                        let decoded =
+                            show_text(bytes, &gs, &mut text_object, runs.as_deref_mut(), marked);
+                        text_line.push_str(&decoded);
                     }
                 }
-                "Do" => {
-                    // Draw XObject
-                    if i >= 1 {
-                        if let Token::Name(xobj_name) = &tokens[i - 1] {
-                            if let Some(xobj_text) =
-                                process_xobject(xobj_name, resources, objects, fonts)
-                            {
-                                if !text.is_empty() {
-                                    text.push(' ');
-                                }
-                                text.push_str(&xobj_text);
+                "BMC" | "BDC" => marked.begin(&tokens, i, resources, objects),
+                "EMC" if marked.depth() > marked_floor => {
+                    if let Some(replacement) = marked.end() {
+                        replace_content(
+                            replacement,
+                            in_text,
+                            &mut text,
+                            &mut text_line,
+                            runs.as_deref_mut(),
+                        );
+                    }
+                }
+                "Do" if i >= 1 && !marked.hidden() => {
+                    // Draw XObject, unless it is an artifact being left out
+                    if let Token::Name(xobj_name) = &tokens[i - 1] {
+                        if let Some(xobj_text) = process_xobject(
+                            xobj_name,
+                            resources,
+                            objects,
+                            fonts,
+                            gs.ctm,
+                            runs.as_deref_mut(),
+                            marked,
+                        ) {
+                            if !text.is_empty() {
+                                text.push(' ');
                             }
+                            text.push_str(&xobj_text);
                         }
                     }
                 }
@@ -181,6 +342,19 @@ fn extract_text_from_stream(
         i += 1;
     }
 
+    // Close sequences left open by the stream
+    while marked.depth() > marked_floor {
+        if let Some(replacement) = marked.end() {
+            replace_content(
+                replacement,
+                in_text,
+                &mut text,
+                &mut text_line,
+                runs.as_deref_mut(),
+            );
+        }
+    }
+
     // Don't forget remaining text
     if !text_line.is_empty() {
         if !text.is_empty() {
@@ -192,6 +366,75 @@ fn extract_text_from_stream(
     text
 }
 
+/// The `N` numeric operands right before the operator at `index`
+fn operands<const N: usize>(tokens: &[Token], index: usize) -> Option<[f32; N]> {
+    let mut values = [0.0; N];
+    for (value, token) in values
+        .iter_mut()
+        .zip(tokens.get(index.checked_sub(N)?..index)?)
+    {
+        *value = token.number()?;
+    }
+    Some(values)
+}
+
+/// Decode a string operand and, when collecting runs, record where it is shown.
+/// Text hidden or replaced by marked content comes back empty.
+fn show_text(
+    bytes: &[u8],
+    gs: &GraphicsState,
+    text_object: &mut TextObject,
+    runs: Option<&mut Vec<TextRun>>,
+    marked: &mut MarkedContentStack,
+) -> String {
+    let decoded = decode_text(bytes, gs.text.font);
+    let run = runs
+        .is_some()
+        .then(|| text_object.show(decoded.clone(), &char_codes(bytes, gs.text.font), gs));
+    let Some((decoded, run)) = marked.show(decoded, run) else {
+        return String::new();
+    };
+    if let (Some(runs), Some(run)) = (runs, run) {
+        if !run.text.is_empty() {
+            runs.push(run);
+        }
+    }
+    decoded
+}
+
+/// Output the `/ActualText` (or `/Alt`) of a marked-content sequence in place
+/// of the text it contained
+fn replace_content(
+    (replacement, run): Replacement,
+    in_text: bool,
+    text: &mut String,
+    text_line: &mut String,
+    runs: Option<&mut Vec<TextRun>>,
+) {
+    if in_text {
+        text_line.push_str(&replacement);
+    } else if !replacement.is_empty() {
+        // `BT` clears the line, so text outside a text object goes out directly
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&replacement);
+    }
+    if let (Some(runs), Some(run)) = (runs, run) {
+        if !run.text.is_empty() {
+            runs.push(run);
+        }
+    }
+}
+
+/// Split a string operand into character codes, single bytes without a font
+fn char_codes(bytes: &[u8], font: Option<&PdfFont>) -> Vec<u32> {
+    match font {
+        Some(font) => font.char_codes(bytes),
+        None => bytes.iter().map(|&b| b as u32).collect(),
+    }
+}
+
 fn decode_text(bytes: &[u8], font: Option<&PdfFont>) -> String {
     if let Some(font) = font {
         decode_with_font(bytes, font)
@@ -210,34 +453,44 @@ fn decode_text(bytes: &[u8], font: Option<&PdfFont>) -> String {
     }
 }
 
-fn decode_with_font(bytes: &[u8], font: &PdfFont) -> String {
+pub(crate) fn decode_with_font(bytes: &[u8], font: &PdfFont) -> String {
     let mut result = String::new();
 
     // Check if it's a CID font (Type0)
-    let is_cid =
-        font.subtype == "Type0" || font.encoding == "Identity-H" || font.encoding == "Identity-V";
-
-    if is_cid {
-        // CID fonts - 2 bytes per character
-        let mut i = 0;
-        while i < bytes.len() {
-            let cid = if i + 1 < bytes.len() {
-                ((bytes[i] as u32) << 8) | (bytes[i + 1] as u32)
-            } else {
-                bytes[i] as u32
-            };
-            i += 2;
-
+    if font.is_cid() {
+        // CID fonts - codes split by the font's code space
+        for cid in font.char_codes(bytes) {
             // Check ToUnicode mapping first
             if let Some(unicode_map) = &font.to_unicode {
-                if let Some(unicode_str) = unicode_map.get(&cid) {
-                    result.push_str(unicode_str);
+                if let Some(unicode_str) = unicode_map.get(cid) {
+                    result.push_str(&unicode_str);
                     continue;
                 }
             }
 
-            // For CID fonts without ToUnicode, we should use replacement character
-            // as direct CID to Unicode conversion is rarely correct
+            // Then the encoding, when it is a predefined CMap that maps to Unicode
+            if let Some(ch) = font
+                .encoding_cmap
+                .as_ref()
+                .and_then(|cmap| cmap.decode(cid))
+            {
+                result.push(ch);
+                continue;
+            }
+
+            // Then the embedded font program
+            if let Some(text) = font.embedded_unicode.as_ref().and_then(|map| map.get(&cid)) {
+                result.push_str(text);
+                continue;
+            }
+
+            // Then the Adobe character collection the CIDs belong to
+            if let Some(ch) = font.cid_ordering.and_then(|ordering| ordering.get(cid)) {
+                result.push(ch);
+                continue;
+            }
+
+            // Otherwise there is no telling what the CID shows
             result.push('�');
         }
     } else {
@@ -245,22 +498,48 @@ fn decode_with_font(bytes: &[u8], font: &PdfFont) -> String {
         for &byte in bytes {
             let code = byte as u32;
 
-            // Check differences first
-            if let Some(differences) = &font.differences {
-                if let Some(glyph_name) = differences.get(&code) {
-                    result.push_str(glyph_name);
+            // Check ToUnicode first
+            if let Some(unicode_map) = &font.to_unicode {
+                if let Some(unicode_str) = unicode_map.get(code) {
+                    result.push_str(&unicode_str);
                     continue;
                 }
             }
 
-            // Check ToUnicode
-            if let Some(unicode_map) = &font.to_unicode {
-                if let Some(unicode_str) = unicode_map.get(&code) {
-                    result.push_str(unicode_str);
+            // Then the glyph name given by the differences
+            let difference = font
+                .differences
+                .as_ref()
+                .and_then(|differences| differences.get(&code));
+            if let Some(Some(text)) = difference {
+                result.push_str(text);
+                continue;
+            }
+
+            // The embedded font program, unless a standard encoding names
+            // the glyph
+            if difference.is_some()
+                || !matches!(
+                    font.encoding.as_str(),
+                    "WinAnsiEncoding" | "MacRomanEncoding"
+                )
+            {
+                if let Some(text) = font
+                    .embedded_unicode
+                    .as_ref()
+                    .and_then(|map| map.get(&code))
+                {
+                    result.push_str(text);
                     continue;
                 }
             }
 
+            // A glyph name nothing could resolve
+            if difference.is_some() {
+                result.push('?');
+                continue;
+            }
+
             // Apply encoding
             let ch = match font.encoding.as_str() {
                 "WinAnsiEncoding" => decode_winansi(byte),
@@ -284,8 +563,11 @@ fn decode_with_font(bytes: &[u8], font: &PdfFont) -> String {
 fn process_xobject(
     xobj_name: &str,
     resources: &BTreeMap<String, PdfObj>,
-    objects: &BTreeMap<(u32, u16), PdfObj>,
-    parent_fonts: &BTreeMap<String, PdfFont>,
+    objects: &Document,
+    parent_fonts: &BTreeMap<String, Rc<PdfFont>>,
+    ctm: Matrix,
+    runs: Option<&mut Vec<TextRun>>,
+    marked: &mut MarkedContentStack,
 ) -> Option<String> {
     let xobjects = match resources.get("XObject") {
         Some(PdfObj::Dictionary(dict)) => dict,
@@ -315,40 +597,68 @@ fn process_xobject(
                 return None;
             }
 
-            // Get stream data
-            let data = handle_stream_filters(&stream.dict, &stream.data).ok()?;
-
-            // Get resources
-            let mut xobj_resources = BTreeMap::new();
-            match stream.dict.get("Resources") {
-                Some(PdfObj::Dictionary(res)) => {
-                    xobj_resources = res.clone();
-                }
-                Some(PdfObj::Reference(res_ref)) => {
-                    if let Some(PdfObj::Dictionary(res)) = resolve_reference(objects, res_ref) {
-                        xobj_resources = res.clone();
-                    }
-                }
-                _ => {}
+            // A form drawing itself, directly or not, is skipped
+            if !objects.enter_form(*xobj_ref) {
+                return None;
             }
+            let text = form_text(stream, parent_fonts, objects, ctm, runs, marked);
+            objects.leave_form();
+            text
+        }
+        _ => None,
+    }
+}
 
-            // Extract fonts from XObject resources
-            let mut fonts = parent_fonts.clone();
-            if let Some(PdfObj::Dictionary(font_dict)) = xobj_resources.get("Font") {
-                let xobj_fonts = crate::font::extract_fonts(font_dict, objects);
-                fonts.extend(xobj_fonts);
+/// Interpret a form XObject (or annotation appearance) drawn with `ctm`
+pub(crate) fn form_text(
+    stream: &PdfStream,
+    parent_fonts: &BTreeMap<String, Rc<PdfFont>>,
+    objects: &Document,
+    ctm: Matrix,
+    runs: Option<&mut Vec<TextRun>>,
+    marked: &mut MarkedContentStack,
+) -> Option<String> {
+    // Get stream data
+    let data = objects.decode_stream(stream).ok()?;
+
+    // Get resources
+    let mut xobj_resources = BTreeMap::new();
+    match stream.dict.get("Resources") {
+        Some(PdfObj::Dictionary(res)) => {
+            xobj_resources = res.clone();
+        }
+        Some(PdfObj::Reference(res_ref)) => {
+            if let Some(PdfObj::Dictionary(res)) = resolve_reference(objects, res_ref) {
+                xobj_resources = res.clone();
             }
-
-            // Extract text from form
-            Some(extract_text_from_stream(
-                &data,
-                &fonts,
-                &xobj_resources,
-                objects,
-            ))
         }
-        _ => None,
+        _ => {}
     }
+
+    // Extract fonts from XObject resources
+    let mut fonts = parent_fonts.clone();
+    if let Some(PdfObj::Dictionary(font_dict)) = xobj_resources.get("Font") {
+        let xobj_fonts = crate::font::extract_fonts(font_dict, objects);
+        fonts.extend(xobj_fonts);
+    }
+
+    // The form matrix maps form space into the user space at `Do`
+    let form_matrix = stream
+        .dict
+        .get("Matrix")
+        .and_then(Matrix::from_obj)
+        .unwrap_or(Matrix::IDENTITY);
+
+    // Extract text from form
+    Some(extract_text_from_stream(
+        &data,
+        &fonts,
+        &xobj_resources,
+        objects,
+        form_matrix.multiply(&ctm),
+        runs,
+        marked,
+    ))
 }
 
 fn decode_winansi(byte: u8) -> char {
@@ -4,7 +4,8 @@ use core::str;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
-    Number(f32),
+    Integer(i64),
+    Real(f64),
     String(Vec<u8>),
     Name(String),
     Operator(String),
@@ -14,6 +15,17 @@ pub enum Token {
     DictEnd,
 }
 
+impl Token {
+    /// An integer or real operand as `f32`
+    pub fn number(&self) -> Option<f32> {
+        match self {
+            Token::Integer(n) => Some(*n as f32),
+            Token::Real(n) => Some(*n as f32),
+            _ => None,
+        }
+    }
+}
+
 pub struct TokenParser<'a> {
     data: &'a [u8],
     pos: usize,
@@ -80,10 +92,15 @@ impl<'a> TokenParser<'a> {
         self.data.get(self.pos + offset).copied()
     }
 
+    /// Skip whitespace and `%` comments, which run to the end of the line
     fn skip_whitespace(&mut self) {
         while let Some(ch) = self.peek() {
             if ch.is_ascii_whitespace() {
                 self.pos += 1;
+            } else if ch == b'%' {
+                while self.peek().is_some_and(|ch| ch != b'\n' && ch != b'\r') {
+                    self.pos += 1;
+                }
             } else {
                 break;
             }
@@ -213,8 +230,14 @@ impl<'a> TokenParser<'a> {
         }
 
         let num_str = str::from_utf8(&self.data[start..self.pos]).ok()?;
-        let num = num_str.parse::<f32>().ok()?;
-        Some(Token::Number(num))
+        // Integers too large for `i64` are kept as reals
+        if !has_dot {
+            if let Ok(num) = num_str.parse::<i64>() {
+                return Some(Token::Integer(num));
+            }
+        }
+        let num = num_str.parse::<f64>().ok()?;
+        Some(Token::Real(num))
     }
 
     fn parse_operator(&mut self) -> Option<Token> {
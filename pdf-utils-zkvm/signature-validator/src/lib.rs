@@ -37,8 +37,8 @@ impl fmt::Display for SignatureAlgorithm {
 }
 
 pub fn verify_pdf_signature(pdf_bytes: &[u8]) -> Result<bool, String> {
-    // First extract the signature DER and signed data from the PDF
-    let (signature_der, signed_data) = signed_bytes_extractor::get_signature_der(pdf_bytes)?;
+    // First extract the signature DER and the signed segments from the PDF
+    let (signature_der, signed_segments) = signed_bytes_extractor::get_signature_der(pdf_bytes)?;
 
     // Parse the PKCS#7 signed data first to get the digest algorithm
     // Parse the PKCS#7 structure using reference implementation
@@ -46,7 +46,7 @@ pub fn verify_pdf_signature(pdf_bytes: &[u8]) -> Result<bool, String> {
 
     // Calculate hash of the actual signed PDF data using the algorithm from PKCS#7
     let calculated_signed_data_hash =
-        calculate_pdf_data_hash(&signed_data, &verifier_params.sig_algorithm)?;
+        calculate_pdf_data_hash(&signed_segments, &verifier_params.sig_algorithm)?;
 
     // Store the calculated hash as the actual message digest
     verifier_params.actual_message_digest = Some(calculated_signed_data_hash.clone());
@@ -146,33 +146,26 @@ fn calculate_signed_attrs_hash(
     Ok(hash)
 }
 
+/// Hash the signed segments in order, as if they were one contiguous buffer
 fn calculate_pdf_data_hash(
-    signed_data: &[u8],
+    signed_segments: &[&[u8]],
     algorithm: &SignatureAlgorithm,
 ) -> Result<Vec<u8>, String> {
     use sha1::Digest;
 
-    let hash = match algorithm {
-        SignatureAlgorithm::Sha1WithRsaEncryption => {
-            let mut hasher = Sha1::new();
-            hasher.update(signed_data);
-            hasher.finalize().to_vec()
-        }
-        SignatureAlgorithm::Sha256WithRsaEncryption => {
-            let mut hasher = Sha256::new();
-            hasher.update(signed_data);
-            hasher.finalize().to_vec()
-        }
-        SignatureAlgorithm::Sha384WithRsaEncryption => {
-            let mut hasher = Sha384::new();
-            hasher.update(signed_data);
-            hasher.finalize().to_vec()
-        }
-        SignatureAlgorithm::Sha512WithRsaEncryption => {
-            let mut hasher = Sha512::new();
-            hasher.update(signed_data);
-            hasher.finalize().to_vec()
+    fn digest_segments<D: Digest>(segments: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = D::new();
+        for segment in segments {
+            hasher.update(segment);
         }
+        hasher.finalize().to_vec()
+    }
+
+    let hash = match algorithm {
+        SignatureAlgorithm::Sha1WithRsaEncryption => digest_segments::<Sha1>(signed_segments),
+        SignatureAlgorithm::Sha256WithRsaEncryption => digest_segments::<Sha256>(signed_segments),
+        SignatureAlgorithm::Sha384WithRsaEncryption => digest_segments::<Sha384>(signed_segments),
+        SignatureAlgorithm::Sha512WithRsaEncryption => digest_segments::<Sha512>(signed_segments),
     };
 
     Ok(hash)
@@ -3,7 +3,9 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
-pub(crate) fn get_signature_der(pdf_bytes: &[u8]) -> Result<(Vec<u8>, Vec<u8>), String> {
+/// Returns the signature DER and the two signed ByteRange segments, borrowed
+/// from `pdf_bytes` so they can be hashed without concatenating them
+pub(crate) fn get_signature_der(pdf_bytes: &[u8]) -> Result<(Vec<u8>, [&[u8]; 2]), String> {
     #[cfg(feature = "debug")]
     pdf_logger::debug_log!("Looking for signature in PDF of {} bytes", pdf_bytes.len());
 
@@ -76,22 +78,23 @@ fn extract_byte_range(pdf_bytes: &[u8]) -> Result<ByteRange, String> {
     })
 }
 
-fn extract_signed_data(pdf_bytes: &[u8], byte_range: &ByteRange) -> Result<Vec<u8>, String> {
-    let mut signed_data = Vec::new();
-
-    let end1 = byte_range.offset1 + byte_range.length1;
-    if end1 > pdf_bytes.len() {
-        return Err(String::from("First ByteRange segment out of bounds"));
-    }
-    signed_data.extend_from_slice(&pdf_bytes[byte_range.offset1..end1]);
-
-    let end2 = byte_range.offset2 + byte_range.length2;
-    if end2 > pdf_bytes.len() {
-        return Err(String::from("Second ByteRange segment out of bounds"));
-    }
-    signed_data.extend_from_slice(&pdf_bytes[byte_range.offset2..end2]);
-
-    Ok(signed_data)
+fn extract_signed_data<'a>(
+    pdf_bytes: &'a [u8],
+    byte_range: &ByteRange,
+) -> Result<[&'a [u8]; 2], String> {
+    let first = byte_range
+        .offset1
+        .checked_add(byte_range.length1)
+        .and_then(|end1| pdf_bytes.get(byte_range.offset1..end1))
+        .ok_or_else(|| String::from("First ByteRange segment out of bounds"))?;
+
+    let second = byte_range
+        .offset2
+        .checked_add(byte_range.length2)
+        .and_then(|end2| pdf_bytes.get(byte_range.offset2..end2))
+        .ok_or_else(|| String::from("Second ByteRange segment out of bounds"))?;
+
+    Ok([first, second])
 }
 
 fn extract_signature_hex(pdf_bytes: &[u8], byte_range: &ByteRange) -> Result<String, String> {
@@ -5,7 +5,8 @@ set -e
 echo "Building RISC-V program..."
 # Ensure we're using the correct linker scripts
 export RUSTFLAGS="-C target-feature=+m,-unaligned-scalar-mem,+relax -C link-arg=-Tlds/memory.x -C link-arg=-Tlds/link.x -C link-arg=--save-temps -C force-frame-pointers"
-cargo build --release --target riscv32im-unknown-none-elf
+# Extra arguments are passed to cargo, e.g. ./build.sh --features cjk
+cargo build --release --target riscv32im-unknown-none-elf "$@"
 
 # Find LLVM tools - try rustup first, then system
 OBJCOPY=$(find ~/.rustup -name "llvm-objcopy" 2>/dev/null | head -1)
@@ -1,20 +1,174 @@
 #!/usr/bin/env python3
 """
 Prepare input data for PDF validation RISC-V program in Airbender format.
-Usage: python prepare_input.py <pdf_file> [expected_text] > input.txt
+Usage: python prepare_input.py <pdf_file> [expected_text] [--regex] [--normalize STEPS] > input.txt
+       python prepare_input.py <pdf_file> <anchor> [--disclose-chars N] [--disclose-until DELIM] > input.txt
+       python prepare_input.py <pdf_file> <label> --compare OP VALUE [--date ORDER | --decimal-separator C --group-separator C] > input.txt
+       python prepare_input.py <pdf_file> <expected_text> --region X0 Y0 X1 Y1 [--page N] [--normalize STEPS] > input.txt
+       python prepare_input.py <pdf_file> <field_name> --field-equals VALUE [--normalize STEPS] > input.txt
+Any of these accepts --password PASSWORD for an encrypted PDF.
 """
 
-import sys
+import argparse
+import struct
+
+# Predicate modes, must match PREDICATE_* in src/main.rs
+PREDICATE_SUBSTRING = 0
+PREDICATE_REGEX = 1
+PREDICATE_DISCLOSE = 2
+PREDICATE_NUMERIC = 3
+PREDICATE_REGION = 4
+PREDICATE_FORM_FIELD = 5
+
+# Field comparison encoding, must match pdf_utils_zkvm_core::numeric
+COMPARISONS = ["<", "<=", "==", ">=", ">", "!="]
+DATE_ORDERS = ["dmy", "mdy", "ymd"]
+
+# Normalisation flags, must match pdf_utils_zkvm_core::normalize
+NORMALIZATION_FLAGS = {
+    "whitespace": 1 << 0,
+    "case": 1 << 1,
+    "nfkc": 1 << 2,
+    "ligatures": 1 << 3,
+    "hyphenation": 1 << 4,
+}
+
+
+def parse_profile(steps):
+    """Turn a comma-separated list of normalisation steps into profile bits"""
+    profile = 0
+    for step in filter(None, steps.split(",")):
+        if step == "all":
+            profile |= sum(NORMALIZATION_FLAGS.values())
+        elif step in NORMALIZATION_FLAGS:
+            profile |= NORMALIZATION_FLAGS[step]
+        else:
+            raise argparse.ArgumentTypeError(f"unknown normalisation step: {step}")
+    return profile
+
 
 def main():
-    if len(sys.argv) < 2:
-        print(
-            "Usage: python prepare_input.py <pdf_file> [expected_text]", file=sys.stderr
+    parser = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    parser.add_argument("pdf_file")
+    parser.add_argument("expected_text", nargs="?", default="")
+    parser.add_argument(
+        "--regex",
+        action="store_true",
+        help="treat expected_text as a regular expression",
+    )
+    parser.add_argument(
+        "--normalize",
+        type=parse_profile,
+        default=0,
+        metavar="STEPS",
+        help="normalise page and expected text before matching, comma-separated: "
+        + ", ".join(NORMALIZATION_FLAGS)
+        + " or all",
+    )
+    parser.add_argument(
+        "--disclose-chars",
+        type=int,
+        metavar="N",
+        help="disclose at most N characters following expected_text",
+    )
+    parser.add_argument(
+        "--disclose-until",
+        metavar="DELIM",
+        help="disclose the text following expected_text up to DELIM",
+    )
+    parser.add_argument(
+        "--compare",
+        nargs=2,
+        metavar=("OP", "VALUE"),
+        help="compare the value following the label in expected_text, "
+        "OP is one of " + " ".join(COMPARISONS) + ", VALUE is a canonical "
+        "number (3000.00) or date (2024-01-31)",
+    )
+    parser.add_argument(
+        "--date",
+        choices=DATE_ORDERS,
+        help="the compared field is a date in the given field order",
+    )
+    parser.add_argument(
+        "--decimal-separator",
+        default=".",
+        metavar="C",
+        help="decimal separator of the compared number (default: .)",
+    )
+    parser.add_argument(
+        "--group-separator",
+        default=",",
+        metavar="C",
+        help="thousands separator of the compared number, empty for none (default: ,)",
+    )
+    parser.add_argument(
+        "--region",
+        nargs=4,
+        type=float,
+        metavar=("X0", "Y0", "X1", "Y1"),
+        help="require expected_text inside this rectangle of the page as displayed, in points from its lower-left corner",
+    )
+    parser.add_argument(
+        "--page",
+        type=int,
+        default=0,
+        metavar="N",
+        help="page (from 0) the region refers to (default: 0)",
+    )
+    parser.add_argument(
+        "--password",
+        default="",
+        help="user or owner password of an encrypted PDF (default: empty)",
+    )
+    parser.add_argument(
+        "--field-equals",
+        metavar="VALUE",
+        help="require the form field named by expected_text (fully qualified, "
+        "e.g. applicant.name) to have this value",
+    )
+    args = parser.parse_args()
+
+    pdf_file = args.pdf_file
+    expected_text = args.expected_text
+    disclose = args.disclose_chars is not None or args.disclose_until is not None
+    compare = args.compare is not None
+    if disclose and args.regex:
+        parser.error("--regex cannot be combined with disclosure options")
+    if compare and (args.regex or disclose):
+        parser.error("--compare cannot be combined with --regex or disclosure options")
+    if compare and not expected_text:
+        parser.error("--compare requires a field label")
+    if compare and args.compare[0] not in COMPARISONS:
+        parser.error(f"unknown comparison: {args.compare[0]}")
+    if disclose and not expected_text:
+        parser.error("disclosure requires an anchor text")
+    region = args.region is not None
+    if region and (args.regex or disclose or compare):
+        parser.error("--region cannot be combined with --regex, --compare or disclosure options")
+    if region and not expected_text:
+        parser.error("--region requires an expected text")
+    if region and not 0 <= args.page < 0xFFFFFFFF:
+        parser.error("--page must be a non-negative page index")
+    form_field = args.field_equals is not None
+    if form_field and (args.regex or disclose or compare or region):
+        parser.error(
+            "--field-equals cannot be combined with --regex, --compare, --region or disclosure options"
         )
-        sys.exit(1)
+    if form_field and not expected_text:
+        parser.error("--field-equals requires a field name")
 
-    pdf_file = sys.argv[1]
-    expected_text = sys.argv[2] if len(sys.argv) > 2 else ""
+    if form_field:
+        predicate_mode = PREDICATE_FORM_FIELD
+    elif region:
+        predicate_mode = PREDICATE_REGION
+    elif compare:
+        predicate_mode = PREDICATE_NUMERIC
+    elif disclose:
+        predicate_mode = PREDICATE_DISCLOSE
+    elif args.regex:
+        predicate_mode = PREDICATE_REGEX
+    else:
+        predicate_mode = PREDICATE_SUBSTRING
 
     # Read PDF file
     with open(pdf_file, "rb") as f:
@@ -28,7 +182,17 @@ def main():
     # 2. PDF data
     # 3. Expected text size (4 bytes, big-endian)
     # 4. Expected text data
-    # 5. Page number (4 bytes) - 0xFFFFFFFF means check all pages
+    # 5. Page number (4 bytes) - region mode only, otherwise 0xFFFFFFFF
+    # 6. Predicate mode (4 bytes) - 0 = substring, 1 = regex, 2 = disclose, 3 = numeric,
+    #    4 = region, 5 = form field
+    # 7. Normalisation profile (4 bytes)
+    # 8. Password size (4 bytes) and password data
+    # 9. Disclosure mode only: max span chars (4 bytes, 0 = no limit),
+    #    delimiter size (4 bytes) and delimiter data
+    # 10. Numeric mode only: field format (4 bytes), comparison (4 bytes),
+    #     threshold size (4 bytes) and threshold data
+    # 11. Region mode only: x0, y0, x1, y1 (4 bytes each, big-endian f32)
+    # 12. Form field mode only: value size (4 bytes) and value data
 
     input_data = bytearray()
 
@@ -48,8 +212,61 @@ def main():
     # Add expected text data
     input_data.extend(expected_bytes)
 
+    # Pad expected text to multiple of 4 bytes so the following words stay aligned
+    while len(input_data) % 4 != 0:
+        input_data.append(0)
+
     # Add page number (0xFFFFFFFF = check all pages)
-    input_data.extend(b"\xff\xff\xff\xff")
+    if region:
+        input_data.extend(args.page.to_bytes(4, byteorder="big"))
+    else:
+        input_data.extend(b"\xff\xff\xff\xff")
+
+    # Add predicate mode
+    input_data.extend(predicate_mode.to_bytes(4, byteorder="big"))
+
+    # Add normalisation profile
+    input_data.extend(args.normalize.to_bytes(4, byteorder="big"))
+
+    # Add password
+    password = args.password.encode("utf-8")
+    input_data.extend(len(password).to_bytes(4, byteorder="big"))
+    input_data.extend(password)
+    while len(input_data) % 4 != 0:
+        input_data.append(0)
+
+    # Add span parameters
+    if disclose:
+        delimiter = (args.disclose_until or "").encode("utf-8")
+        input_data.extend((args.disclose_chars or 0).to_bytes(4, byteorder="big"))
+        input_data.extend(len(delimiter).to_bytes(4, byteorder="big"))
+        input_data.extend(delimiter)
+
+    # Add comparison parameters
+    if compare:
+        if args.date:
+            format_word = (1 << 24) | DATE_ORDERS.index(args.date)
+        else:
+            group = args.group_separator.encode("latin-1")
+            decimal = args.decimal_separator.encode("latin-1")
+            if len(decimal) != 1 or len(group) > 1:
+                parser.error("separators must be single Latin-1 characters")
+            format_word = decimal[0] | ((group[0] if group else 0) << 8)
+        threshold = args.compare[1].encode("utf-8")
+        input_data.extend(format_word.to_bytes(4, byteorder="big"))
+        input_data.extend(COMPARISONS.index(args.compare[0]).to_bytes(4, byteorder="big"))
+        input_data.extend(len(threshold).to_bytes(4, byteorder="big"))
+        input_data.extend(threshold)
+
+    # Add region rectangle
+    if region:
+        input_data.extend(struct.pack(">4f", *args.region))
+
+    # Add expected field value
+    if form_field:
+        value = args.field_equals.encode("utf-8")
+        input_data.extend(len(value).to_bytes(4, byteorder="big"))
+        input_data.extend(value)
 
     # Pad to multiple of 4 bytes (since airbender reads 32-bit words)
     while len(input_data) % 4 != 0:
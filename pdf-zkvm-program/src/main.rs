@@ -93,7 +93,74 @@ pub extern "C" fn machine_start_trap_rust(_trap_frame: *mut MachineTrapFrame) ->
 // - PDF data (variable)
 // - Expected text size (4 bytes)
 // - Expected text (variable)
-// - Page number to check (4 bytes) - optional, 0xFFFFFFFF means check all pages
+// - Page number to check (4 bytes) - only used in region mode, 0xFFFFFFFF means check all pages
+// - Predicate mode (4 bytes) - how the expected text is matched, see PREDICATE_*
+// - Normalisation profile (4 bytes) - flags from `pdf_utils_zkvm_core::normalize`
+// - Password size (4 bytes)
+// - Password (variable) - user or owner password of an encrypted PDF, empty to try the empty password
+// - Disclosure mode only:
+//   - Maximum span length in characters (4 bytes) - 0 means no limit
+//   - Delimiter size (4 bytes)
+//   - Delimiter (variable)
+// - Numeric mode only:
+//   - Field format (4 bytes) - see `pdf_utils_zkvm_core::numeric::FieldFormat::from_word`
+//   - Comparison (4 bytes) - 0 = <, 1 = <=, 2 = ==, 3 = >=, 4 = >, 5 = !=
+//   - Threshold size (4 bytes)
+//   - Threshold (variable) - canonical number ("3000.00") or date ("2024-01-31")
+// - Region mode only:
+//   - Region x0, y0, x1, y1 (4 bytes each) - f32 bit patterns, PDF user space
+// - Form field mode only:
+//   - Value size (4 bytes)
+//   - Value (variable) - compared with the value of the field named by the expected text
+
+/// Expected text must appear verbatim in a page
+const PREDICATE_SUBSTRING: u32 = 0;
+/// Expected text is a regular expression (see `pdf_utils_zkvm_core::pattern`)
+const PREDICATE_REGEX: u32 = 1;
+/// Expected text is an anchor, the span following it is disclosed
+const PREDICATE_DISCLOSE: u32 = 2;
+/// Expected text is a field label, the value following it is compared
+const PREDICATE_NUMERIC: u32 = 3;
+/// Expected text must appear inside a rectangle of the given page
+const PREDICATE_REGION: u32 = 4;
+/// Expected text is a form field name, the field value is compared
+const PREDICATE_FORM_FIELD: u32 = 5;
+
+/// Disclosed spans up to this many bytes are revealed in the output
+const DISCLOSED_BYTES_MAX: usize = 12;
+
+/// result[0] flag: the signature is valid
+const FLAG_SIGNATURE_VALID: u32 = 1 << 0;
+/// result[0] flag: the document is encrypted
+const FLAG_ENCRYPTED: u32 = 1 << 1;
+/// result[0] flag, disclosure mode: the anchor was found
+const FLAG_ANCHOR_FOUND: u32 = 1 << 2;
+/// result[0] flag, disclosure mode: the span is revealed in result[1..4]
+const FLAG_SPAN_REVEALED: u32 = 1 << 3;
+
+/// Short hash of an error message, to tell failures apart in the output
+fn error_hash(message: &str) -> u32 {
+    let mut hash = 0u32;
+    // Only hash the first 16 bytes
+    for byte in message.bytes().take(16) {
+        hash = hash.rotate_left(3) ^ (byte as u32);
+    }
+    hash
+}
+
+/// Read `len` bytes packed big-endian into input words.
+///
+/// The buffer is allocated once at its final size and the padding of the last
+/// word is dropped, so reading never grows the heap beyond `len` bytes.
+fn read_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    while bytes.len() < len {
+        let word = csr_read_word().to_be_bytes();
+        let take = (len - bytes.len()).min(4);
+        bytes.extend_from_slice(&word[..take]);
+    }
+    bytes
+}
 
 unsafe fn workload() -> ! {
     // Create UART for debugging
@@ -154,27 +221,9 @@ unsafe fn workload() -> ! {
         ]);
     }
 
-    // Read PDF data word by word
-    let mut pdf_data = Vec::with_capacity(input_size);
-    let words_to_read = input_size.div_ceil(4); // Round up to next word
-
-    for _ in 0..words_to_read {
-        let word = csr_read_word();
-        // Extract bytes from word (big-endian order to match hex string)
-        if pdf_data.len() < input_size {
-            pdf_data.push(((word >> 24) & 0xFF) as u8);
-        }
-        if pdf_data.len() < input_size {
-            pdf_data.push(((word >> 16) & 0xFF) as u8);
-        }
-        if pdf_data.len() < input_size {
-            pdf_data.push(((word >> 8) & 0xFF) as u8);
-        }
-        if pdf_data.len() < input_size {
-            pdf_data.push((word & 0xFF) as u8);
-        }
-    }
-    pdf_data.truncate(input_size);
+    // Read PDF data word by word. This is the only copy of the document, every
+    // later stage borrows it.
+    let pdf_data = read_bytes(input_size);
 
     let pdf_len = pdf_data.len();
     let _ = write!(uart, "Read {pdf_len} bytes of PDF data");
@@ -215,34 +264,196 @@ unsafe fn workload() -> ! {
 
     // Read expected text if provided
     let expected_text = if expected_text_size > 0 {
-        let mut text = Vec::with_capacity(expected_text_size);
-        let words_to_read = expected_text_size.div_ceil(4);
-
-        for _ in 0..words_to_read {
-            let word = csr_read_word();
-            // Extract bytes in big-endian order to match hex string
-            if text.len() < expected_text_size {
-                text.push(((word >> 24) & 0xFF) as u8);
+        Some(read_bytes(expected_text_size))
+    } else {
+        None
+    };
+
+    // Read page number, only region mode restricts the search to one page
+    let page_number_word = csr_read_word();
+
+    let predicate_mode = csr_read_word();
+    let _ = write!(uart, "Predicate mode: {predicate_mode}");
+    if !matches!(
+        predicate_mode,
+        PREDICATE_SUBSTRING
+            | PREDICATE_REGEX
+            | PREDICATE_DISCLOSE
+            | PREDICATE_NUMERIC
+            | PREDICATE_REGION
+            | PREDICATE_FORM_FIELD
+    ) {
+        // Return error code 4: invalid predicate
+        zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, 0, 0, 0, 0, 0]);
+    }
+
+    let profile_word = csr_read_word();
+    let _ = write!(uart, "Normalisation profile: 0x{profile_word:x}");
+    let profile = match pdf_utils_zkvm_core::NormalizationProfile::from_bits(profile_word) {
+        Ok(profile) => profile,
+        Err(_) => {
+            // Return error code 4: invalid predicate
+            zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, profile_word, 0, 0, 0, 0]);
+        }
+    };
+
+    // Read the password, which stays private
+    let password_size = csr_read_word() as usize;
+    let password = read_bytes(password_size);
+
+    // Read the span parameters in disclosure mode, along with the bytes the
+    // commitment covers: mode, profile, maximum length, delimiter and anchor
+    let span_spec = if predicate_mode == PREDICATE_DISCLOSE {
+        let max_chars = csr_read_word();
+        let delimiter_size = csr_read_word();
+        let delimiter = read_bytes(delimiter_size as usize);
+
+        let anchor = expected_text.as_deref().unwrap_or_default();
+        let mut committed = Vec::with_capacity(24 + delimiter.len() + anchor.len());
+        committed.extend_from_slice(&predicate_mode.to_be_bytes());
+        committed.extend_from_slice(&profile.bits().to_be_bytes());
+        committed.extend_from_slice(&max_chars.to_be_bytes());
+        committed.extend_from_slice(&delimiter_size.to_be_bytes());
+        committed.extend_from_slice(&delimiter);
+        committed.extend_from_slice(&(anchor.len() as u32).to_be_bytes());
+        committed.extend_from_slice(anchor);
+
+        match (
+            core::str::from_utf8(anchor),
+            alloc::string::String::from_utf8(delimiter),
+        ) {
+            (Ok(anchor), Ok(delimiter)) if !anchor.is_empty() => {
+                // The delimiter is normalised like the anchor. Collapsing
+                // whitespace trims a whitespace delimiter away, so it stands
+                // for the single space left between words.
+                let mut normalized = profile.apply(&delimiter);
+                if normalized.is_empty() && !delimiter.is_empty() && delimiter.trim().is_empty() {
+                    normalized = alloc::string::String::from(" ");
+                }
+                let spec = pdf_utils_zkvm_core::SpanSpec {
+                    // The anchor is searched for in the normalised pages
+                    anchor: profile.apply(anchor),
+                    max_chars: (max_chars > 0).then_some(max_chars as usize),
+                    delimiter: (!normalized.is_empty()).then_some(normalized),
+                };
+                Some((spec, committed))
             }
-            if text.len() < expected_text_size {
-                text.push(((word >> 16) & 0xFF) as u8);
+            _ => {
+                // Return error code 4: invalid predicate
+                zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, 0, 0, 0, 0, 0]);
             }
-            if text.len() < expected_text_size {
-                text.push(((word >> 8) & 0xFF) as u8);
+        }
+    } else {
+        None
+    };
+
+    // Read the comparison parameters in numeric mode, along with the bytes
+    // the predicate hash commits to
+    let field_predicate = if predicate_mode == PREDICATE_NUMERIC {
+        let format_word = csr_read_word();
+        let comparison_word = csr_read_word();
+        let threshold_size = csr_read_word() as usize;
+        let threshold = read_bytes(threshold_size);
+        let label = expected_text.as_deref().unwrap_or_default();
+
+        let format = pdf_utils_zkvm_core::numeric::FieldFormat::from_word(format_word);
+        let comparison = pdf_utils_zkvm_core::numeric::Comparison::from_word(comparison_word);
+        let threshold_value = format.and_then(|format| {
+            let threshold = core::str::from_utf8(&threshold).ok()?;
+            pdf_utils_zkvm_core::numeric::FieldValue::parse(threshold, &format)
+        });
+
+        match (
+            core::str::from_utf8(label),
+            format,
+            comparison,
+            threshold_value,
+        ) {
+            (Ok(label), Some(format), Some(comparison), Some(threshold_value))
+                if !label.is_empty() =>
+            {
+                let mut committed = Vec::with_capacity(20 + threshold.len() + label.len());
+                committed.extend_from_slice(&predicate_mode.to_be_bytes());
+                committed.extend_from_slice(&profile.bits().to_be_bytes());
+                committed.extend_from_slice(&format_word.to_be_bytes());
+                committed.extend_from_slice(&comparison_word.to_be_bytes());
+                committed.extend_from_slice(&(threshold_size as u32).to_be_bytes());
+                committed.extend_from_slice(&threshold);
+                committed.extend_from_slice(label.as_bytes());
+
+                let predicate = pdf_utils_zkvm_core::FieldPredicate {
+                    label: profile.apply(label),
+                    format,
+                    comparison,
+                    threshold: threshold_value,
+                };
+                Some((predicate, committed))
             }
-            if text.len() < expected_text_size {
-                text.push((word & 0xFF) as u8);
+            _ => {
+                // Return error code 4: invalid predicate
+                zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, 0, 0, 0, 0, 0]);
             }
         }
-        text.truncate(expected_text_size);
-        Some(text)
     } else {
         None
     };
 
-    // Read page number (kept for backward compatibility, but ignored)
-    let _page_number_word = csr_read_word();
-    // Always check all pages like the reference implementation
+    // Read the rectangle in region mode, along with its words for the predicate hash
+    let region = if predicate_mode == PREDICATE_REGION {
+        let region_words = [
+            csr_read_word(),
+            csr_read_word(),
+            csr_read_word(),
+            csr_read_word(),
+        ];
+        let [x0, y0, x1, y1] = region_words.map(f32::from_bits);
+        let valid = [x0, y0, x1, y1].iter().all(|v| v.is_finite())
+            && page_number_word != 0xFFFFFFFF
+            && expected_text.is_some();
+        if !valid {
+            // Return error code 4: invalid predicate
+            zksync_os_finish_success(&[
+                0xFFFFFFFF,
+                4,
+                predicate_mode,
+                page_number_word,
+                0,
+                0,
+                0,
+                0,
+            ]);
+        }
+        let rect = pdf_utils_zkvm_core::Rect::new(x0, y0, x1, y1);
+        Some((page_number_word as usize, rect, region_words))
+    } else {
+        None
+    };
+
+    // Read the expected value in form field mode, along with the bytes the
+    // predicate hash commits to
+    let form_predicate = if predicate_mode == PREDICATE_FORM_FIELD {
+        let value_size = csr_read_word() as usize;
+        let value = read_bytes(value_size);
+        let name = expected_text.as_deref().unwrap_or_default();
+
+        match (core::str::from_utf8(name), core::str::from_utf8(&value)) {
+            (Ok(name), Ok(value_str)) if !name.is_empty() => {
+                let mut committed = Vec::with_capacity(12 + value.len() + name.len());
+                committed.extend_from_slice(&predicate_mode.to_be_bytes());
+                committed.extend_from_slice(&profile.bits().to_be_bytes());
+                committed.extend_from_slice(&(value_size as u32).to_be_bytes());
+                committed.extend_from_slice(&value);
+                committed.extend_from_slice(name.as_bytes());
+                Some((name, profile.apply(value_str), committed))
+            }
+            _ => {
+                // Return error code 4: invalid predicate
+                zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, 0, 0, 0, 0, 0]);
+            }
+        }
+    } else {
+        None
+    };
 
     let _ = write!(uart, "Starting PDF validation...");
 
@@ -338,45 +549,133 @@ unsafe fn workload() -> ! {
     };
     let _ = write!(uart, "Signature validation complete");
 
+    // Parse the document once, decrypting it with the password if needed
+    let document = match pdf_utils_zkvm_core::Document::with_password(&pdf_data, &password) {
+        Ok(document) => document,
+        Err(e) => {
+            let _ = write!(uart, "PDF parsing failed: {e}");
+
+            // Let's examine the PDF structure near the end to debug
+            if pdf_data.len() > 100 {
+                let end_preview = &pdf_data[pdf_data.len() - 100..];
+                if let Ok(preview_str) = core::str::from_utf8(end_preview) {
+                    let _ = write!(uart, "PDF end preview: {preview_str}");
+                }
+            }
+            // Return error code 6 when the document cannot be decrypted,
+            // error code 7 when it exceeds the parse limits, otherwise error
+            // code 2: PDF validation/extraction failed.
+            // Include a hash of the error message for debugging
+            let code = match e {
+                pdf_utils_zkvm_core::PdfError::EncryptionError(_) => 6,
+                pdf_utils_zkvm_core::PdfError::LimitExceeded(_) => 7,
+                _ => 2,
+            };
+            zksync_os_finish_success(&[
+                0xFFFFFFFF,
+                code,
+                error_hash(&alloc::format!("{e}")),
+                pdf_data.len() as u32,
+                0,
+                0,
+                0,
+                0,
+            ]);
+        }
+    };
+    let encrypted = document.is_encrypted();
+    let _ = write!(uart, "Encrypted: {encrypted}");
+    let mut flags = 0;
+    if signature_valid {
+        flags |= FLAG_SIGNATURE_VALID;
+    }
+    if encrypted {
+        flags |= FLAG_ENCRYPTED;
+    }
+
+    // Form field mode reads the form instead of page text and reveals only
+    // the outcome:
+    // result[0] = flags: signature valid (bit 0), document encrypted (bit 1)
+    // result[1] = predicate holds (1) or not (0)
+    // result[2] = field found (1) or not (0)
+    // result[3] = total number of pages
+    // result[4..8] = commitment to the document and the predicate
+    if let Some((name, expected_value, committed)) = form_predicate {
+        let fields = pdf_utils_zkvm_core::extract_form_fields_from_document(&document);
+        if let Err(e) = document.check_limits() {
+            let _ = write!(uart, "Form extraction failed: {e}");
+            // Return error code 7: the document exceeds the parse limits
+            zksync_os_finish_success(&[
+                0xFFFFFFFF,
+                7,
+                error_hash(&alloc::format!("{e}")),
+                pdf_data.len() as u32,
+                0,
+                0,
+                0,
+                0,
+            ]);
+        }
+        let field = fields.iter().find(|field| field.name == name);
+        let found = field.is_some() as u32;
+        let holds = field
+            .and_then(|field| field.value.as_deref())
+            .is_some_and(|value| profile.apply(value) == expected_value) as u32;
+        let num_pages = document.pages().len() as u32;
+        let commitment = pdf_utils_zkvm_core::statement_commitment(&pdf_data, &committed);
+        let _ = write!(
+            uart,
+            "Form field predicate: found={found}, holds={holds}, commitment={commitment:08x?}"
+        );
+
+        zksync_os_finish_success(&[
+            flags,
+            holds,
+            found,
+            num_pages,
+            commitment[0],
+            commitment[1],
+            commitment[2],
+            commitment[3],
+        ]);
+    }
+
     // Extract text regardless of signature validation result
-    let result = match pdf_utils_zkvm_core::extract_text(pdf_data.clone()) {
-        Ok(text_pages) => {
+    let extracted = match &region {
+        Some((page, rect, _)) => {
+            pdf_utils_zkvm_core::extract_text_and_region(&document, *page, rect)
+                .map(|(text_pages, region_text)| (text_pages, Some(region_text)))
+        }
+        None => pdf_utils_zkvm_core::extract_text_from_document(document.pages(), &document)
+            .map_err(pdf_utils_zkvm_core::PdfError::ParseError)
+            .and_then(|text_pages| document.check_limits().map(|()| (text_pages, None))),
+    };
+    let (result, region_text) = match extracted {
+        Ok((text_pages, region_text)) => {
             let _ = write!(
                 uart,
                 "Text extraction successful! {} pages",
                 text_pages.len()
             );
-            pdf_utils_zkvm_core::PdfValidationResult {
+            let result = pdf_utils_zkvm_core::PdfValidationResult {
                 signature_valid,
                 text_pages,
-            }
+            };
+            (result, region_text)
         }
         Err(e) => {
             let _ = write!(uart, "Text extraction failed: {e}");
-
-            // Let's examine the PDF structure near the end to debug
-            if pdf_data.len() > 100 {
-                let end_preview = &pdf_data[pdf_data.len() - 100..];
-                if let Ok(preview_str) = core::str::from_utf8(end_preview) {
-                    let _ = write!(uart, "PDF end preview: {preview_str}");
-                }
-            }
-            // Return error code 2: PDF validation/extraction failed
+            // Return error code 7 when the document exceeds the parse limits,
+            // otherwise error code 2: PDF validation/extraction failed.
             // Include a hash of the error message for debugging
-            let error_hash = {
-                let mut hash = 0u32;
-                for (i, byte) in alloc::format!("{e}").bytes().enumerate() {
-                    if i >= 16 {
-                        break;
-                    } // Only hash first 16 bytes
-                    hash = hash.rotate_left(3) ^ (byte as u32);
-                }
-                hash
+            let code = match e {
+                pdf_utils_zkvm_core::PdfError::LimitExceeded(_) => 7,
+                _ => 2,
             };
             zksync_os_finish_success(&[
                 0xFFFFFFFF,
-                2,
-                error_hash,
+                code,
+                error_hash(&alloc::format!("{e}")),
                 pdf_data.len() as u32,
                 0,
                 0,
@@ -386,8 +685,117 @@ unsafe fn workload() -> ! {
         }
     };
 
-    // Check signature validity
-    let sig_valid = if result.signature_valid { 1u32 } else { 0u32 };
+    // Pages to match against, normalised if a profile was requested
+    let normalized = if profile.is_none() {
+        None
+    } else {
+        Some(result.normalized(&profile))
+    };
+    let search = normalized.as_ref().unwrap_or(&result);
+
+    // Disclosure mode has its own output layout:
+    // result[0] = flags: signature valid (bit 0), document encrypted (bit 1),
+    //   anchor found (bit 2), span revealed (bit 3)
+    // result[1..4] = span bytes, zero-padded, if the span is at most
+    //   DISCLOSED_BYTES_MAX bytes without NUL bytes
+    // result[4..8] = commitment to the document, the predicate (mode,
+    //   normalisation profile, maximum length, delimiter and anchor) and
+    //   the span
+    if let Some((spec, mut committed)) = span_spec {
+        let span = search.find_span(&spec).map(|m| search.matched_text(&m));
+        let span_bytes = span.unwrap_or_default().as_bytes();
+
+        let mut flags = flags;
+        if span.is_some() {
+            flags |= FLAG_ANCHOR_FOUND;
+        }
+        let mut revealed = [0u8; DISCLOSED_BYTES_MAX];
+        if span.is_some() && span_bytes.len() <= DISCLOSED_BYTES_MAX && !span_bytes.contains(&0) {
+            flags |= FLAG_SPAN_REVEALED;
+            revealed[..span_bytes.len()].copy_from_slice(span_bytes);
+        }
+        let revealed_word = |i: usize| {
+            u32::from_be_bytes([
+                revealed[i],
+                revealed[i + 1],
+                revealed[i + 2],
+                revealed[i + 3],
+            ])
+        };
+
+        // A missing anchor commits to no span at all, an empty span to a
+        // zero length
+        if span.is_some() {
+            committed.push(1);
+            committed.extend_from_slice(&(span_bytes.len() as u32).to_be_bytes());
+            committed.extend_from_slice(span_bytes);
+        } else {
+            committed.push(0);
+        }
+        let commitment = pdf_utils_zkvm_core::statement_commitment(&pdf_data, &committed);
+        let _ = write!(
+            uart,
+            "Disclosed span: flags={flags}, size={}, commitment={commitment:08x?}",
+            span_bytes.len()
+        );
+
+        zksync_os_finish_success(&[
+            flags,
+            revealed_word(0),
+            revealed_word(4),
+            revealed_word(8),
+            commitment[0],
+            commitment[1],
+            commitment[2],
+            commitment[3],
+        ]);
+    }
+
+    // Numeric mode has its own output layout, revealing only the outcome:
+    // result[0] = flags: signature valid (bit 0), document encrypted (bit 1)
+    // result[1] = predicate holds (1) or not (0)
+    // result[2] = field found (1) or not (0)
+    // result[3] = total number of pages
+    // result[4..8] = commitment to the document and the predicate
+    if let Some((predicate, committed)) = field_predicate {
+        let (found, holds) = match search.find_field(&predicate) {
+            Some((_, value)) => (1u32, predicate.evaluate(&value) as u32),
+            None => (0u32, 0u32),
+        };
+        let num_pages = result.text_pages.len() as u32;
+        let commitment = pdf_utils_zkvm_core::statement_commitment(&pdf_data, &committed);
+        let _ = write!(
+            uart,
+            "Field predicate: found={found}, holds={holds}, commitment={commitment:08x?}"
+        );
+
+        zksync_os_finish_success(&[
+            flags,
+            holds,
+            found,
+            num_pages,
+            commitment[0],
+            commitment[1],
+            commitment[2],
+            commitment[3],
+        ]);
+    }
+
+    // Commit to the predicate: mode, normalisation profile, page and
+    // rectangle in region mode, and expected text
+    let expected_bytes = expected_text.as_deref().unwrap_or_default();
+    let mut predicate = Vec::with_capacity(28 + expected_bytes.len());
+    predicate.extend_from_slice(&predicate_mode.to_be_bytes());
+    predicate.extend_from_slice(&profile.bits().to_be_bytes());
+    if let Some((_, _, region_words)) = &region {
+        predicate.extend_from_slice(&page_number_word.to_be_bytes());
+        for word in region_words {
+            predicate.extend_from_slice(&word.to_be_bytes());
+        }
+    }
+    predicate.extend_from_slice(expected_bytes);
+    let commitment = pdf_utils_zkvm_core::statement_commitment(&pdf_data, &predicate);
+    let _ = write!(uart, "Commitment: {commitment:08x?}");
 
     // Check if extracted text contains expected text
     let (text_found, page_found) = if let Some(expected_bytes) = expected_text {
@@ -407,70 +815,84 @@ unsafe fn workload() -> ! {
 
         let _ = write!(uart, "Expected text: '{expected_str}'");
 
-        // Always check all pages like the reference implementation
-        let mut found = false;
-        let mut found_page = 0u32;
-        for (idx, page_text) in result.text_pages.iter().enumerate() {
-            let _ = write!(uart, "Page {idx} text: '{page_text}'");
-            let trimmed = page_text.trim();
-            let _ = write!(uart, "  Trimmed: '{trimmed}'");
-            let text_len = page_text.len();
-            let preview_bytes = &page_text.as_bytes()[..core::cmp::min(50, page_text.len())];
-            let _ = write!(uart, "  Length: {text_len}, bytes: {preview_bytes:?}");
-
-            // Debug: Check if all characters are spaces
-            let all_spaces = page_text.chars().all(|c| c == ' ');
-            let _ = write!(uart, "  All spaces: {all_spaces}");
-
-            if page_text.contains(expected_str) {
-                found = true;
-                found_page = idx as u32;
-                break;
+        if predicate_mode == PREDICATE_REGEX {
+            let pattern = match pdf_utils_zkvm_core::Pattern::compile(expected_str) {
+                Ok(pattern) => pattern,
+                Err(e) => {
+                    let _ = write!(uart, "Invalid pattern: {e}");
+                    // Return error code 4: invalid predicate
+                    zksync_os_finish_success(&[0xFFFFFFFF, 4, predicate_mode, 0, 0, 0, 0, 0]);
+                }
+            };
+            // The pattern is matched against normalised text as written
+            let (found, found_page) = match search.find_pattern(&pattern) {
+                Some(m) => {
+                    let _ = write!(uart, "Pattern matched on page {}", m.page);
+                    (1u32, m.page as u32)
+                }
+                None => (0u32, 0u32),
+            };
+            (found, found_page)
+        } else if let Some(region_text) = &region_text {
+            let expected = profile.apply(expected_str);
+            let _ = write!(uart, "Region text: '{region_text}'");
+            let found = profile.apply(region_text).contains(expected.as_str());
+            let page = if found { page_number_word } else { 0 };
+            (found as u32, page)
+        } else {
+            let expected = profile.apply(expected_str);
+
+            // Always check all pages like the reference implementation
+            let mut found = false;
+            let mut found_page = 0u32;
+            for (idx, page_text) in search.text_pages.iter().enumerate() {
+                let _ = write!(uart, "Page {idx} text: '{page_text}'");
+                let trimmed = page_text.trim();
+                let _ = write!(uart, "  Trimmed: '{trimmed}'");
+                let text_len = page_text.len();
+                let preview_bytes = &page_text.as_bytes()[..core::cmp::min(50, page_text.len())];
+                let _ = write!(uart, "  Length: {text_len}, bytes: {preview_bytes:?}");
+
+                // Debug: Check if all characters are spaces
+                let all_spaces = page_text.chars().all(|c| c == ' ');
+                let _ = write!(uart, "  All spaces: {all_spaces}");
+
+                if page_text.contains(expected.as_str()) {
+                    found = true;
+                    found_page = idx as u32;
+                    break;
+                }
             }
+            (if found { 1u32 } else { 0u32 }, found_page)
         }
-        (if found { 1u32 } else { 0u32 }, found_page)
     } else {
         // No text to check
         (1u32, 0u32)
     };
 
-    // Calculate a hash of the first page text (for proof of content)
-    let first_page_hash = if !result.text_pages.is_empty() {
-        let first_page = &result.text_pages[0];
-        let mut hash = 0u32;
-        for byte in first_page.bytes().take(32) {
-            hash = hash.rotate_left(7) ^ (byte as u32);
-        }
-        hash
-    } else {
-        0u32
-    };
-
     // Return comprehensive results
-    // result[0] = signature valid (1) or not (0)
+    // result[0] = flags: signature valid (bit 0), document encrypted (bit 1)
     // result[1] = text found (1) or not (0)
     // result[2] = page where text was found (or 0)
     // result[3] = total number of pages
-    // result[4] = PDF size
-    // result[5] = first page text hash
-    // result[6] = expected text size (for verification)
-    // result[7] = reserved for future use
+    // result[4..8] = commitment to the document and the predicate (mode,
+    //   normalisation profile, region and expected text)
     let num_pages = result.text_pages.len() as u32;
 
     let _ = write!(
         uart,
-        "Success! Sig={sig_valid}, TextFound={text_found}, Page={page_found}, NumPages={num_pages}"
+        "Success! Flags={flags}, TextFound={text_found}, Page={page_found}, NumPages={num_pages}"
     );
 
     zksync_os_finish_success(&[
-        sig_valid,
+        flags,
         text_found,
         page_found,
         num_pages,
-        input_size as u32,
-        first_page_hash,
-        expected_text_size as u32,
-        0,
+        commitment[0],
+        commitment[1],
+        commitment[2],
+        commitment[3],
     ]);
 }
 